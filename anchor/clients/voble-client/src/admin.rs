@@ -0,0 +1,140 @@
+//! Authority-signed setup instructions
+
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::{rent, system_program};
+use anchor_spl::token;
+use voble::state::{PeriodType, VaultKind};
+
+use crate::{accounts, args, build_ix, pda, Pubkey, PROGRAM_ID};
+
+/// Build `initialize_global_config`
+///
+/// # Arguments
+/// * `authority` - Signer that becomes the config authority and pays rent
+/// * `args` - Prices, splits, mint and optional settings
+pub fn build_initialize_global_config_ix(
+    authority: Pubkey,
+    args: args::InitializeGlobalConfig,
+) -> Instruction {
+    build_ix(
+        accounts::InitializeGlobalConfig {
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Build `initialize_vaults` for a mint owned by the classic token program
+///
+/// # Arguments
+/// * `authority` - Config authority, pays rent for the five vaults
+/// * `usdc_mint` - Must equal `GlobalConfig.usdc_mint`
+pub fn build_initialize_vaults_ix(authority: Pubkey, usdc_mint: Pubkey) -> Instruction {
+    build_ix(
+        accounts::InitializeVaults {
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            daily_prize_vault: pda::derive_daily_vault_pda(&PROGRAM_ID).0,
+            weekly_prize_vault: pda::derive_weekly_vault_pda(&PROGRAM_ID).0,
+            monthly_prize_vault: pda::derive_monthly_vault_pda(&PROGRAM_ID).0,
+            platform_vault: pda::derive_platform_vault_pda(&PROGRAM_ID).0,
+            lucky_draw_vault: pda::derive_lucky_draw_vault_pda(&PROGRAM_ID).0,
+            usdc_mint,
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: rent::ID,
+        },
+        args::InitializeVaults {},
+    )
+}
+
+/// Token vault tracked by a vault ledger
+pub fn vault_address(kind: VaultKind) -> Pubkey {
+    match kind {
+        VaultKind::Daily => pda::derive_daily_vault_pda(&PROGRAM_ID).0,
+        VaultKind::Weekly => pda::derive_weekly_vault_pda(&PROGRAM_ID).0,
+        VaultKind::Monthly => pda::derive_monthly_vault_pda(&PROGRAM_ID).0,
+        VaultKind::Platform => pda::derive_platform_vault_pda(&PROGRAM_ID).0,
+        VaultKind::LuckyDraw => pda::derive_lucky_draw_vault_pda(&PROGRAM_ID).0,
+    }
+}
+
+/// Build `initialize_vault_ledger` for one vault
+///
+/// # Arguments
+/// * `authority` - Config authority, pays rent for the ledger
+/// * `kind` - Vault the ledger tracks
+pub fn build_initialize_vault_ledger_ix(authority: Pubkey, kind: VaultKind) -> Instruction {
+    build_ix(
+        accounts::InitializeVaultLedger {
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            vault_ledger: pda::derive_vault_ledger_pda(kind, &PROGRAM_ID).0,
+            vault: vault_address(kind),
+            authority,
+            system_program: system_program::ID,
+        },
+        args::InitializeVaultLedger { kind: kind as u8 },
+    )
+}
+
+/// Build `initialize_period_leaderboard`
+///
+/// # Arguments
+/// * `authority` - Config authority, pays rent for the board
+/// * `period_id` - Canonical period ID matching `period_type` (e.g., "W12")
+/// * `period_type` - Daily, weekly or monthly
+/// * `aggregation` - Optional aggregation mode (`LeaderboardMode` as u8)
+pub fn build_initialize_period_leaderboard_ix(
+    authority: Pubkey,
+    period_id: &str,
+    period_type: PeriodType,
+    aggregation: Option<u8>,
+) -> Instruction {
+    build_ix(
+        accounts::InitializePeriodLeaderboard {
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            leaderboard: pda::derive_leaderboard_pda(period_id, period_type, &PROGRAM_ID).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args::InitializePeriodLeaderboard {
+            period_id: period_id.to_string(),
+            period_type: period_type.seed_byte(),
+            aggregation,
+        },
+    )
+}
+
+/// Build `initialize_future_leaderboards` for the periods after the current one
+///
+/// # Arguments
+/// * `authority` - Config authority, pays rent for the new boards
+/// * `period_type` - Daily, weekly or monthly
+/// * `period_ids` - The `count` upcoming period IDs, from
+///   `voble::instructions::leaderboard::future_period_ids` at the current time
+pub fn build_initialize_future_leaderboards_ix(
+    authority: Pubkey,
+    period_type: PeriodType,
+    period_ids: &[String],
+) -> Instruction {
+    let mut ix = build_ix(
+        accounts::InitializeFutureLeaderboards {
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            authority,
+            system_program: system_program::ID,
+        },
+        args::InitializeFutureLeaderboards {
+            period_type: period_type.seed_byte(),
+            count: period_ids.len() as u8,
+        },
+    );
+    ix.accounts.extend(period_ids.iter().map(|period_id| {
+        AccountMeta::new(
+            pda::derive_leaderboard_pda(period_id, period_type, &PROGRAM_ID).0,
+            false,
+        )
+    }));
+    ix
+}
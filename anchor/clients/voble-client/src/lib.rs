@@ -0,0 +1,65 @@
+//! Voble Client
+//!
+//! Instruction builders for off-chain services (cranks, the lucky draw bot,
+//! analytics backfills) so they don't rebuild account lists from the IDL.
+//!
+//! The program crate is linked with `no-entrypoint`, so seeds, PDA helpers
+//! and argument structs come straight from the program and can't drift.
+//!
+//! # Modules
+//!
+//! ## `admin`
+//! Config, vault, ledger and leaderboard setup signed by the authority
+//!
+//! ## `player`
+//! Profile, session and ticket instructions signed by the player
+//!
+//! ## `settlement`
+//! Magic Action stats handlers, in the positional order they expect
+//!
+//! # Other Instructions
+//!
+//! Every instruction has an accounts struct in [`accounts`] and an argument
+//! struct in [`args`]; pass both to [`build_ix`] for the ones without a
+//! dedicated helper.
+//!
+//! ```rust,ignore
+//! let ix = voble_client::build_ix(
+//!     voble_client::accounts::SetPeriodPaused { global_config, leaderboard, authority },
+//!     voble_client::args::SetPeriodPaused { period_id, period_type: 0, paused: true },
+//! );
+//! ```
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+pub mod admin;
+pub mod player;
+pub mod settlement;
+
+pub use admin::*;
+pub use player::*;
+pub use settlement::*;
+
+pub use anchor_lang::prelude::Pubkey;
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+/// Accounts struct of every instruction, in the order the program reads them
+pub use voble::accounts;
+/// Argument struct of every instruction, mirroring the handler parameters
+pub use voble::instruction as args;
+/// PDA derivations shared with the program
+pub use voble::utils::pda;
+pub use voble::ID as PROGRAM_ID;
+
+/// Build any Voble instruction from its accounts and arguments
+///
+/// # Arguments
+/// * `accounts` - The instruction's struct from [`accounts`]
+/// * `args` - The instruction's struct from [`args`]
+pub fn build_ix(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
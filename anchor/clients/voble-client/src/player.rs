@@ -0,0 +1,142 @@
+//! Player-signed profile, session and ticket instructions
+
+use anchor_lang::solana_program::{instruction::Instruction, system_program};
+use anchor_spl::{associated_token, token};
+use voble::state::{GlobalConfig, PeriodType, VaultKind};
+
+use crate::{accounts, args, build_ix, pda, Pubkey, PROGRAM_ID};
+
+/// Build `initialize_user_profile`
+///
+/// # Arguments
+/// * `player` - Wallet that owns the profile and pays rent
+/// * `username` - Display name (validated on-chain)
+pub fn build_initialize_user_profile_ix(player: Pubkey, username: &str) -> Instruction {
+    build_ix(
+        accounts::InitializeUserProfile {
+            user_profile: pda::derive_user_profile_pda(&player, &PROGRAM_ID).0,
+            payer: player,
+            system_program: system_program::ID,
+        },
+        args::InitializeUserProfile {
+            username: username.to_string(),
+        },
+    )
+}
+
+/// Build `initialize_session`
+///
+/// # Arguments
+/// * `player` - Wallet that owns the session and pays rent (its profile must exist)
+/// * `session_nonce` - The profile's `session_nonce` (0 until `recover_stale_session`)
+pub fn build_initialize_session_ix(player: Pubkey, session_nonce: u32) -> Instruction {
+    build_ix(
+        accounts::InitializeSession {
+            payer: player,
+            session: pda::derive_session_pda_with_nonce(&player, session_nonce, &PROGRAM_ID).0,
+            user_profile: Some(pda::derive_user_profile_pda(&player, &PROGRAM_ID).0),
+            system_program: system_program::ID,
+        },
+        args::InitializeSession {},
+    )
+}
+
+/// Optional accounts and arguments of `buy_ticket_and_start_game`
+///
+/// The defaults buy a plain ticket: no happy hour or prize schedule override
+/// and the current session signer.
+#[derive(Clone, Debug, Default)]
+pub struct BuyTicketOptions {
+    /// Ephemeral key to authorize for gameplay on the ER
+    pub session_signer: Option<Pubkey>,
+    /// Pass the period's `ScoreMultiplierWindow` (it must exist)
+    pub score_multiplier: bool,
+    /// Pass the period's `PrizeScheduleOverride` (it must exist)
+    pub prize_schedule: bool,
+}
+
+/// Build `buy_ticket_and_start_game` with the default options
+///
+/// # Arguments
+/// * `player` - Wallet buying the ticket; pays with its associated token account
+/// * `period_id` - Current daily period ID (e.g., "D123")
+/// * `config` - The deployed `GlobalConfig`, for the mint
+pub fn build_buy_ticket_ix(player: Pubkey, period_id: &str, config: &GlobalConfig) -> Instruction {
+    build_buy_ticket_ix_with(player, period_id, config, &BuyTicketOptions::default())
+}
+
+/// Build `buy_ticket_and_start_game`
+///
+/// # Arguments
+/// * `player` - Wallet buying the ticket; pays with its associated token account
+/// * `period_id` - Current daily period ID (e.g., "D123")
+/// * `config` - The deployed `GlobalConfig`, for the mint
+/// * `options` - Optional accounts and arguments
+///
+/// # Notes
+/// - Assumes the mint is owned by the classic token program
+/// - The daily leaderboard, participation, receipt and the player's token
+///   account are created by the instruction if they don't exist yet
+pub fn build_buy_ticket_ix_with(
+    player: Pubkey,
+    period_id: &str,
+    config: &GlobalConfig,
+    options: &BuyTicketOptions,
+) -> Instruction {
+    let optional = |enabled: bool, seeds: &[&[u8]]| {
+        enabled.then(|| Pubkey::find_program_address(seeds, &PROGRAM_ID).0)
+    };
+
+    build_ix(
+        accounts::BuyTicketAndStartGame {
+            payer: player,
+            mint: config.usdc_mint,
+            user_profile: pda::derive_user_profile_pda(&player, &PROGRAM_ID).0,
+            blocked_player: pda::derive_blocked_player_pda(&player, &PROGRAM_ID).0,
+            global_config: pda::derive_global_config_pda(&PROGRAM_ID).0,
+            score_multiplier: optional(
+                options.score_multiplier,
+                &[voble::SEED_SCORE_MULTIPLIER, period_id.as_bytes()],
+            ),
+            prize_schedule: optional(
+                options.prize_schedule,
+                &[voble::SEED_PRIZE_SCHEDULE, period_id.as_bytes()],
+            ),
+            word_bank: Pubkey::find_program_address(&[voble::SEED_WORD_BANK], &PROGRAM_ID).0,
+            session_counter: pda::derive_session_counter_pda(&PROGRAM_ID).0,
+            period_leaderboard: pda::derive_leaderboard_pda(
+                period_id,
+                PeriodType::Daily,
+                &PROGRAM_ID,
+            )
+            .0,
+            participation: pda::derive_player_participation_pda(period_id, &player, &PROGRAM_ID).0,
+            ticket_receipt: pda::derive_ticket_receipt_pda(period_id, &player, &PROGRAM_ID).0,
+            session_signer: options.session_signer,
+            daily_prize_vault: pda::derive_daily_vault_pda(&PROGRAM_ID).0,
+            weekly_prize_vault: pda::derive_weekly_vault_pda(&PROGRAM_ID).0,
+            monthly_prize_vault: pda::derive_monthly_vault_pda(&PROGRAM_ID).0,
+            platform_vault: pda::derive_platform_vault_pda(&PROGRAM_ID).0,
+            lucky_draw_vault: pda::derive_lucky_draw_vault_pda(&PROGRAM_ID).0,
+            daily_vault_ledger: pda::derive_vault_ledger_pda(VaultKind::Daily, &PROGRAM_ID).0,
+            weekly_vault_ledger: pda::derive_vault_ledger_pda(VaultKind::Weekly, &PROGRAM_ID).0,
+            monthly_vault_ledger: pda::derive_vault_ledger_pda(VaultKind::Monthly, &PROGRAM_ID).0,
+            platform_vault_ledger: pda::derive_vault_ledger_pda(VaultKind::Platform, &PROGRAM_ID).0,
+            lucky_draw_vault_ledger: pda::derive_vault_ledger_pda(
+                VaultKind::LuckyDraw,
+                &PROGRAM_ID,
+            )
+            .0,
+            payer_token_account: associated_token::get_associated_token_address(
+                &player,
+                &config.usdc_mint,
+            ),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+        },
+        args::BuyTicketAndStartGame {
+            period_id: period_id.to_string(),
+        },
+    )
+}
@@ -0,0 +1,190 @@
+//! Magic Action stats handlers
+//!
+//! The ER normally schedules these from `commit_and_update_stats`; the
+//! builders here are for replaying a settlement off-chain or in tests.
+//! Leaderboards always go daily, weekly, monthly (`HANDLER_LEADERBOARD_ORDER`)
+//! and are the boards of the day the ticket was bought, not of the commit.
+
+use anchor_lang::solana_program::instruction::Instruction;
+use voble::instructions::game::score_deadline::settlement_period_ids;
+use voble::instructions::game::HANDLER_LEADERBOARD_ORDER;
+use voble::state::GlobalConfig;
+
+use crate::{accounts, args, build_ix, pda, Pubkey, PROGRAM_ID};
+
+/// Accounts shared by every stats handler
+#[derive(Clone, Copy, Debug)]
+pub struct StatsHandlerAccounts {
+    /// Player whose session was committed
+    pub player: Pubkey,
+    /// Generation of the committed session (`SessionAccount.session_nonce`)
+    pub session_nonce: u32,
+    /// Escrow account injected by Magic Actions
+    pub escrow: Pubkey,
+    /// Escrow authority injected by Magic Actions
+    pub escrow_auth: Pubkey,
+}
+
+/// Daily, weekly and monthly leaderboards a session settles into
+///
+/// # Arguments
+/// * `session_period_id` - Period the ticket was bought in (`session.period_id`)
+/// * `config` - The deployed `GlobalConfig`, for the period epoch
+///
+/// # Returns
+/// `None` if `session_period_id` is not a daily period ID
+pub fn handler_leaderboards(session_period_id: &str, config: &GlobalConfig) -> Option<[Pubkey; 3]> {
+    let period_ids = settlement_period_ids(session_period_id, config.period_epoch())?;
+    Some(std::array::from_fn(|i| {
+        pda::derive_leaderboard_pda(&period_ids[i], HANDLER_LEADERBOARD_ORDER[i], &PROGRAM_ID).0
+    }))
+}
+
+/// Build `update_player_stats`
+///
+/// # Arguments
+/// * `handler` - Player and Magic Action escrow accounts
+/// * `session_period_id` - Period the ticket was bought in
+/// * `config` - The deployed `GlobalConfig`, for the period epoch
+///
+/// # Returns
+/// `None` if `session_period_id` is not a daily period ID
+pub fn build_update_player_stats_ix(
+    handler: &StatsHandlerAccounts,
+    session_period_id: &str,
+    config: &GlobalConfig,
+) -> Option<Instruction> {
+    let [daily, weekly, monthly] = handler_leaderboards(session_period_id, config)?;
+    Some(build_ix(
+        accounts::UpdatePlayerStats {
+            daily_leaderboard: daily,
+            weekly_leaderboard: weekly,
+            monthly_leaderboard: monthly,
+            user_profile: pda::derive_user_profile_pda(&handler.player, &PROGRAM_ID).0,
+            committed_session: pda::derive_session_pda_with_nonce(
+                &handler.player,
+                handler.session_nonce,
+                &PROGRAM_ID,
+            )
+            .0,
+            session_counter: pda::derive_session_counter_pda(&PROGRAM_ID).0,
+            escrow_auth: handler.escrow_auth,
+            escrow: handler.escrow,
+        },
+        args::UpdatePlayerStats {},
+    ))
+}
+
+/// Build `update_leaderboard_stats`, the leaderboard half of a split settlement
+///
+/// # Returns
+/// `None` if `session_period_id` is not a daily period ID
+pub fn build_update_leaderboard_stats_ix(
+    handler: &StatsHandlerAccounts,
+    session_period_id: &str,
+    config: &GlobalConfig,
+) -> Option<Instruction> {
+    let [daily, weekly, monthly] = handler_leaderboards(session_period_id, config)?;
+    Some(build_ix(
+        accounts::UpdateLeaderboardStats {
+            daily_leaderboard: daily,
+            weekly_leaderboard: weekly,
+            monthly_leaderboard: monthly,
+            user_profile: pda::derive_user_profile_pda(&handler.player, &PROGRAM_ID).0,
+            committed_session: pda::derive_session_pda_with_nonce(
+                &handler.player,
+                handler.session_nonce,
+                &PROGRAM_ID,
+            )
+            .0,
+            escrow_auth: handler.escrow_auth,
+            escrow: handler.escrow,
+        },
+        args::UpdateLeaderboardStats {},
+    ))
+}
+
+/// Build `update_profile_stats`, the profile half of a split settlement
+pub fn build_update_profile_stats_ix(handler: &StatsHandlerAccounts) -> Instruction {
+    build_ix(
+        accounts::UpdateProfileStats {
+            user_profile: pda::derive_user_profile_pda(&handler.player, &PROGRAM_ID).0,
+            committed_session: pda::derive_session_pda_with_nonce(
+                &handler.player,
+                handler.session_nonce,
+                &PROGRAM_ID,
+            )
+            .0,
+            session_counter: pda::derive_session_counter_pda(&PROGRAM_ID).0,
+            escrow_auth: handler.escrow_auth,
+            escrow: handler.escrow,
+        },
+        args::UpdateProfileStats {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountDeserialize;
+    use voble::state::PeriodType;
+
+    fn config() -> GlobalConfig {
+        let data = vec![0u8; 8 + <GlobalConfig as anchor_lang::Space>::INIT_SPACE];
+        GlobalConfig::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_update_player_stats_account_order() {
+        let handler = StatsHandlerAccounts {
+            player: Pubkey::new_unique(),
+            session_nonce: 0,
+            escrow: Pubkey::new_unique(),
+            escrow_auth: Pubkey::new_unique(),
+        };
+        let config = config();
+        let epoch = config.period_epoch();
+        let [daily_id, weekly_id, monthly_id] = settlement_period_ids("D13", epoch).unwrap();
+
+        let ix = build_update_player_stats_ix(&handler, "D13", &config).unwrap();
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            keys,
+            vec![
+                pda::derive_leaderboard_pda(&daily_id, PeriodType::Daily, &PROGRAM_ID).0,
+                pda::derive_leaderboard_pda(&weekly_id, PeriodType::Weekly, &PROGRAM_ID).0,
+                pda::derive_leaderboard_pda(&monthly_id, PeriodType::Monthly, &PROGRAM_ID).0,
+                pda::derive_user_profile_pda(&handler.player, &PROGRAM_ID).0,
+                pda::derive_session_pda_with_nonce(
+                    &handler.player,
+                    handler.session_nonce,
+                    &PROGRAM_ID
+                )
+                .0,
+                pda::derive_session_counter_pda(&PROGRAM_ID).0,
+                handler.escrow_auth,
+                handler.escrow,
+            ]
+        );
+
+        // Leaderboards, profile, counter and escrow are written; the session is only read
+        let writable: Vec<bool> = ix.accounts.iter().map(|meta| meta.is_writable).collect();
+        assert_eq!(
+            writable,
+            vec![true, true, true, true, false, true, false, true]
+        );
+        assert!(ix.accounts.iter().all(|meta| !meta.is_signer));
+    }
+
+    #[test]
+    fn test_free_form_period_has_no_handler() {
+        let handler = StatsHandlerAccounts {
+            player: Pubkey::new_unique(),
+            session_nonce: 0,
+            escrow: Pubkey::new_unique(),
+            escrow_auth: Pubkey::new_unique(),
+        };
+        assert!(build_update_player_stats_ix(&handler, "legacy", &config()).is_none());
+        assert!(build_update_leaderboard_stats_ix(&handler, "W3", &config()).is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! Compute units of the program's hot paths, before and after their optimizations
+//!
+//! Needs a `debug-logs` build of the program, which adds the benchmark
+//! instructions and the `ComputeMeter` logs they report through:
+//! `anchor build -- --features debug-logs && cargo test -p voble-client
+//! --features debug-logs -- --ignored --nocapture`.
+#![cfg(feature = "debug-logs")]
+
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account, compute_budget::ComputeBudgetInstruction, hash::Hash,
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use voble::state::{AggregationStrategy, PeriodLeaderboard};
+use voble_client::*;
+
+/// Fixture games written per aggregation strategy
+const SEEDS: u64 = 20;
+
+async fn start(accounts: &[Pubkey], space: usize) -> (BanksClient, Keypair, Hash) {
+    if std::env::var_os("SBF_OUT_DIR").is_none() {
+        let deploy = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/deploy");
+        std::env::set_var("SBF_OUT_DIR", deploy);
+    }
+    let mut program_test = ProgramTest::new("voble", PROGRAM_ID, None);
+    program_test.prefer_bpf(true);
+    for address in accounts {
+        program_test.add_account(
+            *address,
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![0; space],
+                owner: PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+    program_test.start().await
+}
+
+/// Run one instruction and return the units its `label` meter logged at `point`
+async fn measure(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    blockhash: Hash,
+    ix: Instruction,
+    label: &str,
+    point: &str,
+) -> u64 {
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            ix,
+        ],
+        Some(&payer.pubkey()),
+        &[payer],
+        blockhash,
+    );
+    let result = banks.process_transaction_with_metadata(tx).await.unwrap();
+    result.result.unwrap();
+
+    let prefix = format!("[{label}] {point}: ");
+    result
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| {
+            let used = log.split_once(&prefix)?.1;
+            used.split_once(" CU used")?.0.parse().ok()
+        })
+        .unwrap_or_else(|| panic!("no `{prefix}` log; was voble.so built with debug-logs?"))
+}
+
+#[tokio::test]
+#[ignore = "needs voble.so from anchor build -- --features debug-logs"]
+async fn test_leaderboard_write_compute_units() {
+    let current_board = Pubkey::new_unique();
+    let legacy_board = Pubkey::new_unique();
+    let space = PeriodLeaderboard::space(100);
+    let (mut banks, payer, blockhash) = start(&[current_board, legacy_board], space).await;
+
+    for aggregation in [
+        AggregationStrategy::Best,
+        AggregationStrategy::Sum,
+        AggregationStrategy::Average,
+    ] {
+        let (mut current_total, mut legacy_total) = (0, 0);
+        for seed in 0..SEEDS {
+            for (board, legacy) in [(current_board, false), (legacy_board, true)] {
+                let ix = build_ix(
+                    accounts::BenchLeaderboardWrite { board },
+                    args::BenchLeaderboardWrite {
+                        aggregation,
+                        legacy,
+                        seed,
+                    },
+                );
+                let used = measure(
+                    &mut banks,
+                    &payer,
+                    blockhash,
+                    ix,
+                    "bench_leaderboard_write",
+                    "write entry",
+                )
+                .await;
+                if legacy {
+                    legacy_total += used;
+                } else {
+                    current_total += used;
+                }
+            }
+
+            let current = banks.get_account(current_board).await.unwrap().unwrap();
+            let legacy = banks.get_account(legacy_board).await.unwrap().unwrap();
+            assert_eq!(current.data, legacy.data, "{aggregation:?} seed {seed}");
+        }
+
+        println!(
+            "{aggregation:?}: in place {} CU/game, legacy {} CU/game",
+            current_total / SEEDS,
+            legacy_total / SEEDS
+        );
+        assert!(current_total < legacy_total, "{aggregation:?}");
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs voble.so from anchor build -- --features debug-logs"]
+async fn test_cached_bumps_compute_units() {
+    let (mut banks, payer, blockhash) = start(&[], 0).await;
+
+    let mut used = [0; 2];
+    for (units, cached) in used.iter_mut().zip([false, true]) {
+        let ix = build_ix(
+            accounts::BenchConfigBumps {},
+            args::BenchConfigBumps { cached },
+        );
+        *units = measure(
+            &mut banks,
+            &payer,
+            blockhash,
+            ix,
+            "bench_config_bumps",
+            "derive pdas",
+        )
+        .await;
+    }
+
+    let [searched, cached] = used;
+    println!("config and vault PDAs: find_program_address {searched} CU, cached bumps {cached} CU");
+    assert!(cached < searched);
+}
@@ -0,0 +1,230 @@
+//! Run the builders against the compiled program
+//!
+//! Loads `voble.so` from `SBF_OUT_DIR`, defaulting to the workspace's
+//! `target/deploy`. The program can't run natively: anchor-lang 0.32 routes
+//! CPIs through `solana-invoke`, which only works on-chain. Build it first:
+//! `anchor build && cargo test -p voble-client -- --ignored`.
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::AccountDeserialize;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account, clock::Clock, hash::Hash, instruction::Instruction, program_option::COption,
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use voble::instructions::admin::ConfigInit;
+use voble::state::{GlobalConfig, TicketReceipt, VaultKind};
+use voble::utils::period::{get_current_period_id, PeriodType};
+use voble_client::*;
+
+const TICKET_PRICE: u64 = 1_000_000;
+const PLAYER_USDC: u64 = 5 * TICKET_PRICE;
+
+struct Setup {
+    banks: BanksClient,
+    authority: Keypair,
+    player: Keypair,
+    blockhash: Hash,
+    usdc_mint: Pubkey,
+}
+
+async fn setup() -> Setup {
+    if std::env::var_os("SBF_OUT_DIR").is_none() {
+        let deploy = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/deploy");
+        std::env::set_var("SBF_OUT_DIR", deploy);
+    }
+    let mut program_test = ProgramTest::new("voble", PROGRAM_ID, None);
+    program_test.prefer_bpf(true);
+
+    let mint_authority = Pubkey::new_unique();
+    let usdc_mint = Pubkey::new_unique();
+    let player = Keypair::new();
+
+    let mut mint = [0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: PLAYER_USDC,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut mint);
+    program_test.add_account(usdc_mint, token_account(mint.to_vec()));
+
+    let mut player_usdc = [0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint: usdc_mint,
+        owner: player.pubkey(),
+        amount: PLAYER_USDC,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut player_usdc);
+    program_test.add_account(
+        get_associated_token_address(&player.pubkey(), &usdc_mint),
+        token_account(player_usdc.to_vec()),
+    );
+
+    program_test.add_account(
+        player.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            ..Default::default()
+        },
+    );
+
+    let (banks, authority, blockhash) = program_test.start().await;
+    Setup {
+        banks,
+        authority,
+        player,
+        blockhash,
+        usdc_mint,
+    }
+}
+
+fn token_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+async fn send(setup: &mut Setup, ixs: &[Instruction], signer: &Keypair) {
+    let mut signers = vec![&setup.authority];
+    if signer.pubkey() != setup.authority.pubkey() {
+        signers.push(signer);
+    }
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&setup.authority.pubkey()),
+        &signers,
+        setup.blockhash,
+    );
+    setup.banks.process_transaction(tx).await.unwrap();
+}
+
+async fn initialize_program(setup: &mut Setup) {
+    let authority = setup.authority.insecure_clone();
+    let init_config = build_initialize_global_config_ix(
+        authority.pubkey(),
+        args::InitializeGlobalConfig {
+            init: ConfigInit {
+                ticket_price: TICKET_PRICE,
+                prize_split_daily: 4000,
+                prize_split_weekly: 3000,
+                prize_split_monthly: 2000,
+                platform_revenue_split: 700,
+                lucky_draw_split: 300,
+                winner_splits: vec![5000, 3000, 2000],
+                usdc_mint: setup.usdc_mint,
+                period_epoch_start: None,
+                currency_symbol: None,
+                currency_decimals: None,
+            },
+        },
+    );
+    let init_vaults = build_initialize_vaults_ix(authority.pubkey(), setup.usdc_mint);
+    send(setup, &[init_config, init_vaults], &authority).await;
+
+    let ledgers: Vec<Instruction> = [
+        VaultKind::Daily,
+        VaultKind::Weekly,
+        VaultKind::Monthly,
+        VaultKind::Platform,
+        VaultKind::LuckyDraw,
+    ]
+    .into_iter()
+    .map(|kind| build_initialize_vault_ledger_ix(authority.pubkey(), kind))
+    .collect();
+    send(setup, &ledgers, &authority).await;
+}
+
+async fn fetch<T: AccountDeserialize>(setup: &mut Setup, address: Pubkey) -> T {
+    let account = setup.banks.get_account(address).await.unwrap().unwrap();
+    T::try_deserialize(&mut &account.data[..]).unwrap()
+}
+
+#[tokio::test]
+#[ignore = "needs voble.so from anchor build"]
+async fn test_setup_instructions_execute() {
+    let mut setup = setup().await;
+    initialize_program(&mut setup).await;
+
+    let config: GlobalConfig =
+        fetch(&mut setup, pda::derive_global_config_pda(&PROGRAM_ID).0).await;
+    assert_eq!(config.authority, setup.authority.pubkey());
+    assert_eq!(config.usdc_mint, setup.usdc_mint);
+    assert_eq!(config.bumps, pda::derive_config_bumps(&PROGRAM_ID));
+
+    let clock: Clock = setup.banks.get_sysvar().await.unwrap();
+    let weekly_id = get_current_period_id(
+        PeriodType::Weekly,
+        clock.unix_timestamp,
+        config.period_epoch(),
+    );
+    let authority = setup.authority.insecure_clone();
+    let init_board = build_initialize_period_leaderboard_ix(
+        authority.pubkey(),
+        &weekly_id,
+        voble::state::PeriodType::Weekly,
+        None,
+    );
+    send(&mut setup, &[init_board], &authority).await;
+
+    let board =
+        pda::derive_leaderboard_pda(&weekly_id, voble::state::PeriodType::Weekly, &PROGRAM_ID).0;
+    assert!(setup.banks.get_account(board).await.unwrap().is_some());
+}
+
+#[tokio::test]
+#[ignore = "needs voble.so from anchor build"]
+async fn test_buy_ticket_executes() {
+    let mut setup = setup().await;
+    initialize_program(&mut setup).await;
+
+    let config: GlobalConfig =
+        fetch(&mut setup, pda::derive_global_config_pda(&PROGRAM_ID).0).await;
+    let clock: Clock = setup.banks.get_sysvar().await.unwrap();
+    let period_id = get_current_period_id(
+        PeriodType::Daily,
+        clock.unix_timestamp,
+        config.period_epoch(),
+    );
+
+    let player = setup.player.insecure_clone();
+    send(
+        &mut setup,
+        &[
+            build_initialize_user_profile_ix(player.pubkey(), "builder"),
+            build_initialize_session_ix(player.pubkey(), 0),
+            build_buy_ticket_ix(player.pubkey(), &period_id, &config),
+        ],
+        &player,
+    )
+    .await;
+
+    let receipt: TicketReceipt = fetch(
+        &mut setup,
+        pda::derive_ticket_receipt_pda(&period_id, &player.pubkey(), &PROGRAM_ID).0,
+    )
+    .await;
+    assert_eq!(receipt.player, player.pubkey());
+
+    let player_usdc = setup
+        .banks
+        .get_account(get_associated_token_address(
+            &player.pubkey(),
+            &setup.usdc_mint,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let player_usdc = spl_token::state::Account::unpack(&player_usdc.data).unwrap();
+    assert_eq!(player_usdc.amount, PLAYER_USDC - TICKET_PRICE);
+}
@@ -5,12 +5,18 @@ use anchor_lang::prelude::*;
 /// Global config account seed
 pub const SEED_GLOBAL_CONFIG: &[u8] = b"global_config_v2";
 
+/// Seed of the v1 global config, read once by migrate_config_v1_to_v2
+pub const SEED_GLOBAL_CONFIG_V1: &[u8] = b"global_config";
+
 /// User profile account seed
 pub const SEED_USER_PROFILE: &[u8] = b"user_profile";
 
 /// Game session account seed
 pub const SEED_SESSION: &[u8] = b"session";
 
+/// Active session counter seed
+pub const SEED_SESSION_COUNTER: &[u8] = b"session_counter";
+
 /// Leaderboard account seed
 pub const SEED_LEADERBOARD: &[u8] = b"leaderboard";
 
@@ -29,9 +35,57 @@ pub const SEED_MONTHLY_PRIZE_VAULT: &[u8] = b"monthly_prize_vault";
 pub const SEED_PLATFORM_VAULT: &[u8] = b"platform_vault";
 pub const SEED_LUCKY_DRAW_VAULT: &[u8] = b"lucky_draw_vault";
 
+/// Platform vault bookkeeping account seed
+pub const SEED_VAULT_STATE: &[u8] = b"vault_state";
+
+/// Per-vault inflow/outflow ledger seed (followed by the VaultKind byte)
+pub const SEED_VAULT_LEDGER: &[u8] = b"vault_ledger";
+
+/// Happy hour score multiplier account seed
+pub const SEED_SCORE_MULTIPLIER: &[u8] = b"score_multiplier";
+
+/// Flair catalog account seed
+pub const SEED_FLAIR_CATALOG: &[u8] = b"flair_catalog";
+
+/// Seed for a period's commit-reveal daily word commitment
+pub const SEED_DAILY_WORD: &[u8] = b"daily_word";
+
+/// Word bank (per-word difficulty) account seed
+pub const SEED_WORD_BANK: &[u8] = b"word_bank";
+
+/// Recently used shared daily words account seed
+pub const SEED_USED_WORDS: &[u8] = b"used_words";
+
+/// Shared daily word assignment account seed
+pub const SEED_SHARED_WORD: &[u8] = b"shared_word";
+
 /// Ticket receipt account seed
 pub const SEED_TICKET_RECEIPT: &[u8] = b"ticket_receipt";
 
+/// Feature flags account seed
+pub const SEED_FEATURE_FLAGS: &[u8] = b"feature_flags";
+
+/// Per-period player participation marker seed
+pub const SEED_PLAYER_PARTICIPATION: &[u8] = b"participation";
+
+/// Opt-in public profile account seed
+pub const SEED_PUBLIC_PROFILE: &[u8] = b"public_profile";
+
+/// Per-period prize split override seed
+pub const SEED_PRIZE_SCHEDULE: &[u8] = b"prize_schedule";
+
+/// Per-player blocklist entry seed
+pub const SEED_BLOCKED_PLAYER: &[u8] = b"blocked_player";
+
+/// Queued timelocked admin action seed (followed by the payload hash)
+pub const SEED_PENDING_ADMIN_ACTION: &[u8] = b"pending_admin_action";
+
+/// Duel account seed (followed by period ID, challenger and opponent)
+pub const SEED_DUEL: &[u8] = b"duel";
+
+/// Duel escrow token account seed (followed by the duel address)
+pub const SEED_DUEL_ESCROW: &[u8] = b"duel_escrow";
+
 // ============ PERIOD CONFIGURATION ============
 
 /// Daily period duration (24 hours)
@@ -43,7 +97,10 @@ pub const PERIOD_WEEKLY_DURATION: i64 = 7 * 24 * 60 * 60; // seconds
 /// Monthly period duration (30 days)
 pub const PERIOD_MONTHLY_DURATION: i64 = 30 * 24 * 60 * 60; // seconds
 
-/// Epoch start timestamp (2024-01-01 00:00:00 UTC+8)
+/// Default epoch start for period numbering (2024-01-01 00:00:00 UTC+8)
+///
+/// Used when `initialize_global_config` isn't given one, and for configs
+/// created before `period_epoch_start` existed.
 pub const PERIOD_EPOCH_START: i64 = 1704038400; // January 1, 2024 00:00:00 UTC+8
 
 // ============ GAME CONFIGURATION ============
@@ -54,28 +111,112 @@ pub const WORD_LENGTH: usize = 6;
 /// Maximum number of guesses allowed
 pub const MAX_GUESSES: u8 = 7;
 
-/// Minimum ticket price (0.001 SOL)
-pub const MIN_TICKET_PRICE: u64 = 1_000_000; // lamports
+/// Maximum keystrokes recorded for a single guess
+pub const MAX_KEYSTROKES_PER_GUESS: usize = 40;
+
+/// Maximum keystrokes recorded per game (SessionAccount.keystrokes capacity)
+pub const MAX_KEYSTROKES_PER_SESSION: usize = 200;
+
+/// Program logic version, bumped on every release that changes game or payout rules
+///
+/// Stamped on GlobalConfig (initialize_global_config / record_upgrade),
+/// PeriodState and SessionAccount so results can be traced to a ruleset.
+pub const PROGRAM_VERSION: u16 = 1;
+
+/// Current SessionAccount layout version (0 = legacy, pre-state accounts)
+pub const SESSION_ACCOUNT_VERSION: u8 = 1;
+
+/// Default ER commit frequency for a delegated session
+pub const DEFAULT_COMMIT_FREQUENCY_MS: u32 = 30_000;
+
+/// Most frequent ER commits a session may request
+pub const MIN_COMMIT_FREQUENCY_MS: u32 = 5_000;
+
+/// Least frequent ER commits a session may request
+pub const MAX_COMMIT_FREQUENCY_MS: u32 = 60_000;
+
+/// Default age after which anyone may undelegate a session stuck on the ER (2 hours)
+pub const DEFAULT_SESSION_STALE_AFTER: i64 = 2 * 60 * 60; // seconds
+
+/// Shortest staleness window the authority may configure (a game can't be cut off mid-play)
+pub const MIN_SESSION_STALE_AFTER: i64 = 30 * 60; // seconds
+
+/// Default cap on sessions delegated to the ER at once
+pub const DEFAULT_MAX_ACTIVE_SESSIONS: u32 = 10_000;
+
+/// Default compute budget for each settlement call handler
+pub const DEFAULT_HANDLER_COMPUTE_UNITS: u32 = 400_000;
+
+/// Smallest handler compute budget the authority may configure
+pub const MIN_HANDLER_COMPUTE_UNITS: u32 = 100_000;
+
+/// Largest handler compute budget (Solana per-transaction limit)
+pub const MAX_HANDLER_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Default time after a period ends that its games may still be committed (30 minutes)
+pub const DEFAULT_SCORE_GRACE_WINDOW: i64 = 30 * 60; // seconds
+
+/// Longest grace window the authority may configure (one day)
+pub const MAX_SCORE_GRACE_WINDOW: i64 = PERIOD_DAILY_DURATION;
+
+/// Default number of recent shared daily words a new one may not repeat
+pub const DEFAULT_SHARED_WORD_WINDOW: u8 = 7;
+
+/// Longest no-repeat window for shared daily words (kept below the daily list size)
+pub const MAX_SHARED_WORD_WINDOW: usize = 14;
+
+/// Default slots between two calls of the same session lifecycle instruction (~2s on the base layer)
+pub const DEFAULT_SESSION_ACTION_SLOT_GAP: u64 = 5;
+
+/// Longest session action cool-down the authority may configure (~1 hour on the base layer)
+pub const MAX_SESSION_ACTION_SLOT_GAP: u64 = 9_000;
+
+/// Minimum ticket price in the payment mint's base units (1 USDC at 6 decimals)
+pub const MIN_TICKET_PRICE: u64 = 1_000_000;
 
 // ============ LEADERBOARD CONFIGURATION ============
 
-/// Maximum leaderboard entries to track
-pub const MAX_LEADERBOARD_SIZE: usize = 10;
+/// Entries a leaderboard keeps unless the authority configures otherwise
+/// (the largest board `init` can allocate in one go)
+pub const DEFAULT_LEADERBOARD_CAPACITY: u16 = 100;
+
+/// Largest configurable leaderboard capacity
+///
+/// Ranks are stored as u8. Boards above the default are allocated past the
+/// 10 KiB `init` limit by `grow_leaderboard`, 10 KiB per call.
+pub const MAX_LEADERBOARD_CAPACITY: u16 = 250;
+
+/// Most upcoming leaderboards `initialize_future_leaderboards` creates per call
+/// (a month of daily boards)
+pub const MAX_FUTURE_LEADERBOARDS: u8 = 31;
 
 /// Number of top winners per period
 pub const TOP_WINNERS_COUNT: usize = 3;
 
+/// Maximum FinalStandingEntry events per emit_final_standings call (log limit)
+pub const MAX_STANDINGS_PER_CALL: usize = 20;
+
 // ============ STRING LENGTH LIMITS ============
 
 /// Minimum username length
 pub const MIN_USERNAME_LENGTH: usize = 3;
 
-/// Maximum username length
+/// Maximum username length in ASCII characters
+/// (keep in sync with `#[max_len]` on UserProfile/LeaderEntry usernames)
 pub const MAX_USERNAME_LENGTH: usize = 32;
 
 /// Maximum period ID length
 pub const MAX_PERIOD_ID_LENGTH: usize = 20;
 
+/// Maximum public profile bio length in bytes (keep in sync with `#[max_len]` on PublicProfile)
+pub const MAX_BIO_LENGTH: usize = 160;
+
+/// Maximum public profile avatar URI length in bytes (keep in sync with `#[max_len]` on PublicProfile)
+pub const MAX_AVATAR_URI_LENGTH: usize = 128;
+
+/// URI schemes accepted for public profile avatars
+pub const AVATAR_URI_SCHEMES: [&str; 2] = ["https://", "ipfs://"];
+
 /// Maximum session ID length
 pub const MAX_SESSION_ID_LENGTH: usize = 50;
 
@@ -108,7 +249,46 @@ pub const SCORE_GUESS_6: u32 = 200;
 /// Score for winning in 7 guesses
 pub const SCORE_GUESS_7: u32 = 100;
 
+// ============ HAPPY HOUR CONFIGURATION ============
+
+/// Neutral score multiplier (1x) in basis points
+pub const SCORE_MULTIPLIER_NONE: u16 = 10_000;
+
+/// Maximum happy hour score multiplier (3x) in basis points
+pub const MAX_SCORE_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Word difficulty range (1 = easiest, 5 = hardest)
+pub const MIN_WORD_DIFFICULTY: u8 = 1;
+pub const MAX_WORD_DIFFICULTY: u8 = 5;
+
+/// Difficulty of words the authority hasn't tagged (1x score)
+pub const DEFAULT_WORD_DIFFICULTY: u8 = 3;
+
+/// Maximum flat bonus for solving on the first guess (0 = no bonus)
+pub const MAX_HOLE_IN_ONE_BONUS: u32 = 5_000;
+
+// ============ FEATURE FLAGS ============
+
+/// Number of feature flags (bits of FeatureFlags.enabled)
+pub const FEATURE_FLAG_COUNT: usize = 64;
+
+/// Feature flag bits - new gated features take the next free bit
+pub const FEATURE_HARD_MODE: u8 = 0;
+pub const FEATURE_HINTS: u8 = 1;
+pub const FEATURE_TEAMS: u8 = 2;
+pub const FEATURE_PRIVACY_EVENTS: u8 = 3;
+
+/// Maximum happy hour windows per period
+pub const MAX_MULTIPLIER_WINDOWS: usize = 4;
+
+/// Maximum flairs in the catalog (owned flairs are a u64 bitmask, ids 1-64)
+pub const MAX_FLAIRS: usize = 64;
+
+/// Equipped flair value meaning "no flair"
+pub const FLAIR_NONE: u8 = 0;
+
 // ============ TIME BONUS THRESHOLDS ============
+// For a neutral word; scaled by word difficulty in `calculate_time_bonus`
 
 /// Time threshold for tier 1 bonus (30 seconds)
 pub const TIME_BONUS_TIER_1: u64 = 30_000; // milliseconds
@@ -122,6 +302,9 @@ pub const TIME_BONUS_TIER_3: u64 = 120_000; // milliseconds
 /// Time threshold for tier 4 bonus (5 minutes)
 pub const TIME_BONUS_TIER_4: u64 = 300_000; // milliseconds
 
+/// Fastest plausible guess, used to flag impossible settled times
+pub const MIN_MS_PER_GUESS: u64 = 1_000; // milliseconds
+
 /// Time bonus for tier 1 (speed demon)
 pub const BONUS_TIER_1: u32 = 500;
 
@@ -151,25 +334,92 @@ pub const ACHIEVEMENT_STREAK_3: u8 = 4;
 /// Achievement: 7-game winning streak
 pub const ACHIEVEMENT_STREAK_7: u8 = 5;
 
+/// Win streak lengths that emit StreakMilestoneReached, in increasing order
+pub const STREAK_MILESTONES: [u32; 4] = [3, 7, 14, 30];
+
 /// Achievement: Win 10 games with 3 or fewer guesses (perfectionist)
 pub const ACHIEVEMENT_PERFECTIONIST: u8 = 6;
 
 /// Achievement: Social butterfly (unused - no friend system yet)
 pub const ACHIEVEMENT_SOCIAL_BUTTERFLY: u8 = 7;
 
+/// Minimum time between achievement syncs for one profile (24 hours)
+pub const ACHIEVEMENT_SYNC_INTERVAL: i64 = 86_400; // seconds
+
 // ============ FINANCIAL CONFIGURATION ============
 
 /// Basis points total (100%)
 pub const BASIS_POINTS_TOTAL: u16 = 10_000;
 
+/// Minimum accepted decimals for the payment mint
+pub const MIN_MINT_DECIMALS: u8 = 1;
+
+/// Maximum accepted decimals for the payment mint (same as SOL)
+pub const MAX_MINT_DECIMALS: u8 = 9;
+
+/// Currency symbol shown by clients when the config doesn't set one
+pub const DEFAULT_CURRENCY_SYMBOL: &str = "USDC";
+
+/// Display decimals used by clients when the config doesn't set them
+pub const DEFAULT_CURRENCY_DECIMALS: u8 = 6;
+
+/// Maximum length of the currency symbol (bytes)
+pub const MAX_CURRENCY_SYMBOL_LENGTH: usize = 8;
+
+/// Largest "you won" SOL nudge sent to winners on entitlement creation (0.01 SOL)
+pub const MAX_NOTIFY_LAMPORTS: u64 = 10_000_000; // lamports
+
+/// Default minimum interval between platform revenue sweeps (24 hours)
+pub const DEFAULT_SWEEP_INTERVAL: i64 = 24 * 60 * 60; // seconds
+
+/// Maximum entitlements paid out by one `claim_all` (one per period type)
+pub const MAX_BATCH_CLAIMS: usize = 3;
+
+/// Maximum wallets a prize can be split between when claimed
+pub const MAX_PRIZE_DESTINATIONS: usize = 3;
+
+/// Default delay between queueing a timelocked admin action and executing it (24 hours)
+pub const DEFAULT_ADMIN_TIMELOCK: i64 = 24 * 60 * 60; // seconds
+
+/// Shortest timelock delay the authority may configure (1 hour)
+pub const MIN_ADMIN_TIMELOCK: i64 = 60 * 60; // seconds
+
+/// Default platform withdrawal above which the timelock applies (1,000 USDC at 6 decimals)
+pub const DEFAULT_TIMELOCK_WITHDRAW_THRESHOLD: u64 = 1_000_000_000;
+
+/// Ticket price change, either way, that set_config may make without the timelock (10%)
+pub const TIMELOCK_FREE_PRICE_CHANGE_BPS: u64 = 1_000;
+
+/// Platform fee taken from a won duel's pot, paid into the platform vault (5%)
+pub const DUEL_PLATFORM_FEE_BPS: u16 = 500;
+
 // ============ EXTERNAL PROGRAM IDS ============
 
 /// MagicBlock Ephemeral Rollups - Asia validator (Devnet)
 pub const ER_VALIDATOR_ASIA: Pubkey = pubkey!("MAS1Dt9qreoRMQ14YQuhg8UTZMMzDdKhmkZMECCzk57");
 
 /// Demo word list for testing (INSECURE - replace with VRF)
+///
+/// Daily words. Word indexes are global across the three lists:
+/// daily 0..20, weekly 20..30, monthly 30..40 (see `utils::words::word_list`).
 pub const VOBLE_WORDS: [&str; 20] = [
     "ANCHOR", "BRIDGE", "CASTLE", "DRAGON", "ENERGY", "FOREST", "GARDEN", "HAMMER", "ISLAND",
     "JUNGLE", "KERNEL", "LADDER", "MARKET", "NATURE", "ORANGE", "PUZZLE", "QUARTZ", "ROCKET",
     "SOLANA", "TEMPLE",
 ];
+
+/// Harder words for sessions whose word source is the weekly competition
+pub const VOBLE_WORDS_WEEKLY: [&str; 10] = [
+    "ZEPHYR", "QUORUM", "JIGSAW", "SPHINX", "RHYTHM", "VORTEX", "OXYGEN", "JOCKEY", "FJORDS",
+    "PYTHON",
+];
+
+/// Hardest words for sessions whose word source is the monthly competition
+pub const VOBLE_WORDS_MONTHLY: [&str; 10] = [
+    "SYZYGY", "CRYPTS", "GLYPHS", "NYMPHS", "JUJUBE", "SQUAWK", "JINXED", "FIZZLE", "WHELKS",
+    "BYWAYS",
+];
+
+/// Number of global word indexes across the daily, weekly and monthly lists
+pub const TOTAL_WORD_COUNT: usize =
+    VOBLE_WORDS.len() + VOBLE_WORDS_WEEKLY.len() + VOBLE_WORDS_MONTHLY.len();
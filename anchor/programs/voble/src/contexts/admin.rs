@@ -22,9 +22,543 @@ pub struct InitializeGlobalConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Context for extending a config created before fields were appended
+#[derive(Accounts)]
+pub struct GrowGlobalConfig<'info> {
+    /// CHECK: Checked manually - a short config fails `Account` parsing
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for moving a v1 config to the v2 PDA
+#[derive(Accounts)]
+pub struct MigrateConfigV1ToV2<'info> {
+    /// CHECK: Deserialized manually - the v1 layout fails `Account` parsing
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG_V1],
+        bump
+    )]
+    pub legacy_config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GlobalConfig::INIT_SPACE,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Update configuration settings
 #[derive(Accounts)]
 pub struct SetConfig<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Matured queued action authorizing a timelocked change (omit otherwise)
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Queue a timelocked admin action
+#[derive(Accounts)]
+#[instruction(action_kind: u8, payload_hash: [u8; 32])]
+pub struct QueueAdminAction<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingAdminAction::INIT_SPACE,
+        seeds = [SEED_PENDING_ADMIN_ACTION, payload_hash.as_ref()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a queued admin action, returning its rent to the authority
+#[derive(Accounts)]
+#[instruction(payload_hash: [u8; 32])]
+pub struct CancelAdminAction<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PENDING_ADMIN_ACTION, payload_hash.as_ref()],
+        bump = pending_action.bump,
+        close = authority
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Read-only check of the signer against the config authority
+#[derive(Accounts)]
+pub struct IsAuthority<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Schedule a happy hour score multiplier window for a period
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct SetHappyHour<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ScoreMultiplierWindow::INIT_SPACE,
+        seeds = [SEED_SCORE_MULTIPLIER, period_id.as_bytes()],
+        bump
+    )]
+    pub score_multiplier: Account<'info, ScoreMultiplierWindow>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit to a period's daily word before it starts
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct CommitDailyWord<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DailyWordCommitment::INIT_SPACE,
+        seeds = [SEED_DAILY_WORD, period_id.as_bytes()],
+        bump
+    )]
+    pub daily_word_commitment: Account<'info, DailyWordCommitment>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reveal a committed daily word after its period ends
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct RevealDailyWord<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DAILY_WORD, period_id.as_bytes()],
+        bump = daily_word_commitment.bump
+    )]
+    pub daily_word_commitment: Account<'info, DailyWordCommitment>,
+
+    /// Shared word the period's sessions played
+    #[account(
+        seeds = [SEED_SHARED_WORD, period_id.as_bytes()],
+        bump = shared_word.bump
+    )]
+    pub shared_word: Account<'info, SharedWord>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Cancel a commit-reveal period whose word was never revealed
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct CancelUnrevealedPeriod<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [SEED_DAILY_WORD, period_id.as_bytes()],
+        bump = daily_word_commitment.bump
+    )]
+    pub daily_word_commitment: Account<'info, DailyWordCommitment>,
+
+    #[account(
+        mut,
+        seeds = [SEED_LEADERBOARD, period_id.as_bytes(), PeriodType::Daily.seed()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// CHECK: Daily period state - created by `finalize_daily`, may not exist
+    /// yet; read in the handler to reject periods whose prizes were finalized
+    #[account(seeds = [SEED_DAILY_PERIOD, period_id.as_bytes()], bump)]
+    pub period_state: UncheckedAccount<'info>,
+
+    /// Ledger of the daily prize vault the refunds are paid from
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Daily.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    /// Anyone can cancel once the reveal deadline has passed
+    pub caller: Signer<'info>,
+}
+
+/// List or update a flair in the catalog
+#[derive(Accounts)]
+pub struct SetFlair<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FlairCatalog::INIT_SPACE,
+        seeds = [SEED_FLAIR_CATALOG],
+        bump
+    )]
+    pub flair_catalog: Account<'info, FlairCatalog>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Tag a word in the word bank with its difficulty
+#[derive(Accounts)]
+pub struct SetWordDifficulty<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + WordBank::INIT_SPACE,
+        seeds = [SEED_WORD_BANK],
+        bump
+    )]
+    pub word_bank: Account<'info, WordBank>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Assign the next shared daily word, avoiding recently used ones
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct AssignSharedWord<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UsedWords::INIT_SPACE,
+        seeds = [SEED_USED_WORDS],
+        bump
+    )]
+    pub used_words: Account<'info, UsedWords>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SharedWord::INIT_SPACE,
+        seeds = [SEED_SHARED_WORD, period_id.as_bytes()],
+        bump
+    )]
+    pub shared_word: Account<'info, SharedWord>,
+
+    /// CHECK: Per-word difficulty; read as a `WordBank` once one exists (checked in the handler)
+    #[account(
+        seeds = [SEED_WORD_BANK],
+        bump
+    )]
+    pub word_bank: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pause or resume a single period's leaderboard
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct SetPeriodPaused<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Cancel a period's leaderboard so its tickets can be refunded
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct CancelPeriod<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Ledger of the period's prize vault (vault kinds 0-2 match period types)
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, &[period_type]],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Switch a feature flag on or off
+#[derive(Accounts)]
+pub struct SetFeature<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeatureFlags::INIT_SPACE,
+        seeds = [SEED_FEATURE_FLAGS],
+        bump
+    )]
+    pub feature_flags: Account<'info, FeatureFlags>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Schedule prize split overrides for a future period
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct CreatePrizeScheduleOverride<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PrizeScheduleOverride::INIT_SPACE,
+        seeds = [SEED_PRIZE_SCHEDULE, period_id.as_bytes()],
+        bump
+    )]
+    pub prize_schedule: Account<'info, PrizeScheduleOverride>,
+
+    /// Matured queued `PrizeSchedule` action for this period and splits
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a period's prize split overrides
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct ClosePrizeScheduleOverride<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [SEED_PRIZE_SCHEDULE, period_id.as_bytes()],
+        bump = prize_schedule.bump
+    )]
+    pub prize_schedule: Account<'info, PrizeScheduleOverride>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Add a wallet to the blocklist
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct BlockPlayer<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BlockedPlayer::INIT_SPACE,
+        seeds = [SEED_BLOCKED_PLAYER, player.as_ref()],
+        bump
+    )]
+    pub blocked_player: Account<'info, BlockedPlayer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a wallet from the blocklist
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct UnblockPlayer<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [SEED_BLOCKED_PLAYER, player.as_ref()],
+        bump = blocked_player.bump
+    )]
+    pub blocked_player: Account<'info, BlockedPlayer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Store canonical bumps on period accounts passed as remaining accounts
+#[derive(Accounts)]
+pub struct BackfillAccountBumps<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Cache canonical PDA bumps on a config created before bump caching
+#[derive(Accounts)]
+pub struct CacheConfigBumps<'info> {
     #[account(
         mut,
         seeds = [SEED_GLOBAL_CONFIG],
@@ -36,11 +570,17 @@ pub struct SetConfig<'info> {
     pub authority: Signer<'info>,
 }
 
+/// No accounts: the config bump benchmark only derives addresses (`debug-logs` builds only)
+#[cfg(feature = "debug-logs")]
+#[derive(Accounts)]
+pub struct BenchConfigBumps {}
+
 #[derive(Accounts)]
 pub struct InitializeVaults<'info> {
     #[account(
+        mut,
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
@@ -95,6 +635,7 @@ pub struct InitializeVaults<'info> {
     )]
     pub lucky_draw_vault: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(address = global_config.usdc_mint)]
     pub usdc_mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
@@ -108,8 +649,9 @@ pub struct InitializeVaults<'info> {
 #[derive(Accounts)]
 pub struct WithdrawPlatformRevenue<'info> {
     #[account(
+        mut,
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
@@ -117,23 +659,134 @@ pub struct WithdrawPlatformRevenue<'info> {
     #[account(
         mut,
         seeds = [SEED_PLATFORM_VAULT],
-        bump,
+        bump = global_config.bumps.platform_vault,
         token::mint = global_config.usdc_mint,
         token::authority = platform_vault,
     )]
     pub platform_vault: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Platform.seed()],
+        bump = platform_vault_ledger.bump
+    )]
+    pub platform_vault_ledger: Account<'info, VaultLedger>,
+
+    /// Matured queued action for a withdrawal above the timelock threshold (omit otherwise)
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Any wallet's account of the USDC mint (mint and frozen state checked in the handler)
+    #[account(mut)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Checked against global_config.usdc_mint in the handler
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless crank sweeping platform revenue to the configured treasury
+#[derive(Accounts)]
+pub struct SweepPlatformRevenue<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + VaultState::INIT_SPACE,
+        seeds = [SEED_VAULT_STATE],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+
     #[account(
         mut,
+        seeds = [SEED_PLATFORM_VAULT],
+        bump = global_config.bumps.platform_vault,
         token::mint = global_config.usdc_mint,
+        token::authority = platform_vault,
     )]
-    pub destination: InterfaceAccount<'info, TokenAccount>,
+    pub platform_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Platform.seed()],
+        bump = platform_vault_ledger.bump
+    )]
+    pub platform_vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(
+        mut,
+        address = global_config.treasury,
+        token::mint = global_config.usdc_mint,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = global_config.usdc_mint,
+        token::authority = cranker,
+    )]
+    pub cranker_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(address = global_config.usdc_mint)]
     pub usdc_mint: InterfaceAccount<'info, Mint>,
 
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
 }
+
+/// Create the inflow/outflow ledger for one vault
+#[derive(Accounts)]
+#[instruction(kind: u8)]
+pub struct InitializeVaultLedger<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VaultLedger::INIT_SPACE,
+        seeds = [SEED_VAULT_LEDGER, &[kind]],
+        bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    /// Vault being tracked (checked against `kind` in the handler)
+    #[account(token::mint = global_config.usdc_mint)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Read-only context for reporting a vault's ledger against its balance
+#[derive(Accounts)]
+pub struct GetVaultReport<'info> {
+    #[account(
+        seeds = [SEED_VAULT_LEDGER, vault_ledger.kind.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(address = vault_ledger.vault)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+
+/// Open a duel and escrow the challenger's stake
+#[derive(Accounts)]
+#[instruction(opponent: Pubkey, stake_amount: u64, period_id: String)]
+pub struct CreateDuel<'info> {
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + Duel::INIT_SPACE,
+        seeds = [SEED_DUEL, period_id.as_bytes(), challenger.key().as_ref(), opponent.as_ref()],
+        bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        init,
+        payer = challenger,
+        seeds = [SEED_DUEL_ESCROW, duel.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = escrow,
+        token::token_program = token_program,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = challenger,
+        associated_token::token_program = token_program
+    )]
+    pub challenger_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Accept a duel and escrow the opponent's matching stake
+#[derive(Accounts)]
+pub struct AcceptDuel<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_DUEL,
+            duel.period_id.as_bytes(),
+            duel.challenger.as_ref(),
+            opponent.key().as_ref()
+        ],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DUEL_ESCROW, duel.key().as_ref()],
+        bump = duel.escrow_bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub opponent: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = opponent,
+        associated_token::token_program = token_program
+    )]
+    pub opponent_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw an unaccepted duel before its deadline
+#[derive(Accounts)]
+pub struct CancelDuel<'info> {
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            SEED_DUEL,
+            duel.period_id.as_bytes(),
+            challenger.key().as_ref(),
+            duel.opponent.as_ref()
+        ],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DUEL_ESCROW, duel.key().as_ref()],
+        bump = duel.escrow_bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = challenger,
+        associated_token::token_program = token_program
+    )]
+    pub challenger_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless crank paying out an accepted duel from the finalized daily board
+#[derive(Accounts)]
+pub struct ResolveDuel<'info> {
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            SEED_DUEL,
+            duel.period_id.as_bytes(),
+            duel.challenger.as_ref(),
+            duel.opponent.as_ref()
+        ],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DUEL_ESCROW, duel.key().as_ref()],
+        bump = duel.escrow_bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            duel.period_id.as_bytes(),
+            PeriodType::Daily.seed()
+        ],
+        bump = daily_leaderboard.bump
+    )]
+    pub daily_leaderboard: Box<Account<'info, PeriodLeaderboard>>,
+
+    /// Receives the duel and escrow rent (paid at creation)
+    #[account(mut, address = duel.challenger)]
+    pub challenger: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = duel.challenger,
+        associated_token::token_program = token_program
+    )]
+    pub challenger_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = duel.opponent,
+        associated_token::token_program = token_program
+    )]
+    pub opponent_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_VAULT],
+        bump = global_config.bumps.platform_vault,
+        token::mint = global_config.usdc_mint,
+        token::authority = platform_vault,
+    )]
+    pub platform_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Platform.seed()],
+        bump = platform_vault_ledger.bump
+    )]
+    pub platform_vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless crank returning stakes of a duel that can't be played out
+#[derive(Accounts)]
+pub struct RefundDuel<'info> {
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            SEED_DUEL,
+            duel.period_id.as_bytes(),
+            duel.challenger.as_ref(),
+            duel.opponent.as_ref()
+        ],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [SEED_DUEL_ESCROW, duel.key().as_ref()],
+        bump = duel.escrow_bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Daily leaderboard PDA of the duel's period (address checked in
+    /// the handler, may not exist if nobody played that day)
+    pub daily_leaderboard: UncheckedAccount<'info>,
+
+    /// Receives the duel and escrow rent (paid at creation)
+    #[account(mut, address = duel.challenger)]
+    pub challenger: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = duel.challenger,
+        associated_token::token_program = token_program
+    )]
+    pub challenger_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Opponent's token account; needed to refund an accepted duel
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = duel.opponent,
+        associated_token::token_program = token_program
+    )]
+    pub opponent_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -3,6 +3,7 @@ use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{ self, Mint, TokenAccount, TokenInterface };
 
 use crate::constants::*;
+use crate::errors::VobleError;
 use crate::state::*;
 use ephemeral_rollups_sdk::anchor::{commit, delegate};
 
@@ -13,6 +14,7 @@ pub struct BuyTicketAndStartGame<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(address = global_config.usdc_mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
 
@@ -23,18 +25,86 @@ pub struct BuyTicketAndStartGame<'info> {
         bump
     )]
     pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Payer's blocklist entry; must not exist (checked in the handler)
+    #[account(
+        seeds = [SEED_BLOCKED_PLAYER, payer.key().as_ref()],
+        bump
+    )]
+    pub blocked_player: UncheckedAccount<'info>,
     
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump
+        bump = global_config.bumps.global_config
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Happy hour windows for this period (omit if none are scheduled)
+    #[account(
+        seeds = [SEED_SCORE_MULTIPLIER, period_id.as_bytes()],
+        bump = score_multiplier.bump
+    )]
+    pub score_multiplier: Option<Box<Account<'info, ScoreMultiplierWindow>>>,
+
+    /// Prize split override for this period (omit if none is scheduled)
+    #[account(
+        seeds = [SEED_PRIZE_SCHEDULE, period_id.as_bytes()],
+        bump = prize_schedule.bump
+    )]
+    pub prize_schedule: Option<Box<Account<'info, PrizeScheduleOverride>>>,
+
+    /// CHECK: Per-word difficulty; read as a `WordBank` once one exists (checked in the handler)
+    #[account(
+        seeds = [SEED_WORD_BANK],
+        bump
+    )]
+    pub word_bank: UncheckedAccount<'info>,
+
+    /// Daily leaderboard of `period_id`, counts entrants; the day's first ticket creates it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PeriodLeaderboard::init_if_needed_space(
+            period_leaderboard,
+            global_config.leaderboard_capacity() as usize
+        ),
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[PeriodType::Daily as u8]
+        ],
+        bump
+    )]
+    pub period_leaderboard: Box<Account<'info, PeriodLeaderboard>>,
+
+    /// First ticket in the period creates it; counts the player once per period
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerParticipation::INIT_SPACE,
+        seeds = [SEED_PLAYER_PARTICIPATION, period_id.as_bytes(), payer.key().as_ref()],
+        bump
+    )]
+    pub participation: Box<Account<'info, PlayerParticipation>>,
+
+    /// First ticket in the period creates it; sums the pool shares for refunds
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TicketReceipt::INIT_SPACE,
+        seeds = [SEED_TICKET_RECEIPT, period_id.as_bytes(), payer.key().as_ref()],
+        bump
+    )]
+    pub ticket_receipt: Box<Account<'info, TicketReceipt>>,
+
+    /// CHECK: Ephemeral key authorized to sign gameplay on the ER (omit to keep current)
+    pub session_signer: Option<UncheckedAccount<'info>>,
     
     // Prize vaults for payment distribution
     #[account(
         mut,
         seeds = [SEED_DAILY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.daily_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = daily_prize_vault,
     )]
@@ -43,7 +113,7 @@ pub struct BuyTicketAndStartGame<'info> {
     #[account(
         mut,
         seeds = [SEED_WEEKLY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.weekly_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = weekly_prize_vault,
     )]
@@ -52,7 +122,7 @@ pub struct BuyTicketAndStartGame<'info> {
     #[account(
         mut,
         seeds = [SEED_MONTHLY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.monthly_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = monthly_prize_vault,
     )]
@@ -61,7 +131,7 @@ pub struct BuyTicketAndStartGame<'info> {
     #[account(
         mut,
         seeds = [SEED_PLATFORM_VAULT],
-        bump,
+        bump = global_config.bumps.platform_vault,
         token::mint = global_config.usdc_mint,
         token::authority = platform_vault,
     )]
@@ -70,15 +140,63 @@ pub struct BuyTicketAndStartGame<'info> {
     #[account(
         mut,
         seeds = [SEED_LUCKY_DRAW_VAULT],
-        bump,
+        bump = global_config.bumps.lucky_draw_vault,
         token::mint = global_config.usdc_mint,
         token::authority = lucky_draw_vault,
     )]
     pub lucky_draw_vault: Box<InterfaceAccount<'info, TokenAccount>>,
-    
+
+    // Inflow ledgers for the vaults above
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Daily.seed()],
+        bump = daily_vault_ledger.bump
+    )]
+    pub daily_vault_ledger: Box<Account<'info, VaultLedger>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Weekly.seed()],
+        bump = weekly_vault_ledger.bump
+    )]
+    pub weekly_vault_ledger: Box<Account<'info, VaultLedger>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Monthly.seed()],
+        bump = monthly_vault_ledger.bump
+    )]
+    pub monthly_vault_ledger: Box<Account<'info, VaultLedger>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Platform.seed()],
+        bump = platform_vault_ledger.bump
+    )]
+    pub platform_vault_ledger: Box<Account<'info, VaultLedger>>,
+
     #[account(
         mut,
-        associated_token::mint = global_config.usdc_mint,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::LuckyDraw.seed()],
+        bump = lucky_draw_vault_ledger.bump
+    )]
+    pub lucky_draw_vault_ledger: Box<Account<'info, VaultLedger>>,
+
+    /// Takes the player's active session slot for the game being bought
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SessionCounter::INIT_SPACE,
+        seeds = [SEED_SESSION_COUNTER],
+        bump
+    )]
+    pub session_counter: Box<Account<'info, SessionCounter>>,
+    
+    /// Created empty on a first purchase so the balance check reports the real problem
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
         associated_token::authority = payer,
         associated_token::token_program = token_program
     )]
@@ -95,14 +213,46 @@ pub struct BuyTicketAndStartGame<'info> {
 pub struct SubmitGuess<'info> {
     #[account(
         mut,
-        seeds = [SEED_SESSION, session.player.as_ref()],
-        bump
+        seeds = [SEED_SESSION, session.player.as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
     )]
     pub session: Account<'info, SessionAccount>,
-    
+
+    #[account(
+        seeds = [SEED_USER_PROFILE, session.player.as_ref()],
+        bump,
+        constraint = user_profile.player == session.player @ VobleError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Daily leaderboard of the session's period (read-only, checked for a pause)
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            session.period_id.as_bytes(),
+            &[PeriodType::Daily as u8]
+        ],
+        bump = daily_leaderboard.bump
+    )]
+    pub daily_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Read-only; supplies the character class guesses are checked against
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Player wallet or the session signer registered on the profile
+    pub signer: Signer<'info>,
 }
 
 /// Handler context for Magic Actions - updates leaderboard after game completion
+///
+/// Accounts are positional and come from `plan_stats_handlers`. The
+/// leaderboards must be daily, weekly, monthly (`HANDLER_LEADERBOARD_ORDER`);
+/// the handler checks each board's type and PDA and rejects any other
+/// order with `HandlerAccountOrderInvalid`.
 #[derive(Accounts)]
 pub struct UpdatePlayerStats<'info> {
     /// Daily leaderboard to update - THIRD
@@ -124,6 +274,11 @@ pub struct UpdatePlayerStats<'info> {
     /// CHECK: Committed session account (manually deserialized) - SEVENTH
     pub committed_session: UncheckedAccount<'info>,
 
+    /// CHECK: Active session counter; settling a game frees the player's slot
+    /// (skipped if no slot was ever taken) - EIGHTH
+    #[account(mut, seeds = [SEED_SESSION_COUNTER], bump)]
+    pub session_counter: UncheckedAccount<'info>,
+
     /// CHECK: Injected by Magic Actions (escrow authority) - SECOND
     pub escrow_auth: UncheckedAccount<'info>,
     
@@ -133,6 +288,60 @@ pub struct UpdatePlayerStats<'info> {
     
 }
 
+/// Handler context for Magic Actions - leaderboard half of a split settlement
+///
+/// Same account order as `UpdatePlayerStats`, checked the same way.
+#[derive(Accounts)]
+pub struct UpdateLeaderboardStats<'info> {
+    /// Daily leaderboard to update - THIRD
+    #[account(mut)]
+    pub daily_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Weekly leaderboard to update - FOURTH
+    #[account(mut)]
+    pub weekly_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Monthly leaderboard to update - FIFTH
+    #[account(mut)]
+    pub monthly_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// User profile, read for the username and the last applied epoch - SIXTH
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Committed session account (manually deserialized) - SEVENTH
+    pub committed_session: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow authority) - SECOND
+    pub escrow_auth: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow account) - FIRST
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
+/// Handler context for Magic Actions - profile half of a split settlement
+#[derive(Accounts)]
+pub struct UpdateProfileStats<'info> {
+    /// User profile to update stats - THIRD
+    #[account(mut)]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Committed session account (manually deserialized) - FOURTH
+    pub committed_session: UncheckedAccount<'info>,
+
+    /// CHECK: Active session counter; settling a game frees the player's slot
+    /// (skipped if no slot was ever taken) - FIFTH
+    #[account(mut, seeds = [SEED_SESSION_COUNTER], bump)]
+    pub session_counter: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow authority) - SECOND
+    pub escrow_auth: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow account) - FIRST
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
 
 /// Context for initializing session (one-time setup)
 #[derive(Accounts)]
@@ -144,10 +353,21 @@ pub struct InitializeSession<'info> {
         init,
         payer = payer,
         space = 8 + SessionAccount::INIT_SPACE,
-        seeds = [SEED_SESSION, payer.key().as_ref()],
+        seeds = [
+            SEED_SESSION,
+            payer.key().as_ref(),
+            &SessionAccount::nonce_seed(user_profile.as_ref().map_or(0, |p| p.session_nonce))
+        ],
         bump
     )]
     pub session: Account<'info, SessionAccount>,
+
+    /// Supplies the session generation (omit before the first recovery)
+    #[account(
+        seeds = [SEED_USER_PROFILE, payer.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Option<Account<'info, UserProfile>>,
     
     pub system_program: Program<'info, System>,
 }
@@ -162,12 +382,164 @@ pub struct DelegateSession<'info> {
     /// CHECK: Session PDA to delegate to ER
     #[account(mut, del)]
     pub pda: AccountInfo<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_PROFILE, payer.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SessionCounter::INIT_SPACE,
+        seeds = [SEED_SESSION_COUNTER],
+        bump
+    )]
+    pub session_counter: Account<'info, SessionCounter>,
 }
 
+/// Handler context for freeing a player's active session slot
+///
+/// Scheduled as a Magic Action by every undelegation; anyone may also call
+/// it directly for a session that is back on the base layer.
 #[derive(Accounts)]
-pub struct RecordKeystroke<'info> {
+pub struct ReleaseSessionSlot<'info> {
+    /// CHECK: Active session counter (skipped if no slot was ever taken) - THIRD
+    #[account(mut, seeds = [SEED_SESSION_COUNTER], bump)]
+    pub session_counter: UncheckedAccount<'info>,
+
+    /// Profile holding the slot - FOURTH
     #[account(mut)]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Committed session account (manually deserialized) - FIFTH
+    pub committed_session: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow authority) - SECOND
+    pub escrow_auth: UncheckedAccount<'info>,
+
+    /// CHECK: Injected by Magic Actions (escrow account) - FIRST
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
+/// Context for rewriting a pre-compaction session into the current layout
+#[derive(Accounts)]
+pub struct MigrateSession<'info> {
+    pub player: Signer<'info>,
+
+    /// CHECK: Deserialized manually - legacy layouts fail `Account` parsing
+    #[account(
+        mut,
+        seeds = [SEED_SESSION, player.key().as_ref()],
+        bump
+    )]
+    pub session: UncheckedAccount<'info>,
+}
+
+/// Read-only context for revealing a finalized daily period's word
+#[derive(Accounts)]
+#[instruction(period_id: String)]
+pub struct RevealPeriodWord<'info> {
+    #[account(
+        seeds = [SEED_DAILY_PERIOD, period_id.as_bytes()],
+        bump = period_state.bump,
+        constraint = period_state.finalized @ VobleError::InvalidPeriodState
+    )]
+    pub period_state: Account<'info, PeriodState>,
+}
+
+/// Read-only context for sharing a finished board
+#[derive(Accounts)]
+pub struct GetShareableBoard<'info> {
+    #[account(
+        seeds = [SEED_SESSION, session.player.as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionAccount>,
+}
+
+/// Read-only context for logging a whole session (`debug-logs` builds only)
+#[cfg(feature = "debug-logs")]
+#[derive(Accounts)]
+pub struct DumpSessionState<'info> {
+    #[account(
+        seeds = [SEED_SESSION, session.player.as_ref()],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionAccount>,
+}
+
+/// Scratch board for the leaderboard write benchmark (`debug-logs` builds only)
+#[cfg(feature = "debug-logs")]
+#[derive(Accounts)]
+pub struct BenchLeaderboardWrite<'info> {
+    /// CHECK: Program-owned scratch account the resulting board is written
+    /// to; never read back
+    #[account(mut, owner = crate::ID)]
+    pub board: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecordKeystroke<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_SESSION, session.player.as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionAccount>,
+
+    /// Read-only; supplies the character class keys are checked against
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [SEED_USER_PROFILE, session.player.as_ref()],
+        bump,
+        constraint = user_profile.player == session.player @ VobleError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Player wallet or the session signer registered on the profile
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RetypeCurrentGuess<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_SESSION, session.player.as_ref()],
+        bump = session.bump
+    )]
     pub session: Account<'info, SessionAccount>,
+
+    /// Read-only; supplies the mulligan switch and character class
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [SEED_USER_PROFILE, session.player.as_ref()],
+        bump,
+        constraint = user_profile.player == session.player @ VobleError::Unauthorized
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Player wallet or the session signer registered on the profile
+    pub signer: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -175,12 +547,33 @@ pub struct RecordKeystroke<'info> {
 pub struct ResetSession<'info> {
     #[account(mut)]
     pub session: Account<'info, SessionAccount>,
+
+    /// Read-only; supplies the reset cool-down
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
     
     #[account(
         seeds = [SEED_USER_PROFILE, session.player.as_ref()],
         bump
     )]
     pub user_profile: Account<'info, UserProfile>,
+
+    /// Daily word commitment for this period (omit under deterministic selection)
+    #[account(
+        seeds = [SEED_DAILY_WORD, period_id.as_bytes()],
+        bump = daily_word_commitment.bump
+    )]
+    pub daily_word_commitment: Option<Account<'info, DailyWordCommitment>>,
+
+    /// Shared word of this period, played under commit-reveal (omit otherwise)
+    #[account(
+        seeds = [SEED_SHARED_WORD, period_id.as_bytes()],
+        bump = shared_word.bump
+    )]
+    pub shared_word: Option<Account<'info, SharedWord>>,
 }
 
 /// Context for undelegating session from ER
@@ -196,11 +589,127 @@ pub struct UndelegateSession<'info> {
     
     #[account(
         mut,
-        seeds = [SEED_SESSION, player.key().as_ref()],
-        bump
+        seeds = [SEED_SESSION, player.key().as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
     )]
     pub session: Account<'info, SessionAccount>,
 
+    /// Read for the escrow that pays for the slot release handler
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Context for the authority to force-close a session stuck on the ER
+#[commit]
+#[derive(Accounts)]
+pub struct ForceUndelegateSession<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: The player who owns the stuck session
+    pub player: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SESSION, player.key().as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionAccount>,
+}
+
+/// Context for anyone to undelegate a session left on the ER past the staleness window
+#[commit]
+#[derive(Accounts)]
+#[instruction(
+    daily_period_id: String,
+    weekly_period_id: String,
+    monthly_period_id: String
+)]
+pub struct ForceUndelegateStaleSession<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// CHECK: The player who owns the stuck session
+    pub player: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SESSION, player.key().as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
+    )]
+    pub session: Account<'info, SessionAccount>,
+
+    /// CHECK: Daily leaderboard - settles a finished game, writable set in handler
+    #[account(seeds = [SEED_LEADERBOARD, daily_period_id.as_bytes(), PeriodType::Daily.seed()], bump)]
+    pub daily_leaderboard: UncheckedAccount<'info>,
+
+    /// CHECK: Weekly leaderboard - settles a finished game, writable set in handler
+    #[account(seeds = [SEED_LEADERBOARD, weekly_period_id.as_bytes(), PeriodType::Weekly.seed()], bump)]
+    pub weekly_leaderboard: UncheckedAccount<'info>,
+
+    /// CHECK: Monthly leaderboard - settles a finished game, writable set in handler
+    #[account(seeds = [SEED_LEADERBOARD, monthly_period_id.as_bytes(), PeriodType::Monthly.seed()], bump)]
+    pub monthly_leaderboard: UncheckedAccount<'info>,
+
+    /// CHECK: User profile - settles a finished game, writable set in handler
+    #[account(seeds = [SEED_USER_PROFILE, player.key().as_ref()], bump)]
+    pub user_profile: UncheckedAccount<'info>,
+}
+
+/// Context for retiring a session stuck on an unreachable ER, on the base layer
+#[derive(Accounts)]
+pub struct RecoverStaleSession<'info> {
+    /// Read for the staleness window
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub cranker: Signer<'info>,
+
+    /// CHECK: The player who owns the stuck session
+    pub player: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Owned by the delegation program while stuck; its last committed
+    /// data is read in the handler
+    #[account(
+        seeds = [
+            SEED_SESSION,
+            player.key().as_ref(),
+            &SessionAccount::nonce_seed(user_profile.session_nonce)
+        ],
+        bump
+    )]
+    pub session: UncheckedAccount<'info>,
+
+    /// CHECK: Active session counter; the retired session's slot is freed
+    /// (skipped if no slot was ever taken)
+    #[account(mut, seeds = [SEED_SESSION_COUNTER], bump)]
+    pub session_counter: UncheckedAccount<'info>,
 }
 
 #[commit]
@@ -219,21 +728,21 @@ pub struct CommitAndUpdateStats<'info> {
 
     #[account(
         mut,
-        seeds = [SEED_SESSION, player.key().as_ref()],
-        bump
+        seeds = [SEED_SESSION, player.key().as_ref(), &SessionAccount::nonce_seed(session.session_nonce)],
+        bump = session.bump
     )]
     pub session: Account<'info, SessionAccount>,
 
     /// CHECK: Daily leaderboard - not mut here, writable set in handler
-    #[account(seeds = [SEED_LEADERBOARD, daily_period_id.as_bytes(), &[0]], bump)]
+    #[account(seeds = [SEED_LEADERBOARD, daily_period_id.as_bytes(), PeriodType::Daily.seed()], bump)]
     pub daily_leaderboard: UncheckedAccount<'info>,
 
     /// CHECK: Weekly leaderboard - not mut here, writable set in handler
-    #[account(seeds = [SEED_LEADERBOARD, weekly_period_id.as_bytes(), &[1]], bump)]
+    #[account(seeds = [SEED_LEADERBOARD, weekly_period_id.as_bytes(), PeriodType::Weekly.seed()], bump)]
     pub weekly_leaderboard: UncheckedAccount<'info>,
 
     /// CHECK: Monthly leaderboard - not mut here, writable set in handler
-    #[account(seeds = [SEED_LEADERBOARD, monthly_period_id.as_bytes(), &[2]], bump)]
+    #[account(seeds = [SEED_LEADERBOARD, monthly_period_id.as_bytes(), PeriodType::Monthly.seed()], bump)]
     pub monthly_leaderboard: UncheckedAccount<'info>,
     
     /// CHECK: User profile - not mut here, writable set in handler
@@ -242,4 +751,11 @@ pub struct CommitAndUpdateStats<'info> {
 
     /// CHECK: Your program ID
     pub program_id: AccountInfo<'info>,
+
+    /// Read for the handler compute budget, escrow index and split flag
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
 }
\ No newline at end of file
@@ -6,10 +6,18 @@ use crate::state::*;
 #[derive(Accounts)]
 #[instruction(period_id: String, period_type: u8)]
 pub struct InitializePeriodLeaderboard<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    // Capacities past the 10 KiB init limit are allocated by grow_leaderboard
     #[account(
         init,
         payer = authority,
-        space = 8 + PeriodLeaderboard::INIT_SPACE,
+        space = PeriodLeaderboard::next_space(0, global_config.leaderboard_capacity() as usize),
         seeds = [
             SEED_LEADERBOARD,
             period_id.as_bytes(),
@@ -19,16 +27,77 @@ pub struct InitializePeriodLeaderboard<'info> {
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
     
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the leaderboards of the next few periods of one type
+///
+/// The boards are passed as writable remaining accounts, one per period in
+/// order; boards that already exist are skipped.
+#[derive(Accounts)]
+pub struct InitializeFutureLeaderboards<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
-    
+
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Allocate the next 10 KiB of a leaderboard larger than one `init`
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct GrowLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump,
+        realloc = PeriodLeaderboard::next_space(
+            leaderboard.to_account_info().data_len(),
+            leaderboard.capacity()
+        ),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite a leaderboard created with the old entry layout
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct MigrateLeaderboard<'info> {
+    /// CHECK: Deserialized manually - the old entry layout fails `Account` parsing
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump
+    )]
+    pub leaderboard: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -43,7 +112,7 @@ pub struct UpdateLeaderboard<'info> {
             period_id.as_bytes(),
             &[period_type]
         ],
-        bump
+        bump = leaderboard.bump
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
     
@@ -69,13 +138,13 @@ pub struct FinalizeLeaderboard<'info> {
             period_id.as_bytes(),
             &[period_type]
         ],
-        bump
+        bump = leaderboard.bump
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
     
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
@@ -83,3 +152,117 @@ pub struct FinalizeLeaderboard<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 }
+
+/// Read-only lookup of a player's rank on the daily, weekly and monthly boards
+///
+/// Boards are taken unchecked so that a period whose leaderboard has not been
+/// initialized yet still resolves (to no rank) instead of failing the call.
+/// Their addresses are checked against the requested period ids.
+#[derive(Accounts)]
+#[instruction(player: Pubkey, daily_period_id: String, weekly_period_id: String, monthly_period_id: String)]
+pub struct GetPlayerRanks<'info> {
+    /// CHECK: Daily leaderboard - deserialized manually, may be uninitialized
+    #[account(seeds = [SEED_LEADERBOARD, daily_period_id.as_bytes(), PeriodType::Daily.seed()], bump)]
+    pub daily_leaderboard: UncheckedAccount<'info>,
+
+    /// CHECK: Weekly leaderboard - deserialized manually, may be uninitialized
+    #[account(seeds = [SEED_LEADERBOARD, weekly_period_id.as_bytes(), PeriodType::Weekly.seed()], bump)]
+    pub weekly_leaderboard: UncheckedAccount<'info>,
+
+    /// CHECK: Monthly leaderboard - deserialized manually, may be uninitialized
+    #[account(seeds = [SEED_LEADERBOARD, monthly_period_id.as_bytes(), PeriodType::Monthly.seed()], bump)]
+    pub monthly_leaderboard: UncheckedAccount<'info>,
+}
+
+/// Export a chunk of a finalized leaderboard's standings
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct EmitFinalStandings<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    pub cranker: Signer<'info>,
+}
+
+/// Fold a finalized daily leaderboard into its weekly and monthly boards
+#[derive(Accounts)]
+#[instruction(daily_period_id: String)]
+pub struct RollDailyIntoWeekly<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            daily_period_id.as_bytes(),
+            &[PeriodType::Daily as u8]
+        ],
+        bump = daily_leaderboard.bump
+    )]
+    pub daily_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Checked against the week containing the day in the handler
+    #[account(mut)]
+    pub weekly_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Checked against the month containing the day in the handler
+    #[account(mut)]
+    pub monthly_leaderboard: Account<'info, PeriodLeaderboard>,
+
+    pub cranker: Signer<'info>,
+}
+
+/// Read-only period timing for this deployment
+#[derive(Accounts)]
+pub struct GetPeriodTiming<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Read-only checksum of a leaderboard's standings
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct GetLeaderboardChecksum<'info> {
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+}
+
+/// Refresh the caller's own leaderboard username
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct UpdateLeaderboardUsername<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    #[account(
+        seeds = [SEED_USER_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub player: Signer<'info>,
+}
@@ -1,5 +1,6 @@
 // Domain-organized contexts
 pub mod admin;
+pub mod duel;
 pub mod gameplay;
 pub mod leaderboard;
 pub mod prize;
@@ -7,6 +8,7 @@ pub mod profile;
 
 // Re-export all public types
 pub use admin::*;
+pub use duel::*;
 pub use gameplay::*;
 pub use leaderboard::*;
 pub use prize::*;
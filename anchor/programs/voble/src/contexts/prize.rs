@@ -1,44 +1,204 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::state::*;
+use crate::utils::period_id_seed;
 use anchor_spl::token_interface::{self, TokenInterface, TokenAccount, Mint};
 use anchor_spl::associated_token::AssociatedToken;
 
+/// Read-only prize estimate for the caller on a running period
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct EstimateMyPrize<'info> {
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Prize vault of `period_type` (address checked in the handler)
+    #[account(token::mint = global_config.usdc_mint)]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub player: Signer<'info>,
+}
+
+/// Refund a ticket's share of a canceled period's pool
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8, ticket_period_id: String)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TICKET_RECEIPT, ticket_period_id.as_bytes(), player.key().as_ref()],
+        bump = ticket_receipt.bump,
+        has_one = player
+    )]
+    pub ticket_receipt: Account<'info, TicketReceipt>,
+
+    /// Prize vault of `period_type` (address checked in the handler)
+    #[account(mut, token::mint = global_config.usdc_mint)]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ledger of `prize_vault` (checked in the handler)
+    #[account(mut)]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = player,
+        associated_token::token_program = token_program
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = global_config.usdc_mint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Read-only prize projection for any player on a running period
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct GetProjectedPrize<'info> {
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    /// Prize vault of `period_type` (address checked in the handler)
+    #[account(token::mint = global_config.usdc_mint)]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Read-only dry run of a period's finalization
+#[derive(Accounts)]
+#[instruction(period_id: String, period_type: u8)]
+pub struct PreviewFinalize<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+        has_one = authority
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: PeriodState finalize would create (address checked in the handler, may not exist yet)
+    pub period_state: UncheckedAccount<'info>,
+
+    /// Prize vault of `period_type` (address checked in the handler)
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ledger of `prize_vault` (vault kinds 0-2 match period types)
+    #[account(
+        seeds = [SEED_VAULT_LEDGER, &[period_type]],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(
+        seeds = [
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            &[period_type]
+        ],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, PeriodLeaderboard>,
+
+    pub authority: Signer<'info>,
+}
+
 /// Finalize daily period
 #[derive(Accounts)]
 #[instruction(period_id: String)]
 pub struct FinalizeDaily<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + PeriodState::INIT_SPACE,
-        seeds = [SEED_DAILY_PERIOD, period_id.as_bytes()],
+        seeds = [SEED_DAILY_PERIOD, period_id_seed(&period_id, PeriodType::Daily)?],
         bump
     )]
     pub period_state: Account<'info, PeriodState>,
 
     #[account(
         seeds = [SEED_DAILY_PRIZE_VAULT],
-        bump
+        bump = global_config.bumps.daily_prize_vault,
+        token::mint = global_config.usdc_mint,
+        token::authority = daily_prize_vault,
     )]
-    /// CHECK: This is a PDA vault account
-    pub daily_prize_vault: AccountInfo<'info>,
+    pub daily_prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ledger of the prize vault; its refund liability is held back from prizes
+    #[account(
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Daily.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
 
     /// Leaderboard to get top winners
     #[account(
         mut,
-        seeds = [SEED_LEADERBOARD, period_id.as_bytes(), &[0]],
-        bump
+        seeds = [SEED_LEADERBOARD, period_id_seed(&period_id, PeriodType::Daily)?, PeriodType::Daily.seed()],
+        bump = leaderboard.bump
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
 
+    /// Revealed daily word (required when the config uses commit-reveal)
+    #[account(
+        seeds = [SEED_DAILY_WORD, period_id_seed(&period_id, PeriodType::Daily)?],
+        bump = daily_word_commitment.bump
+    )]
+    pub daily_word_commitment: Option<Account<'info, DailyWordCommitment>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -51,32 +211,40 @@ pub struct FinalizeDaily<'info> {
 pub struct FinalizeWeekly<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + PeriodState::INIT_SPACE,
-        seeds = [SEED_WEEKLY_PERIOD, period_id.as_bytes()],
+        seeds = [SEED_WEEKLY_PERIOD, period_id_seed(&period_id, PeriodType::Weekly)?],
         bump
     )]
     pub period_state: Account<'info, PeriodState>,
 
     #[account(
         seeds = [SEED_WEEKLY_PRIZE_VAULT],
-        bump
+        bump = global_config.bumps.weekly_prize_vault,
+        token::mint = global_config.usdc_mint,
+        token::authority = weekly_prize_vault,
     )]
-    /// CHECK: This is a PDA vault account
-    pub weekly_prize_vault: AccountInfo<'info>,
+    pub weekly_prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ledger of the prize vault; its refund liability is held back from prizes
+    #[account(
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Weekly.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
 
     /// Leaderboard to get top winners
     #[account(
         mut,
-        seeds = [SEED_LEADERBOARD, period_id.as_bytes(), &[1]],
-        bump
+        seeds = [SEED_LEADERBOARD, period_id_seed(&period_id, PeriodType::Weekly)?, PeriodType::Weekly.seed()],
+        bump = leaderboard.bump
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
 
@@ -92,32 +260,40 @@ pub struct FinalizeWeekly<'info> {
 pub struct FinalizeMonthly<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + PeriodState::INIT_SPACE,
-        seeds = [SEED_MONTHLY_PERIOD, period_id.as_bytes()],
+        seeds = [SEED_MONTHLY_PERIOD, period_id_seed(&period_id, PeriodType::Monthly)?],
         bump
     )]
     pub period_state: Account<'info, PeriodState>,
 
     #[account(
         seeds = [SEED_MONTHLY_PRIZE_VAULT],
-        bump
+        bump = global_config.bumps.monthly_prize_vault,
+        token::mint = global_config.usdc_mint,
+        token::authority = monthly_prize_vault,
     )]
-    /// CHECK: This is a PDA vault account
-    pub monthly_prize_vault: AccountInfo<'info>,
+    pub monthly_prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ledger of the prize vault; its refund liability is held back from prizes
+    #[account(
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Monthly.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
 
     /// Leaderboard to get top winners
     #[account(
         mut,
-        seeds = [SEED_LEADERBOARD, period_id.as_bytes(), &[2]],
-        bump
+        seeds = [SEED_LEADERBOARD, period_id_seed(&period_id, PeriodType::Monthly)?, PeriodType::Monthly.seed()],
+        bump = leaderboard.bump
     )]
     pub leaderboard: Account<'info, PeriodLeaderboard>,
 
@@ -131,35 +307,38 @@ pub struct FinalizeMonthly<'info> {
 pub struct ClaimDaily<'info> {
     #[account(
         mut,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"daily", winner_entitlement.period_id.as_ref()],
-        bump
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Daily.to_str().as_bytes(), period_id_seed(&winner_entitlement.period_id, PeriodType::Daily)?],
+        bump = winner_entitlement.bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
     #[account(
         mut,
         seeds = [SEED_DAILY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.daily_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = daily_prize_vault,
     )]
     pub daily_prize_vault: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Daily.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
     #[account(mut)]
     pub winner: Signer<'info>,
 
-    #[account(
-        init_if_needed,
-        payer = winner,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = winner,
-        associated_token::token_program = token_program
-    )]
-    pub winner_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Winner's account of the USDC mint, created as their associated
+    /// token account when empty (mint, owner and frozen state checked in the handler)
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
 
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -173,34 +352,38 @@ pub struct ClaimDaily<'info> {
 pub struct ClaimWeekly<'info> {
     #[account(
         mut,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"weekly", winner_entitlement.period_id.as_ref()],
-        bump
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Weekly.to_str().as_bytes(), period_id_seed(&winner_entitlement.period_id, PeriodType::Weekly)?],
+        bump = winner_entitlement.bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
     #[account(
         mut,
         seeds = [SEED_WEEKLY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.weekly_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = weekly_prize_vault,
     )]
     pub weekly_prize_vault: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(mut)]
-    pub winner: Signer<'info>,
-
     #[account(
         mut,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = winner,
-        associated_token::token_program = token_program
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Weekly.seed()],
+        bump = vault_ledger.bump
     )]
-    pub winner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub vault_ledger: Account<'info, VaultLedger>,
+
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// CHECK: Winner's account of the USDC mint, created as their associated
+    /// token account when empty (mint, owner and frozen state checked in the handler)
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
 
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -214,57 +397,131 @@ pub struct ClaimWeekly<'info> {
 pub struct ClaimMonthly<'info> {
     #[account(
         mut,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"monthly", winner_entitlement.period_id.as_ref()],
-        bump
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Monthly.to_str().as_bytes(), period_id_seed(&winner_entitlement.period_id, PeriodType::Monthly)?],
+        bump = winner_entitlement.bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
     #[account(
         mut,
         seeds = [SEED_MONTHLY_PRIZE_VAULT],
-        bump,
+        bump = global_config.bumps.monthly_prize_vault,
         token::mint = global_config.usdc_mint,
         token::authority = monthly_prize_vault,
     )]
     pub monthly_prize_vault: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [SEED_VAULT_LEDGER, VaultKind::Monthly.seed()],
+        bump = vault_ledger.bump
+    )]
+    pub vault_ledger: Account<'info, VaultLedger>,
+
     #[account(mut)]
     pub winner: Signer<'info>,
 
+    /// CHECK: Winner's account of the USDC mint, created as their associated
+    /// token account when empty (mint, owner and frozen state checked in the handler)
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
+
     #[account(
-        mut,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = winner,
-        associated_token::token_program = token_program
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
     )]
-    pub winner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Claim up to MAX_BATCH_CLAIMS entitlements in one transaction
+///
+/// Entitlements and their prize vaults are passed as remaining accounts
+/// (see `claim_all`).
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// CHECK: Winner's account of the USDC mint, created as their associated
+    /// token account when empty (mint, owner and frozen state checked in the handler)
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
 
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = global_config.usdc_mint)]
     pub usdc_mint: InterfaceAccount<'info, Mint>,
 }
 
+/// Close a claimed entitlement of any period type
+#[derive(Accounts)]
+pub struct CloseClaimedEntitlement<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump = global_config.bumps.global_config,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            SEED_WINNER_ENTITLEMENT,
+            winner_entitlement.player.as_ref(),
+            winner_entitlement.period_type.as_bytes(),
+            winner_entitlement.period_id.as_bytes()
+        ],
+        bump = winner_entitlement.bump,
+        constraint = PeriodType::try_from(winner_entitlement.period_type.as_str()).is_ok()
+            @ crate::errors::VobleError::InvalidPeriodType
+    )]
+    pub winner_entitlement: Account<'info, WinnerEntitlement>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::utils::pda::get_period_seed_prefix(&winner_entitlement.period_type)
+                .unwrap_or_default(),
+            winner_entitlement.period_id.as_bytes()
+        ],
+        bump = period_state.bump
+    )]
+    pub period_state: Account<'info, PeriodState>,
+
+    /// CHECK: Receives the entitlement's rent; must be the config authority
+    #[account(mut, address = global_config.authority @ crate::errors::VobleError::Unauthorized)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The entitlement's winner or the config authority
+    pub signer: Signer<'info>,
+}
+
 /// Create daily winner entitlement
 #[derive(Accounts)]
 #[instruction(period_id: String, rank: u8)]
 pub struct CreateDailyWinnerEntitlement<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        seeds = [SEED_DAILY_PERIOD, period_id.as_bytes()],
-        bump,
+        seeds = [SEED_DAILY_PERIOD, period_id_seed(&period_id, PeriodType::Daily)?],
+        bump = period_state.bump,
         constraint = period_state.finalized @ crate::errors::VobleError::InvalidPeriodState
     )]
     pub period_state: Account<'info, PeriodState>,
@@ -273,12 +530,13 @@ pub struct CreateDailyWinnerEntitlement<'info> {
         init,
         payer = authority,
         space = 8 + WinnerEntitlement::INIT_SPACE,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"daily", period_id.as_bytes()],
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Daily.to_str().as_bytes(), period_id_seed(&period_id, PeriodType::Daily)?],
         bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
-    /// CHECK: Winner's public key
+    /// CHECK: Winner's wallet (writable to receive the notify_lamports nudge)
+    #[account(mut)]
     pub winner: AccountInfo<'info>,
 
     #[account(mut)]
@@ -293,14 +551,14 @@ pub struct CreateDailyWinnerEntitlement<'info> {
 pub struct CreateWeeklyWinnerEntitlement<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        seeds = [SEED_WEEKLY_PERIOD, period_id.as_bytes()],
-        bump,
+        seeds = [SEED_WEEKLY_PERIOD, period_id_seed(&period_id, PeriodType::Weekly)?],
+        bump = period_state.bump,
         constraint = period_state.finalized @ crate::errors::VobleError::InvalidPeriodState
     )]
     pub period_state: Account<'info, PeriodState>,
@@ -309,12 +567,13 @@ pub struct CreateWeeklyWinnerEntitlement<'info> {
         init,
         payer = authority,
         space = 8 + WinnerEntitlement::INIT_SPACE,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"weekly", period_id.as_bytes()],
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Weekly.to_str().as_bytes(), period_id_seed(&period_id, PeriodType::Weekly)?],
         bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
-    /// CHECK: Winner's public key
+    /// CHECK: Winner's wallet (writable to receive the notify_lamports nudge)
+    #[account(mut)]
     pub winner: AccountInfo<'info>,
 
     #[account(mut)]
@@ -329,14 +588,14 @@ pub struct CreateWeeklyWinnerEntitlement<'info> {
 pub struct CreateMonthlyWinnerEntitlement<'info> {
     #[account(
         seeds = [SEED_GLOBAL_CONFIG],
-        bump,
+        bump = global_config.bumps.global_config,
         has_one = authority
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
     #[account(
-        seeds = [SEED_MONTHLY_PERIOD, period_id.as_bytes()],
-        bump,
+        seeds = [SEED_MONTHLY_PERIOD, period_id_seed(&period_id, PeriodType::Monthly)?],
+        bump = period_state.bump,
         constraint = period_state.finalized @ crate::errors::VobleError::InvalidPeriodState
     )]
     pub period_state: Account<'info, PeriodState>,
@@ -345,12 +604,13 @@ pub struct CreateMonthlyWinnerEntitlement<'info> {
         init,
         payer = authority,
         space = 8 + WinnerEntitlement::INIT_SPACE,
-        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), b"monthly", period_id.as_bytes()],
+        seeds = [SEED_WINNER_ENTITLEMENT, winner.key().as_ref(), PeriodType::Monthly.to_str().as_bytes(), period_id_seed(&period_id, PeriodType::Monthly)?],
         bump
     )]
     pub winner_entitlement: Account<'info, WinnerEntitlement>,
 
-    /// CHECK: Winner's public key
+    /// CHECK: Winner's wallet (writable to receive the notify_lamports nudge)
+    #[account(mut)]
     pub winner: AccountInfo<'info>,
 
     #[account(mut)]
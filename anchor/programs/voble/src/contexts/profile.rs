@@ -20,3 +20,99 @@ pub struct InitializeUserProfile<'info> {
     
     pub system_program: Program<'info, System>,
 }
+
+/// Buy a flair with score points
+#[derive(Accounts)]
+pub struct PurchaseFlair<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_USER_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [SEED_FLAIR_CATALOG],
+        bump = flair_catalog.bump
+    )]
+    pub flair_catalog: Account<'info, FlairCatalog>,
+
+    pub player: Signer<'info>,
+}
+
+/// Equip an owned flair
+#[derive(Accounts)]
+pub struct EquipFlair<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_USER_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub player: Signer<'info>,
+}
+
+/// Re-check achievements against the caller's own profile
+#[derive(Accounts)]
+pub struct SyncAchievements<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_USER_PROFILE, player.key().as_ref()],
+        bump,
+        realloc = 8 + UserProfile::INIT_SPACE,
+        realloc::payer = player,
+        realloc::zero = false
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Read-only achievement progress for any player
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct GetAchievementProgress<'info> {
+    #[account(
+        seeds = [SEED_USER_PROFILE, player.as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+}
+
+/// Create or update the caller's opt-in public profile
+#[derive(Accounts)]
+pub struct SetPublicProfile<'info> {
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PublicProfile::INIT_SPACE,
+        seeds = [SEED_PUBLIC_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub public_profile: Account<'info, PublicProfile>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Close the caller's public profile (rent goes back to the player)
+#[derive(Accounts)]
+pub struct ClosePublicProfile<'info> {
+    #[account(
+        mut,
+        close = player,
+        seeds = [SEED_PUBLIC_PROFILE, player.key().as_ref()],
+        bump = public_profile.bump
+    )]
+    pub public_profile: Account<'info, PublicProfile>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
@@ -66,4 +66,190 @@ pub enum VobleError {
     InvalidTicketReceipt,
     #[msg("Ticket receipt already used for this session")]
     TicketAlreadyUsed,
+    #[msg("Platform revenue sweep interval has not elapsed yet")]
+    SweepTooSoon,
+    #[msg("Treasury account not configured")]
+    TreasuryNotConfigured,
+    #[msg("Invalid session state for this operation")]
+    InvalidSessionState,
+    #[msg("Leaderboard must be finalized before the period can be finalized")]
+    LeaderboardNotFinalized,
+    #[msg("Invalid happy hour window (must start in the future, end after start, 1x-3x)")]
+    InvalidMultiplierWindow,
+    #[msg("Happy hour window overlaps an existing window")]
+    MultiplierWindowOverlap,
+    #[msg("Too many happy hour windows for this period")]
+    TooManyMultiplierWindows,
+    #[msg("Ticket cooldown has not elapsed yet")]
+    TicketCooldown,
+    #[msg("Invalid mint decimals (must be between 1 and 9)")]
+    InvalidMintDecimals,
+    #[msg("Invalid flair id")]
+    InvalidFlair,
+    #[msg("Flair supply is exhausted")]
+    FlairSoldOut,
+    #[msg("Flair already owned")]
+    FlairAlreadyOwned,
+    #[msg("Flair not owned")]
+    FlairNotOwned,
+    #[msg("Not enough points")]
+    InsufficientPoints,
+    #[msg("Player has no entry on this leaderboard")]
+    NotOnLeaderboard,
+    #[msg("Commit frequency must be between 5000 and 60000 ms")]
+    InvalidCommitFrequency,
+    #[msg("Achievements can only be synced once per day")]
+    AchievementSyncTooSoon,
+    #[msg("Invalid aggregation strategy (must be 0, 1, or 2)")]
+    InvalidAggregationStrategy,
+    #[msg("Daily word must be committed before the period starts")]
+    DailyWordCommitTooLate,
+    #[msg("Daily word can only be revealed after the period ends")]
+    DailyWordRevealTooEarly,
+    #[msg("Daily word reveal deadline has passed")]
+    DailyWordRevealExpired,
+    #[msg("Daily word already revealed")]
+    DailyWordAlreadyRevealed,
+    #[msg("Revealed word does not match the commitment")]
+    InvalidWordReveal,
+    #[msg("Daily word has not been revealed for this period")]
+    DailyWordNotRevealed,
+    #[msg("Invalid word strategy (must be 0 or 1)")]
+    InvalidWordStrategy,
+    #[msg("Word list for this period type is empty")]
+    WordListEmpty,
+    #[msg("Committed session does not belong to this profile")]
+    ProfileSessionMismatch,
+    #[msg("Username must only contain ASCII characters")]
+    UsernameNotAscii,
+    #[msg("Vault ledger counter overflow")]
+    VaultLedgerOverflow,
+    #[msg("Guess must only contain ASCII letters (A-Z)")]
+    NonAsciiGuess,
+    #[msg("Word difficulty must be between 1 and 5")]
+    InvalidWordDifficulty,
+    #[msg("Standings chunk must continue from the export cursor and hold 1-20 entries")]
+    InvalidStandingsChunk,
+    #[msg("Period has reached its participant cap")]
+    PeriodFull,
+    #[msg("Period leaderboard account is required while a participant cap is set")]
+    PeriodLeaderboardRequired,
+    #[msg("Invalid leaderboard mode (must be 0 or 1)")]
+    InvalidLeaderboardMode,
+    #[msg("Daily leaderboard has already been rolled into the weekly and monthly boards")]
+    DailyAlreadyRolledUp,
+    #[msg("Keystroke limit exceeded (40 per guess, 200 per game)")]
+    KeystrokeLimitExceeded,
+    #[msg("This feature is not enabled")]
+    FeatureDisabled,
+    #[msg("Invalid feature flag (must be below 64)")]
+    InvalidFeatureFlag,
+    #[msg("This period is paused")]
+    PeriodPaused,
+    #[msg("Session has not been delegated long enough to be force-undelegated")]
+    SessionNotStale,
+    #[msg("Session was replaced by recover_stale_session; play on the player's current session")]
+    SessionRetired,
+    #[msg("Session isn't stuck on the ER; use force_undelegate_stale_session or play it normally")]
+    SessionNotStuck,
+    #[msg("Too many sessions are delegated to the ER right now, try again shortly")]
+    TooManyActiveSessions,
+    #[msg("Leaderboard periods don't match the period the ticket was bought in")]
+    SettlementPeriodMismatch,
+    #[msg("Insufficient USDC balance to buy a ticket")]
+    InsufficientTicketFunds,
+    #[msg("Bio is too long (max 160 bytes) or contains control characters")]
+    InvalidBio,
+    #[msg("Avatar URI must be an https:// or ipfs:// link of at most 128 bytes")]
+    InvalidAvatarUri,
+    #[msg("Country code must be two uppercase letters")]
+    InvalidCountryCode,
+    #[msg("Text contains inappropriate content")]
+    InappropriateContent,
+    #[msg("Players drew their own words this period; there is no shared word to reveal")]
+    PerPlayerWords,
+    #[msg("Session guesses don't match the transcript recorded at completion")]
+    TranscriptMismatch,
+    #[msg("This wallet has been blocked from playing")]
+    PlayerBlocked,
+    #[msg("Period has already started")]
+    PeriodAlreadyStarted,
+    #[msg("Entitlement has not been claimed yet")]
+    EntitlementNotClaimed,
+    #[msg("Entitlement was already claimed and closed")]
+    EntitlementClosed,
+    #[msg("Too many session actions in a short time; try again in a few seconds")]
+    RateLimited,
+    #[msg("Settled score doesn't match the one its guesses and time would earn")]
+    ScoreMismatch,
+    #[msg("Settled time is too short for the number of guesses made")]
+    ImplausibleTime,
+    #[msg("Mulligans are not enabled")]
+    MulliganDisabled,
+    #[msg("The mulligan for this game has already been used")]
+    MulliganUsed,
+    #[msg("Period type must be \"daily\", \"weekly\" or \"monthly\"")]
+    InvalidPeriodType,
+    #[msg("A shared word was already assigned to this or a later period")]
+    SharedWordAlreadyAssigned,
+    #[msg("Period has been canceled")]
+    PeriodCanceled,
+    #[msg("Period has not been canceled")]
+    PeriodNotCanceled,
+    #[msg("This ticket's contribution to the period was already refunded")]
+    AlreadyRefunded,
+    #[msg("No ticket contribution to refund for this period")]
+    NothingToRefund,
+    #[msg("This change must be queued with queue_admin_action first")]
+    TimelockRequired,
+    #[msg("The queued admin action's delay has not passed yet")]
+    TimelockNotMatured,
+    #[msg("Queued admin action doesn't match this call's arguments")]
+    TimelockPayloadMismatch,
+    #[msg("Admin action must execute at least the configured timelock delay from now")]
+    TimelockTooShort,
+    #[msg("Handler leaderboards must be the session's daily, weekly and monthly boards, in that order")]
+    HandlerAccountOrderInvalid,
+    #[msg("Tickets can only be bought for the current daily period")]
+    PeriodNotCurrent,
+    #[msg("Period ID prefix doesn't match the period type (D daily, W weekly, M monthly)")]
+    PeriodTypeMismatch,
+    #[msg("Currency symbol must be 1-8 printable ASCII characters")]
+    InvalidCurrencySymbol,
+    #[msg("Future leaderboard count must be between 1 and 31, with one account per board")]
+    FutureLeaderboardCountInvalid,
+    #[msg("A duel needs a stake above zero and an opponent other than the challenger")]
+    InvalidDuel,
+    #[msg("Duel is not in the required state for this action")]
+    DuelWrongStatus,
+    #[msg("The duel's acceptance window has closed")]
+    DuelAcceptWindowClosed,
+    #[msg("Duel can't be refunded yet")]
+    DuelNotRefundable,
+    #[msg("Session must be created with initialize_session by this player before delegating")]
+    SessionNotInitialized,
+    #[msg("Word lists contain words outside the configured character class")]
+    WordListCharClassMismatch,
+    #[msg("Token account or mint doesn't match the configured USDC mint")]
+    PayoutMintMismatch,
+    #[msg("Receiving token account is frozen")]
+    PayoutAccountFrozen,
+    #[msg("Receiving token account belongs to another wallet")]
+    PayoutOwnerMismatch,
+    #[msg("Word source must be 0 (daily), 1 (weekly) or 2 (monthly)")]
+    InvalidWordSource,
+    #[msg("Every day of the period must be rolled up before the board is finalized")]
+    RollupIncomplete,
+    #[msg("Leaderboard uses the old entry layout; run migrate_leaderboard first")]
+    LegacyLeaderboardLayout,
+    #[msg("Config currency decimals don't match the payment mint's decimals")]
+    CurrencyDecimalsMismatch,
+    #[msg("No shared word has been assigned to this period")]
+    SharedWordNotAssigned,
+    #[msg("Daily word has not been committed for this period")]
+    DailyWordNotCommitted,
+    #[msg("Daily word reveal deadline has not passed yet")]
+    DailyWordRevealPending,
+    #[msg("Commit-reveal periods play the daily word list; set ticket_word_source to 0")]
+    CommitRevealNeedsDailyWords,
 }
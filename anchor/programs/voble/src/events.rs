@@ -1,10 +1,39 @@
 use anchor_lang::prelude::*;
-use crate::state::{LetterResult, PeriodType};
+use crate::state::{
+    AdminActionKind, AggregationStrategy, LetterResult, PeriodType, RemainderPolicy, ScoreDeadlineRule, SessionState,
+    VaultKind,
+};
 
 #[event]
 pub struct GlobalConfigInitialized {
     pub authority: Pubkey,
     pub ticket_price: u64,
+    pub currency_symbol: String,
+    pub currency_decimals: u8,
+}
+
+#[event]
+pub struct ConfigGrown {
+    pub authority: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub fully_allocated: bool,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub authority: Pubkey,
+    pub legacy_config: Pubkey,
+    pub ticket_price: u64,
+    pub reclaimed_lamports: u64,
+}
+
+#[event]
+pub struct ProgramVersionRecorded {
+    pub authority: Pubkey,
+    pub previous_version: u16,
+    pub program_version: u16,
+    pub recorded_at: i64,
 }
 
 #[event]
@@ -16,6 +45,10 @@ pub struct TicketPurchased {
     pub monthly_amount: u64,
     pub platform_amount: u64,
     pub lucky_draw_amount: u64, 
+    pub period_id: String,
+    pub new_participant: bool, // First ticket of this player in the period
+    pub schedule_override: bool, // Pool amounts follow the period's PrizeScheduleOverride
+    pub currency_symbol: String, // GlobalConfig.currency_symbol, for display
 }
 
 #[event]
@@ -41,6 +74,22 @@ pub struct PeriodFinalized {
     pub period_id: String,
     pub vault_balance: u64,
     pub winner_amounts: Vec<u64>,
+    pub winner_splits: Vec<u16>,
+    pub winner_ranks: Vec<u8>, // Rank per winner; tied winners share one when prizes are split
+    pub program_version: u16,
+}
+
+#[event]
+pub struct PeriodFinalizePreview {
+    pub period_type: String,
+    pub period_id: String,
+    pub vault_balance: u64,
+    pub allocated_balance: u64,
+    pub reserved: u64,
+    pub total_participants: u32,
+    pub winners: Vec<Pubkey>,
+    pub winner_ranks: Vec<u8>,
+    pub winner_amounts: Vec<u64>,
 }
 
 #[event]
@@ -49,9 +98,37 @@ pub struct PrizeClaimed {
     pub period_type: String,
     pub period_id: String,
     pub rank: u8,
+    pub amount: u64,         // Paid to `destination` (the whole prize unless split)
+    pub destination: Pubkey, // Wallet paid (the winner unless split)
+    pub split_bps: u16,      // Share of the prize paid to `destination`
+}
+
+#[event]
+pub struct PrizeEstimated {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub period_type: u8,
+    pub rank: Option<u8>,
     pub amount: u64,
 }
 
+#[event]
+pub struct PrizesRolledOver {
+    pub period_type: String,
+    pub period_id: String,
+    pub total_participants: u32,
+    pub min_participants: u32,
+    pub amount: u64, // Vault balance left in place for the next period
+}
+
+#[event]
+pub struct PrizeRemainderRouted {
+    pub period_type: String,
+    pub period_id: String,
+    pub policy: RemainderPolicy,
+    pub amount: u64, // Left in the prize vault, owed to the lucky draw or platform
+}
+
 #[event]
 pub struct PeriodFinalizedWithWinners {
     pub period_type: String,
@@ -80,6 +157,39 @@ pub struct PlatformRevenueWithdrawn {
     pub remaining_balance: u64,
 }
 
+#[event]
+pub struct AdminActionQueued {
+    pub authority: Pubkey,
+    pub kind: AdminActionKind,
+    pub payload_hash: [u8; 32],
+    pub queued_at: i64,
+    pub execute_after: i64,
+}
+
+#[event]
+pub struct AdminActionExecuted {
+    pub authority: Pubkey,
+    pub kind: AdminActionKind,
+    pub payload_hash: [u8; 32],
+}
+
+#[event]
+pub struct AdminActionCanceled {
+    pub authority: Pubkey,
+    pub kind: AdminActionKind,
+    pub payload_hash: [u8; 32],
+}
+
+#[event]
+pub struct PlatformRevenueSwept {
+    pub cranker: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub bounty: u64,
+    pub remaining_balance: u64,
+    pub swept_at: i64,
+}
+
 #[event]
 pub struct SessionEnded {
     pub player: Pubkey,
@@ -97,6 +207,30 @@ pub struct UserProfileCreated {
     pub created_at: i64,
 }
 
+#[event]
+pub struct WinnerNotified {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub period_type: String,
+    pub rank: u8,
+    pub lamports: u64,
+    pub memo: String, // "You won" message for wallets and indexers to surface
+}
+
+#[event]
+pub struct PublicProfileUpdated {
+    pub player: Pubkey,
+    pub bio: String,
+    pub country: [u8; 2],
+    pub avatar_uri: String,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct PublicProfileClosed {
+    pub player: Pubkey,
+}
+
 #[event]
 pub struct GameSessionStarted {
     pub player: Pubkey,
@@ -122,6 +256,25 @@ pub struct KeystrokeRecorded {
     pub timestamp_ms: u64,
     pub current_input: String,
     pub guess_index: u8,
+    pub game_epoch: u64,
+}
+
+#[event]
+pub struct GuessRetyped {
+    pub player: Pubkey,
+    pub session_id: String,
+    pub current_input: String, // Buffer after the mulligan
+    pub guess_index: u8,
+    pub game_epoch: u64,
+}
+
+#[event]
+pub struct KeystrokeRejected {
+    pub player: Pubkey,
+    pub session_id: String,
+    pub guess_index: u8,
+    pub rejected_keystrokes: u16,
+    pub game_epoch: u64,
 }
 
 #[event]
@@ -154,6 +307,22 @@ pub struct AchievementUnlocked {
     pub unlocked_at: i64,
 }
 
+#[event]
+pub struct AchievementsSynced {
+    pub player: Pubkey,
+    pub unlocked: Vec<u8>,
+    pub synced_at: i64,
+}
+
+#[event]
+pub struct AchievementProgress {
+    pub player: Pubkey,
+    pub achievement_id: u8,
+    pub current: u32,
+    pub target: u32,
+    pub remaining: u32, // 0 = qualifies, unlocks at the next settlement or sync
+}
+
 #[event]
 pub struct BatchLeaderboardMigrated {
     pub player: Pubkey,
@@ -198,6 +367,9 @@ pub struct VobleGameStarted {
     pub period_id: String,
     pub target_word_hash: String, // Should be hashed in production
     pub timestamp: i64,
+    pub difficulty: u8, // Word difficulty snapshotted at ticket purchase (1-5)
+    pub current_entrants: u32, // Players on today's board, this one included, at ticket purchase
+    pub prize_pool: u64,       // Daily prize vault balance at ticket purchase
 }
 
 #[event]
@@ -208,6 +380,246 @@ pub struct GuessSubmitted {
     pub guess_number: u8,
     pub is_correct: bool,
     pub result: [LetterResult; 6],
+    pub game_epoch: u64,
+}
+
+#[event]
+pub struct ShareableBoard {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub board: Vec<[LetterResult; 6]>, // One row per guess, no letters
+    pub guesses_used: u8,
+    pub is_solved: bool,
+}
+
+#[event]
+pub struct HappyHourSet {
+    pub period_id: String,
+    pub start: i64,
+    pub end: i64,
+    pub multiplier_bps: u16,
+}
+
+#[event]
+pub struct LeaderboardUsernameUpdated {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub period_type: u8,
+    pub username: String,
+}
+
+#[event]
+pub struct SessionForceClosed {
+    pub player: Pubkey,
+    pub authority: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub previous_state: SessionState,
+}
+
+#[event]
+pub struct PeriodTiming {
+    pub epoch_start: i64,
+    pub now: i64,
+    pub daily_period_id: String,
+    pub daily_ends_at: i64,
+    pub weekly_period_id: String,
+    pub weekly_ends_at: i64,
+    pub monthly_period_id: String,
+    pub monthly_ends_at: i64,
+}
+
+#[event]
+pub struct CommitScheduled {
+    pub player: Pubkey,
+    pub game_epoch: u64,
+    pub state: SessionState,
+    pub handlers: u8,
+    pub compute_units: u32,
+    pub escrow_index: u8,
+}
+
+#[event]
+pub struct ActiveSessionsChanged {
+    pub player: Pubkey,
+    pub holds_slot: bool,
+    pub active_sessions: u32,
+}
+
+#[event]
+pub struct StaleSessionUndelegated {
+    pub player: Pubkey,
+    pub cranker: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub previous_state: SessionState,
+    pub started_at: i64,
+}
+
+#[event]
+pub struct StaleSessionRecovered {
+    pub player: Pubkey,
+    pub cranker: Pubkey,
+    pub retired_session: Pubkey,
+    pub session_nonce: u32, // Generation the player's next session is created under
+    pub game_epoch: u64,
+    pub previous_state: SessionState,
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct DailyWordCommitted {
+    pub period_id: String,
+    pub commitment: [u8; 32],
+    pub reveal_deadline: i64,
+}
+
+#[event]
+pub struct DailyWordRevealed {
+    pub period_id: String,
+    pub word_index: u32,
+    pub salt: [u8; 32],
+}
+
+#[event]
+pub struct PeriodWordRevealed {
+    pub period_id: String,
+    pub word_index: u32,
+    pub word: String,
+}
+
+#[event]
+pub struct PeriodPauseSet {
+    pub period_id: String,
+    pub period_type: PeriodType,
+    pub paused: bool,
+    pub paused_periods: u32,
+}
+
+#[event]
+pub struct PeriodCanceled {
+    pub period_id: String,
+    pub period_type: PeriodType,
+    pub entrants: u32,
+    pub canceled_at: i64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub player: Pubkey,
+    pub period_id: String,        // Canceled period
+    pub period_type: PeriodType,
+    pub ticket_period_id: String, // Daily period the tickets were bought in
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeatureToggled {
+    pub flag: u8,
+    pub enabled: bool,
+    pub enabled_flags: u64,
+    pub activated_at: i64, // 0 while the feature is off
+}
+
+#[event]
+pub struct SharedWordAssigned {
+    pub period_id: String,
+    pub word_index: u32,   // Index into VOBLE_WORDS
+    pub hashed_index: u32, // word_index_for_period before probing past recent words
+}
+
+#[event]
+pub struct WordDifficultySet {
+    pub word_index: u32,
+    pub difficulty: u8,
+}
+
+#[event]
+pub struct AuthorityCheck {
+    pub signer: Pubkey,
+    pub is_authority: bool,
+}
+
+#[event]
+pub struct FlairListed {
+    pub flair_id: u8,
+    pub cost: u64,
+    pub supply_cap: u32,
+}
+
+#[event]
+pub struct FlairPurchased {
+    pub player: Pubkey,
+    pub flair_id: u8,
+    pub cost: u64,
+    pub points_balance: u64,
+    pub minted: u32,
+}
+
+#[event]
+pub struct FlairEquipped {
+    pub player: Pubkey,
+    pub flair_id: u8,
+}
+
+#[event]
+pub struct GameSettled {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub is_solved: bool,
+    pub guesses_used: u8,
+    pub score: u32,
+    pub multiplier_bps: u16,
+    pub difficulty: u8,
+    pub distribution_bucket: Option<u8>, // guess_distribution index incremented by this game (wins only)
+    pub previous_average_guesses: f32,
+    pub average_guesses: f32,
+    pub rejected_keystrokes: u16, // Anti-abuse signal: keystrokes dropped by record_keystroke limits
+    pub deadline_rule: ScoreDeadlineRule, // Whether the game reached its period's leaderboards
+    pub program_version: u16,             // Version the game was played under (SessionAccount.program_version)
+}
+
+#[event]
+pub struct LateScore {
+    pub player: Pubkey,
+    pub period_id: String, // Period the ticket was bought in
+    pub game_epoch: u64,
+    pub score: u32, // Counted on the profile but not on any leaderboard
+}
+
+#[event]
+pub struct ScoreFlagged {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub score: u32, // Counted on the profile but not on any leaderboard
+    pub guesses_used: u8,
+    pub time_ms: u64,
+}
+
+#[event]
+pub struct StreakMilestoneReached {
+    pub player: Pubkey,
+    pub streak: u32, // Milestone from STREAK_MILESTONES
+    pub period_id: String,
+}
+
+#[event]
+pub struct StreakBroken {
+    pub player: Pubkey,
+    pub streak: u32, // Length of the streak that was lost
+    pub period_id: String,
+}
+
+#[event]
+pub struct HoleInOne {
+    pub player: Pubkey,
+    pub period_id: String,
+    pub game_epoch: u64,
+    pub score: u32, // Final score, bonus included
+    pub bonus: u32, // Hole in one bonus added to the score (0 = bonus off)
 }
 
 #[event]
@@ -243,9 +655,19 @@ pub struct VobleStatsCalculated {
 pub struct LeaderboardInitialized {
     pub period_id: String,
     pub period_type: PeriodType,
+    pub aggregation: AggregationStrategy,
     pub created_at: i64,
 }
 
+#[event]
+pub struct LeaderboardGrown {
+    pub period_id: String,
+    pub period_type: PeriodType,
+    pub data_len: u32,
+    pub capacity: u16,
+    pub fully_allocated: bool,
+}
+
 #[event]
 pub struct LeaderboardUpdated {
     pub period_id: String,
@@ -273,3 +695,127 @@ pub struct LeaderboardFinalized {
     pub finalized_at: i64,
 }
 
+
+#[event]
+pub struct PlayerRanks {
+    pub player: Pubkey,
+    pub daily_period_id: String,
+    pub weekly_period_id: String,
+    pub monthly_period_id: String,
+    pub daily: Option<u8>,
+    pub weekly: Option<u8>,
+    pub monthly: Option<u8>,
+}
+
+#[event]
+pub struct DailyRolledUp {
+    pub daily_period_id: String,
+    pub weekly_period_id: String,
+    pub monthly_period_id: String,
+    pub entries: u32, // Daily entries folded into each rollup-mode board
+}
+
+#[event]
+pub struct FinalStandingEntry {
+    pub period_id: String,
+    pub period_type: u8,
+    pub rank: u8, // 1-based
+    pub player: Pubkey,
+    pub username: String,
+    pub score: u32,
+}
+
+#[event]
+pub struct LeaderboardChecksum {
+    pub period_id: String,
+    pub period_type: u8,
+    pub entry_count: u32,
+    pub checksum: [u8; 32], // leaderboard_checksum over the entries in board order
+}
+
+#[event]
+pub struct EntitlementClosed {
+    pub player: Pubkey,
+    pub period_type: String,
+    pub period_id: String,
+    pub rank: u8,
+    pub amount: u64,
+    pub closed_by: Pubkey,
+    pub claimed_count: u8, // Claimed entitlements closed in this period so far
+}
+
+#[event]
+pub struct PrizeScheduleOverrideSet {
+    pub period_id: String,
+    pub prize_split_daily: u16,
+    pub prize_split_weekly: u16,
+    pub prize_split_monthly: u16,
+}
+
+#[event]
+pub struct PrizeScheduleOverrideClosed {
+    pub period_id: String,
+}
+
+#[event]
+pub struct PlayerBlocked {
+    pub player: Pubkey,
+    pub authority: Pubkey,
+    pub blocked_at: i64,
+}
+
+#[event]
+pub struct PlayerUnblocked {
+    pub player: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct VaultReport {
+    pub kind: VaultKind,
+    pub vault: Pubkey,
+    pub opening_balance: u64,
+    pub total_in: u128,
+    pub total_out: u128,
+    pub balance: u64,
+    pub discrepancy: i128, // balance - (opening_balance + total_in - total_out)
+    pub last_updated: i64,
+}
+
+#[event]
+pub struct DuelCreated {
+    pub duel: Pubkey,
+    pub challenger: Pubkey,
+    pub opponent: Pubkey,
+    pub period_id: String,
+    pub stake: u64,
+    pub accept_deadline: i64,
+}
+
+#[event]
+pub struct DuelAccepted {
+    pub duel: Pubkey,
+    pub challenger: Pubkey,
+    pub opponent: Pubkey,
+    pub period_id: String,
+    pub pot: u64,
+}
+
+#[event]
+pub struct DuelResolved {
+    pub duel: Pubkey,
+    pub period_id: String,
+    pub challenger_score: u32,
+    pub opponent_score: u32,
+    pub winner: Option<Pubkey>, // None = tie, both stakes returned
+    pub payout: u64,            // Paid to the winner (stake each on a tie)
+    pub fee: u64,               // Sent to the platform vault
+}
+
+#[event]
+pub struct DuelRefunded {
+    pub duel: Pubkey,
+    pub period_id: String,
+    pub challenger_refund: u64,
+    pub opponent_refund: u64,
+}
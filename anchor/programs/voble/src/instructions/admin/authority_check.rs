@@ -0,0 +1,25 @@
+use crate::{contexts::*, events::*};
+use anchor_lang::prelude::*;
+
+/// Report whether the signer is the config authority
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config and the signer
+///
+/// # Notes
+/// - Read-only and never fails on a non-authority signer; the answer is
+///   the `AuthorityCheck` event (simulate the transaction to read it)
+/// - Lets clients hide admin actions without decoding the config account
+pub fn is_authority(ctx: Context<IsAuthority>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let is_authority = signer == ctx.accounts.global_config.authority;
+
+    msg!("🔑 Authority check for {}: {}", signer, is_authority);
+
+    emit!(AuthorityCheck {
+        signer,
+        is_authority,
+    });
+
+    Ok(())
+}
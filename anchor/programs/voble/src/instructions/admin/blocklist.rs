@@ -0,0 +1,113 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::BlockedPlayer};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Ban a wallet from buying tickets
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the new blocklist entry and authority
+/// * `player` - Wallet to block (used for PDA derivation)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - The player must not already be blocked (the entry PDA must not exist)
+///
+/// # Notes
+/// - A game already in progress can still be finished and settled; only new
+///   tickets are rejected (`PlayerBlocked`)
+pub fn block_player(ctx: Context<BlockPlayer>, player: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let blocked_player = &mut ctx.accounts.blocked_player;
+    blocked_player.player = player;
+    blocked_player.blocked_at = now;
+    blocked_player.bump = ctx.bumps.blocked_player;
+
+    emit!(PlayerBlocked {
+        player,
+        authority: ctx.accounts.authority.key(),
+        blocked_at: now,
+    });
+
+    msg!("🚫 Player blocked: {}", player);
+
+    Ok(())
+}
+
+/// Lift a ban and return the entry's rent to the authority
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the blocklist entry and authority
+/// * `player` - Wallet to unblock (used for PDA derivation)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - The player must currently be blocked
+pub fn unblock_player(ctx: Context<UnblockPlayer>, player: Pubkey) -> Result<()> {
+    emit!(PlayerUnblocked {
+        player,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    msg!("✅ Player unblocked: {}", player);
+
+    Ok(())
+}
+
+/// Reject ticket purchases from a blocked wallet
+///
+/// # Arguments
+/// * `owner` - Owner of the player's blocklist PDA
+/// * `data` - Data of the player's blocklist PDA
+///
+/// # Validation
+/// - The PDA must not hold a `BlockedPlayer` account (`PlayerBlocked`); an
+///   uninitialized or closed PDA means the player is not blocked
+pub fn require_not_blocked(owner: &Pubkey, data: &[u8]) -> Result<()> {
+    let blocked = owner == &crate::ID && data.starts_with(BlockedPlayer::DISCRIMINATOR);
+    require!(!blocked, VobleError::PlayerBlocked);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocked_entry(player: Pubkey) -> Vec<u8> {
+        let mut data = Vec::new();
+        BlockedPlayer {
+            player,
+            blocked_at: 1_700_000_000,
+            bump: 255,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_blocked_player_cannot_start_game() {
+        let data = blocked_entry(Pubkey::new_unique());
+
+        assert_eq!(
+            require_not_blocked(&crate::ID, &data).unwrap_err(),
+            VobleError::PlayerBlocked.into()
+        );
+    }
+
+    #[test]
+    fn test_unblocked_player_can_start_game() {
+        // Never blocked: the PDA is an empty system account
+        assert!(require_not_blocked(&anchor_lang::system_program::ID, &[]).is_ok());
+
+        // Unblocked: closed entries are handed back to the system program
+        assert!(require_not_blocked(&crate::ID, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_entry_must_be_owned_by_program() {
+        let data = blocked_entry(Pubkey::new_unique());
+
+        assert!(require_not_blocked(&Pubkey::new_unique(), &data).is_ok());
+    }
+}
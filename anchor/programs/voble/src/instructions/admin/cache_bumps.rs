@@ -0,0 +1,158 @@
+use crate::{contexts::*, errors::VobleError, state::*, utils::pda};
+use crate::instructions::leaderboard::is_legacy_leaderboard;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Cache the canonical PDA bumps on the global config
+///
+/// Configs created by `initialize_global_config` + `initialize_vaults` already
+/// have their bumps cached. This one-off instruction backfills them for configs
+/// created before caching existed; until it runs, contexts that read
+/// `global_config.bumps` reject the config.
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config account and authority
+///
+/// # Validation
+/// - Only the authority can call this instruction
+///
+/// # Notes
+/// Derives every bump once with `find_program_address`. Afterwards every
+/// context verifies the config and vault PDAs with the cached bump (a single
+/// `create_program_address`) instead of searching for it.
+pub fn cache_config_bumps(ctx: Context<CacheConfigBumps>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+
+    config.bumps = pda::derive_config_bumps(&crate::ID);
+
+    msg!("📌 Config bumps cached");
+    msg!("   Config: {}", config.bumps.global_config);
+    msg!(
+        "   Vaults: daily={}, weekly={}, monthly={}, platform={}, lucky_draw={}",
+        config.bumps.daily_prize_vault,
+        config.bumps.weekly_prize_vault,
+        config.bumps.monthly_prize_vault,
+        config.bumps.platform_vault,
+        config.bumps.lucky_draw_vault
+    );
+
+    Ok(())
+}
+
+/// Measure verifying the config and vault PDAs with and without cached bumps (developer builds only)
+///
+/// Only compiled with the `debug-logs` feature. Re-derives all six PDAs in
+/// `ConfigBumps` either by searching for the bump (`find_program_address`,
+/// what a bare `bump` constraint does) or from the cached bump
+/// (`create_program_address`), and logs the compute units spent.
+///
+/// # Arguments
+/// * `ctx` - The (empty) benchmark context
+/// * `cached` - Derive from the cached bumps instead of searching
+#[cfg(feature = "debug-logs")]
+pub fn bench_config_bumps(_ctx: Context<BenchConfigBumps>, cached: bool) -> Result<()> {
+    use crate::constants::*;
+
+    let seeds: [&[u8]; 6] = [
+        SEED_GLOBAL_CONFIG,
+        SEED_DAILY_PRIZE_VAULT,
+        SEED_WEEKLY_PRIZE_VAULT,
+        SEED_MONTHLY_PRIZE_VAULT,
+        SEED_PLATFORM_VAULT,
+        SEED_LUCKY_DRAW_VAULT,
+    ];
+    let expected = seeds.map(|seed| Pubkey::find_program_address(&[seed], &crate::ID));
+
+    let mut addresses = [Pubkey::default(); 6];
+    let mut meter = crate::utils::debug::ComputeMeter::start("bench_config_bumps");
+    for (address, (seed, (_, bump))) in addresses.iter_mut().zip(seeds.iter().zip(expected)) {
+        *address = if cached {
+            Pubkey::create_program_address(&[seed, &[bump]], &crate::ID)
+                .map_err(|_| ErrorCode::ConstraintSeeds)?
+        } else {
+            Pubkey::find_program_address(&[seed], &crate::ID).0
+        };
+    }
+    meter.checkpoint("derive pdas");
+
+    for (address, (expected, _)) in addresses.iter().zip(expected) {
+        require_keys_eq!(*address, expected, ErrorCode::ConstraintSeeds);
+    }
+    Ok(())
+}
+
+/// Store the canonical bump on period accounts created before bumps were stored
+///
+/// Leaderboards, period states and winner entitlements now carry their own
+/// bump, and their contexts verify the PDA with it. Accounts created before
+/// that read a bump of 0 and are rejected until this instruction backfills
+/// them (e.g. an unclaimed entitlement from an earlier period).
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config and authority
+///
+/// # Remaining Accounts
+/// Writable PeriodLeaderboard, PeriodState or WinnerEntitlement accounts
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Each account must be owned by this program and sit at the PDA derived
+///   from its own fields
+///
+/// # Notes
+/// - Sessions are backfilled by `migrate_session` instead, since they usually
+///   live on the ER
+/// - Leaderboards in the old entry layout are rejected
+///   (`LegacyLeaderboardLayout`); `migrate_leaderboard` stores their bump
+pub fn backfill_account_bumps(ctx: Context<BackfillAccountBumps>) -> Result<()> {
+    for info in ctx.remaining_accounts.iter() {
+        require!(info.owner == &crate::ID, VobleError::Unauthorized);
+        require!(info.is_writable, VobleError::Unauthorized);
+
+        let mut data = info.try_borrow_mut_data()?;
+        let bump = if data.starts_with(PeriodLeaderboard::DISCRIMINATOR) {
+            // Parsing the old entry layout as the current one misreads it
+            require!(!is_legacy_leaderboard(&data), VobleError::LegacyLeaderboardLayout);
+            let mut leaderboard = PeriodLeaderboard::try_deserialize(&mut &data[..])?;
+            let (address, bump) = pda::derive_leaderboard_pda(
+                &leaderboard.period_id,
+                leaderboard.period_type,
+                &crate::ID,
+            );
+            require_keys_eq!(address, info.key(), VobleError::Unauthorized);
+            leaderboard.bump = bump;
+            leaderboard.try_serialize(&mut &mut data[..])?;
+            bump
+        } else if data.starts_with(PeriodState::DISCRIMINATOR) {
+            let mut period_state = PeriodState::try_deserialize(&mut &data[..])?;
+            let prefix = pda::get_period_seed_prefix(&period_state.period_type)
+                .ok_or(VobleError::InvalidPeriodState)?;
+            let (address, bump) = Pubkey::find_program_address(
+                &[prefix, period_state.period_id.as_bytes()],
+                &crate::ID,
+            );
+            require_keys_eq!(address, info.key(), VobleError::Unauthorized);
+            period_state.bump = bump;
+            period_state.try_serialize(&mut &mut data[..])?;
+            bump
+        } else if data.starts_with(WinnerEntitlement::DISCRIMINATOR) {
+            let mut entitlement = WinnerEntitlement::try_deserialize(&mut &data[..])?;
+            let (address, bump) = pda::derive_winner_entitlement_pda(
+                &entitlement.player,
+                &entitlement.period_type,
+                &entitlement.period_id,
+                &crate::ID,
+            );
+            require_keys_eq!(address, info.key(), VobleError::Unauthorized);
+            entitlement.bump = bump;
+            entitlement.try_serialize(&mut &mut data[..])?;
+            bump
+        } else {
+            return err!(VobleError::InvalidPeriodState);
+        };
+
+        msg!("📌 Bump {} stored on {}", bump, info.key());
+    }
+
+    Ok(())
+}
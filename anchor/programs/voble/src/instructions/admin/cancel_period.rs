@@ -0,0 +1,105 @@
+use super::record_pool_cancellation;
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Void a compromised period (bad word, exploit) and open it for refunds
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the leaderboard, its prize
+///   vault's ledger and authority
+/// * `period_id` - Period to cancel (e.g., "D123")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Leaderboard must match the period ID and type (PDA seeds)
+/// - Leaderboard must not be finalized or already canceled
+/// - The period's pool must still be tracked by the vault ledger (its current
+///   or previous pool, or a later period)
+///
+/// # Notes
+/// - Cancellation is permanent: the board can't be finalized, so the period
+///   pays no prizes
+/// - A canceled daily board rejects new tickets and guesses for its period
+///   with `PeriodCanceled`
+/// - Players recover their tickets' share of this period's pool with
+///   `claim_refund`; the other pools their tickets fed are unaffected
+/// - The pool is held back from the shared prize vault as `refund_liability`
+///   until refunded, so later periods' prizes can't spend it
+pub fn cancel_period(
+    ctx: Context<CancelPeriod>,
+    period_id: String,
+    period_type: u8,
+) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let now = Clock::get()?.unix_timestamp;
+
+    apply_cancellation(leaderboard)?;
+    let liability = record_pool_cancellation(&mut ctx.accounts.vault_ledger, &leaderboard.period_id)?;
+
+    msg!("🚫 Period {} (type {}) canceled", period_id, period_type);
+    msg!("   Entrants eligible for refunds: {}", leaderboard.participants_count());
+    msg!("   Pool held for refunds: {} base units", liability);
+
+    emit!(PeriodCanceled {
+        period_id,
+        period_type: leaderboard.period_type,
+        entrants: leaderboard.participants_count(),
+        canceled_at: now,
+    });
+
+    Ok(())
+}
+
+/// Mark a leaderboard canceled
+///
+/// # Validation
+/// - Leaderboard must not be finalized (`PeriodAlreadyFinalized`)
+/// - Leaderboard must not be canceled already (`PeriodCanceled`)
+pub fn apply_cancellation(leaderboard: &mut PeriodLeaderboard) -> Result<()> {
+    require!(!leaderboard.finalized, VobleError::PeriodAlreadyFinalized);
+    require!(!leaderboard.canceled, VobleError::PeriodCanceled);
+
+    leaderboard.canceled = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::admin::require_period_open;
+
+    fn daily_board() -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("D42", PeriodType::Daily);
+        board.entrants = 3;
+        board
+    }
+
+    #[test]
+    fn test_canceled_period_rejects_play() {
+        let mut board = daily_board();
+        apply_cancellation(&mut board).unwrap();
+
+        assert!(board.canceled);
+        assert_eq!(
+            require_period_open(&board).unwrap_err(),
+            VobleError::PeriodCanceled.into()
+        );
+        assert_eq!(
+            apply_cancellation(&mut board).unwrap_err(),
+            VobleError::PeriodCanceled.into()
+        );
+    }
+
+    #[test]
+    fn test_finalized_period_cannot_be_canceled() {
+        let mut board = daily_board();
+        board.finalized = true;
+
+        assert_eq!(
+            apply_cancellation(&mut board).unwrap_err(),
+            VobleError::PeriodAlreadyFinalized.into()
+        );
+        assert!(!board.canceled);
+    }
+}
@@ -0,0 +1,513 @@
+use super::record_pool_cancellation;
+use crate::utils::period::{self, get_period_end_timestamp, get_period_start_timestamp};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+use solana_program::hash::hashv;
+
+/// Commit to a daily period's word before the period starts
+///
+/// Part of the commit-reveal word strategy: publishing
+/// `sha256(word_index_le || salt)` up front lets anyone check after the
+/// period that the word wasn't picked once results were known.
+///
+/// # Arguments
+/// * `ctx` - The context containing the commitment account and authority
+/// * `period_id` - Daily period the word belongs to (e.g., "D123")
+/// * `commitment` - `word_commitment(word_index, salt)`
+/// * `reveal_deadline` - Latest timestamp at which the reveal is accepted
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Period must be a daily period that hasn't started yet
+/// - Reveal deadline must be after the period ends
+/// - One commitment per period (enforced by init constraint)
+///
+/// # Notes
+/// - Commit to the index `assign_shared_word` gives the period: sessions
+///   play that word, and the reveal must open to it
+pub fn commit_daily_word(
+    ctx: Context<CommitDailyWord>,
+    period_id: String,
+    commitment: [u8; 32],
+    reveal_deadline: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(
+        period_id.len() <= MAX_PERIOD_ID_LENGTH,
+        VobleError::PeriodIdTooLong
+    );
+    check_commit_window(
+        &period_id,
+        now,
+        reveal_deadline,
+        ctx.accounts.global_config.period_epoch(),
+    )?;
+
+    // ========== SAVE COMMITMENT ==========
+    let account = &mut ctx.accounts.daily_word_commitment;
+    account.period_id = period_id.clone();
+    account.commitment = commitment;
+    account.committed_at = now;
+    account.reveal_deadline = reveal_deadline;
+    account.revealed = false;
+    account.word_index = 0;
+    account.salt = [0; 32];
+    account.bump = ctx.bumps.daily_word_commitment;
+
+    msg!("🔒 Daily word committed for {}", period_id);
+    msg!("   Commitment: {:x?}", &commitment[..8]);
+    msg!("   Reveal deadline: {}", reveal_deadline);
+
+    emit!(DailyWordCommitted {
+        period_id,
+        commitment,
+        reveal_deadline,
+    });
+
+    Ok(())
+}
+
+/// Reveal a committed daily word once its period is over
+///
+/// # Arguments
+/// * `ctx` - The context containing the commitment account, the period's
+///   shared word and authority
+/// * `period_id` - Daily period the word belongs to
+/// * `word_index` - Index into VOBLE_WORDS (the daily list)
+/// * `salt` - Salt used when committing
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Period must have ended and the reveal deadline must not have passed
+/// - `word_commitment(word_index, salt)` must equal the stored commitment
+/// - `word_index` must be the shared word the period's sessions played
+/// - Word can only be revealed once
+///
+/// # Notes
+/// - `finalize_daily` copies the revealed index into the PeriodState
+/// - A commitment that doesn't open to the played word can't be revealed;
+///   once the deadline passes the period is voided with
+///   `cancel_unrevealed_period`
+pub fn reveal_daily_word(
+    ctx: Context<RevealDailyWord>,
+    period_id: String,
+    word_index: u32,
+    salt: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let epoch_start = ctx.accounts.global_config.period_epoch();
+    let account = &mut ctx.accounts.daily_word_commitment;
+
+    // ========== VALIDATION ==========
+    verify_word_reveal(
+        account,
+        word_index,
+        &salt,
+        ctx.accounts.shared_word.word_index,
+        now,
+        epoch_start,
+    )?;
+
+    // ========== SAVE REVEAL ==========
+    account.revealed = true;
+    account.word_index = word_index;
+    account.salt = salt;
+
+    msg!("🔓 Daily word revealed for {}", period_id);
+    msg!("   Word index: {}", word_index);
+
+    emit!(DailyWordRevealed {
+        period_id,
+        word_index,
+        salt,
+    });
+
+    Ok(())
+}
+
+/// Void a commit-reveal period whose word was never revealed
+///
+/// # Arguments
+/// * `ctx` - The context containing the commitment, the period's daily
+///   leaderboard and period state, and the daily vault ledger
+/// * `period_id` - Daily period to cancel (e.g., "D123")
+///
+/// # Validation
+/// - Permissionless
+/// - The word must not be revealed and its reveal deadline must have passed
+/// - The period's prizes must not be finalized (see `cancel_unrevealed_board`)
+/// - Leaderboard must not be canceled already
+///
+/// # Notes
+/// - Same outcome as `cancel_period` for the daily board: the period pays
+///   no prizes and its pool is held back for `claim_refund`
+/// - Unlike `cancel_period`, a finalized leaderboard can be canceled: boards
+///   are locked at period end, before the reveal deadline
+pub fn cancel_unrevealed_period(
+    ctx: Context<CancelUnrevealedPeriod>,
+    period_id: String,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require_reveal_missed(&ctx.accounts.daily_word_commitment, now)?;
+    let prizes_finalized = period_finalized(&ctx.accounts.period_state)?;
+
+    // ========== CANCEL ==========
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    cancel_unrevealed_board(leaderboard, prizes_finalized)?;
+    let liability = record_pool_cancellation(&mut ctx.accounts.vault_ledger, &leaderboard.period_id)?;
+
+    msg!("🚫 Period {} canceled: word never revealed", period_id);
+    msg!("   Reveal deadline: {}", ctx.accounts.daily_word_commitment.reveal_deadline);
+    msg!("   Pool held for refunds: {} base units", liability);
+
+    emit!(PeriodCanceled {
+        period_id,
+        period_type: leaderboard.period_type,
+        entrants: leaderboard.participants_count(),
+        canceled_at: now,
+    });
+
+    Ok(())
+}
+
+/// Mark a daily board canceled after its word reveal was missed
+///
+/// Unlike `apply_cancellation`, a finalized (locked) board is accepted: only
+/// prizes already finalized from it block the cancel.
+///
+/// # Validation
+/// - The period's prizes must not be finalized (`PeriodAlreadyFinalized`)
+/// - Leaderboard must not be canceled already (`PeriodCanceled`)
+pub fn cancel_unrevealed_board(leaderboard: &mut PeriodLeaderboard, prizes_finalized: bool) -> Result<()> {
+    require!(!prizes_finalized, VobleError::PeriodAlreadyFinalized);
+    require!(!leaderboard.canceled, VobleError::PeriodCanceled);
+
+    leaderboard.canceled = true;
+    Ok(())
+}
+
+/// Whether a period state account holds finalized prizes
+///
+/// `false` while the account doesn't exist yet, as `finalize_daily` creates it.
+fn period_finalized(info: &AccountInfo) -> Result<bool> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(false);
+    }
+    Ok(PeriodState::try_deserialize(&mut &info.try_borrow_data()?[..])?.finalized)
+}
+
+/// Commitment for a word index and salt: `sha256(word_index_le || salt)`
+pub fn word_commitment(word_index: u32, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&word_index.to_le_bytes()[..], salt]).to_bytes()
+}
+
+/// Check that a commitment is being published in time
+pub fn check_commit_window(
+    period_id: &str,
+    now: i64,
+    reveal_deadline: i64,
+    epoch_start: i64,
+) -> Result<()> {
+    let is_daily = matches!(
+        period::parse_period_id(period_id),
+        Some((period::PeriodType::Daily, _))
+    );
+    require!(is_daily, VobleError::InvalidPeriodState);
+
+    let start = get_period_start_timestamp(period_id, epoch_start)
+        .ok_or(VobleError::InvalidPeriodState)?;
+    let end = get_period_end_timestamp(period_id, epoch_start)
+        .ok_or(VobleError::InvalidPeriodState)?;
+
+    require!(now < start, VobleError::DailyWordCommitTooLate);
+    require!(reveal_deadline > end, VobleError::InvalidInput);
+
+    Ok(())
+}
+
+/// Check a reveal against the stored commitment and the word that was played
+pub fn verify_word_reveal(
+    commitment: &DailyWordCommitment,
+    word_index: u32,
+    salt: &[u8; 32],
+    played_index: u32,
+    now: i64,
+    epoch_start: i64,
+) -> Result<()> {
+    require!(!commitment.revealed, VobleError::DailyWordAlreadyRevealed);
+
+    let end = get_period_end_timestamp(&commitment.period_id, epoch_start)
+        .ok_or(VobleError::InvalidPeriodState)?;
+    require!(now >= end, VobleError::DailyWordRevealTooEarly);
+    require!(
+        now <= commitment.reveal_deadline,
+        VobleError::DailyWordRevealExpired
+    );
+
+    require!(
+        (word_index as usize) < VOBLE_WORDS.len(),
+        VobleError::InvalidWordReveal
+    );
+    require!(
+        word_commitment(word_index, salt) == commitment.commitment,
+        VobleError::InvalidWordReveal
+    );
+    require!(word_index == played_index, VobleError::InvalidWordReveal);
+
+    Ok(())
+}
+
+/// Commitment a new session records under the config's word strategy
+///
+/// # Returns
+/// The period's commitment, or zero under deterministic selection without one
+///
+/// # Errors
+/// `DailyWordNotCommitted` under `CommitReveal` without a commitment
+pub fn session_word_commitment(
+    strategy: WordStrategy,
+    commitment: Option<&DailyWordCommitment>,
+) -> Result<[u8; 32]> {
+    match (strategy, commitment) {
+        (_, Some(commitment)) => Ok(commitment.commitment),
+        (WordStrategy::Deterministic, None) => Ok([0; 32]),
+        (WordStrategy::CommitReveal, None) => err!(VobleError::DailyWordNotCommitted),
+    }
+}
+
+/// Check that a commitment's reveal deadline passed without a reveal
+pub fn require_reveal_missed(commitment: &DailyWordCommitment, now: i64) -> Result<()> {
+    require!(!commitment.revealed, VobleError::DailyWordAlreadyRevealed);
+    require!(
+        now > commitment.reveal_deadline,
+        VobleError::DailyWordRevealPending
+    );
+    Ok(())
+}
+
+/// Word index a commit-reveal period settles against
+///
+/// # Returns
+/// The revealed index, `DailyWordNotRevealed` while the reveal is still
+/// pending, or `DailyWordRevealExpired` once the deadline has passed
+///
+/// # Validation
+/// - A stored reveal must open the commitment (`InvalidWordReveal`)
+pub fn revealed_word_index(commitment: &DailyWordCommitment, now: i64) -> Result<u32> {
+    if commitment.revealed {
+        require!(
+            word_commitment(commitment.word_index, &commitment.salt) == commitment.commitment,
+            VobleError::InvalidWordReveal
+        );
+        return Ok(commitment.word_index);
+    }
+
+    require!(
+        now <= commitment.reveal_deadline,
+        VobleError::DailyWordRevealExpired
+    );
+    Err(VobleError::DailyWordNotRevealed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERIOD: &str = "D700";
+    const SALT: [u8; 32] = [42; 32];
+    const WORD_INDEX: u32 = 18;
+
+    fn period_end() -> i64 {
+        get_period_end_timestamp(PERIOD, PERIOD_EPOCH_START).unwrap()
+    }
+
+    fn committed() -> DailyWordCommitment {
+        DailyWordCommitment {
+            period_id: PERIOD.to_string(),
+            commitment: word_commitment(WORD_INDEX, &SALT),
+            committed_at: 0,
+            reveal_deadline: period_end() + 3_600,
+            revealed: false,
+            word_index: 0,
+            salt: [0; 32],
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_commit_only_before_period_starts() {
+        let start = get_period_start_timestamp(PERIOD, PERIOD_EPOCH_START).unwrap();
+        let deadline = period_end() + 3_600;
+
+        assert!(check_commit_window(PERIOD, start - 1, deadline, PERIOD_EPOCH_START).is_ok());
+        assert_eq!(
+            check_commit_window(PERIOD, start, deadline, PERIOD_EPOCH_START).unwrap_err(),
+            VobleError::DailyWordCommitTooLate.into()
+        );
+        assert_eq!(
+            check_commit_window(PERIOD, start - 1, period_end(), PERIOD_EPOCH_START).unwrap_err(),
+            VobleError::InvalidInput.into()
+        );
+        assert_eq!(
+            check_commit_window("W100", 0, deadline, PERIOD_EPOCH_START).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+    }
+
+    #[test]
+    fn test_reveal_matches_commitment() {
+        let commitment = committed();
+
+        assert!(
+            verify_word_reveal(&commitment, WORD_INDEX, &SALT, WORD_INDEX, period_end(), PERIOD_EPOCH_START)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_reveal_rejects_wrong_word_or_salt() {
+        let commitment = committed();
+
+        assert_eq!(
+            verify_word_reveal(&commitment, WORD_INDEX + 1, &SALT, WORD_INDEX, period_end(), PERIOD_EPOCH_START)
+                .unwrap_err(),
+            VobleError::InvalidWordReveal.into()
+        );
+        assert_eq!(
+            verify_word_reveal(&commitment, WORD_INDEX, &[7; 32], WORD_INDEX, period_end(), PERIOD_EPOCH_START)
+                .unwrap_err(),
+            VobleError::InvalidWordReveal.into()
+        );
+    }
+
+    #[test]
+    fn test_reveal_window() {
+        let commitment = committed();
+
+        assert_eq!(
+            verify_word_reveal(&commitment, WORD_INDEX, &SALT, WORD_INDEX, period_end() - 1, PERIOD_EPOCH_START)
+                .unwrap_err(),
+            VobleError::DailyWordRevealTooEarly.into()
+        );
+        assert_eq!(
+            verify_word_reveal(
+                &commitment,
+                WORD_INDEX,
+                &SALT,
+                WORD_INDEX,
+                commitment.reveal_deadline + 1,
+                PERIOD_EPOCH_START
+            )
+            .unwrap_err(),
+            VobleError::DailyWordRevealExpired.into()
+        );
+    }
+
+    #[test]
+    fn test_settlement_requires_reveal() {
+        let mut commitment = committed();
+        let deadline = commitment.reveal_deadline;
+
+        assert_eq!(
+            revealed_word_index(&commitment, deadline).unwrap_err(),
+            VobleError::DailyWordNotRevealed.into()
+        );
+        assert_eq!(
+            revealed_word_index(&commitment, deadline + 1).unwrap_err(),
+            VobleError::DailyWordRevealExpired.into()
+        );
+
+        commitment.revealed = true;
+        commitment.word_index = WORD_INDEX;
+        commitment.salt = SALT;
+        assert_eq!(revealed_word_index(&commitment, deadline + 1).unwrap(), WORD_INDEX);
+
+        // A reveal that doesn't open the commitment never settles
+        commitment.salt = [7; 32];
+        assert_eq!(
+            revealed_word_index(&commitment, deadline).unwrap_err(),
+            VobleError::InvalidWordReveal.into()
+        );
+    }
+
+    #[test]
+    fn test_commit_reveal_session_needs_commitment() {
+        let commitment = committed();
+
+        assert_eq!(
+            session_word_commitment(WordStrategy::CommitReveal, None).unwrap_err(),
+            VobleError::DailyWordNotCommitted.into()
+        );
+        assert_eq!(
+            session_word_commitment(WordStrategy::CommitReveal, Some(&commitment)).unwrap(),
+            commitment.commitment
+        );
+        assert_eq!(
+            session_word_commitment(WordStrategy::Deterministic, None).unwrap(),
+            [0; 32]
+        );
+    }
+
+    #[test]
+    fn test_reveal_must_match_played_word() {
+        let commitment = committed();
+
+        // The commitment opens, but sessions played another shared word
+        assert_eq!(
+            verify_word_reveal(&commitment, WORD_INDEX, &SALT, WORD_INDEX + 1, period_end(), PERIOD_EPOCH_START)
+                .unwrap_err(),
+            VobleError::InvalidWordReveal.into()
+        );
+    }
+
+    #[test]
+    fn test_missed_reveal_opens_cancellation() {
+        let mut commitment = committed();
+        let deadline = commitment.reveal_deadline;
+
+        assert_eq!(
+            require_reveal_missed(&commitment, deadline).unwrap_err(),
+            VobleError::DailyWordRevealPending.into()
+        );
+        assert!(require_reveal_missed(&commitment, deadline + 1).is_ok());
+
+        commitment.revealed = true;
+        assert_eq!(
+            require_reveal_missed(&commitment, deadline + 1).unwrap_err(),
+            VobleError::DailyWordAlreadyRevealed.into()
+        );
+    }
+
+    #[test]
+    fn test_finalized_board_canceled_after_missed_reveal() {
+        use crate::instructions::leaderboard::lock_leaderboard;
+
+        let commitment = committed();
+        let after_deadline = commitment.reveal_deadline + 1;
+        let mut board = PeriodLeaderboard::default_for_test(PERIOD, PeriodType::Daily);
+
+        // The board locks at period end; the reveal then never comes
+        lock_leaderboard(&mut board, PERIOD, period_end()).unwrap();
+        assert_eq!(
+            revealed_word_index(&commitment, after_deadline).unwrap_err(),
+            VobleError::DailyWordRevealExpired.into()
+        );
+
+        // Prizes can't be finalized, so the period can still be voided
+        require_reveal_missed(&commitment, after_deadline).unwrap();
+        assert_eq!(
+            cancel_unrevealed_board(&mut board.clone(), true).unwrap_err(),
+            VobleError::PeriodAlreadyFinalized.into()
+        );
+        cancel_unrevealed_board(&mut board, false).unwrap();
+        assert!(board.finalized && board.canceled);
+        assert_eq!(
+            cancel_unrevealed_board(&mut board, false).unwrap_err(),
+            VobleError::PeriodCanceled.into()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Switch a feature on or off
+///
+/// # Arguments
+/// * `ctx` - The context containing the feature flags account and authority
+/// * `flag` - Feature bit (FEATURE_HARD_MODE, FEATURE_HINTS, ...)
+/// * `enabled` - New state of the feature
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Flag must be below FEATURE_FLAG_COUNT
+///
+/// # Notes
+/// - The first call creates the account with every feature off
+/// - Switching a feature on records the activation time; switching it off
+///   keeps the last activation time for reference
+pub fn set_feature(ctx: Context<SetFeature>, flag: u8, enabled: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let flags = &mut ctx.accounts.feature_flags;
+    if flags.bump == 0 {
+        flags.bump = ctx.bumps.feature_flags;
+    }
+
+    toggle_feature(flags, flag, enabled, now)?;
+
+    msg!(
+        "🚩 Feature {} {}",
+        flag,
+        if enabled { "enabled" } else { "disabled" }
+    );
+    msg!("   Enabled flags: {:#066b}", flags.enabled);
+
+    emit!(FeatureToggled {
+        flag,
+        enabled,
+        enabled_flags: flags.enabled,
+        activated_at: if enabled { now } else { 0 },
+    });
+
+    Ok(())
+}
+
+/// Set or clear a feature bit
+pub fn toggle_feature(flags: &mut FeatureFlags, flag: u8, enabled: bool, now: i64) -> Result<()> {
+    require!(
+        (flag as usize) < FEATURE_FLAG_COUNT,
+        VobleError::InvalidFeatureFlag
+    );
+
+    let bit = 1u64 << flag;
+    if enabled {
+        if flags.enabled & bit == 0 {
+            flags.activated_at[flag as usize] = now;
+        }
+        flags.enabled |= bit;
+    } else {
+        flags.enabled &= !bit;
+    }
+
+    Ok(())
+}
+
+/// Reject a gated instruction while its feature is off
+///
+/// # Arguments
+/// * `flags` - The FeatureFlags account, if the caller passed it
+/// * `flag` - Feature the instruction belongs to
+///
+/// # Notes
+/// - Call at the entry of every gated instruction
+/// - A missing account means the program never had a feature switched on,
+///   so every gated feature is off
+pub fn require_feature(flags: Option<&FeatureFlags>, flag: u8) -> Result<()> {
+    require!(
+        flags.is_some_and(|flags| flags.is_enabled(flag)),
+        VobleError::FeatureDisabled
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn flags() -> FeatureFlags {
+        FeatureFlags {
+            enabled: 0,
+            activated_at: [0; FEATURE_FLAG_COUNT],
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_gated_features_error_when_off() {
+        let flags = flags();
+
+        for flag in [FEATURE_HARD_MODE, FEATURE_HINTS] {
+            assert_eq!(
+                require_feature(Some(&flags), flag).unwrap_err(),
+                VobleError::FeatureDisabled.into()
+            );
+            assert_eq!(
+                require_feature(None, flag).unwrap_err(),
+                VobleError::FeatureDisabled.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_toggle_feature() {
+        let mut flags = flags();
+
+        toggle_feature(&mut flags, FEATURE_HARD_MODE, true, NOW).unwrap();
+        assert!(require_feature(Some(&flags), FEATURE_HARD_MODE).is_ok());
+        assert_eq!(
+            require_feature(Some(&flags), FEATURE_HINTS).unwrap_err(),
+            VobleError::FeatureDisabled.into()
+        );
+        assert_eq!(flags.activated_at[FEATURE_HARD_MODE as usize], NOW);
+
+        // Re-enabling keeps the original activation time
+        toggle_feature(&mut flags, FEATURE_HARD_MODE, true, NOW + 60).unwrap();
+        assert_eq!(flags.activated_at[FEATURE_HARD_MODE as usize], NOW);
+
+        toggle_feature(&mut flags, FEATURE_HARD_MODE, false, NOW + 120).unwrap();
+        assert_eq!(
+            require_feature(Some(&flags), FEATURE_HARD_MODE).unwrap_err(),
+            VobleError::FeatureDisabled.into()
+        );
+        assert_eq!(flags.enabled, 0);
+    }
+
+    #[test]
+    fn test_flag_out_of_range() {
+        let mut flags = flags();
+
+        assert_eq!(
+            toggle_feature(&mut flags, FEATURE_FLAG_COUNT as u8, true, NOW).unwrap_err(),
+            VobleError::InvalidFeatureFlag.into()
+        );
+        assert!(!flags.is_enabled(FEATURE_FLAG_COUNT as u8));
+        assert!(!flags.is_enabled(u8::MAX));
+    }
+}
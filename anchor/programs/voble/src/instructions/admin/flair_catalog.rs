@@ -0,0 +1,59 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use crate::instructions::profile::flair_bit;
+use anchor_lang::prelude::*;
+
+/// List a cosmetic flair in the catalog, or update its price and supply
+///
+/// # Arguments
+/// * `ctx` - The context containing the flair catalog and authority
+/// * `flair_id` - Catalog id (1-64, also the bit in `UserProfile.owned_flairs`)
+/// * `cost` - Price in score points
+/// * `supply_cap` - Maximum copies sold (0 = unlimited)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Flair id must be within 1..=MAX_FLAIRS
+/// - A capped supply cannot be lowered below copies already sold
+///
+/// # Notes
+/// - Flairs cannot be delisted; set a cap equal to `minted` to stop sales
+pub fn set_flair(ctx: Context<SetFlair>, flair_id: u8, cost: u64, supply_cap: u32) -> Result<()> {
+    // ========== VALIDATION ==========
+    flair_bit(flair_id)?;
+
+    let catalog = &mut ctx.accounts.flair_catalog;
+    catalog.bump = ctx.bumps.flair_catalog;
+
+    // ========== UPSERT FLAIR ==========
+    match catalog.flairs.iter_mut().find(|flair| flair.id == flair_id) {
+        Some(entry) => {
+            require!(
+                supply_cap == 0 || supply_cap >= entry.minted,
+                VobleError::InvalidFlair
+            );
+            entry.cost = cost;
+            entry.supply_cap = supply_cap;
+        }
+        None => {
+            require!(catalog.flairs.len() < MAX_FLAIRS, VobleError::InvalidFlair);
+            catalog.flairs.push(FlairEntry {
+                id: flair_id,
+                cost,
+                supply_cap,
+                minted: 0,
+            });
+        }
+    }
+
+    msg!("🎨 Flair {} listed", flair_id);
+    msg!("   Cost: {} points", cost);
+    msg!("   Supply cap: {}", supply_cap);
+
+    emit!(FlairListed {
+        flair_id,
+        cost,
+        supply_cap,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::{contexts::*, events::*};
+use crate::instructions::game::{release_slot_call_handler, session_state};
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::ephem::{
+    CommitAndUndelegate, CommitType, MagicAction, MagicInstructionBuilder, UndelegateType,
+};
+
+/// Force-close a session that is stuck delegated to the ER
+///
+/// Operational recovery tool for when a game cannot finish normally (e.g.
+/// the ER validator went down mid-game). Marks the running game abandoned,
+/// then commits and undelegates the session on the player's behalf so they
+/// can buy a new ticket.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, authority, player and session
+///
+/// # Validation
+/// - Only the config authority can call this instruction
+///
+/// # Notes
+/// - An in-progress game is marked Abandoned and never reaches the
+///   leaderboard or profile stats
+/// - A completed-but-unsettled game is kept: once the session is delegated
+///   again, `commit_and_update_stats` settles it before the next game starts
+/// - Idle or settled sessions are committed and undelegated unchanged
+/// - `release_session_slot` frees the player's active session slot once the
+///   session is back on the base layer
+pub fn force_undelegate_session(ctx: Context<ForceUndelegateSession>) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+
+    msg!("🛠️ Force-closing session for {}", session.player);
+    msg!("   State: {:?} (epoch {})", session.current_state(), session.game_epoch);
+
+    // ========== MARK ABANDONED ==========
+    let previous_state = session_state::abandon(session)?;
+    if previous_state != session.current_state() {
+        session.exit(&crate::ID)?;
+    }
+
+    emit!(SessionForceClosed {
+        player: session.player,
+        authority: ctx.accounts.authority.key(),
+        period_id: session.period_id.clone(),
+        game_epoch: session.game_epoch,
+        previous_state,
+    });
+
+    // ========== COMMIT AND UNDELEGATE ==========
+    let release_handler =
+        release_slot_call_handler(
+            &mut ctx.accounts.session,
+            ctx.accounts.global_config.handler_escrow_index,
+            &ctx.accounts.authority,
+        )?;
+
+    MagicInstructionBuilder {
+        payer: ctx.accounts.authority.to_account_info(),
+        magic_context: ctx.accounts.magic_context.to_account_info(),
+        magic_program: ctx.accounts.magic_program.to_account_info(),
+        magic_action: MagicAction::CommitAndUndelegate(CommitAndUndelegate {
+            commit_type: CommitType::WithHandler {
+                commited_accounts: vec![ctx.accounts.session.to_account_info()],
+                call_handlers: vec![release_handler],
+            },
+            undelegate_type: UndelegateType::Standalone,
+        }),
+    }
+    .build_and_invoke()?;
+
+    msg!("✅ Session force-closed and undelegated");
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+/// Extend the `global_config` account to the current `GlobalConfig` layout
+///
+/// Fields appended to `GlobalConfig` after a deployment don't fit its
+/// account, so it no longer deserializes. Growing the account zero-fills the
+/// new tail, and every appended field reads zero as its default.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config and its authority
+///
+/// # Validation
+/// - Config must be owned by this program and carry the GlobalConfig discriminator
+/// - Signer must be the authority stored in the config
+///
+/// # Notes
+/// - The authority pays the extra rent
+/// - Grows at most 10 KiB per call; `fully_allocated` in `ConfigGrown` says when to stop
+/// - Calling it on a config already at the current size changes nothing
+pub fn grow_global_config(ctx: Context<GrowGlobalConfig>) -> Result<()> {
+    let config_info = ctx.accounts.global_config.to_account_info();
+
+    // ========== VALIDATION ==========
+    require!(config_info.owner == &crate::ID, VobleError::Unauthorized);
+
+    let old_len = config_info.data_len();
+    let authority = {
+        let data = config_info.try_borrow_data()?;
+        stored_config_authority(&data).ok_or(VobleError::InvalidInput)?
+    };
+    require_keys_eq!(
+        authority,
+        ctx.accounts.authority.key(),
+        VobleError::Unauthorized
+    );
+
+    if old_len >= GlobalConfig::SPACE {
+        msg!("✅ Global config already uses the current layout");
+        return Ok(());
+    }
+
+    // ========== GROW ==========
+    let new_len = GlobalConfig::next_space(old_len);
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(config_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: config_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    config_info.resize(new_len)?;
+    config_info.try_borrow_mut_data()?[old_len..].fill(0);
+
+    let fully_allocated = new_len == GlobalConfig::SPACE;
+    if fully_allocated {
+        GlobalConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    }
+
+    // ========== EMIT EVENT ==========
+    emit!(ConfigGrown {
+        authority,
+        old_len: old_len as u32,
+        new_len: new_len as u32,
+        fully_allocated,
+    });
+
+    msg!("📐 Global config grown from {} to {} bytes", old_len, new_len);
+
+    Ok(())
+}
+
+/// Authority stored in a config account's data
+///
+/// # Returns
+/// `None` if the data doesn't start with the GlobalConfig discriminator
+/// followed by an authority
+pub fn stored_config_authority(data: &[u8]) -> Option<Pubkey> {
+    let body = data.strip_prefix(GlobalConfig::DISCRIMINATOR)?;
+    Pubkey::try_from(body.get(..32)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bytes taken by the sweep fields at the end of the layout
+    const SWEEP_FIELDS_LEN: usize = 32 + 8 + 8 + 8;
+
+    /// A config serialized before the sweep fields were appended
+    fn short_config_data(authority: Pubkey) -> Vec<u8> {
+        let mut config =
+            GlobalConfig::try_deserialize_unchecked(&mut &vec![0u8; GlobalConfig::SPACE][..])
+                .unwrap();
+        config.authority = authority;
+        config.ticket_price = 1_000_000;
+
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        data.truncate(data.len() - SWEEP_FIELDS_LEN);
+        data
+    }
+
+    #[test]
+    fn test_grown_config_reads_appended_fields_as_defaults() {
+        let authority = Pubkey::new_unique();
+        let mut data = short_config_data(authority);
+        assert_eq!(stored_config_authority(&data), Some(authority));
+        assert!(GlobalConfig::try_deserialize(&mut &data[..]).is_err());
+
+        let new_len = GlobalConfig::next_space(data.len());
+        assert_eq!(new_len, GlobalConfig::SPACE);
+        data.resize(new_len, 0);
+
+        let config = GlobalConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.ticket_price, 1_000_000);
+        assert_eq!(config.treasury, Pubkey::default());
+        assert_eq!(config.sweep_bounty, 0);
+    }
+
+    #[test]
+    fn test_config_never_shrinks() {
+        assert_eq!(GlobalConfig::next_space(GlobalConfig::SPACE), GlobalConfig::SPACE);
+        assert_eq!(GlobalConfig::next_space(GlobalConfig::SPACE + 16), GlobalConfig::SPACE + 16);
+        assert!(stored_config_authority(GlobalConfig::DISCRIMINATOR).is_none());
+    }
+}
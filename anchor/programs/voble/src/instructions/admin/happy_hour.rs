@@ -0,0 +1,78 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use crate::instructions::game::scoring;
+use anchor_lang::prelude::*;
+
+/// Schedule a happy hour score multiplier window within a period
+///
+/// Tickets bought while the window is active get their final score scaled
+/// by `multiplier_bps` (e.g. 20000 = double score). The multiplier is
+/// stamped on the player's profile at purchase and copied into the session
+/// on reset, since the ER cannot read this account mid-game.
+///
+/// # Arguments
+/// * `ctx` - The context containing the multiplier account and authority
+/// * `period_id` - The period the window belongs to (e.g., "D123")
+/// * `start` - Window start timestamp (inclusive)
+/// * `end` - Window end timestamp (exclusive)
+/// * `multiplier_bps` - Score multiplier in basis points (10000 = 1x)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Window must start in the future and end after it starts
+/// - Multiplier must be between 1x and 3x (MAX_SCORE_MULTIPLIER_BPS)
+/// - Window must not overlap an existing window for the period
+/// - At most MAX_MULTIPLIER_WINDOWS windows per period
+pub fn set_happy_hour(
+    ctx: Context<SetHappyHour>,
+    period_id: String,
+    start: i64,
+    end: i64,
+    multiplier_bps: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(
+        period_id.len() <= MAX_PERIOD_ID_LENGTH,
+        VobleError::PeriodIdTooLong
+    );
+    require!(start > now, VobleError::InvalidMultiplierWindow);
+    require!(end > start, VobleError::InvalidMultiplierWindow);
+    require!(
+        (SCORE_MULTIPLIER_NONE..=MAX_SCORE_MULTIPLIER_BPS).contains(&multiplier_bps),
+        VobleError::InvalidMultiplierWindow
+    );
+
+    let account = &mut ctx.accounts.score_multiplier;
+    account.period_id = period_id.clone();
+    account.bump = ctx.bumps.score_multiplier;
+
+    require!(
+        !scoring::window_overlaps(&account.windows, start, end),
+        VobleError::MultiplierWindowOverlap
+    );
+    require!(
+        account.windows.len() < MAX_MULTIPLIER_WINDOWS,
+        VobleError::TooManyMultiplierWindows
+    );
+
+    // ========== SAVE WINDOW ==========
+    account.windows.push(MultiplierWindow {
+        start,
+        end,
+        multiplier_bps,
+    });
+
+    msg!("🎉 Happy hour scheduled for period {}", period_id);
+    msg!("   Window: {} -> {}", start, end);
+    msg!("   Multiplier: {} bps", multiplier_bps);
+
+    emit!(HappyHourSet {
+        period_id,
+        start,
+        end,
+        multiplier_bps,
+    });
+
+    Ok(())
+}
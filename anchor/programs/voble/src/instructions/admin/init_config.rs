@@ -1,6 +1,34 @@
-use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::{CharClass, ConfigBumps, GlobalConfig, LeaderboardMode, PeriodType, RemainderPolicy, WordStrategy}};
+use crate::utils::validation::{validate_currency_symbol, validate_mint_decimals};
 use anchor_lang::prelude::*;
 
+/// Settings `initialize_global_config` starts the config with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigInit {
+    /// Price to play one game, in the payment mint's base units
+    pub ticket_price: u64,
+    /// Basis points (0-10000) for the daily prize pool
+    pub prize_split_daily: u16,
+    /// Basis points (0-10000) for the weekly prize pool
+    pub prize_split_weekly: u16,
+    /// Basis points (0-10000) for the monthly prize pool
+    pub prize_split_monthly: u16,
+    /// Basis points (0-10000) for platform revenue
+    pub platform_revenue_split: u16,
+    /// Basis points (0-10000) for the lucky draw
+    pub lucky_draw_split: u16,
+    /// Basis points for the 1st, 2nd and 3rd place winners
+    pub winner_splits: Vec<u16>,
+    /// Payment mint
+    pub usdc_mint: Pubkey,
+    /// Timestamp of period 0 (None = PERIOD_EPOCH_START)
+    pub period_epoch_start: Option<i64>,
+    /// Ticker clients show for amounts (None = "USDC")
+    pub currency_symbol: Option<String>,
+    /// Decimals clients format amounts with (None = 6)
+    pub currency_decimals: Option<u8>,
+}
+
 /// Initialize the global configuration for the Voble game
 ///
 /// This instruction sets up the core game parameters including:
@@ -11,37 +39,48 @@ use anchor_lang::prelude::*;
 ///
 /// # Arguments
 /// * `ctx` - The context containing the global config account and authority
-/// * `ticket_price` - Price in lamports to play one game
-/// * `prize_split_daily` - Basis points (0-10000) for daily prize pool
-/// * `prize_split_weekly` - Basis points (0-10000) for weekly prize pool
-/// * `prize_split_monthly` - Basis points (0-10000) for monthly prize pool
-/// * `platform_revenue_split` - Basis points (0-10000) for platform revenue
-/// * `winner_splits` - Vec of 3 basis points for 1st, 2nd, 3rd place winners
+/// * `init` - The starting settings (see `ConfigInit`)
 ///
 /// # Validation
-/// - Ticket price must be at least MIN_TICKET_PRICE (0.0001 SOL)
+/// - Ticket price must be at least MIN_TICKET_PRICE (1 USDC at 6 decimals)
 /// - All prize splits must add up to exactly 10000 (100%)
 /// - Winner splits must be exactly 3 entries (for 1st, 2nd, 3rd place)
 /// - Winner splits must add up to exactly 10000 (100%)
+/// - Period epoch start, if given, must be positive and not in the future
+/// - Currency symbol, if given, must be 1-8 printable ASCII characters
+/// - Currency decimals, if given, must be within the accepted mint decimals
+///
+/// # Notes
+/// - The currency fields are display metadata only; amounts are always in
+///   the mint's base units
+/// - `initialize_vaults` rejects a mint whose decimals differ from
+///   `currency_decimals`, so pass the mint's decimals for non-6-decimal mints
 pub fn initialize_global_config(
     ctx: Context<InitializeGlobalConfig>,
-    ticket_price: u64,
-    prize_split_daily: u16,
-    prize_split_weekly: u16,
-    prize_split_monthly: u16,
-    platform_revenue_split: u16,
-    lucky_draw_split: u16,
-    winner_splits: Vec<u16>,
-    usdc_mint: Pubkey,
+    init: ConfigInit,
 ) -> Result<()> {
+    let ConfigInit {
+        ticket_price,
+        prize_split_daily,
+        prize_split_weekly,
+        prize_split_monthly,
+        platform_revenue_split,
+        lucky_draw_split,
+        winner_splits,
+        usdc_mint,
+        period_epoch_start,
+        currency_symbol,
+        currency_decimals,
+    } = init;
+
     // ========== VALIDATION: Ticket Price ==========
-    // Validate ticket price (minimum 0.0001 SOL = 100,000 lamports)
+    // Validate ticket price (minimum 1,000,000 base units = 1 USDC)
     require!(
         ticket_price >= MIN_TICKET_PRICE,
         VobleError::InvalidPrizeSplits
     );
 
-    msg!("✅ Ticket price validated: {} lamports", ticket_price);
+    msg!("✅ Ticket price validated: {} base units", ticket_price);
 
     // ========== VALIDATION: Prize Splits (Must Add to 100%) ==========
     // All splits must add up to exactly 10000 basis points (100%)
@@ -89,6 +128,24 @@ pub fn initialize_global_config(
         winner_total
     );
 
+    // ========== VALIDATION: Period Epoch ==========
+    // Period numbers count from the epoch, so it can't be in the future
+    let period_epoch_start = period_epoch_start.unwrap_or(PERIOD_EPOCH_START);
+    require!(
+        period_epoch_start > 0 && period_epoch_start <= Clock::get()?.unix_timestamp,
+        VobleError::InvalidInput
+    );
+
+    msg!("✅ Period epoch start: {}", period_epoch_start);
+
+    // ========== VALIDATION: Currency Display ==========
+    let currency_symbol = currency_symbol.unwrap_or_else(|| DEFAULT_CURRENCY_SYMBOL.to_string());
+    validate_currency_symbol(&currency_symbol)?;
+    let currency_decimals = currency_decimals.unwrap_or(DEFAULT_CURRENCY_DECIMALS);
+    validate_mint_decimals(currency_decimals)?;
+
+    msg!("✅ Currency: {} ({} decimals)", currency_symbol, currency_decimals);
+
     // ========== INITIALIZE CONFIG ==========
     let config = &mut ctx.accounts.global_config;
     config.authority = ctx.accounts.authority.key();
@@ -102,15 +159,138 @@ pub fn initialize_global_config(
     config.paused = false;
     config.usdc_mint = usdc_mint;
 
+    apply_default_settings(config, ctx.bumps.global_config, period_epoch_start);
+    config.reference_ticket_price = ticket_price;
+    config.currency_symbol = currency_symbol;
+    config.currency_decimals = currency_decimals;
+
     // ========== EMIT EVENT ==========
     emit!(GlobalConfigInitialized {
         authority: config.authority,
         ticket_price: config.ticket_price,
+        currency_symbol: config.currency_symbol.clone(),
+        currency_decimals: config.currency_decimals,
     });
 
     msg!("🎮 Global config initialized successfully");
     msg!("📍 Authority: {}", config.authority);
-    msg!("💰 Ticket price: {} lamports", config.ticket_price);
+    msg!("💰 Ticket price: {} base units", config.ticket_price);
 
     Ok(())
 }
+
+/// Reset every setting that `initialize_global_config` doesn't take as an argument
+///
+/// Shared with `migrate_config_v1_to_v2`, so a migrated config starts from
+/// the same defaults as a freshly initialized one.
+///
+/// # Arguments
+/// * `config` - Config being initialized
+/// * `global_config_bump` - Canonical bump of the config PDA
+/// * `period_epoch_start` - Timestamp of period 0 for this deployment
+pub fn apply_default_settings(
+    config: &mut GlobalConfig,
+    global_config_bump: u8,
+    period_epoch_start: i64,
+) {
+    // Auto-sweep stays disabled until a treasury is set via set_config
+    config.treasury = Pubkey::default();
+    config.sweep_interval_seconds = DEFAULT_SWEEP_INTERVAL;
+    config.platform_float = 0;
+    config.sweep_bounty = 0;
+
+    // Ticket cooldown disabled by default
+    config.ticket_cooldown_secs = 0;
+
+    // No participant cap until the authority sets one
+    config.max_participants_per_period = u32::MAX;
+
+    // Every period pays out regardless of turnout until thresholds are set
+    config.min_participants_for_prizes = [0; 3];
+
+    // Every settled game updates all three leaderboards
+    config.leaderboard_mode = LeaderboardMode::LivePerGame;
+
+    // Prize-split rounding dust goes to the 1st place winner
+    config.remainder_policy = RemainderPolicy::ToFirstPlace;
+
+    // No period is paused on its own yet
+    config.paused_periods = 0;
+
+    // Tied winners are paid by leaderboard position
+    config.split_tied_prizes = false;
+
+    // Sessions stuck on the ER can be recovered by anyone after two hours
+    config.session_stale_after_secs = DEFAULT_SESSION_STALE_AFTER;
+
+    // The cap only exists to protect the ER validator (the count is kept in SessionCounter)
+    config.max_active_sessions = DEFAULT_MAX_ACTIVE_SESSIONS;
+
+    // Settlement runs as one call handler paid from the payer's first escrow
+    config.handler_compute_units = DEFAULT_HANDLER_COMPUTE_UNITS;
+    config.handler_escrow_index = 0;
+    config.split_stats_handlers = false;
+
+    // Period numbering for this deployment
+    config.period_epoch_start = period_epoch_start;
+
+    // First-guess solves score like any other win until a bonus is configured
+    config.hole_in_one_bonus = 0;
+
+    // Games committed shortly after midnight still count for the day they were bought
+    config.grace_window_seconds = DEFAULT_SCORE_GRACE_WINDOW;
+
+    // Winners aren't sent a SOL nudge until the authority opts in
+    config.notify_lamports = 0;
+
+    // Streaks survive any gap until the authority sets a decay
+    config.streak_decay_periods = 0;
+
+    // Session lifecycle instructions use the default slot gap
+    config.session_action_gaps = [0; 3];
+
+    // Typed guesses can't be overwritten until the authority enables mulligans
+    config.allow_mulligan = false;
+
+    // Shared daily words don't repeat within the last DEFAULT_SHARED_WORD_WINDOW
+    config.shared_word_window = DEFAULT_SHARED_WORD_WINDOW;
+
+    // New leaderboards keep as many entries as fit a single allocation
+    config.leaderboard_capacity = DEFAULT_LEADERBOARD_CAPACITY;
+
+    // Large price changes and withdrawals wait out the default timelock
+    config.admin_timelock_secs = DEFAULT_ADMIN_TIMELOCK;
+    config.timelock_withdraw_threshold = DEFAULT_TIMELOCK_WITHDRAW_THRESHOLD;
+
+    // Price steps are measured from the price at setup (0 reads as the current price)
+    config.reference_ticket_price = 0;
+
+    // No untimelocked withdrawals counted yet
+    config.withdraw_window_start = 0;
+    config.withdrawn_in_window = 0;
+
+    // Release that wrote this config; bumped by record_upgrade after each deploy
+    config.program_version = PROGRAM_VERSION;
+
+    // Mint decimals are validated and stored by initialize_vaults
+    config.usdc_decimals = 0;
+
+    // Clients show amounts as USDC until the config says otherwise
+    config.currency_symbol = DEFAULT_CURRENCY_SYMBOL.to_string();
+    config.currency_decimals = DEFAULT_CURRENCY_DECIMALS;
+
+    // The built-in word lists are letters only
+    config.char_class = CharClass::Alphabetic;
+
+    // Tickets play a word from the daily list until the authority picks a harder one
+    config.ticket_word_source = PeriodType::Daily;
+
+    // Vault bumps are cached by initialize_vaults once the vaults exist
+    config.bumps = ConfigBumps {
+        global_config: global_config_bump,
+        ..ConfigBumps::default()
+    };
+
+    // Daily word uses hash-derived selection until commit-reveal is enabled
+    config.word_strategy = WordStrategy::Deterministic;
+}
@@ -1,3 +1,4 @@
+use crate::utils::validation::{validate_currency_decimals, validate_mint_decimals};
 use crate::{contexts::*, events::*};
 use anchor_lang::prelude::*;
 
@@ -16,6 +17,8 @@ use anchor_lang::prelude::*;
 /// # Validation
 /// - Only the authority can call this instruction
 /// - Vaults must not already exist (enforced by init constraint)
+/// - Mint must match `GlobalConfig.usdc_mint` and have 1-9 decimals
+/// - Mint decimals must equal the config's `currency_decimals`
 ///
 /// # Notes
 /// The vaults are created as empty PDAs with minimal space (8 bytes).
@@ -26,7 +29,25 @@ use anchor_lang::prelude::*;
 /// - Setting proper ownership
 pub fn initialize_vaults(ctx: Context<InitializeVaults>) -> Result<()> {
     // Vaults are automatically created by the init constraint in the context
-    // No additional logic needed - just emit an event for confirmation
+
+    // ========== VALIDATION: Mint Decimals ==========
+    let decimals = ctx.accounts.usdc_mint.decimals;
+    validate_mint_decimals(decimals)?;
+    validate_currency_decimals(ctx.accounts.global_config.currency_decimals(), decimals)?;
+
+    // Store decimals so claims don't depend on re-reading the mint
+    ctx.accounts.global_config.usdc_decimals = decimals;
+
+    msg!("✅ Mint decimals validated: {}", decimals);
+
+    // Cache canonical vault bumps so later instructions skip find_program_address
+    // (the config's own bump is stored by initialize_global_config)
+    let bumps = &mut ctx.accounts.global_config.bumps;
+    bumps.daily_prize_vault = ctx.bumps.daily_prize_vault;
+    bumps.weekly_prize_vault = ctx.bumps.weekly_prize_vault;
+    bumps.monthly_prize_vault = ctx.bumps.monthly_prize_vault;
+    bumps.platform_vault = ctx.bumps.platform_vault;
+    bumps.lucky_draw_vault = ctx.bumps.lucky_draw_vault;
 
     let daily_vault_key = ctx.accounts.daily_prize_vault.key();
     let weekly_vault_key = ctx.accounts.weekly_prize_vault.key();
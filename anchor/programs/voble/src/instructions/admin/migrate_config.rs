@@ -0,0 +1,218 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::GlobalConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use super::apply_default_settings;
+
+/// GlobalConfig layout stored under the v1 `global_config` seed
+///
+/// v1 had no lucky draw, so its prize splits add up to 100% without one.
+/// Stored with the same `GlobalConfig` discriminator as the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyGlobalConfig {
+    pub authority: Pubkey,
+    pub ticket_price: u64,
+    pub prize_split_daily: u16,
+    pub prize_split_weekly: u16,
+    pub prize_split_monthly: u16,
+    pub platform_revenue_split: u16,
+    pub winner_splits: Vec<u16>,
+    pub paused: bool,
+    pub usdc_mint: Pubkey,
+}
+
+/// Move a v1 deployment's config to the `global_config_v2` PDA
+///
+/// Copies the authority, pricing, splits, pause flag and mint from the v1
+/// account into a new v2 config, then closes the v1 account.
+///
+/// # Arguments
+/// * `ctx` - The context containing the v1 config, the new v2 config and the v1 authority
+/// * `period_epoch_start` - Optional timestamp of period 0 (defaults to PERIOD_EPOCH_START)
+///
+/// # Validation
+/// - The v1 account must be owned by this program and carry the GlobalConfig discriminator
+/// - Signer must be the v1 authority
+/// - The v1 prize and winner splits must still add up to exactly 10000
+/// - Period epoch start, if given, must be positive and not in the future
+///
+/// # Notes
+/// - Settings v1 didn't have start from the `initialize_global_config`
+///   defaults; `lucky_draw_split` is 0 so the v1 splits stay at 100%
+/// - The v1 account's rent goes back to the authority, which pays for the v2 account
+/// - Vaults are derived from seeds that don't include the config, so they carry over unchanged
+pub fn migrate_config_v1_to_v2(
+    ctx: Context<MigrateConfigV1ToV2>,
+    period_epoch_start: Option<i64>,
+) -> Result<()> {
+    let legacy_info = ctx.accounts.legacy_config.to_account_info();
+
+    // ========== VALIDATION ==========
+    require!(legacy_info.owner == &crate::ID, VobleError::Unauthorized);
+
+    let legacy = {
+        let data = legacy_info.try_borrow_data()?;
+        parse_legacy_config(&data).ok_or(VobleError::InvalidInput)?
+    };
+    require_keys_eq!(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        VobleError::Unauthorized
+    );
+    validate_legacy_splits(&legacy)?;
+
+    let period_epoch_start = period_epoch_start.unwrap_or(PERIOD_EPOCH_START);
+    require!(
+        period_epoch_start > 0 && period_epoch_start <= Clock::get()?.unix_timestamp,
+        VobleError::InvalidInput
+    );
+
+    // ========== MIGRATE ==========
+    let config = &mut ctx.accounts.global_config;
+    config.authority = legacy.authority;
+    config.ticket_price = legacy.ticket_price;
+    config.prize_split_daily = legacy.prize_split_daily;
+    config.prize_split_weekly = legacy.prize_split_weekly;
+    config.prize_split_monthly = legacy.prize_split_monthly;
+    config.platform_revenue_split = legacy.platform_revenue_split;
+    config.lucky_draw_split = 0; // v1 had no lucky draw
+    config.winner_splits = legacy.winner_splits;
+    config.paused = legacy.paused;
+    config.usdc_mint = legacy.usdc_mint;
+
+    apply_default_settings(config, ctx.bumps.global_config, period_epoch_start);
+
+    // ========== CLOSE V1 ==========
+    let reclaimed_lamports = legacy_info.lamports();
+    legacy_info.sub_lamports(reclaimed_lamports)?;
+    ctx.accounts
+        .authority
+        .to_account_info()
+        .add_lamports(reclaimed_lamports)?;
+    legacy_info.assign(&anchor_lang::system_program::ID);
+    legacy_info.resize(0)?;
+
+    // ========== EMIT EVENT ==========
+    emit!(ConfigMigrated {
+        authority: config.authority,
+        legacy_config: legacy_info.key(),
+        ticket_price: config.ticket_price,
+        reclaimed_lamports,
+    });
+
+    msg!("🔀 Global config migrated to v2");
+    msg!("📍 Authority: {}", config.authority);
+    msg!("💰 Ticket price: {} base units", config.ticket_price);
+
+    Ok(())
+}
+
+/// Parse a v1 config account
+///
+/// # Returns
+/// `None` if the data doesn't start with the GlobalConfig discriminator or
+/// doesn't hold a v1 layout
+pub fn parse_legacy_config(data: &[u8]) -> Option<LegacyGlobalConfig> {
+    let body = data.strip_prefix(GlobalConfig::DISCRIMINATOR)?;
+    LegacyGlobalConfig::deserialize(&mut &body[..]).ok()
+}
+
+/// Check that a v1 config's splits are still valid without a lucky draw
+///
+/// # Validation
+/// - Prize splits must add up to exactly 10000
+/// - Winner splits must be exactly 3 entries adding up to exactly 10000
+pub fn validate_legacy_splits(legacy: &LegacyGlobalConfig) -> Result<()> {
+    let total_splits = legacy.prize_split_daily as u32
+        + legacy.prize_split_weekly as u32
+        + legacy.prize_split_monthly as u32
+        + legacy.platform_revenue_split as u32;
+    require!(
+        total_splits == BASIS_POINTS_TOTAL as u32,
+        VobleError::InvalidPrizeSplits
+    );
+
+    require!(
+        legacy.winner_splits.len() == TOP_WINNERS_COUNT,
+        VobleError::InvalidWinnerCount
+    );
+    let winner_total: u32 = legacy.winner_splits.iter().map(|&s| s as u32).sum();
+    require!(
+        winner_total == BASIS_POINTS_TOTAL as u32,
+        VobleError::InvalidWinnerSplits
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_config() -> LegacyGlobalConfig {
+        LegacyGlobalConfig {
+            authority: Pubkey::new_unique(),
+            ticket_price: 1_000_000,
+            prize_split_daily: 4_000,
+            prize_split_weekly: 3_000,
+            prize_split_monthly: 2_000,
+            platform_revenue_split: 1_000,
+            winner_splits: vec![5_000, 3_000, 2_000],
+            paused: true,
+            usdc_mint: Pubkey::new_unique(),
+        }
+    }
+
+    fn account_data(legacy: &LegacyGlobalConfig) -> Vec<u8> {
+        let mut data = GlobalConfig::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_parse_legacy_config() {
+        let legacy = legacy_config();
+        let parsed = parse_legacy_config(&account_data(&legacy)).unwrap();
+
+        assert_eq!(parsed.authority, legacy.authority);
+        assert_eq!(parsed.ticket_price, 1_000_000);
+        assert_eq!(parsed.winner_splits, vec![5_000, 3_000, 2_000]);
+        assert!(parsed.paused);
+        assert_eq!(parsed.usdc_mint, legacy.usdc_mint);
+        assert!(validate_legacy_splits(&parsed).is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_other_accounts() {
+        let mut data = account_data(&legacy_config());
+        data[0] ^= 0xff;
+        assert!(parse_legacy_config(&data).is_none());
+
+        assert!(parse_legacy_config(GlobalConfig::DISCRIMINATOR).is_none());
+        assert!(parse_legacy_config(&[]).is_none());
+    }
+
+    #[test]
+    fn test_legacy_splits_must_still_add_up() {
+        let mut legacy = legacy_config();
+        legacy.platform_revenue_split = 500;
+        assert_eq!(
+            validate_legacy_splits(&legacy).unwrap_err(),
+            VobleError::InvalidPrizeSplits.into()
+        );
+
+        let mut legacy = legacy_config();
+        legacy.winner_splits = vec![10_000];
+        assert_eq!(
+            validate_legacy_splits(&legacy).unwrap_err(),
+            VobleError::InvalidWinnerCount.into()
+        );
+
+        let mut legacy = legacy_config();
+        legacy.winner_splits = vec![5_000, 3_000, 1_000];
+        assert_eq!(
+            validate_legacy_splits(&legacy).unwrap_err(),
+            VobleError::InvalidWinnerSplits.into()
+        );
+    }
+}
@@ -1,9 +1,47 @@
+pub mod authority_check;
+pub mod blocklist;
+pub mod cache_bumps;
+pub mod cancel_period;
+pub mod daily_word;
+pub mod feature_flags;
+pub mod flair_catalog;
+pub mod force_undelegate;
+pub mod grow_config;
+pub mod happy_hour;
 pub mod init_config;
 pub mod init_vaults;
+pub mod migrate_config;
+pub mod period_pause;
+pub mod prize_schedule;
+pub mod record_upgrade;
+pub mod shared_word;
+pub mod sweep_revenue;
+pub mod timelock;
 pub mod update_config;
+pub mod vault_ledger;
 pub mod withdraw_revenue;
+pub mod word_bank;
 
+pub use authority_check::*;
+pub use blocklist::*;
+pub use cache_bumps::*;
+pub use cancel_period::*;
+pub use daily_word::*;
+pub use feature_flags::*;
+pub use flair_catalog::*;
+pub use force_undelegate::*;
+pub use grow_config::*;
+pub use happy_hour::*;
 pub use init_config::*;
 pub use init_vaults::*;
+pub use migrate_config::*;
+pub use period_pause::*;
+pub use prize_schedule::*;
+pub use record_upgrade::*;
+pub use shared_word::*;
+pub use sweep_revenue::*;
+pub use timelock::*;
 pub use update_config::*;
+pub use vault_ledger::*;
 pub use withdraw_revenue::*;
+pub use word_bank::*;
@@ -0,0 +1,122 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Pause or resume a single period
+///
+/// Finer-grained than `config.paused`: freezes one misbehaving period
+/// (e.g. an ambiguous daily word) while every other period keeps running.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the leaderboard and authority
+/// * `period_id` - Period to pause or resume (e.g., "D123")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+/// * `paused` - New pause state
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Leaderboard must match the period ID and type (PDA seeds)
+///
+/// # Notes
+/// - A paused daily board rejects new tickets and guesses for its period
+///   with `PeriodPaused`
+/// - `config.paused_periods` counts paused boards; while it is non-zero,
+///   ticket purchases must pass the period's daily leaderboard
+pub fn set_period_paused(
+    ctx: Context<SetPeriodPaused>,
+    period_id: String,
+    period_type: u8,
+    paused: bool,
+) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let config = &mut ctx.accounts.global_config;
+
+    let changed = apply_period_pause(leaderboard, &mut config.paused_periods, paused);
+
+    msg!(
+        "⏸️  Period {} (type {}) {}{}",
+        period_id,
+        period_type,
+        if paused { "paused" } else { "resumed" },
+        if changed { "" } else { " (unchanged)" }
+    );
+    msg!("   Paused periods: {}", config.paused_periods);
+
+    emit!(PeriodPauseSet {
+        period_id,
+        period_type: leaderboard.period_type,
+        paused,
+        paused_periods: config.paused_periods,
+    });
+
+    Ok(())
+}
+
+/// Set a leaderboard's pause flag and keep the paused-period count in step
+///
+/// # Returns
+/// `true` if the flag changed
+pub fn apply_period_pause(
+    leaderboard: &mut PeriodLeaderboard,
+    paused_periods: &mut u32,
+    paused: bool,
+) -> bool {
+    if leaderboard.paused == paused {
+        return false;
+    }
+
+    leaderboard.paused = paused;
+    *paused_periods = if paused {
+        paused_periods.saturating_add(1)
+    } else {
+        paused_periods.saturating_sub(1)
+    };
+    true
+}
+
+/// Reject new games and guesses in a paused or canceled period
+pub fn require_period_open(leaderboard: &PeriodLeaderboard) -> Result<()> {
+    require!(!leaderboard.canceled, VobleError::PeriodCanceled);
+    require!(!leaderboard.paused, VobleError::PeriodPaused);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn daily_board() -> PeriodLeaderboard {
+        PeriodLeaderboard::default_for_test("D42", PeriodType::Daily)
+    }
+
+    #[test]
+    fn test_paused_period_rejects_play() {
+        let mut board = daily_board();
+        let mut paused_periods = 0;
+        assert!(require_period_open(&board).is_ok());
+
+        assert!(apply_period_pause(&mut board, &mut paused_periods, true));
+        assert_eq!(
+            require_period_open(&board).unwrap_err(),
+            VobleError::PeriodPaused.into()
+        );
+        assert_eq!(paused_periods, 1);
+
+        assert!(apply_period_pause(&mut board, &mut paused_periods, false));
+        assert!(require_period_open(&board).is_ok());
+        assert_eq!(paused_periods, 0);
+    }
+
+    #[test]
+    fn test_repeated_pause_counted_once() {
+        let mut board = daily_board();
+        let mut paused_periods = 0;
+
+        apply_period_pause(&mut board, &mut paused_periods, true);
+        assert!(!apply_period_pause(&mut board, &mut paused_periods, true));
+        assert_eq!(paused_periods, 1);
+
+        apply_period_pause(&mut board, &mut paused_periods, false);
+        assert!(!apply_period_pause(&mut board, &mut paused_periods, false));
+        assert_eq!(paused_periods, 0);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use crate::utils::period::{get_period_end_timestamp, get_period_start_timestamp};
+use super::{consume_pending_action, prize_schedule_payload};
+use anchor_lang::prelude::*;
+
+/// Override how a future period's tickets are split between the prize pools
+///
+/// Lets operators weight a period's tickets towards one pool (e.g. heavier
+/// monthly pots over the last days of a month). Platform revenue and the
+/// lucky draw keep their configured shares.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the new override and authority
+/// * `period_id` - Ticket period the override applies to (e.g., "D123")
+/// * `prize_split_daily` - Daily pool share in basis points
+/// * `prize_split_weekly` - Weekly pool share in basis points
+/// * `prize_split_monthly` - Monthly pool share in basis points
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - The period must not have started yet (`PeriodAlreadyStarted`)
+/// - The three pool splits plus the config's platform and lucky draw splits
+///   must add up to exactly 10000
+/// - Needs a matured `PrizeSchedule` pending action for this period and
+///   these splits (see `queue_admin_action`)
+///
+/// # Notes
+/// - Tickets use the override only when the override account is passed to
+///   `buy_ticket_and_start_game`
+pub fn create_prize_schedule_override(
+    ctx: Context<CreatePrizeScheduleOverride>,
+    period_id: String,
+    prize_split_daily: u16,
+    prize_split_weekly: u16,
+    prize_split_monthly: u16,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(
+        period_id.len() <= MAX_PERIOD_ID_LENGTH,
+        VobleError::PeriodIdTooLong
+    );
+    require_future_period(&period_id, config.period_epoch(), now)?;
+    let splits = [prize_split_daily, prize_split_weekly, prize_split_monthly];
+    validate_schedule_splits(config, splits)?;
+
+    // Redirecting ticket revenue between pools must be announced on-chain first
+    consume_pending_action(
+        &mut ctx.accounts.pending_action,
+        &ctx.accounts.authority,
+        AdminActionKind::PrizeSchedule,
+        prize_schedule_payload(&period_id, splits),
+        now,
+    )?;
+
+    // ========== SAVE OVERRIDE ==========
+    let schedule = &mut ctx.accounts.prize_schedule;
+    schedule.period_id = period_id.clone();
+    schedule.prize_split_daily = prize_split_daily;
+    schedule.prize_split_weekly = prize_split_weekly;
+    schedule.prize_split_monthly = prize_split_monthly;
+    schedule.created_at = now;
+    schedule.bump = ctx.bumps.prize_schedule;
+
+    msg!("📅 Prize schedule override for period {}", period_id);
+    msg!(
+        "   Splits: daily={}, weekly={}, monthly={}",
+        prize_split_daily,
+        prize_split_weekly,
+        prize_split_monthly
+    );
+
+    emit!(PrizeScheduleOverrideSet {
+        period_id,
+        prize_split_daily,
+        prize_split_weekly,
+        prize_split_monthly,
+    });
+
+    Ok(())
+}
+
+/// Remove a period's prize split override and return its rent to the authority
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the override and authority
+/// * `period_id` - Ticket period of the override
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - The period must not be running (`PeriodAlreadyStarted`), so every ticket
+///   of a period is split the same way; overrides of ended periods can be
+///   closed to reclaim rent
+pub fn close_prize_schedule_override(
+    ctx: Context<ClosePrizeScheduleOverride>,
+    period_id: String,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    let ended = get_period_end_timestamp(&period_id, config.period_epoch())
+        .is_some_and(|ends_at| now >= ends_at);
+    if !ended {
+        require_future_period(&period_id, config.period_epoch(), now)?;
+    }
+
+    msg!("🗑️  Prize schedule override closed for period {}", period_id);
+
+    emit!(PrizeScheduleOverrideClosed { period_id });
+
+    Ok(())
+}
+
+/// Reject schedule changes for a period that has already started
+///
+/// # Validation
+/// - `period_id` must be a valid period ID starting after `now`
+pub fn require_future_period(period_id: &str, epoch_start: i64, now: i64) -> Result<()> {
+    let starts_at = get_period_start_timestamp(period_id, epoch_start)
+        .ok_or(VobleError::InvalidPeriodState)?;
+    require!(starts_at > now, VobleError::PeriodAlreadyStarted);
+    Ok(())
+}
+
+/// Check overridden pool splits against the config's other shares
+///
+/// # Arguments
+/// * `config` - Supplies the platform and lucky draw splits
+/// * `splits` - `[daily, weekly, monthly]` in basis points
+///
+/// # Validation
+/// - `splits` plus platform and lucky draw must add up to exactly 10000
+pub fn validate_schedule_splits(config: &GlobalConfig, splits: [u16; 3]) -> Result<()> {
+    let total = splits.iter().map(|&split| split as u32).sum::<u32>()
+        + config.platform_revenue_split as u32
+        + config.lucky_draw_split as u32;
+    require!(
+        total == BASIS_POINTS_TOTAL as u32,
+        VobleError::InvalidPrizeSplits
+    );
+    Ok(())
+}
+
+/// Pool splits a ticket is paid with
+///
+/// # Returns
+/// `[daily, weekly, monthly]` in basis points - the override's when one is
+/// given, otherwise the config's
+pub fn ticket_pool_splits(
+    config: &GlobalConfig,
+    schedule: Option<&PrizeScheduleOverride>,
+) -> [u16; 3] {
+    match schedule {
+        Some(schedule) => [
+            schedule.prize_split_daily,
+            schedule.prize_split_weekly,
+            schedule.prize_split_monthly,
+        ],
+        None => [
+            config.prize_split_daily,
+            config.prize_split_weekly,
+            config.prize_split_monthly,
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::prize::distribution::ticket_share_amounts;
+
+    const EPOCH: i64 = PERIOD_EPOCH_START;
+    const TICKET_PRICE: u64 = 1_000_000;
+
+    fn config() -> GlobalConfig {
+        let mut config =
+            GlobalConfig::try_deserialize_unchecked(&mut &vec![0u8; 8 + GlobalConfig::INIT_SPACE][..])
+                .unwrap();
+        config.ticket_price = TICKET_PRICE;
+        config.prize_split_daily = 4_000;
+        config.prize_split_weekly = 2_500;
+        config.prize_split_monthly = 2_000;
+        config.platform_revenue_split = 1_000;
+        config.lucky_draw_split = 500;
+        config
+    }
+
+    fn month_end_schedule() -> PrizeScheduleOverride {
+        PrizeScheduleOverride {
+            period_id: "D29".to_string(),
+            prize_split_daily: 2_000,
+            prize_split_weekly: 1_500,
+            prize_split_monthly: 5_000,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    /// Vault deltas of one ticket: daily, weekly, monthly, platform, lucky draw
+    fn vault_deltas(config: &GlobalConfig, schedule: Option<&PrizeScheduleOverride>) -> [u64; 5] {
+        let [daily, weekly, monthly] = ticket_pool_splits(config, schedule);
+        ticket_share_amounts(
+            config.ticket_price,
+            [
+                daily,
+                weekly,
+                monthly,
+                config.platform_revenue_split,
+                config.lucky_draw_split,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_purchase_under_override() {
+        let config = config();
+        let schedule = month_end_schedule();
+        assert!(validate_schedule_splits(&config, ticket_pool_splits(&config, Some(&schedule))).is_ok());
+
+        let deltas = vault_deltas(&config, Some(&schedule));
+
+        assert_eq!(deltas, [200_000, 150_000, 500_000, 100_000, 50_000]);
+        assert_eq!(deltas.iter().sum::<u64>(), TICKET_PRICE);
+    }
+
+    #[test]
+    fn test_purchase_without_override_uses_config() {
+        let config = config();
+
+        assert_eq!(
+            vault_deltas(&config, None),
+            [400_000, 250_000, 200_000, 100_000, 50_000]
+        );
+    }
+
+    #[test]
+    fn test_override_must_leave_platform_and_lucky_shares() {
+        let config = config();
+
+        // Pool splits alone adding up to 100% would squeeze out the other shares
+        assert_eq!(
+            validate_schedule_splits(&config, [3_000, 2_000, 5_000]).unwrap_err(),
+            VobleError::InvalidPrizeSplits.into()
+        );
+        assert!(validate_schedule_splits(&config, [3_000, 1_500, 4_000]).is_ok());
+    }
+
+    #[test]
+    fn test_only_future_periods() {
+        let day_start = EPOCH + 29 * PERIOD_DAILY_DURATION;
+
+        assert!(require_future_period("D29", EPOCH, day_start - 1).is_ok());
+        assert_eq!(
+            require_future_period("D29", EPOCH, day_start).unwrap_err(),
+            VobleError::PeriodAlreadyStarted.into()
+        );
+        assert_eq!(
+            require_future_period("legacy", EPOCH, 0).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+/// Record the program version now deployed in the global config
+///
+/// Run by the authority after each upgrade deploy, so the version stamped
+/// on new periods and sessions can be matched to a config history.
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config and authority
+///
+/// # Validation
+/// - Signer must be the config authority
+/// - The deployed PROGRAM_VERSION can't be older than the recorded one
+///
+/// # Notes
+/// Every call emits `ProgramVersionRecorded`; the events form the upgrade log.
+pub fn record_upgrade(ctx: Context<SetConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let previous_version = config.program_version;
+    config.program_version = next_program_version(previous_version, PROGRAM_VERSION)?;
+
+    let recorded_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProgramVersionRecorded {
+        authority: ctx.accounts.authority.key(),
+        previous_version,
+        program_version: config.program_version,
+        recorded_at,
+    });
+
+    msg!(
+        "🏷️  Program version recorded: {} -> {}",
+        previous_version,
+        config.program_version
+    );
+
+    Ok(())
+}
+
+/// Version to record when `deployed` runs against a config at `recorded`
+///
+/// # Returns
+/// `deployed`, or `InvalidInput` if it is older than `recorded`
+pub fn next_program_version(recorded: u16, deployed: u16) -> Result<u16> {
+    require!(deployed >= recorded, VobleError::InvalidInput);
+    Ok(deployed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_records_deployed_version() {
+        assert_eq!(next_program_version(0, PROGRAM_VERSION).unwrap(), PROGRAM_VERSION);
+        assert_eq!(next_program_version(1, 2).unwrap(), 2);
+        assert_eq!(next_program_version(2, 2).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_older_deploy_rejected() {
+        assert_eq!(
+            next_program_version(3, 2).unwrap_err(),
+            VobleError::InvalidInput.into()
+        );
+    }
+}
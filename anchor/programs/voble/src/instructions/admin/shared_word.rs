@@ -0,0 +1,134 @@
+use super::word_difficulty;
+use crate::instructions::game::{next_unused_word_index, word_index_for_period};
+use crate::utils::period::{self, parse_period_id};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Assign a daily period's shared word without repeating a recent one
+///
+/// `word_index_for_period` alone collides across days (`hash % 20`), so
+/// this probes forward from it past every word assigned in the last
+/// `GlobalConfig.shared_word_window` assignments, records the result in the
+/// `UsedWords` ring buffer and stores it in the period's `SharedWord`.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, used words, the period's
+///   shared word, the word bank and authority
+/// * `period_id` - Daily period the word is for (e.g., "D123")
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Period must be a daily period ID
+/// - Periods must be assigned in increasing order, so a word can't be
+///   re-drawn for a period that already has one
+///
+/// # Notes
+/// - The first call creates the `UsedWords` account
+/// - Result is deterministic from the period ID and the recorded history,
+///   so anyone can reproduce it
+/// - Under the commit-reveal word strategy `reset_session` plays this word
+///   for every session of the period, scored with the difficulty stamped here
+pub fn assign_shared_word(ctx: Context<AssignSharedWord>, period_id: String) -> Result<()> {
+    let used_words = &mut ctx.accounts.used_words;
+    if used_words.bump == 0 {
+        used_words.bump = ctx.bumps.used_words;
+    }
+
+    // ========== PICK WORD ==========
+    let window = ctx.accounts.global_config.shared_word_window();
+    let (word_index, hashed_index) = pick_shared_word(used_words, &period_id, window)?;
+
+    let shared_word = &mut ctx.accounts.shared_word;
+    shared_word.period_id = period_id.clone();
+    shared_word.word_index = word_index;
+    shared_word.assigned_at = Clock::get()?.unix_timestamp;
+    shared_word.bump = ctx.bumps.shared_word;
+    // Every session of the period is scored with this word's difficulty
+    let word_bank = ctx.accounts.word_bank.to_account_info();
+    shared_word.word_difficulty =
+        word_difficulty(word_bank.owner, &word_bank.try_borrow_data()?, word_index)?;
+
+    msg!("🔤 Shared word for {}: index {}", period_id, word_index);
+    msg!("   Difficulty: {}", shared_word.word_difficulty);
+    if word_index != hashed_index {
+        msg!("   Probed past recent index {}", hashed_index);
+    }
+
+    emit!(SharedWordAssigned {
+        period_id,
+        word_index,
+        hashed_index,
+    });
+
+    Ok(())
+}
+
+/// Pick and record the shared word of the next daily period
+///
+/// # Returns
+/// `(word_index, hashed_index)`: the assigned index and the
+/// `word_index_for_period` it was probed from
+///
+/// # Validation
+/// - Period must be a daily period ID (`InvalidPeriodState`)
+/// - Period must come after the last assigned one (`SharedWordAlreadyAssigned`)
+pub fn pick_shared_word(
+    used_words: &mut UsedWords,
+    period_id: &str,
+    window: usize,
+) -> Result<(u32, u32)> {
+    let (period_type, period_number) =
+        parse_period_id(period_id).ok_or(VobleError::InvalidPeriodState)?;
+    require!(
+        period_type == period::PeriodType::Daily,
+        VobleError::InvalidPeriodState
+    );
+    require_next_period(used_words, period_number)?;
+
+    let recent = used_words.recent_window(window);
+    let hashed_index = word_index_for_period(period_id, VOBLE_WORDS.len());
+    let word_index = next_unused_word_index(period_id, VOBLE_WORDS.len(), &recent);
+    used_words.record(period_number, word_index);
+
+    Ok((word_index, hashed_index))
+}
+
+/// Check that a period comes after the last one given a shared word
+pub fn require_next_period(used_words: &UsedWords, period_number: u64) -> Result<()> {
+    require!(
+        used_words.len == 0 || period_number > used_words.last_period,
+        VobleError::SharedWordAlreadyAssigned
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn used_words() -> UsedWords {
+        UsedWords {
+            recent: [0; MAX_SHARED_WORD_WINDOW],
+            len: 0,
+            next: 0,
+            last_period: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_periods_assigned_in_order() {
+        let mut used = used_words();
+        assert!(require_next_period(&used, 0).is_ok());
+
+        used.record(10, 3);
+        assert!(require_next_period(&used, 11).is_ok());
+        assert!(require_next_period(&used, 40).is_ok());
+        for period_number in [0, 9, 10] {
+            assert_eq!(
+                require_next_period(&used, period_number).unwrap_err(),
+                VobleError::SharedWordAlreadyAssigned.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::record_outflow;
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+
+/// Sweep platform revenue from the platform vault to the treasury
+///
+/// Permissionless crank that moves accumulated platform revenue to the
+/// treasury token account configured in `GlobalConfig`, so finance does not
+/// have to rely on manual `withdraw_platform_revenue` calls. The authority
+/// can still withdraw manually at any time.
+///
+/// # Arguments
+/// * `ctx` - The context containing the platform vault, treasury and cranker
+///
+/// # Validation
+/// - Treasury must be configured (set via `set_config`)
+/// - At least `sweep_interval_seconds` must have elapsed since the last sweep
+/// - Treasury must match `GlobalConfig.treasury` (enforced by the context)
+///
+/// # Sweep Amount
+/// Everything above `platform_float` is swept. Out of that amount the
+/// cranker receives `sweep_bounty` (capped at the swept amount) and the
+/// treasury receives the rest. If nothing is above the float, the call is a
+/// no-op and does not advance the schedule.
+pub fn sweep_platform_revenue(ctx: Context<SweepPlatformRevenue>) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(
+        config.treasury != Pubkey::default(),
+        VobleError::TreasuryNotConfigured
+    );
+
+    let vault_state = &mut ctx.accounts.vault_state;
+    vault_state.bump = ctx.bumps.vault_state;
+
+    require!(
+        is_sweep_due(vault_state.last_sweep, now, config.sweep_interval_seconds),
+        VobleError::SweepTooSoon
+    );
+
+    let vault_balance = ctx.accounts.platform_vault.amount;
+    let (treasury_amount, bounty_amount) =
+        calculate_sweep(vault_balance, config.platform_float, config.sweep_bounty);
+
+    msg!("🧹 Sweeping platform revenue");
+    msg!("   Vault balance: {} base units", vault_balance);
+    msg!("   Float retained: {} base units", config.platform_float);
+    msg!("   To treasury: {} base units", treasury_amount);
+    msg!("   Cranker bounty: {} base units", bounty_amount);
+
+    if treasury_amount == 0 && bounty_amount == 0 {
+        msg!("ℹ️  Nothing above the float - sweep skipped");
+        return Ok(());
+    }
+
+    // ========== TRANSFERS ==========
+    let vault_bump = ctx.accounts.global_config.bumps.platform_vault;
+    let vault_seeds = &[SEED_PLATFORM_VAULT, &[vault_bump]];
+    let signer_seeds = &[&vault_seeds[..]];
+    let decimals = ctx.accounts.usdc_mint.decimals;
+
+    if treasury_amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.platform_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.platform_vault.to_account_info(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            treasury_amount,
+            decimals,
+        )?;
+    }
+
+    if bounty_amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.platform_vault.to_account_info(),
+                    to: ctx.accounts.cranker_token_account.to_account_info(),
+                    authority: ctx.accounts.platform_vault.to_account_info(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            bounty_amount,
+            decimals,
+        )?;
+    }
+
+    // ========== UPDATE SCHEDULE ==========
+    let swept = treasury_amount + bounty_amount;
+    vault_state.last_sweep = now;
+    vault_state.total_swept = vault_state.total_swept.saturating_add(swept);
+    record_outflow(&mut ctx.accounts.platform_vault_ledger, swept, now)?;
+
+    let remaining_balance = vault_balance - swept;
+
+    // ========== EMIT EVENT ==========
+    emit!(PlatformRevenueSwept {
+        cranker: ctx.accounts.cranker.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount: treasury_amount,
+        bounty: bounty_amount,
+        remaining_balance,
+        swept_at: now,
+    });
+
+    msg!("✅ Platform revenue swept");
+    msg!("🏦 Remaining vault balance: {} base units", remaining_balance);
+
+    Ok(())
+}
+
+/// Check whether enough time has passed since the last sweep
+///
+/// # Arguments
+/// * `last_sweep` - Timestamp of the previous sweep (0 if never swept)
+/// * `now` - Current Unix timestamp
+/// * `interval` - Minimum seconds between sweeps
+pub fn is_sweep_due(last_sweep: i64, now: i64, interval: i64) -> bool {
+    now.saturating_sub(last_sweep) >= interval
+}
+
+/// Split the sweepable balance between the treasury and the cranker bounty
+///
+/// # Arguments
+/// * `vault_balance` - Current platform vault balance
+/// * `float` - Amount that must stay in the vault
+/// * `bounty` - Bounty owed to the cranker
+///
+/// # Returns
+/// Tuple of (treasury_amount, bounty_amount). The bounty never exceeds the
+/// sweepable amount and the float is never touched.
+pub fn calculate_sweep(vault_balance: u64, float: u64, bounty: u64) -> (u64, u64) {
+    let sweepable = vault_balance.saturating_sub(float);
+    let bounty_amount = bounty.min(sweepable);
+
+    (sweepable - bounty_amount, bounty_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_sweep_due() {
+        // First sweep is always allowed
+        assert!(is_sweep_due(0, 1_700_000_000, DEFAULT_SWEEP_INTERVAL));
+
+        // Inside the interval
+        assert!(!is_sweep_due(1_000, 1_000 + DEFAULT_SWEEP_INTERVAL - 1, DEFAULT_SWEEP_INTERVAL));
+
+        // Exactly at the interval boundary
+        assert!(is_sweep_due(1_000, 1_000 + DEFAULT_SWEEP_INTERVAL, DEFAULT_SWEEP_INTERVAL));
+    }
+
+    #[test]
+    fn test_calculate_sweep_retains_float() {
+        let (treasury, bounty) = calculate_sweep(1_000_000, 100_000, 1_000);
+
+        assert_eq!(treasury, 899_000);
+        assert_eq!(bounty, 1_000);
+        assert_eq!(1_000_000 - treasury - bounty, 100_000); // Float untouched
+    }
+
+    #[test]
+    fn test_calculate_sweep_below_float() {
+        assert_eq!(calculate_sweep(50_000, 100_000, 1_000), (0, 0));
+        assert_eq!(calculate_sweep(100_000, 100_000, 1_000), (0, 0));
+    }
+
+    #[test]
+    fn test_calculate_sweep_bounty_capped() {
+        // Bounty larger than sweepable amount takes only what is available
+        assert_eq!(calculate_sweep(100_500, 100_000, 1_000), (0, 500));
+    }
+}
@@ -0,0 +1,484 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+use solana_program::hash::hashv;
+
+/// Announce a treasury-sensitive admin action before executing it
+///
+/// The guarded instruction later passes this account and only runs if its
+/// arguments hash to `payload_hash` and `execute_after` has passed; the
+/// account is closed when it executes.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the new pending action and authority
+/// * `action_kind` - 0 = ticket price, 1 = platform withdrawal, 2 = timelock settings,
+///   3 = prize schedule override, 4 = sweep settings
+/// * `payload_hash` - Hash of the guarded arguments (`ticket_price_payload`,
+///   `withdraw_payload`, `timelock_settings_payload`, `prize_schedule_payload`
+///   or `sweep_settings_payload`)
+/// * `execute_after` - Earliest time the action may execute
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Action kind must be 0 to 4
+/// - `execute_after` must be at least `admin_timelock()` seconds from now
+/// - The same payload can't be queued twice (PDA seeds)
+pub fn queue_admin_action(
+    ctx: Context<QueueAdminAction>,
+    action_kind: u8,
+    payload_hash: [u8; 32],
+    execute_after: i64,
+) -> Result<()> {
+    let kind = admin_action_kind(action_kind)?;
+    let now = Clock::get()?.unix_timestamp;
+    let delay = ctx.accounts.global_config.admin_timelock();
+
+    // ========== VALIDATION ==========
+    require!(
+        execute_after >= now.saturating_add(delay),
+        VobleError::TimelockTooShort
+    );
+
+    // ========== QUEUE ==========
+    let action = &mut ctx.accounts.pending_action;
+    action.authority = ctx.accounts.authority.key();
+    action.kind = kind;
+    action.payload_hash = payload_hash;
+    action.queued_at = now;
+    action.execute_after = execute_after;
+    action.bump = ctx.bumps.pending_action;
+
+    msg!("⏳ Admin action queued: {:?}", kind);
+    msg!("   Executable after: {}", execute_after);
+
+    emit!(AdminActionQueued {
+        authority: action.authority,
+        kind,
+        payload_hash,
+        queued_at: now,
+        execute_after,
+    });
+
+    Ok(())
+}
+
+/// Withdraw a queued admin action before it executes
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, the pending action and authority
+/// * `payload_hash` - Payload hash the action was queued with
+///
+/// # Notes
+/// The account's rent goes back to the authority
+pub fn cancel_admin_action(ctx: Context<CancelAdminAction>, payload_hash: [u8; 32]) -> Result<()> {
+    let action = &ctx.accounts.pending_action;
+
+    msg!("🗑️  Admin action canceled: {:?}", action.kind);
+
+    emit!(AdminActionCanceled {
+        authority: ctx.accounts.authority.key(),
+        kind: action.kind,
+        payload_hash,
+    });
+
+    Ok(())
+}
+
+/// Set the admin timelock delay and the withdrawal threshold
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config account and authority
+/// * `delay_secs` - Optional delay between queueing and executing an action
+/// * `withdraw_threshold` - Optional platform withdrawal above which the timelock applies
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Delay must be >= MIN_ADMIN_TIMELOCK and the threshold positive
+/// - Loosening either (shorter delay, higher threshold) requires a matured
+///   `TimelockSettings` action for the new values; tightening applies at once
+pub fn set_admin_timelock(
+    ctx: Context<SetConfig>,
+    delay_secs: Option<i64>,
+    withdraw_threshold: Option<u64>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let old_delay = config.admin_timelock();
+    let old_threshold = config.timelock_withdraw_threshold();
+    let delay = delay_secs.unwrap_or(old_delay);
+    let threshold = withdraw_threshold.unwrap_or(old_threshold);
+
+    // ========== VALIDATION ==========
+    require!(delay >= MIN_ADMIN_TIMELOCK, VobleError::InvalidInput);
+    require!(threshold > 0, VobleError::InvalidInput);
+
+    if delay < old_delay || threshold > old_threshold {
+        consume_pending_action(
+            &mut ctx.accounts.pending_action,
+            &ctx.accounts.authority,
+            AdminActionKind::TimelockSettings,
+            timelock_settings_payload(delay, threshold),
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
+    // ========== UPDATE ==========
+    config.admin_timelock_secs = delay;
+    config.timelock_withdraw_threshold = threshold;
+
+    msg!("⏳ Admin timelock: {} -> {} seconds", old_delay, delay);
+    msg!("   Withdrawal threshold: {} -> {} base units", old_threshold, threshold);
+
+    Ok(())
+}
+
+/// Parse an action kind argument
+pub fn admin_action_kind(action_kind: u8) -> Result<AdminActionKind> {
+    match action_kind {
+        0 => Ok(AdminActionKind::TicketPrice),
+        1 => Ok(AdminActionKind::WithdrawRevenue),
+        2 => Ok(AdminActionKind::TimelockSettings),
+        3 => Ok(AdminActionKind::PrizeSchedule),
+        4 => Ok(AdminActionKind::SweepSettings),
+        _ => Err(VobleError::InvalidInput.into()),
+    }
+}
+
+/// Payload hash of a timelocked ticket price change
+pub fn ticket_price_payload(ticket_price: u64) -> [u8; 32] {
+    hashv(&[
+        &[AdminActionKind::TicketPrice as u8][..],
+        &ticket_price.to_le_bytes()[..],
+    ])
+    .to_bytes()
+}
+
+/// Payload hash of a timelocked platform withdrawal
+///
+/// Binds the exact amount, so a withdrawal queued without one must be
+/// executed with the amount that was hashed.
+pub fn withdraw_payload(amount: u64, destination: &Pubkey) -> [u8; 32] {
+    hashv(&[
+        &[AdminActionKind::WithdrawRevenue as u8][..],
+        &amount.to_le_bytes()[..],
+        destination.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Payload hash of loosened timelock settings
+pub fn timelock_settings_payload(delay_secs: i64, withdraw_threshold: u64) -> [u8; 32] {
+    hashv(&[
+        &[AdminActionKind::TimelockSettings as u8][..],
+        &delay_secs.to_le_bytes()[..],
+        &withdraw_threshold.to_le_bytes()[..],
+    ])
+    .to_bytes()
+}
+
+/// Payload hash of a timelocked prize schedule override
+///
+/// Binds the period and all three pool splits.
+pub fn prize_schedule_payload(period_id: &str, splits: [u16; 3]) -> [u8; 32] {
+    hashv(&[
+        &[AdminActionKind::PrizeSchedule as u8][..],
+        period_id.as_bytes(),
+        &splits[0].to_le_bytes()[..],
+        &splits[1].to_le_bytes()[..],
+        &splits[2].to_le_bytes()[..],
+    ])
+    .to_bytes()
+}
+
+/// Payload hash of timelocked sweep settings
+///
+/// Binds all three settings as they will be after the change, so fields left
+/// unchanged must still match the config when the action executes.
+pub fn sweep_settings_payload(treasury: &Pubkey, platform_float: u64, sweep_bounty: u64) -> [u8; 32] {
+    hashv(&[
+        &[AdminActionKind::SweepSettings as u8][..],
+        treasury.as_ref(),
+        &platform_float.to_le_bytes()[..],
+        &sweep_bounty.to_le_bytes()[..],
+    ])
+    .to_bytes()
+}
+
+/// Check whether a ticket price change is small enough to skip the timelock
+///
+/// # Arguments
+/// * `reference_price` - `GlobalConfig::ticket_price_reference()`, not the
+///   current price, so successive small steps can't add up
+/// * `new_price` - Requested ticket price
+///
+/// # Returns
+/// `true` within ±TIMELOCK_FREE_PRICE_CHANGE_BPS of the reference price
+pub fn is_small_price_change(reference_price: u64, new_price: u64) -> bool {
+    let change = reference_price.abs_diff(new_price) as u128 * BASIS_POINTS_TOTAL as u128;
+    change <= reference_price as u128 * TIMELOCK_FREE_PRICE_CHANGE_BPS as u128
+}
+
+/// Count an untimelocked platform withdrawal against its window
+///
+/// Windows last `window_secs` (the admin timelock), so within any one of
+/// them the untimelocked withdrawals add up to at most the threshold;
+/// splitting a large withdrawal gains nothing over waiting out the timelock.
+///
+/// # Returns
+/// `(window_start, withdrawn)` after counting `amount`; a window that has
+/// run out restarts at `now` holding only `amount`
+pub fn next_withdraw_window(
+    window_start: i64,
+    withdrawn: u64,
+    now: i64,
+    window_secs: i64,
+    amount: u64,
+) -> (i64, u64) {
+    if now >= window_start.saturating_add(window_secs) {
+        (now, amount)
+    } else {
+        (window_start, withdrawn.saturating_add(amount))
+    }
+}
+
+/// Check a pending action against the call it is meant to authorize
+///
+/// # Validation
+/// - Must have been queued by `authority` (`Unauthorized`)
+/// - Kind and payload hash must match the call (`TimelockPayloadMismatch`)
+/// - `execute_after` must have passed (`TimelockNotMatured`)
+pub fn check_pending_action(
+    action: &PendingAdminAction,
+    authority: &Pubkey,
+    kind: AdminActionKind,
+    payload_hash: &[u8; 32],
+    now: i64,
+) -> Result<()> {
+    require_keys_eq!(action.authority, *authority, VobleError::Unauthorized);
+    require!(
+        action.kind == kind && action.payload_hash == *payload_hash,
+        VobleError::TimelockPayloadMismatch
+    );
+    require!(now >= action.execute_after, VobleError::TimelockNotMatured);
+    Ok(())
+}
+
+/// Authorize a timelocked call with its matured pending action and close it
+///
+/// # Validation
+/// - A pending action must be passed (`TimelockRequired`)
+/// - Everything `check_pending_action` checks
+pub fn consume_pending_action<'info>(
+    pending_action: &mut Option<Account<'info, PendingAdminAction>>,
+    authority: &Signer<'info>,
+    kind: AdminActionKind,
+    payload_hash: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    let action = pending_action
+        .take()
+        .ok_or(VobleError::TimelockRequired)?;
+    check_pending_action(&action, &authority.key(), kind, &payload_hash, now)?;
+
+    action.close(authority.to_account_info())?;
+
+    msg!("🔓 Timelocked {:?} executed", kind);
+    emit!(AdminActionExecuted {
+        authority: authority.key(),
+        kind,
+        payload_hash,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUEUED_AT: i64 = 1_700_000_000;
+
+    fn pending(authority: Pubkey, kind: AdminActionKind, payload_hash: [u8; 32]) -> PendingAdminAction {
+        PendingAdminAction {
+            authority,
+            kind,
+            payload_hash,
+            queued_at: QUEUED_AT,
+            execute_after: QUEUED_AT + DEFAULT_ADMIN_TIMELOCK,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_payload_hash_mismatch_rejected() {
+        let authority = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let action = pending(
+            authority,
+            AdminActionKind::WithdrawRevenue,
+            withdraw_payload(5_000_000_000, &destination),
+        );
+        let matured = action.execute_after;
+
+        assert!(check_pending_action(
+            &action,
+            &authority,
+            AdminActionKind::WithdrawRevenue,
+            &withdraw_payload(5_000_000_000, &destination),
+            matured
+        )
+        .is_ok());
+
+        // A different amount, destination or kind doesn't match the queued payload
+        for (kind, payload_hash) in [
+            (
+                AdminActionKind::WithdrawRevenue,
+                withdraw_payload(5_000_000_001, &destination),
+            ),
+            (
+                AdminActionKind::WithdrawRevenue,
+                withdraw_payload(5_000_000_000, &Pubkey::new_unique()),
+            ),
+            (AdminActionKind::TicketPrice, ticket_price_payload(5_000_000_000)),
+        ] {
+            assert_eq!(
+                check_pending_action(&action, &authority, kind, &payload_hash, matured)
+                    .unwrap_err(),
+                VobleError::TimelockPayloadMismatch.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_action_executes_only_after_delay() {
+        let authority = Pubkey::new_unique();
+        let payload_hash = ticket_price_payload(2_000_000);
+        let action = pending(authority, AdminActionKind::TicketPrice, payload_hash);
+
+        assert_eq!(
+            check_pending_action(
+                &action,
+                &authority,
+                AdminActionKind::TicketPrice,
+                &payload_hash,
+                action.execute_after - 1
+            )
+            .unwrap_err(),
+            VobleError::TimelockNotMatured.into()
+        );
+        assert_eq!(
+            check_pending_action(
+                &action,
+                &Pubkey::new_unique(),
+                AdminActionKind::TicketPrice,
+                &payload_hash,
+                action.execute_after
+            )
+            .unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+
+    #[test]
+    fn test_small_price_changes_skip_timelock() {
+        assert!(is_small_price_change(1_000_000, 1_100_000));
+        assert!(is_small_price_change(1_000_000, 900_000));
+        assert!(is_small_price_change(1_000_000, 1_000_000));
+        assert!(!is_small_price_change(1_000_000, 1_100_001));
+        assert!(!is_small_price_change(1_000_000, 899_999));
+    }
+
+    #[test]
+    fn test_repeated_small_price_steps_stay_in_band() {
+        // Each +10% step is small against the step before it, but the band
+        // is measured from the reference, so the second step needs the timelock
+        let reference = 1_000_000;
+        assert!(is_small_price_change(reference, 1_100_000));
+        assert!(is_small_price_change(1_100_000, 1_210_000));
+        assert!(!is_small_price_change(reference, 1_210_000));
+    }
+
+    #[test]
+    fn test_split_withdrawals_add_up_within_window() {
+        let threshold = DEFAULT_TIMELOCK_WITHDRAW_THRESHOLD;
+        let window = DEFAULT_ADMIN_TIMELOCK;
+        let step = threshold / 2 + 1;
+
+        // First withdrawal opens a window, the second one falls in it and
+        // takes the total past the threshold
+        let (start, withdrawn) = next_withdraw_window(0, 0, QUEUED_AT, window, step);
+        assert_eq!((start, withdrawn), (QUEUED_AT, step));
+        let (_, withdrawn) = next_withdraw_window(start, withdrawn, QUEUED_AT + 60, window, step);
+        assert!(withdrawn > threshold);
+
+        // Once the window has run out, the count starts over
+        assert_eq!(
+            next_withdraw_window(start, step, QUEUED_AT + window, window, step),
+            (QUEUED_AT + window, step)
+        );
+    }
+
+    #[test]
+    fn test_payload_hashes_are_distinct_per_kind() {
+        // Same numeric argument under two kinds can't authorize each other
+        assert_ne!(ticket_price_payload(7), timelock_settings_payload(7, 0));
+        assert_eq!(admin_action_kind(1).unwrap(), AdminActionKind::WithdrawRevenue);
+        assert_eq!(admin_action_kind(3).unwrap(), AdminActionKind::PrizeSchedule);
+        assert_eq!(admin_action_kind(4).unwrap(), AdminActionKind::SweepSettings);
+        assert_eq!(
+            admin_action_kind(5).unwrap_err(),
+            VobleError::InvalidInput.into()
+        );
+    }
+
+    #[test]
+    fn test_sweep_settings_payload_binds_all_three() {
+        let treasury = Pubkey::new_unique();
+        let payload_hash = sweep_settings_payload(&treasury, 1_000_000, 5_000);
+
+        // Each setting, including one the call leaves unchanged, is part of the hash
+        for other in [
+            sweep_settings_payload(&Pubkey::new_unique(), 1_000_000, 5_000),
+            sweep_settings_payload(&treasury, 0, 5_000),
+            sweep_settings_payload(&treasury, 1_000_000, 5_001),
+        ] {
+            assert_ne!(payload_hash, other);
+        }
+    }
+
+    #[test]
+    fn test_prize_schedule_payload_binds_period_and_splits() {
+        let authority = Pubkey::new_unique();
+        let splits = [2_000, 1_500, 5_000];
+        let action = pending(
+            authority,
+            AdminActionKind::PrizeSchedule,
+            prize_schedule_payload("D29", splits),
+        );
+        let matured = action.execute_after;
+
+        assert!(check_pending_action(
+            &action,
+            &authority,
+            AdminActionKind::PrizeSchedule,
+            &prize_schedule_payload("D29", splits),
+            matured
+        )
+        .is_ok());
+
+        for payload_hash in [
+            prize_schedule_payload("D30", splits),
+            prize_schedule_payload("D29", [2_000, 5_000, 1_500]),
+        ] {
+            assert_eq!(
+                check_pending_action(
+                    &action,
+                    &authority,
+                    AdminActionKind::PrizeSchedule,
+                    &payload_hash,
+                    matured
+                )
+                .unwrap_err(),
+                VobleError::TimelockPayloadMismatch.into()
+            );
+        }
+    }
+}
@@ -1,29 +1,160 @@
-use crate::{constants::*, contexts::*, errors::VobleError};
+use crate::{constants::*, contexts::*, errors::VobleError, state::{AdminActionKind, CharClass, LeaderboardMode, PeriodType, RemainderPolicy, WordStrategy}};
+use super::{consume_pending_action, is_small_price_change, sweep_settings_payload, ticket_price_payload};
+use crate::instructions::game::require_word_source_playable;
+use crate::utils::words::word_list;
+use crate::utils::ratelimit::{session_action_gap, SessionAction};
+use crate::utils::validation::validate_word_list;
 use anchor_lang::prelude::*;
 
+/// Fields `set_config` changes; `None` leaves a field as it is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct ConfigUpdate {
+    /// New ticket price in the payment mint's base units
+    pub ticket_price: Option<u64>,
+    /// New pause state (true = game paused, false = active)
+    pub paused: Option<bool>,
+    /// Treasury token account for swept revenue
+    pub treasury: Option<Pubkey>,
+    /// Minimum interval between sweeps
+    pub sweep_interval_seconds: Option<i64>,
+    /// Amount kept in the platform vault on sweep
+    pub platform_float: Option<u64>,
+    /// Bounty paid to the sweep cranker
+    pub sweep_bounty: Option<u64>,
+    /// Minimum seconds between purchases (0 = off)
+    pub ticket_cooldown_secs: Option<i64>,
+    /// Daily word strategy (0 = deterministic, 1 = commit-reveal)
+    pub word_strategy: Option<u8>,
+    /// Cap on new entrants per period (u32::MAX = uncapped)
+    pub max_participants_per_period: Option<u32>,
+    /// Mode for new weekly/monthly boards (0 = live, 1 = daily rollup)
+    pub leaderboard_mode: Option<u8>,
+    /// Split remainder destination (0 = 1st place, 1 = lucky draw, 2 = platform)
+    pub remainder_policy: Option<u8>,
+    /// Switch for sharing prizes between winners tied for a paid rank
+    pub split_tied_prizes: Option<bool>,
+    /// Session age after which anyone may undelegate it
+    pub session_stale_after_secs: Option<i64>,
+    /// Cap on sessions delegated to the ER at once
+    pub max_active_sessions: Option<u32>,
+    /// Compute budget per settlement call handler
+    pub handler_compute_units: Option<u32>,
+    /// Magic Actions escrow index paying for the handlers
+    pub handler_escrow_index: Option<u8>,
+    /// Switch for settling leaderboards and profile in separate handlers
+    pub split_stats_handlers: Option<bool>,
+    /// Flat score bonus for a first-guess solve (0 = off)
+    pub hole_in_one_bonus: Option<u32>,
+    /// Time after a period ends that its games still reach the leaderboards
+    pub grace_window_seconds: Option<i64>,
+    /// Lamports sent to each winner on entitlement creation (0 = off)
+    pub notify_lamports: Option<u64>,
+    /// Number of skipped periods after which a win streak resets (0 = unlimited)
+    pub streak_decay_periods: Option<u32>,
+    /// Switch for letting players retype their current guess once per game
+    pub allow_mulligan: Option<bool>,
+    /// Number of recent shared daily words a new one may not repeat
+    pub shared_word_window: Option<u8>,
+    /// Number of entries new leaderboards keep
+    pub leaderboard_capacity: Option<u16>,
+    /// Character class of words and guesses (0 = alphabetic, 1 = numeric, 2 = alphanumeric)
+    pub char_class: Option<u8>,
+    /// Word list tickets draw the session word from (0 = daily, 1 = weekly, 2 = monthly)
+    pub ticket_word_source: Option<u8>,
+}
+
 /// Update the global configuration settings
 ///
 /// This instruction allows the authority to modify game settings:
 /// - Ticket price
 /// - Game pause state
+/// - Platform revenue sweep schedule (treasury, interval, float, bounty)
+/// - Ticket purchase cooldown
+/// - Daily word strategy (deterministic or commit-reveal)
+/// - Per-period participant cap
+/// - Leaderboard mode (live per-game writes or daily rollup)
+/// - Prize split remainder policy
+/// - Prize splitting between tied winners
+/// - Staleness window for permissionless session recovery
+/// - Cap on sessions delegated to the ER at once
+/// - Settlement call handler budget, escrow and layout
+/// - Hole in one bonus
+/// - Grace window for games committed after their period ends
+/// - SOL nudge sent to winners on entitlement creation
+/// - Character class of words and guesses
+/// - Word list tickets draw their session word from
 ///
 /// # Arguments
 /// * `ctx` - The context containing the global config account and authority
-/// * `ticket_price` - Optional new ticket price in lamports
-/// * `paused` - Optional new pause state (true = game paused, false = active)
+/// * `update` - The fields to change (see `ConfigUpdate`)
 ///
 /// # Validation
 /// - Only the authority can call this instruction
-/// - If ticket_price is provided, it must be >= MIN_TICKET_PRICE
+/// - If ticket_price is provided, it must be >= MIN_TICKET_PRICE; a price
+///   beyond ±10% of the reference price (the last timelocked or initial
+///   price) needs a matured `TicketPrice` pending action for the new price
+///   (see `queue_admin_action`)
+/// - Changing treasury, platform_float or sweep_bounty needs a matured
+///   `SweepSettings` pending action for the resulting three values
+/// - If sweep_interval_seconds is provided, it must be positive
+/// - If ticket_cooldown_secs is provided, it must not be negative
+/// - If word_strategy is provided, it must be 0 or 1
+/// - If max_participants_per_period is provided, it must be positive
+/// - If leaderboard_mode is provided, it must be 0 or 1
+/// - If remainder_policy is provided, it must be 0, 1 or 2
+/// - If session_stale_after_secs is provided, it must be >= MIN_SESSION_STALE_AFTER
+/// - If max_active_sessions is provided, it must be positive
+/// - If handler_compute_units is provided, it must be within
+///   MIN_HANDLER_COMPUTE_UNITS..=MAX_HANDLER_COMPUTE_UNITS
+/// - If hole_in_one_bonus is provided, it must be <= MAX_HOLE_IN_ONE_BONUS
+/// - If grace_window_seconds is provided, it must be positive and <= MAX_SCORE_GRACE_WINDOW
+/// - If notify_lamports is provided, it must be <= MAX_NOTIFY_LAMPORTS
+/// - If shared_word_window is provided, it must be within 1..=MAX_SHARED_WORD_WINDOW
+/// - If leaderboard_capacity is provided, it must be within
+///   TOP_WINNERS_COUNT..=MAX_LEADERBOARD_CAPACITY
+/// - If char_class is provided, it must be 0, 1 or 2 and every word in the
+///   daily, weekly and monthly lists must belong to it
+/// - If ticket_word_source is provided, it must be 0, 1 or 2 (`InvalidWordSource`)
+/// - If word_strategy or ticket_word_source is provided, commit-reveal must be
+///   left with the daily word source (`CommitRevealNeedsDailyWords`)
 ///
 /// # Notes
 /// This is a flexible update function that allows updating individual fields
-/// without requiring all fields to be passed.
-pub fn set_config(
-    ctx: Context<SetConfig>,
-    ticket_price: Option<u64>,
-    paused: Option<bool>,
-) -> Result<()> {
+/// without requiring all fields to be passed. Only one pending action can be
+/// passed, so a timelocked price change and a sweep settings change go in
+/// separate calls.
+///
+/// The word lists are compiled into the program, so a numeric variant is a
+/// build with numeric lists; the built-in letter lists reject `char_class` 1.
+pub fn set_config(ctx: Context<SetConfig>, update: ConfigUpdate) -> Result<()> {
+    let ConfigUpdate {
+        ticket_price,
+        paused,
+        treasury,
+        sweep_interval_seconds,
+        platform_float,
+        sweep_bounty,
+        ticket_cooldown_secs,
+        word_strategy,
+        max_participants_per_period,
+        leaderboard_mode,
+        remainder_policy,
+        split_tied_prizes,
+        session_stale_after_secs,
+        max_active_sessions,
+        handler_compute_units,
+        handler_escrow_index,
+        split_stats_handlers,
+        hole_in_one_bonus,
+        grace_window_seconds,
+        notify_lamports,
+        streak_decay_periods,
+        allow_mulligan,
+        shared_word_window,
+        leaderboard_capacity,
+        char_class,
+        ticket_word_source,
+    } = update;
     let config = &mut ctx.accounts.global_config;
     let mut updated_fields = Vec::new();
 
@@ -31,11 +162,28 @@ pub fn set_config(
     if let Some(price) = ticket_price {
         require!(price >= MIN_TICKET_PRICE, VobleError::InvalidPrizeSplits);
 
+        // Prices beyond ±10% of the reference must have been announced on-chain
+        // first. The reference only moves with a timelocked change, so
+        // repeated small steps can't walk the price past the band.
+        let reference = config.ticket_price_reference();
+        if is_small_price_change(reference, price) {
+            config.reference_ticket_price = reference;
+        } else {
+            consume_pending_action(
+                &mut ctx.accounts.pending_action,
+                &ctx.accounts.authority,
+                AdminActionKind::TicketPrice,
+                ticket_price_payload(price),
+                Clock::get()?.unix_timestamp,
+            )?;
+            config.reference_ticket_price = price;
+        }
+
         let old_price = config.ticket_price;
         config.ticket_price = price;
 
         msg!(
-            "💰 Ticket price updated: {} -> {} lamports",
+            "💰 Ticket price updated: {} -> {} base units",
             old_price,
             price
         );
@@ -51,6 +199,304 @@ pub fn set_config(
         updated_fields.push("paused");
     }
 
+    // Sweep destination and amounts move platform funds, so any change must
+    // have been announced on-chain first
+    let new_sweep_settings = (
+        treasury.unwrap_or(config.treasury),
+        platform_float.unwrap_or(config.platform_float),
+        sweep_bounty.unwrap_or(config.sweep_bounty),
+    );
+    if new_sweep_settings != (config.treasury, config.platform_float, config.sweep_bounty) {
+        let (new_treasury, new_float, new_bounty) = new_sweep_settings;
+        consume_pending_action(
+            &mut ctx.accounts.pending_action,
+            &ctx.accounts.authority,
+            AdminActionKind::SweepSettings,
+            sweep_settings_payload(&new_treasury, new_float, new_bounty),
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
+    // Update sweep treasury if provided
+    if let Some(new_treasury) = treasury {
+        config.treasury = new_treasury;
+
+        msg!("🏦 Treasury updated: {}", new_treasury);
+        updated_fields.push("treasury");
+    }
+
+    // Update sweep interval if provided
+    if let Some(interval) = sweep_interval_seconds {
+        require!(interval > 0, VobleError::InvalidInput);
+
+        let old_interval = config.sweep_interval_seconds;
+        config.sweep_interval_seconds = interval;
+
+        msg!("⏱️  Sweep interval updated: {} -> {} seconds", old_interval, interval);
+        updated_fields.push("sweep_interval_seconds");
+    }
+
+    // Update platform float if provided
+    if let Some(float) = platform_float {
+        let old_float = config.platform_float;
+        config.platform_float = float;
+
+        msg!("💵 Platform float updated: {} -> {}", old_float, float);
+        updated_fields.push("platform_float");
+    }
+
+    // Update sweep bounty if provided
+    if let Some(bounty) = sweep_bounty {
+        let old_bounty = config.sweep_bounty;
+        config.sweep_bounty = bounty;
+
+        msg!("🎯 Sweep bounty updated: {} -> {}", old_bounty, bounty);
+        updated_fields.push("sweep_bounty");
+    }
+
+    // Update ticket cooldown if provided
+    if let Some(cooldown) = ticket_cooldown_secs {
+        require!(cooldown >= 0, VobleError::InvalidInput);
+
+        let old_cooldown = config.ticket_cooldown_secs;
+        config.ticket_cooldown_secs = cooldown;
+
+        msg!("🧊 Ticket cooldown updated: {} -> {} seconds", old_cooldown, cooldown);
+        updated_fields.push("ticket_cooldown_secs");
+    }
+
+    // Update word strategy if provided
+    if let Some(strategy) = word_strategy {
+        let new_strategy = match strategy {
+            0 => WordStrategy::Deterministic,
+            1 => WordStrategy::CommitReveal,
+            _ => return Err(VobleError::InvalidWordStrategy.into()),
+        };
+
+        let old_strategy = config.word_strategy;
+        config.word_strategy = new_strategy;
+
+        msg!("🎲 Word strategy updated: {:?} -> {:?}", old_strategy, new_strategy);
+        updated_fields.push("word_strategy");
+    }
+
+    // Update participant cap if provided
+    if let Some(cap) = max_participants_per_period {
+        require!(cap > 0, VobleError::InvalidInput);
+
+        let old_cap = config.max_participants_per_period;
+        config.max_participants_per_period = cap;
+
+        msg!("👥 Participant cap updated: {} -> {}", old_cap, cap);
+        updated_fields.push("max_participants_per_period");
+    }
+
+    // Update leaderboard mode if provided (boards already created keep theirs)
+    if let Some(mode) = leaderboard_mode {
+        let new_mode = match mode {
+            0 => LeaderboardMode::LivePerGame,
+            1 => LeaderboardMode::DailyRollup,
+            _ => return Err(VobleError::InvalidLeaderboardMode.into()),
+        };
+
+        let old_mode = config.leaderboard_mode;
+        config.leaderboard_mode = new_mode;
+
+        msg!("📊 Leaderboard mode updated: {:?} -> {:?}", old_mode, new_mode);
+        updated_fields.push("leaderboard_mode");
+    }
+
+    // Update remainder policy if provided (applies to periods finalized from now on)
+    if let Some(policy) = remainder_policy {
+        let new_policy = match policy {
+            0 => RemainderPolicy::ToFirstPlace,
+            1 => RemainderPolicy::ToLuckyDraw,
+            2 => RemainderPolicy::ToPlatform,
+            _ => return Err(VobleError::InvalidInput.into()),
+        };
+
+        let old_policy = config.remainder_policy;
+        config.remainder_policy = new_policy;
+
+        msg!("🪙 Remainder policy updated: {:?} -> {:?}", old_policy, new_policy);
+        updated_fields.push("remainder_policy");
+    }
+
+    // Update tied prize handling if provided (applies to periods finalized from now on)
+    if let Some(split) = split_tied_prizes {
+        let old_split = config.split_tied_prizes;
+        config.split_tied_prizes = split;
+
+        msg!("🤝 Split tied prizes updated: {} -> {}", old_split, split);
+        updated_fields.push("split_tied_prizes");
+    }
+
+    // Update the stale session window if provided
+    if let Some(window) = session_stale_after_secs {
+        require!(window >= MIN_SESSION_STALE_AFTER, VobleError::InvalidInput);
+
+        let old_window = config.session_stale_after();
+        config.session_stale_after_secs = window;
+
+        msg!("⌛ Session stale window updated: {}s -> {}s", old_window, window);
+        updated_fields.push("session_stale_after_secs");
+    }
+
+    // Update the active session cap if provided (sessions already delegated are unaffected)
+    if let Some(cap) = max_active_sessions {
+        require!(cap > 0, VobleError::InvalidInput);
+
+        let old_cap = config.active_session_cap();
+        config.max_active_sessions = cap;
+
+        msg!("🚦 Max active sessions updated: {} -> {}", old_cap, cap);
+        updated_fields.push("max_active_sessions");
+    }
+
+    // Update the settlement handler budget if provided (applies to the next commit)
+    if let Some(units) = handler_compute_units {
+        require!(
+            (MIN_HANDLER_COMPUTE_UNITS..=MAX_HANDLER_COMPUTE_UNITS).contains(&units),
+            VobleError::InvalidInput
+        );
+
+        let old_units = config.handler_compute_budget();
+        config.handler_compute_units = units;
+
+        msg!("⚙️  Handler compute units updated: {} -> {}", old_units, units);
+        updated_fields.push("handler_compute_units");
+    }
+
+    // Update the handler escrow index if provided
+    if let Some(index) = handler_escrow_index {
+        let old_index = config.handler_escrow_index;
+        config.handler_escrow_index = index;
+
+        msg!("⚙️  Handler escrow index updated: {} -> {}", old_index, index);
+        updated_fields.push("handler_escrow_index");
+    }
+
+    // Update the handler layout if provided
+    if let Some(split) = split_stats_handlers {
+        let old_split = config.split_stats_handlers;
+        config.split_stats_handlers = split;
+
+        msg!("⚙️  Split stats handlers updated: {} -> {}", old_split, split);
+        updated_fields.push("split_stats_handlers");
+    }
+
+    // Update the hole in one bonus if provided (applies to tickets bought afterwards)
+    if let Some(bonus) = hole_in_one_bonus {
+        require!(bonus <= MAX_HOLE_IN_ONE_BONUS, VobleError::InvalidInput);
+
+        let old_bonus = config.hole_in_one_bonus;
+        config.hole_in_one_bonus = bonus;
+
+        msg!("⚙️  Hole in one bonus updated: {} -> {}", old_bonus, bonus);
+        updated_fields.push("hole_in_one_bonus");
+    }
+
+    // Update the score grace window if provided (applies to the next settlement commit)
+    if let Some(window) = grace_window_seconds {
+        require!(
+            window > 0 && window <= MAX_SCORE_GRACE_WINDOW,
+            VobleError::InvalidInput
+        );
+
+        let old_window = config.score_grace_window();
+        config.grace_window_seconds = window;
+
+        msg!("⚙️  Score grace window updated: {}s -> {}s", old_window, window);
+        updated_fields.push("grace_window_seconds");
+    }
+
+    // Update the winner notification amount if provided
+    if let Some(lamports) = notify_lamports {
+        require!(lamports <= MAX_NOTIFY_LAMPORTS, VobleError::InvalidInput);
+
+        let old_lamports = config.notify_lamports;
+        config.notify_lamports = lamports;
+
+        msg!("🔔 Winner notification updated: {} -> {} lamports", old_lamports, lamports);
+        updated_fields.push("notify_lamports");
+    }
+
+    // Update the streak decay if provided (checked on each ticket purchase)
+    if let Some(periods) = streak_decay_periods {
+        let old_periods = config.streak_decay_periods;
+        config.streak_decay_periods = periods;
+
+        msg!("⚙️  Streak decay updated: {} -> {} periods", old_periods, periods);
+        updated_fields.push("streak_decay_periods");
+    }
+
+    // Update the mulligan switch if provided (applies to games in progress)
+    if let Some(allow) = allow_mulligan {
+        let old_allow = config.allow_mulligan;
+        config.allow_mulligan = allow;
+
+        msg!("🔁 Allow mulligan updated: {} -> {}", old_allow, allow);
+        updated_fields.push("allow_mulligan");
+    }
+
+    // Update the shared word no-repeat window if provided (applies to the next assignment)
+    if let Some(window) = shared_word_window {
+        require!(
+            window > 0 && window as usize <= MAX_SHARED_WORD_WINDOW,
+            VobleError::InvalidInput
+        );
+
+        let old_window = config.shared_word_window();
+        config.shared_word_window = window;
+
+        msg!("🔤 Shared word window updated: {} -> {}", old_window, window);
+        updated_fields.push("shared_word_window");
+    }
+
+    // Update the leaderboard capacity if provided (snapshotted by leaderboards initialized afterwards)
+    if let Some(capacity) = leaderboard_capacity {
+        require!(
+            capacity as usize >= TOP_WINNERS_COUNT && capacity <= MAX_LEADERBOARD_CAPACITY,
+            VobleError::InvalidInput
+        );
+
+        let old_capacity = config.leaderboard_capacity();
+        config.leaderboard_capacity = capacity;
+
+        msg!("📊 Leaderboard capacity updated: {} -> {}", old_capacity, capacity);
+        updated_fields.push("leaderboard_capacity");
+    }
+
+    // Update the character class if provided (applies to guesses from now on)
+    if let Some(class) = char_class {
+        let new_class = CharClass::from_u8(class).ok_or(VobleError::InvalidInput)?;
+        for source in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            validate_word_list(word_list(source).0, new_class)?;
+        }
+
+        let old_class = config.char_class;
+        config.char_class = new_class;
+
+        msg!("🔤 Character class updated: {:?} -> {:?}", old_class, new_class);
+        updated_fields.push("char_class");
+    }
+
+    // Update the ticket word source if provided (applies to new tickets)
+    if let Some(source) = ticket_word_source {
+        let new_source = PeriodType::from_u8(source).ok_or(VobleError::InvalidWordSource)?;
+
+        let old_source = config.ticket_word_source;
+        config.ticket_word_source = new_source;
+
+        msg!("📚 Ticket word source updated: {:?} -> {:?}", old_source, new_source);
+        updated_fields.push("ticket_word_source");
+    }
+
+    // Checked once both may have changed, so they can be switched together
+    if word_strategy.is_some() || ticket_word_source.is_some() {
+        require_word_source_playable(config.word_strategy, config.ticket_word_source)?;
+    }
+
     // Log summary
     if updated_fields.is_empty() {
         msg!("ℹ️  No fields updated (no changes provided)");
@@ -63,3 +509,83 @@ pub fn set_config(
 
     Ok(())
 }
+
+/// Set the cool-down of one session lifecycle instruction
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config account and authority
+/// * `action` - 0 = initialize_session, 1 = reset_session, 2 = delegate_session
+/// * `min_slot_gap` - Slots required between two calls (0 = default)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Action must be 0, 1 or 2
+/// - Gap must be <= MAX_SESSION_ACTION_SLOT_GAP
+///
+/// # Notes
+/// reset_session runs on the ER, whose slots are much shorter than the base
+/// layer's; size its gap accordingly.
+pub fn set_session_action_gap(
+    ctx: Context<SetConfig>,
+    action: u8,
+    min_slot_gap: u64,
+) -> Result<()> {
+    let action = SessionAction::from_u8(action).ok_or(VobleError::InvalidInput)?;
+    require!(
+        min_slot_gap <= MAX_SESSION_ACTION_SLOT_GAP,
+        VobleError::InvalidInput
+    );
+
+    let config = &mut ctx.accounts.global_config;
+    let old_gap = session_action_gap(config, action);
+    config.session_action_gaps[action as usize] = min_slot_gap;
+
+    msg!(
+        "⏱️  {:?} cool-down updated: {} -> {} slots",
+        action,
+        old_gap,
+        session_action_gap(config, action)
+    );
+
+    Ok(())
+}
+
+/// Set how many players a period type needs before it pays prizes
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config account and authority
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+/// * `min_participants` - Players required for prizes (0 = always pay out)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Period type must be 0, 1 or 2
+///
+/// # Notes
+/// Periods below the threshold finalize with no winners and their vault
+/// balance rolls forward, so quiet days can't be farmed by a few accounts.
+pub fn set_prize_threshold(
+    ctx: Context<SetConfig>,
+    period_type: u8,
+    min_participants: u32,
+) -> Result<()> {
+    let period_type = match period_type {
+        0 => PeriodType::Daily,
+        1 => PeriodType::Weekly,
+        2 => PeriodType::Monthly,
+        _ => return Err(VobleError::InvalidPeriodState.into()),
+    };
+
+    let config = &mut ctx.accounts.global_config;
+    let old_threshold = config.min_participants_for(period_type);
+    config.min_participants_for_prizes[period_type as usize] = min_participants;
+
+    msg!(
+        "👥 {:?} prize threshold updated: {} -> {} players",
+        period_type,
+        old_threshold,
+        min_participants
+    );
+
+    Ok(())
+}
@@ -0,0 +1,368 @@
+use crate::utils::period::parse_period_id;
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Create the inflow/outflow ledger for one vault
+///
+/// # Arguments
+/// * `ctx` - The context containing the ledger, the vault and authority
+/// * `kind` - Vault to track: 0=Daily, 1=Weekly, 2=Monthly, 3=Platform, 4=LuckyDraw
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - `vault` must be the program's token vault for `kind`
+/// - One ledger per vault (enforced by init constraint)
+///
+/// # Notes
+/// - The vault's current balance is recorded as `opening_balance`, so the
+///   ledger reconciles from the moment it is created
+/// - Every transfer in or out of the vault must pass the ledger afterwards
+pub fn initialize_vault_ledger(ctx: Context<InitializeVaultLedger>, kind: u8) -> Result<()> {
+    let kind = match kind {
+        0 => VaultKind::Daily,
+        1 => VaultKind::Weekly,
+        2 => VaultKind::Monthly,
+        3 => VaultKind::Platform,
+        4 => VaultKind::LuckyDraw,
+        _ => return Err(VobleError::InvalidInput.into()),
+    };
+
+    // ========== VALIDATION ==========
+    let (expected_vault, _) = Pubkey::find_program_address(&[kind.vault_seed()], &crate::ID);
+    require_keys_eq!(
+        ctx.accounts.vault.key(),
+        expected_vault,
+        VobleError::Unauthorized
+    );
+
+    // ========== CREATE LEDGER ==========
+    let ledger = &mut ctx.accounts.vault_ledger;
+    ledger.kind = kind;
+    ledger.vault = expected_vault;
+    ledger.opening_balance = ctx.accounts.vault.amount;
+    ledger.total_in = 0;
+    ledger.total_out = 0;
+    ledger.last_updated = Clock::get()?.unix_timestamp;
+    ledger.bump = ctx.bumps.vault_ledger;
+    ledger.refund_liability = 0;
+    ledger.current_pool = PoolPeriod::default();
+    ledger.previous_pool = PoolPeriod::default();
+
+    msg!("📒 Vault ledger created for {:?} vault", kind);
+    msg!("   Vault: {}", ledger.vault);
+    msg!("   Opening balance: {} base units", ledger.opening_balance);
+
+    Ok(())
+}
+
+/// Emit a vault's cumulative counters next to its live token balance
+///
+/// # Arguments
+/// * `ctx` - The context containing the ledger and its vault
+///
+/// # Notes
+/// - Read-only and permissionless; the result is the `VaultReport` event
+/// - A non-zero `discrepancy` means tokens moved without passing the ledger
+///   (e.g. a direct transfer into the vault)
+pub fn get_vault_report(ctx: Context<GetVaultReport>) -> Result<()> {
+    let ledger = &ctx.accounts.vault_ledger;
+    let balance = ctx.accounts.vault.amount;
+    let discrepancy = vault_discrepancy(ledger, balance)?;
+
+    msg!("📒 {:?} vault report", ledger.kind);
+    msg!("   In: {} | Out: {}", ledger.total_in, ledger.total_out);
+    msg!("   Balance: {} | Discrepancy: {}", balance, discrepancy);
+
+    emit!(VaultReport {
+        kind: ledger.kind,
+        vault: ledger.vault,
+        opening_balance: ledger.opening_balance,
+        total_in: ledger.total_in,
+        total_out: ledger.total_out,
+        balance,
+        discrepancy,
+        last_updated: ledger.last_updated,
+    });
+
+    Ok(())
+}
+
+/// Record tokens transferred into a ledger's vault
+pub fn record_inflow(ledger: &mut VaultLedger, amount: u64, now: i64) -> Result<()> {
+    ledger.total_in = ledger
+        .total_in
+        .checked_add(amount as u128)
+        .ok_or(VobleError::VaultLedgerOverflow)?;
+    ledger.last_updated = now;
+    Ok(())
+}
+
+/// Record tokens transferred out of a ledger's vault
+pub fn record_outflow(ledger: &mut VaultLedger, amount: u64, now: i64) -> Result<()> {
+    ledger.total_out = ledger
+        .total_out
+        .checked_add(amount as u128)
+        .ok_or(VobleError::VaultLedgerOverflow)?;
+    ledger.last_updated = now;
+    Ok(())
+}
+
+/// Record a ticket's share of one period's pool in its prize vault's ledger
+///
+/// # Notes
+/// - A new period moves `current_pool` to `previous_pool`
+/// - Inflow into a canceled period is owed back too, so it adds to
+///   `refund_liability`
+pub fn record_pool_inflow(ledger: &mut VaultLedger, period_id: &str, amount: u64) -> Result<()> {
+    if ledger.current_pool.period_id != period_id && ledger.previous_pool.period_id != period_id {
+        ledger.previous_pool = std::mem::replace(
+            &mut ledger.current_pool,
+            PoolPeriod {
+                period_id: period_id.to_string(),
+                ..PoolPeriod::default()
+            },
+        );
+    }
+
+    let pool = if ledger.current_pool.period_id == period_id {
+        &mut ledger.current_pool
+    } else {
+        &mut ledger.previous_pool
+    };
+    pool.inflow = pool
+        .inflow
+        .checked_add(amount)
+        .ok_or(VobleError::VaultLedgerOverflow)?;
+
+    if pool.canceled {
+        ledger.refund_liability = ledger
+            .refund_liability
+            .checked_add(amount)
+            .ok_or(VobleError::VaultLedgerOverflow)?;
+    }
+    Ok(())
+}
+
+/// Owe a canceled period's pool back to its players
+///
+/// # Returns
+/// The liability added: the pool's inflow so far
+///
+/// # Validation
+/// - The period must be one of the ledger's two tracked pools, after them, or
+///   between them (`InvalidPeriodState`); an older pool is no longer known
+///
+/// # Notes
+/// - A period after `current_pool` is tracked from now on, so tickets bought
+///   into it later are owed back as well
+/// - A period between the two tracked pools took no tickets and owes nothing
+pub fn record_pool_cancellation(ledger: &mut VaultLedger, period_id: &str) -> Result<u64> {
+    let number = |id: &str| parse_period_id(id).map(|(_, number)| number);
+    let canceled = number(period_id).ok_or(VobleError::InvalidPeriodState)?;
+
+    let pool = if ledger.current_pool.period_id == period_id {
+        &mut ledger.current_pool
+    } else if ledger.previous_pool.period_id == period_id {
+        &mut ledger.previous_pool
+    } else {
+        let after_current = number(&ledger.current_pool.period_id).is_none_or(|n| canceled > n);
+        if after_current {
+            ledger.previous_pool = std::mem::replace(
+                &mut ledger.current_pool,
+                PoolPeriod {
+                    period_id: period_id.to_string(),
+                    inflow: 0,
+                    canceled: true,
+                },
+            );
+            return Ok(0);
+        }
+
+        let after_previous = number(&ledger.previous_pool.period_id).is_some_and(|n| canceled > n);
+        require!(after_previous, VobleError::InvalidPeriodState);
+        return Ok(0);
+    };
+
+    require!(!pool.canceled, VobleError::PeriodCanceled);
+    pool.canceled = true;
+    let liability = pool.inflow;
+
+    ledger.refund_liability = ledger
+        .refund_liability
+        .checked_add(liability)
+        .ok_or(VobleError::VaultLedgerOverflow)?;
+    Ok(liability)
+}
+
+/// Release liability as a refund is paid out of the vault
+pub fn release_refund_liability(ledger: &mut VaultLedger, amount: u64) {
+    ledger.refund_liability = ledger.refund_liability.saturating_sub(amount);
+}
+
+/// Vault balance free for prizes: the balance minus refunds still owed
+pub fn prize_balance(ledger: &VaultLedger, balance: u64) -> u64 {
+    balance.saturating_sub(ledger.refund_liability)
+}
+
+/// Live balance minus the balance implied by the ledger
+///
+/// # Returns
+/// `balance - (opening_balance + total_in - total_out)`; zero when every
+/// transfer went through the ledger
+pub fn vault_discrepancy(ledger: &VaultLedger, balance: u64) -> Result<i128> {
+    let overflow = || error!(VobleError::VaultLedgerOverflow);
+
+    let total_in = i128::try_from(ledger.total_in).map_err(|_| overflow())?;
+    let total_out = i128::try_from(ledger.total_out).map_err(|_| overflow())?;
+    let expected = (ledger.opening_balance as i128)
+        .checked_add(total_in)
+        .and_then(|value| value.checked_sub(total_out))
+        .ok_or_else(overflow)?;
+
+    (balance as i128).checked_sub(expected).ok_or_else(overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_START: i64 = 1_700_000_000;
+
+    fn ledger(kind: VaultKind, opening_balance: u64) -> VaultLedger {
+        VaultLedger {
+            kind,
+            vault: Pubkey::new_unique(),
+            opening_balance,
+            total_in: 0,
+            total_out: 0,
+            last_updated: 0,
+            bump: 255,
+            refund_liability: 0,
+            current_pool: PoolPeriod::default(),
+            previous_pool: PoolPeriod::default(),
+        }
+    }
+
+    #[test]
+    fn test_day_of_activity_reconciles() {
+        let mut daily = ledger(VaultKind::Daily, 2_000_000);
+        let mut platform = ledger(VaultKind::Platform, 0);
+        let (mut daily_balance, mut platform_balance) = (2_000_000u64, 0u64);
+        let mut now = DAY_START;
+
+        // 50 tickets: 600_000 to the daily pool, 100_000 to platform each
+        for _ in 0..50 {
+            now += 600;
+            record_inflow(&mut daily, 600_000, now).unwrap();
+            daily_balance += 600_000;
+            record_inflow(&mut platform, 100_000, now).unwrap();
+            platform_balance += 100_000;
+        }
+
+        // Yesterday's three winners claim
+        for amount in [5_000_000, 3_000_000, 2_000_000] {
+            now += 60;
+            record_outflow(&mut daily, amount, now).unwrap();
+            daily_balance -= amount;
+        }
+
+        // Crank sweep above a 1 USDC float, then a manual withdrawal
+        now += 60;
+        record_outflow(&mut platform, 3_900_000, now).unwrap();
+        platform_balance -= 3_900_000;
+        record_outflow(&mut platform, 600_000, now).unwrap();
+        platform_balance -= 600_000;
+
+        assert_eq!(vault_discrepancy(&daily, daily_balance).unwrap(), 0);
+        assert_eq!(vault_discrepancy(&platform, platform_balance).unwrap(), 0);
+        assert_eq!(
+            daily.opening_balance as u128 + daily.total_in - daily.total_out,
+            daily_balance as u128
+        );
+        assert_eq!(platform.total_in - platform.total_out, platform_balance as u128);
+        assert_eq!(daily.last_updated, now - 60);
+        assert_eq!(platform.last_updated, now);
+    }
+
+    #[test]
+    fn test_untracked_transfer_shows_as_discrepancy() {
+        let mut weekly = ledger(VaultKind::Weekly, 1_000);
+        record_inflow(&mut weekly, 500, DAY_START).unwrap();
+
+        // Someone sent 250 straight to the vault
+        assert_eq!(vault_discrepancy(&weekly, 1_750).unwrap(), 250);
+        // And 100 left without passing the ledger
+        assert_eq!(vault_discrepancy(&weekly, 1_400).unwrap(), -100);
+    }
+
+    #[test]
+    fn test_counters_are_checked() {
+        let mut monthly = ledger(VaultKind::Monthly, 0);
+        monthly.total_in = u128::MAX;
+        monthly.total_out = u128::MAX;
+
+        assert_eq!(
+            record_inflow(&mut monthly, 1, DAY_START).unwrap_err(),
+            VobleError::VaultLedgerOverflow.into()
+        );
+        assert_eq!(
+            record_outflow(&mut monthly, 1, DAY_START).unwrap_err(),
+            VobleError::VaultLedgerOverflow.into()
+        );
+        assert_eq!(
+            vault_discrepancy(&monthly, 0).unwrap_err(),
+            VobleError::VaultLedgerOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_canceled_pool_becomes_refund_liability() {
+        let mut daily = ledger(VaultKind::Daily, 0);
+        record_pool_inflow(&mut daily, "D9", 400).unwrap();
+        record_pool_inflow(&mut daily, "D9", 400).unwrap();
+        record_pool_inflow(&mut daily, "D10", 300).unwrap();
+
+        assert_eq!(record_pool_cancellation(&mut daily, "D9").unwrap(), 800);
+        assert_eq!(daily.refund_liability, 800);
+        assert_eq!(prize_balance(&daily, 1_100), 300);
+
+        // D11 rolls D9 out; its liability stays until refunded
+        record_pool_inflow(&mut daily, "D11", 500).unwrap();
+        assert_eq!(daily.previous_pool.period_id, "D10");
+        assert_eq!(daily.refund_liability, 800);
+
+        release_refund_liability(&mut daily, 400);
+        assert_eq!(prize_balance(&daily, 1_200), 800);
+    }
+
+    #[test]
+    fn test_canceling_ahead_owes_later_tickets() {
+        let mut weekly = ledger(VaultKind::Weekly, 0);
+        record_pool_inflow(&mut weekly, "W1", 300).unwrap();
+
+        // W2 is canceled before its first ticket
+        assert_eq!(record_pool_cancellation(&mut weekly, "W2").unwrap(), 0);
+        record_pool_inflow(&mut weekly, "W2", 300).unwrap();
+        assert_eq!(weekly.refund_liability, 300);
+        assert_eq!(
+            record_pool_cancellation(&mut weekly, "W2").unwrap_err(),
+            VobleError::PeriodCanceled.into()
+        );
+    }
+
+    #[test]
+    fn test_untracked_pool_cannot_be_canceled() {
+        let mut daily = ledger(VaultKind::Daily, 0);
+        record_pool_inflow(&mut daily, "D9", 400).unwrap();
+        record_pool_inflow(&mut daily, "D12", 400).unwrap();
+
+        // D10 and D11 took no tickets
+        assert_eq!(record_pool_cancellation(&mut daily, "D11").unwrap(), 0);
+        // D8 fell out of the ledger's window
+        assert_eq!(
+            record_pool_cancellation(&mut daily, "D8").unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+        assert_eq!(daily.refund_liability, 0);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use super::{consume_pending_action, next_withdraw_window, record_outflow, withdraw_payload};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::AdminActionKind};
+use crate::utils::validation::{validate_payout_account, PayoutAccount};
 use anchor_lang::prelude::*;
 
 /// Withdraw platform revenue from the platform vault
@@ -8,12 +10,19 @@ use anchor_lang::prelude::*;
 ///
 /// # Arguments
 /// * `ctx` - The context containing the platform vault and authority
-/// * `amount` - Optional amount to withdraw in lamports. If None, withdraws all available funds
+/// * `amount` - Optional amount to withdraw in the mint's base units. If None, withdraws all available funds
 ///
 /// # Validation
 /// - Only the authority can call this instruction
 /// - Vault must have sufficient balance (minus rent-exempt minimum)
 /// - Vault must remain rent-exempt after withdrawal
+/// - Mint and destination must be for the configured USDC mint
+///   (`PayoutMintMismatch`) and the destination not frozen
+///   (`PayoutAccountFrozen`)
+/// - A withdrawal that takes the untimelocked total of the current window
+///   (`admin_timelock()` seconds long) above `timelock_withdraw_threshold()`
+///   needs a matured `WithdrawRevenue` pending action for the exact amount
+///   and destination; timelocked withdrawals don't count toward the window
 ///
 /// # Safety
 /// - Uses PDA signer seeds to authorize the transfer
@@ -43,13 +52,51 @@ pub fn withdraw_platform_revenue(
         VobleError::InsufficientVaultBalance
     );
 
+    // The destination may belong to any wallet (e.g. a treasury multisig)
+    let config_mint = ctx.accounts.global_config.usdc_mint;
+    require_keys_eq!(
+        ctx.accounts.usdc_mint.key(),
+        config_mint,
+        VobleError::PayoutMintMismatch
+    );
+    validate_payout_account(
+        &PayoutAccount::from(&*ctx.accounts.destination),
+        &config_mint,
+        None,
+    )?;
+
+    // Large withdrawals must have been announced on-chain first, including
+    // several smaller ones that add up within a window
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.global_config;
+    let (window_start, withdrawn) = next_withdraw_window(
+        config.withdraw_window_start,
+        config.withdrawn_in_window,
+        now,
+        config.admin_timelock(),
+        withdraw_amount,
+    );
+    if withdrawn > config.timelock_withdraw_threshold() {
+        consume_pending_action(
+            &mut ctx.accounts.pending_action,
+            &ctx.accounts.authority,
+            AdminActionKind::WithdrawRevenue,
+            withdraw_payload(withdraw_amount, &ctx.accounts.destination.key()),
+            now,
+        )?;
+    } else {
+        config.withdraw_window_start = window_start;
+        config.withdrawn_in_window = withdrawn;
+    }
+
     msg!("💰 Withdrawal validation passed");
-    msg!("   Vault balance: {} USDC", vault_balance);
-    msg!("   Requested amount: {} USDC", withdraw_amount);
+    msg!("   Vault balance: {} base units", vault_balance);
+    msg!("   Requested amount: {} base units", withdraw_amount);
 
     // ========== TRANSFER ==========
     // Transfer from platform vault to destination using secure CPI
-    let vault_seeds = &[SEED_PLATFORM_VAULT, &[ctx.bumps.platform_vault]];
+    let vault_bump = ctx.accounts.global_config.bumps.platform_vault;
+    let vault_seeds = &[SEED_PLATFORM_VAULT, &[vault_bump]];
     let signer_seeds = &[&vault_seeds[..]];
 
     let decimals = ctx.accounts.usdc_mint.decimals;
@@ -69,6 +116,8 @@ pub fn withdraw_platform_revenue(
         decimals,
     )?;
 
+    record_outflow(&mut ctx.accounts.platform_vault_ledger, withdraw_amount, now)?;
+
     let remaining_balance = vault_balance - withdraw_amount;
 
     // ========== EMIT EVENT ==========
@@ -80,8 +129,8 @@ pub fn withdraw_platform_revenue(
     });
 
     msg!("✅ Platform revenue withdrawn successfully");
-    msg!("💸 Amount withdrawn: {} USDC", withdraw_amount);
-    msg!("🏦 Remaining vault balance: {} USDC", remaining_balance);
+    msg!("💸 Amount withdrawn: {} base units", withdraw_amount);
+    msg!("🏦 Remaining vault balance: {} base units", remaining_balance);
     msg!("📍 Destination: {}", ctx.accounts.destination.key());
 
     Ok(())
@@ -0,0 +1,138 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Tag a word with its difficulty
+///
+/// # Arguments
+/// * `ctx` - The context containing the word bank and authority
+/// * `word_index` - Global word index (daily, weekly and monthly lists back to back)
+/// * `difficulty` - 1 (easiest) to 5 (hardest)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Word index must be within TOTAL_WORD_COUNT
+/// - Difficulty must be within MIN..=MAX_WORD_DIFFICULTY
+///
+/// # Notes
+/// - The first call creates the bank with every word at DEFAULT_WORD_DIFFICULTY
+/// - Tickets already bought keep the difficulty stamped at purchase
+pub fn set_word_difficulty(
+    ctx: Context<SetWordDifficulty>,
+    word_index: u32,
+    difficulty: u8,
+) -> Result<()> {
+    // ========== VALIDATION ==========
+    require!(
+        (word_index as usize) < TOTAL_WORD_COUNT,
+        VobleError::InvalidInput
+    );
+    validate_word_difficulty(difficulty)?;
+
+    let word_bank = &mut ctx.accounts.word_bank;
+    if word_bank.bump == 0 {
+        // Freshly created - untagged words play at the default difficulty
+        word_bank.difficulties = [DEFAULT_WORD_DIFFICULTY; TOTAL_WORD_COUNT];
+        word_bank.bump = ctx.bumps.word_bank;
+    }
+
+    // ========== TAG WORD ==========
+    word_bank.difficulties[word_index as usize] = difficulty;
+
+    msg!("📚 Word {} tagged with difficulty {}", word_index, difficulty);
+
+    emit!(WordDifficultySet {
+        word_index,
+        difficulty,
+    });
+
+    Ok(())
+}
+
+/// Check that a difficulty is within MIN..=MAX_WORD_DIFFICULTY
+pub fn validate_word_difficulty(difficulty: u8) -> Result<()> {
+    require!(
+        (MIN_WORD_DIFFICULTY..=MAX_WORD_DIFFICULTY).contains(&difficulty),
+        VobleError::InvalidWordDifficulty
+    );
+    Ok(())
+}
+
+/// Difficulty a ticket's word is scored with
+///
+/// # Arguments
+/// * `owner` - Owner of the word bank PDA
+/// * `data` - Data of the word bank PDA
+/// * `word_index` - Global index of the ticket's word
+///
+/// # Notes
+/// - Until `set_word_difficulty` creates the bank every word plays at
+///   DEFAULT_WORD_DIFFICULTY; once it exists its tags always apply, since the
+///   PDA is a required account the player can't leave out
+pub fn word_difficulty(owner: &Pubkey, data: &[u8], word_index: u32) -> Result<u8> {
+    if owner != &crate::ID || !data.starts_with(WordBank::DISCRIMINATOR) {
+        return Ok(DEFAULT_WORD_DIFFICULTY);
+    }
+    let word_bank = WordBank::try_deserialize(&mut &data[..])?;
+    Ok(word_bank.difficulty_of(word_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difficulty_range() {
+        for difficulty in MIN_WORD_DIFFICULTY..=MAX_WORD_DIFFICULTY {
+            assert!(validate_word_difficulty(difficulty).is_ok());
+        }
+        assert_eq!(
+            validate_word_difficulty(0).unwrap_err(),
+            VobleError::InvalidWordDifficulty.into()
+        );
+        assert_eq!(
+            validate_word_difficulty(MAX_WORD_DIFFICULTY + 1).unwrap_err(),
+            VobleError::InvalidWordDifficulty.into()
+        );
+    }
+
+    #[test]
+    fn test_untagged_words_use_default() {
+        let mut word_bank = WordBank {
+            difficulties: [DEFAULT_WORD_DIFFICULTY; TOTAL_WORD_COUNT],
+            bump: 255,
+        };
+        word_bank.difficulties[16] = 5; // QUARTZ
+
+        assert_eq!(word_bank.difficulty_of(16), 5);
+        assert_eq!(word_bank.difficulty_of(6), DEFAULT_WORD_DIFFICULTY); // GARDEN
+        assert_eq!(
+            word_bank.difficulty_of(TOTAL_WORD_COUNT as u32),
+            DEFAULT_WORD_DIFFICULTY
+        );
+    }
+
+    #[test]
+    fn test_created_bank_always_applies() {
+        let mut word_bank = WordBank {
+            difficulties: [DEFAULT_WORD_DIFFICULTY; TOTAL_WORD_COUNT],
+            bump: 255,
+        };
+        word_bank.difficulties[16] = 5;
+        let mut data = Vec::new();
+        word_bank.try_serialize(&mut data).unwrap();
+
+        assert_eq!(word_difficulty(&crate::ID, &data, 16).unwrap(), 5);
+
+        // Not created yet: empty, system-owned PDA
+        assert_eq!(
+            word_difficulty(&anchor_lang::system_program::ID, &[], 16).unwrap(),
+            DEFAULT_WORD_DIFFICULTY
+        );
+        // Data under another owner is ignored
+        assert_eq!(
+            word_difficulty(&Pubkey::new_unique(), &data, 16).unwrap(),
+            DEFAULT_WORD_DIFFICULTY
+        );
+    }
+}
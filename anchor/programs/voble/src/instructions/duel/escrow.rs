@@ -0,0 +1,89 @@
+use crate::constants::SEED_DUEL_ESCROW;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TransferChecked};
+
+/// A duel's escrow token account and what it needs to sign for itself
+///
+/// The escrow is its own authority (`[SEED_DUEL_ESCROW, duel]`), like the
+/// prize vaults, so payouts and the final close are signed with its seeds.
+pub struct DuelEscrow<'a, 'info> {
+    pub escrow: &'a InterfaceAccount<'info, TokenAccount>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: AccountInfo<'info>,
+    pub duel: Pubkey,
+    pub bump: u8,
+    pub decimals: u8,
+}
+
+impl<'a, 'info> DuelEscrow<'a, 'info> {
+    /// Pay `amount` out of the escrow (no-op for zero)
+    pub fn pay(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let seeds = &[SEED_DUEL_ESCROW, self.duel.as_ref(), &[self.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TransferChecked {
+                    from: self.escrow.to_account_info(),
+                    to,
+                    authority: self.escrow.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+            self.decimals,
+        )
+    }
+
+    /// Close the emptied escrow, returning its rent to `destination`
+    pub fn close(&self, destination: AccountInfo<'info>) -> Result<()> {
+        let seeds = &[SEED_DUEL_ESCROW, self.duel.as_ref(), &[self.bump]];
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            CloseAccount {
+                account: self.escrow.to_account_info(),
+                destination,
+                authority: self.escrow.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))
+    }
+}
+
+/// Move a player's stake into the escrow
+pub fn deposit_stake<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    escrow: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program,
+            TransferChecked {
+                from: from.to_account_info(),
+                to: escrow.to_account_info(),
+                authority,
+                mint: mint.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// Decimals stored at vault init; configs created before that fall back to the mint
+pub fn stake_decimals(config: &GlobalConfig, mint: &InterfaceAccount<Mint>) -> u8 {
+    if config.usdc_decimals == 0 {
+        mint.decimals
+    } else {
+        config.usdc_decimals
+    }
+}
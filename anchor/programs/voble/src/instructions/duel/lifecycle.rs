@@ -0,0 +1,295 @@
+use crate::state::{Duel, DuelStatus, PeriodLeaderboard, PeriodType};
+use crate::utils::pda;
+use crate::utils::period::{self, get_current_period_id, get_period_end_timestamp};
+use crate::{contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+use super::{deposit_stake, stake_decimals, DuelEscrow};
+
+/// Challenge another player to a wager on today's daily period
+///
+/// # Arguments
+/// * `ctx` - The context containing the new duel, its escrow and the
+///   challenger's token account
+/// * `opponent` - Wallet being challenged
+/// * `stake_amount` - Stake each player puts in, in the mint's base units
+/// * `period_id` - Current daily period (e.g., "D123")
+///
+/// # Validation
+/// - Game must not be paused
+/// - Stake must be non-zero and the opponent must not be the challenger
+/// - Period must be the current daily period
+///
+/// # Notes
+/// - The opponent can accept until the period ends (`accept_deadline`)
+/// - One duel per challenger, opponent and day
+pub fn create_duel(
+    ctx: Context<CreateDuel>,
+    opponent: Pubkey,
+    stake_amount: u64,
+    period_id: String,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let challenger = ctx.accounts.challenger.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(!config.paused, VobleError::GamePaused);
+    // Half of u64 so the pot of both stakes can't overflow
+    require!(
+        stake_amount > 0 && stake_amount <= u64::MAX / 2 && opponent != challenger,
+        VobleError::InvalidDuel
+    );
+    require!(
+        period_id == get_current_period_id(period::PeriodType::Daily, now, config.period_epoch()),
+        VobleError::PeriodNotCurrent
+    );
+    let accept_deadline = get_period_end_timestamp(&period_id, config.period_epoch())
+        .ok_or(VobleError::InvalidPeriodState)?;
+
+    msg!("⚔️  Creating duel for {}", period_id);
+    msg!("   Challenger: {}", challenger);
+    msg!("   Opponent: {}", opponent);
+    msg!("   Stake: {}", stake_amount);
+
+    // ========== ESCROW STAKE ==========
+    deposit_stake(
+        &ctx.accounts.challenger_token_account,
+        ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.escrow,
+        &ctx.accounts.usdc_mint,
+        ctx.accounts.token_program.to_account_info(),
+        stake_amount,
+        stake_decimals(config, &ctx.accounts.usdc_mint),
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.challenger = challenger;
+    duel.opponent = opponent;
+    duel.period_id = period_id.clone();
+    duel.stake = stake_amount;
+    duel.status = DuelStatus::Open;
+    duel.created_at = now;
+    duel.accept_deadline = accept_deadline;
+    duel.bump = ctx.bumps.duel;
+    duel.escrow_bump = ctx.bumps.escrow;
+
+    emit!(DuelCreated {
+        duel: duel.key(),
+        challenger,
+        opponent,
+        period_id,
+        stake: stake_amount,
+        accept_deadline,
+    });
+
+    Ok(())
+}
+
+/// Accept a duel by escrowing the same stake
+///
+/// # Validation
+/// - Only the named opponent can accept (duel PDA seeds)
+/// - Duel must be open and its period must not have ended
+pub fn accept_duel(ctx: Context<AcceptDuel>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let duel = &ctx.accounts.duel;
+
+    // ========== VALIDATION ==========
+    require!(duel.status == DuelStatus::Open, VobleError::DuelWrongStatus);
+    require!(now < duel.accept_deadline, VobleError::DuelAcceptWindowClosed);
+
+    msg!("⚔️  {} accepted duel for {}", duel.opponent, duel.period_id);
+
+    // ========== ESCROW STAKE ==========
+    deposit_stake(
+        &ctx.accounts.opponent_token_account,
+        ctx.accounts.opponent.to_account_info(),
+        &ctx.accounts.escrow,
+        &ctx.accounts.usdc_mint,
+        ctx.accounts.token_program.to_account_info(),
+        duel.stake,
+        stake_decimals(&ctx.accounts.global_config, &ctx.accounts.usdc_mint),
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.status = DuelStatus::Accepted;
+
+    emit!(DuelAccepted {
+        duel: duel.key(),
+        challenger: duel.challenger,
+        opponent: duel.opponent,
+        period_id: duel.period_id.clone(),
+        pot: duel.stake * 2,
+    });
+
+    Ok(())
+}
+
+/// Withdraw an open duel and take the stake back
+///
+/// # Validation
+/// - Only the challenger can cancel (duel PDA seeds)
+/// - Duel must still be open; accepted duels settle through
+///   `resolve_duel` or `refund_duel`
+pub fn cancel_duel(ctx: Context<CancelDuel>) -> Result<()> {
+    let duel = &ctx.accounts.duel;
+    require!(duel.status == DuelStatus::Open, VobleError::DuelWrongStatus);
+
+    msg!("⚔️  Canceling duel for {}", duel.period_id);
+
+    let escrow = DuelEscrow {
+        escrow: &ctx.accounts.escrow,
+        mint: &ctx.accounts.usdc_mint,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        duel: duel.key(),
+        bump: duel.escrow_bump,
+        decimals: stake_decimals(&ctx.accounts.global_config, &ctx.accounts.usdc_mint),
+    };
+    escrow.pay(ctx.accounts.challenger_token_account.to_account_info(), duel.stake)?;
+    escrow.close(ctx.accounts.challenger.to_account_info())?;
+
+    emit!(DuelRefunded {
+        duel: duel.key(),
+        period_id: duel.period_id.clone(),
+        challenger_refund: duel.stake,
+        opponent_refund: 0,
+    });
+
+    Ok(())
+}
+
+/// Return the stakes of a duel that can't be played out (permissionless)
+///
+/// # Validation
+/// - `daily_leaderboard` must be the duel period's daily board PDA
+/// - The duel must be refundable (see `duel_refunds`)
+/// - `opponent_token_account` is required when the opponent is refunded
+///
+/// # Notes
+/// - An open duel past its deadline is a no-show: the challenger's stake
+///   comes back. Once accepted, a player who doesn't play is not refunded
+///   here; `resolve_duel` scores them 0
+/// - An accepted duel refunds both stakes if the period was canceled, or if
+///   the day ended without anyone opening its board
+pub fn refund_duel(ctx: Context<RefundDuel>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let duel = &ctx.accounts.duel;
+
+    // ========== VALIDATION ==========
+    let board = &ctx.accounts.daily_leaderboard;
+    let (expected_board, _) =
+        pda::derive_leaderboard_pda(&duel.period_id, PeriodType::Daily, &crate::ID);
+    require_keys_eq!(board.key(), expected_board, VobleError::Unauthorized);
+
+    let board_voided = if board.owner == &crate::ID {
+        PeriodLeaderboard::try_deserialize(&mut &board.try_borrow_data()?[..])?.canceled
+    } else {
+        now >= duel.accept_deadline
+    };
+    let (challenger_refund, opponent_refund) = duel_refunds(duel, now, board_voided)?;
+
+    msg!("↩️  Refunding duel for {}", duel.period_id);
+
+    // ========== REFUND STAKES ==========
+    let escrow = DuelEscrow {
+        escrow: &ctx.accounts.escrow,
+        mint: &ctx.accounts.usdc_mint,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        duel: duel.key(),
+        bump: duel.escrow_bump,
+        decimals: stake_decimals(&ctx.accounts.global_config, &ctx.accounts.usdc_mint),
+    };
+    escrow.pay(
+        ctx.accounts.challenger_token_account.to_account_info(),
+        challenger_refund,
+    )?;
+    if opponent_refund > 0 {
+        let opponent_token_account = ctx
+            .accounts
+            .opponent_token_account
+            .as_ref()
+            .ok_or(VobleError::DuelNotRefundable)?;
+        escrow.pay(opponent_token_account.to_account_info(), opponent_refund)?;
+    }
+    escrow.close(ctx.accounts.challenger.to_account_info())?;
+
+    emit!(DuelRefunded {
+        duel: duel.key(),
+        period_id: duel.period_id.clone(),
+        challenger_refund,
+        opponent_refund,
+    });
+
+    Ok(())
+}
+
+/// Stakes returned by `refund_duel`
+///
+/// # Arguments
+/// * `duel` - The duel being refunded
+/// * `now` - Current Unix timestamp
+/// * `board_voided` - The period's daily board was canceled, or the day
+///   ended without one
+///
+/// # Returns
+/// `(challenger_refund, opponent_refund)`
+///
+/// # Validation
+/// - Open duels are refundable from `accept_deadline` on (the opponent
+///   didn't show); before that the challenger can `cancel_duel`
+/// - Accepted duels are refundable only when the board is voided; otherwise
+///   they wait for `resolve_duel`
+pub fn duel_refunds(duel: &Duel, now: i64, board_voided: bool) -> Result<(u64, u64)> {
+    match duel.status {
+        DuelStatus::Open if now >= duel.accept_deadline => Ok((duel.stake, 0)),
+        DuelStatus::Accepted if board_voided => Ok((duel.stake, duel.stake)),
+        _ => Err(VobleError::DuelNotRefundable.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEADLINE: i64 = 1_700_000_000;
+
+    fn duel(status: DuelStatus) -> Duel {
+        Duel {
+            challenger: Pubkey::new_unique(),
+            opponent: Pubkey::new_unique(),
+            period_id: "D12".to_string(),
+            stake: 2_000_000,
+            status,
+            created_at: DEADLINE - 3_600,
+            accept_deadline: DEADLINE,
+            bump: 255,
+            escrow_bump: 254,
+        }
+    }
+
+    #[test]
+    fn test_no_show_refunds_challenger_after_deadline() {
+        let open = duel(DuelStatus::Open);
+
+        assert_eq!(
+            duel_refunds(&open, DEADLINE - 1, false).unwrap_err(),
+            VobleError::DuelNotRefundable.into()
+        );
+        assert_eq!(duel_refunds(&open, DEADLINE, false).unwrap(), (2_000_000, 0));
+    }
+
+    #[test]
+    fn test_accepted_duel_refunds_both_only_when_board_voided() {
+        let accepted = duel(DuelStatus::Accepted);
+
+        assert_eq!(
+            duel_refunds(&accepted, DEADLINE + 86_400, false).unwrap_err(),
+            VobleError::DuelNotRefundable.into()
+        );
+        assert_eq!(
+            duel_refunds(&accepted, DEADLINE + 86_400, true).unwrap(),
+            (2_000_000, 2_000_000)
+        );
+    }
+}
@@ -0,0 +1,27 @@
+// ================================
+// DUEL INSTRUCTIONS MODULE
+// ================================
+// Escrowed head-to-head wagers on one daily period
+//
+// Lifecycle:
+// 1. create_duel  - challenger names an opponent and escrows a stake
+// 2. accept_duel  - opponent escrows the same stake before the period ends
+//    (or cancel_duel - challenger withdraws an unaccepted duel)
+// 3. both play the daily period as usual
+// 4. resolve_duel - anyone, once the daily board is finalized: higher score
+//    takes the pot minus the platform fee, a tie returns both stakes. Scores
+//    come from the capacity-limited board, so a player off it (or an accepted
+//    opponent who never played) scores 0
+//    (or refund_duel - anyone, for an unaccepted duel after the deadline or
+//    an accepted duel whose period was canceled or never opened)
+//
+// Settled duels close the duel account and its escrow, returning rent to the
+// challenger.
+
+pub mod escrow;
+pub mod lifecycle;
+pub mod resolve;
+
+pub use escrow::*;
+pub use lifecycle::*;
+pub use resolve::*;
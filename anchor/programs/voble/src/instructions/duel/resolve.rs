@@ -0,0 +1,279 @@
+use crate::instructions::admin::record_inflow;
+use crate::state::{DuelStatus, PeriodLeaderboard};
+use crate::utils::math::calculate_bps;
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+use super::{stake_decimals, DuelEscrow};
+
+/// How a duel ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuelOutcome {
+    ChallengerWins,
+    OpponentWins,
+    Tie,
+}
+
+/// Token amounts paid out of a settled duel's escrow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuelPayouts {
+    pub challenger: u64,
+    pub opponent: u64,
+    pub fee: u64, // To the platform vault
+}
+
+/// Pay out an accepted duel from the finalized daily board (permissionless)
+///
+/// # Arguments
+/// * `ctx` - The context containing the duel, its escrow, the period's daily
+///   board, both players' token accounts and the platform vault
+///
+/// # Validation
+/// - Duel must be accepted
+/// - The daily board must be finalized and not canceled (a canceled period
+///   is settled with `refund_duel`)
+///
+/// # Notes
+/// - Each player's score is their entry on the board; a player without one
+///   (didn't play, didn't solve, or fell outside the board's capacity) scores 0
+/// - Two players who both fell outside the board's capacity therefore tie,
+///   whatever they actually scored
+/// - An accepted opponent who never plays is not a no-show: they score 0 and
+///   lose to a challenger on the board (only an unaccepted duel is refunded
+///   as a no-show, through `refund_duel`)
+/// - The winner takes both stakes minus DUEL_PLATFORM_FEE_BPS; a tie returns
+///   each stake with no fee
+pub fn resolve_duel(ctx: Context<ResolveDuel>) -> Result<()> {
+    let duel = &ctx.accounts.duel;
+    let board = &ctx.accounts.daily_leaderboard;
+
+    // ========== VALIDATION ==========
+    require!(duel.status == DuelStatus::Accepted, VobleError::DuelWrongStatus);
+    require!(board.finalized, VobleError::LeaderboardNotFinalized);
+    // A commit-reveal day voided after its board locked refunds instead
+    require!(!board.canceled, VobleError::PeriodCanceled);
+
+    let challenger_score = board_score(board, &duel.challenger);
+    let opponent_score = board_score(board, &duel.opponent);
+    let outcome = duel_outcome(challenger_score, opponent_score);
+    let payouts = duel_payouts(duel.stake, outcome, DUEL_PLATFORM_FEE_BPS);
+
+    msg!("⚔️  Resolving duel for {}", duel.period_id);
+    msg!("   Scores: {} vs {}", challenger_score, opponent_score);
+    msg!("   Outcome: {:?}", outcome);
+
+    // ========== PAY OUT ==========
+    let escrow = DuelEscrow {
+        escrow: &ctx.accounts.escrow,
+        mint: &ctx.accounts.usdc_mint,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        duel: duel.key(),
+        bump: duel.escrow_bump,
+        decimals: stake_decimals(&ctx.accounts.global_config, &ctx.accounts.usdc_mint),
+    };
+    escrow.pay(
+        ctx.accounts.challenger_token_account.to_account_info(),
+        payouts.challenger,
+    )?;
+    escrow.pay(
+        ctx.accounts.opponent_token_account.to_account_info(),
+        payouts.opponent,
+    )?;
+    if payouts.fee > 0 {
+        escrow.pay(ctx.accounts.platform_vault.to_account_info(), payouts.fee)?;
+        record_inflow(
+            &mut ctx.accounts.platform_vault_ledger,
+            payouts.fee,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+    escrow.close(ctx.accounts.challenger.to_account_info())?;
+
+    let (winner, payout) = match outcome {
+        DuelOutcome::ChallengerWins => (Some(duel.challenger), payouts.challenger),
+        DuelOutcome::OpponentWins => (Some(duel.opponent), payouts.opponent),
+        DuelOutcome::Tie => (None, duel.stake),
+    };
+    msg!("✅ Duel settled, fee {}", payouts.fee);
+
+    emit!(DuelResolved {
+        duel: duel.key(),
+        period_id: duel.period_id.clone(),
+        challenger_score,
+        opponent_score,
+        winner,
+        payout,
+        fee: payouts.fee,
+    });
+
+    Ok(())
+}
+
+/// A player's settled score on a board (0 without an entry, including a
+/// player ranked below the board's capacity)
+pub fn board_score(board: &PeriodLeaderboard, player: &Pubkey) -> u32 {
+    board
+        .entries
+        .iter()
+        .find(|entry| entry.player == *player)
+        .map_or(0, |entry| entry.score)
+}
+
+/// Higher score wins; equal scores tie
+pub fn duel_outcome(challenger_score: u32, opponent_score: u32) -> DuelOutcome {
+    match challenger_score.cmp(&opponent_score) {
+        std::cmp::Ordering::Greater => DuelOutcome::ChallengerWins,
+        std::cmp::Ordering::Less => DuelOutcome::OpponentWins,
+        std::cmp::Ordering::Equal => DuelOutcome::Tie,
+    }
+}
+
+/// Split the escrow of a duel with two equal stakes
+///
+/// # Arguments
+/// * `stake` - Each player's stake (at most `u64::MAX / 2`, checked at creation)
+/// * `outcome` - Result of the duel
+/// * `fee_bps` - Platform fee on a won pot
+///
+/// # Returns
+/// Amounts for each player and the platform; they always sum to `2 * stake`
+pub fn duel_payouts(stake: u64, outcome: DuelOutcome, fee_bps: u16) -> DuelPayouts {
+    let pot = stake * 2;
+    let fee = calculate_bps(pot, fee_bps);
+
+    match outcome {
+        DuelOutcome::ChallengerWins => DuelPayouts {
+            challenger: pot - fee,
+            opponent: 0,
+            fee,
+        },
+        DuelOutcome::OpponentWins => DuelPayouts {
+            challenger: 0,
+            opponent: pot - fee,
+            fee,
+        },
+        DuelOutcome::Tie => DuelPayouts {
+            challenger: stake,
+            opponent: stake,
+            fee: 0,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{LeaderEntry, PeriodType};
+
+    #[test]
+    fn test_win_pays_pot_minus_fee() {
+        let outcome = duel_outcome(1_450, 1_200);
+        assert_eq!(outcome, DuelOutcome::ChallengerWins);
+
+        // 2 + 2 USDC pot, 5% fee
+        assert_eq!(
+            duel_payouts(2_000_000, outcome, DUEL_PLATFORM_FEE_BPS),
+            DuelPayouts {
+                challenger: 3_800_000,
+                opponent: 0,
+                fee: 200_000,
+            }
+        );
+        assert_eq!(
+            duel_payouts(2_000_000, duel_outcome(0, 900), DUEL_PLATFORM_FEE_BPS),
+            DuelPayouts {
+                challenger: 0,
+                opponent: 3_800_000,
+                fee: 200_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_tie_returns_both_stakes_without_fee() {
+        // Neither player on the board also ties at 0
+        for (challenger_score, opponent_score) in [(1_000, 1_000), (0, 0)] {
+            let outcome = duel_outcome(challenger_score, opponent_score);
+            assert_eq!(outcome, DuelOutcome::Tie);
+            assert_eq!(
+                duel_payouts(2_000_000, outcome, DUEL_PLATFORM_FEE_BPS),
+                DuelPayouts {
+                    challenger: 2_000_000,
+                    opponent: 2_000_000,
+                    fee: 0,
+                }
+            );
+        }
+    }
+
+    fn board_with(players: &[(Pubkey, u32)]) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("D10", PeriodType::Daily);
+        board.entries = players
+            .iter()
+            .map(|&(player, score)| LeaderEntry {
+                player,
+                score,
+                guesses_used: 3,
+                time_ms: 30_000,
+                timestamp: 0,
+                username: "Test".to_string(),
+                equipped_flair: 0,
+                games_played: 1,
+                counted_days: 0,
+            })
+            .collect();
+        board
+    }
+
+    #[test]
+    fn test_players_below_capacity_tie() {
+        // A full board of others: both duelists played but fell off it
+        let others: Vec<(Pubkey, u32)> = (0..DEFAULT_LEADERBOARD_CAPACITY)
+            .map(|rank| (Pubkey::new_unique(), 2_000 - rank as u32))
+            .collect();
+        let board = board_with(&others);
+        let (challenger, opponent) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert_eq!(board_score(&board, &challenger), 0);
+        assert_eq!(board_score(&board, &opponent), 0);
+        assert_eq!(
+            duel_outcome(board_score(&board, &challenger), board_score(&board, &opponent)),
+            DuelOutcome::Tie
+        );
+    }
+
+    #[test]
+    fn test_accepted_opponent_who_never_plays_loses() {
+        let (challenger, opponent) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let board = board_with(&[(challenger, 850)]);
+
+        let outcome =
+            duel_outcome(board_score(&board, &challenger), board_score(&board, &opponent));
+        assert_eq!(outcome, DuelOutcome::ChallengerWins);
+        assert_eq!(
+            duel_payouts(2_000_000, outcome, DUEL_PLATFORM_FEE_BPS),
+            DuelPayouts {
+                challenger: 3_800_000,
+                opponent: 0,
+                fee: 200_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_payouts_always_empty_the_escrow() {
+        for stake in [1, 7, 999_999, u64::MAX / 2] {
+            for outcome in [
+                DuelOutcome::ChallengerWins,
+                DuelOutcome::OpponentWins,
+                DuelOutcome::Tie,
+            ] {
+                let payouts = duel_payouts(stake, outcome, DUEL_PLATFORM_FEE_BPS);
+                assert_eq!(
+                    payouts.challenger as u128 + payouts.opponent as u128 + payouts.fee as u128,
+                    stake as u128 * 2
+                );
+            }
+        }
+    }
+}
@@ -32,48 +32,9 @@ pub fn check_and_unlock_achievements(
     profile: &mut UserProfile,
     timestamp: i64,
 ) -> Result<()> {
-    // Define achievement conditions
-    let achievements_to_check = [
-        (
-            ACHIEVEMENT_FIRST_GAME,
-            profile.total_games_played >= 1,
-            "First Game",
-        ),
-        (
-            ACHIEVEMENT_FIRST_WIN,
-            profile.games_won >= 1,
-            "First Win",
-        ),
-        (
-            ACHIEVEMENT_LUCKY_GUESS,
-            profile.guess_distribution[0] > 0 || profile.guess_distribution[1] > 0,
-            "Lucky Guess (1-2 guesses)",
-        ),
-        (
-            ACHIEVEMENT_STREAK_3,
-            profile.current_streak >= 3,
-            "3-Game Streak",
-        ),
-        (
-            ACHIEVEMENT_STREAK_7,
-            profile.current_streak >= 7,
-            "7-Game Streak",
-        ),
-        (
-            ACHIEVEMENT_PERFECTIONIST,
-            {
-                let perfect_games = profile.guess_distribution[0]
-                    + profile.guess_distribution[1]
-                    + profile.guess_distribution[2];
-                perfect_games >= 10
-            },
-            "Perfectionist (10+ games with ≤3 guesses)",
-        ),
-    ];
-
     // Check each achievement
-    for (achievement_id, should_unlock, description) in achievements_to_check {
-        if should_unlock {
+    for (achievement_id, current, target, description) in achievement_criteria(profile) {
+        if current >= target {
             // Check if already unlocked
             let already_unlocked = profile
                 .achievements
@@ -90,6 +51,45 @@ pub fn check_and_unlock_achievements(
     Ok(())
 }
 
+/// Each achievement's counter on a profile and the value that unlocks it
+///
+/// # Returns
+/// `(achievement_id, current, target, description)` for every achievement
+/// `check_and_unlock_achievements` checks
+pub fn achievement_criteria(profile: &UserProfile) -> [(u8, u32, u32, &'static str); 6] {
+    let distribution = &profile.guess_distribution;
+    let lucky_games = distribution[0].saturating_add(distribution[1]);
+    let perfect_games = lucky_games.saturating_add(distribution[2]);
+
+    [
+        (ACHIEVEMENT_FIRST_GAME, profile.total_games_played, 1, "First Game"),
+        (ACHIEVEMENT_FIRST_WIN, profile.games_won, 1, "First Win"),
+        (ACHIEVEMENT_LUCKY_GUESS, lucky_games, 1, "Lucky Guess (1-2 guesses)"),
+        (ACHIEVEMENT_STREAK_3, profile.current_streak, 3, "3-Game Streak"),
+        (ACHIEVEMENT_STREAK_7, profile.current_streak, 7, "7-Game Streak"),
+        (
+            ACHIEVEMENT_PERFECTIONIST,
+            perfect_games,
+            10,
+            "Perfectionist (10+ games with ≤3 guesses)",
+        ),
+    ]
+}
+
+/// How far a profile is from each achievement it hasn't unlocked
+///
+/// # Returns
+/// `(achievement_id, current, target)` for every locked achievement; the
+/// remaining count is `target - current`, or 0 if the profile already
+/// qualifies and is waiting for its next settlement or sync
+pub fn locked_achievement_progress(profile: &UserProfile) -> Vec<(u8, u32, u32)> {
+    achievement_criteria(profile)
+        .into_iter()
+        .filter(|&(id, ..)| !is_achievement_unlocked(profile, id))
+        .map(|(id, current, target, _)| (id, current, target))
+        .collect()
+}
+
 /// Unlock a specific achievement for a profile
 ///
 /// # Arguments
@@ -203,24 +203,9 @@ mod tests {
     use super::*;
 
     fn create_test_profile() -> UserProfile {
-        UserProfile {
-            player: Pubkey::new_unique(),
-            username: "TestPlayer".to_string(),
-            total_games_played: 0,
-            games_won: 0,
-            current_streak: 0,
-            max_streak: 0,
-            total_score: 0,
-            best_score: 0,
-            average_guesses: 0.0,
-            guess_distribution: [0; 7],
-            last_played_period: String::new(),
-            last_paid_period: String::new(),
-            has_played_this_period: false,
-            achievements: Vec::new(),
-            created_at: 0,
-            last_played: 0,
-        }
+        let mut profile = UserProfile::default_for_test(Pubkey::new_unique());
+        profile.username = "TestPlayer".to_string();
+        profile
     }
 
     #[test]
@@ -279,6 +264,42 @@ mod tests {
         assert!(!is_achievement_unlocked(&profile, ACHIEVEMENT_FIRST_WIN));
     }
 
+    #[test]
+    fn test_progress_of_locked_achievements() {
+        let mut profile = create_test_profile();
+        profile.total_games_played = 4;
+        profile.games_won = 3;
+        profile.current_streak = 2;
+        profile.guess_distribution = [0, 0, 3, 0, 0, 0, 0];
+        check_and_unlock_achievements(&mut profile, 1000).unwrap();
+
+        // First game and first win are unlocked and no longer reported
+        let progress = locked_achievement_progress(&profile);
+        assert_eq!(
+            progress,
+            vec![
+                (ACHIEVEMENT_LUCKY_GUESS, 0, 1),
+                (ACHIEVEMENT_STREAK_3, 2, 3),
+                (ACHIEVEMENT_STREAK_7, 2, 7),
+                (ACHIEVEMENT_PERFECTIONIST, 3, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_progress_reports_qualified_but_not_yet_synced() {
+        let mut profile = create_test_profile();
+        profile.current_streak = 8;
+
+        let progress = locked_achievement_progress(&profile);
+        assert!(progress.contains(&(ACHIEVEMENT_STREAK_7, 8, 7)));
+
+        check_and_unlock_achievements(&mut profile, 1000).unwrap();
+        assert!(locked_achievement_progress(&profile)
+            .iter()
+            .all(|&(id, ..)| id != ACHIEVEMENT_STREAK_3 && id != ACHIEVEMENT_STREAK_7));
+    }
+
     #[test]
     fn test_max_achievements_limit() {
         let mut profile = create_test_profile();
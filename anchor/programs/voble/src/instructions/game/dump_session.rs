@@ -0,0 +1,66 @@
+use crate::contexts::*;
+use anchor_lang::prelude::*;
+
+use crate::utils::words;
+
+/// Log every field of a session (developer builds only)
+///
+/// Only compiled with the `debug-logs` feature. Logs the target word and the
+/// colour results, so it must never ship in a deployed build.
+///
+/// # Arguments
+/// * `ctx` - The context containing the session account
+pub fn dump_session_state(ctx: Context<DumpSessionState>) -> Result<()> {
+    let session = &ctx.accounts.session;
+    let target = words::get_word_by_index(session.word_index).unwrap_or("");
+
+    msg!("🔎 ========== SESSION STATE ==========");
+    msg!("   player: {}", session.player);
+    msg!("   session_id: {}", session.session_id);
+    msg!("   period_id: {}", session.period_id);
+    msg!("   version: {} (program_version {})", session.version, session.program_version);
+    msg!("   state: {:?}", session.current_state());
+    msg!("   game_epoch: {}", session.game_epoch);
+    msg!("   word_index: {} ({})", session.word_index, target);
+    msg!("   target_word: {:?}", session.target_word);
+    msg!("   target_word_hash: {:?}", session.target_word_hash);
+    msg!("   word_commitment: {:?}", session.word_commitment);
+    msg!("   word_difficulty: {}", session.word_difficulty);
+
+    for (i, guess) in session.guesses.iter().enumerate() {
+        if let Some(guess) = guess {
+            msg!("   guess {}: {} {:?}", i + 1, guess.word(), guess.results(session.word_index));
+        }
+    }
+    msg!("   guesses_used: {}", session.guesses_used);
+    msg!("   is_solved: {} completed: {}", session.is_solved, session.completed);
+    msg!("   time_ms: {} score: {}", session.time_ms, session.score);
+    msg!("   score_multiplier_bps: {}", session.score_multiplier_bps);
+    msg!("   hole_in_one_bonus: {}", session.hole_in_one_bonus);
+    msg!("   deadline_rule: {:?}", session.deadline_rule);
+    msg!("   transcript_hash: {:?}", session.transcript_hash);
+    msg!("   current_input: {:?} (guess {})", session.current_input, session.input_guess_index);
+    msg!(
+        "   keystrokes: {} (rejected {}, vec capacity {})",
+        session.keystrokes.len(),
+        session.rejected_keystrokes,
+        session.keystrokes.capacity()
+    );
+    for keystroke in &session.keystrokes {
+        msg!(
+            "      {} @{}ms (guess {})",
+            keystroke.key,
+            keystroke.timestamp_ms,
+            keystroke.guess_index
+        );
+    }
+    msg!("   mulligan_used: {}", session.mulligan_used);
+    msg!("   vrf_request_timestamp: {}", session.vrf_request_timestamp);
+    msg!("   commit_frequency_ms: {}", session.commit_frequency_ms);
+    msg!("   delegated: {}", session.delegated);
+    msg!("   last_action_slots: {:?}", session.last_action_slots);
+    msg!("   bump: {}", session.bump);
+    crate::utils::debug::log_session(session);
+
+    Ok(())
+}
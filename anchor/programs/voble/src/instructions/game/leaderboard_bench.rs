@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use super::update_player_stats::sort_and_trim_leaderboard;
+#[cfg(feature = "debug-logs")]
+use super::update_player_stats::apply_game_to_leaderboard;
+#[cfg(feature = "debug-logs")]
+use crate::contexts::*;
+#[cfg(feature = "debug-logs")]
+use crate::utils::debug::ComputeMeter;
+
+/// Players the fixture draws from (more than a board keeps, so games both
+/// insert new entries and rewrite existing ones)
+pub const FIXTURE_PLAYERS: u32 = 120;
+
+/// Deterministic stream of settled games for comparing leaderboard writes
+pub struct GameFixture {
+    seed: u64,
+    timestamp: i64,
+}
+
+impl GameFixture {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, timestamp: 0 }
+    }
+
+    /// Next game: a fixture player with a pseudo-random score, time and
+    /// username (one of four, so some writes change it and some don't)
+    pub fn next_game(&mut self) -> LeaderEntry {
+        // LCG so the fixture is identical on every run and on-chain
+        self.seed = self
+            .seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.timestamp += 1;
+
+        LeaderEntry {
+            player: fixture_player((self.seed >> 33) as u32 % FIXTURE_PLAYERS),
+            score: ((self.seed >> 17) % 1_200) as u32,
+            guesses_used: 3,
+            time_ms: (self.seed >> 40) % 90_000,
+            timestamp: self.timestamp,
+            username: format!("P{}", (self.seed >> 50) % 4),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+}
+
+/// Fixed pubkey of fixture player `index`
+pub fn fixture_player(index: u32) -> Pubkey {
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&(index + 1).to_le_bytes());
+    Pubkey::new_from_array(bytes)
+}
+
+/// Leaderboard write before entries were rewritten in place
+///
+/// Clones the whole entry (username included) on every write and re-sorts
+/// the board after every game. Kept as the baseline the in-place path is
+/// benchmarked and tested against.
+pub fn legacy_apply_game(leaderboard: &mut PeriodLeaderboard, new_entry: &LeaderEntry) {
+    if !leaderboard.finalized && new_entry.score > 0 {
+        match leaderboard
+            .entries
+            .iter()
+            .position(|entry| entry.player == new_entry.player)
+        {
+            None => {
+                leaderboard.entries.push(new_entry.clone());
+                leaderboard.total_players += 1;
+            }
+            Some(index) => {
+                let aggregation = leaderboard.aggregation;
+                let entry = &mut leaderboard.entries[index];
+                let previous_games = entry.games_played.max(1);
+                let games_played = previous_games.saturating_add(1);
+                match aggregation {
+                    AggregationStrategy::Best => {
+                        if new_entry.score > entry.score {
+                            *entry = new_entry.clone();
+                        }
+                    }
+                    AggregationStrategy::Sum => {
+                        let score = entry.score.saturating_add(new_entry.score);
+                        *entry = new_entry.clone();
+                        entry.score = score;
+                    }
+                    AggregationStrategy::Average => {
+                        let total =
+                            entry.score as u64 * previous_games as u64 + new_entry.score as u64;
+                        *entry = new_entry.clone();
+                        entry.score = (total / games_played as u64) as u32;
+                    }
+                }
+                entry.games_played = games_played;
+            }
+        }
+    }
+    sort_and_trim_leaderboard(leaderboard);
+}
+
+/// Full weekly board of fixture players, sorted like a live one
+pub fn fixture_leaderboard(aggregation: AggregationStrategy) -> PeriodLeaderboard {
+    let mut board = PeriodLeaderboard::default_for_test("W1", PeriodType::Weekly);
+    board.aggregation = aggregation;
+
+    // One spare slot so the legacy push past a full board doesn't reallocate
+    let capacity = board.capacity();
+    board.entries = Vec::with_capacity(capacity + 1);
+    let mut fixture = GameFixture::new(0xb0a4d);
+    for index in 0..capacity as u32 {
+        let mut entry = fixture.next_game();
+        entry.player = fixture_player(index);
+        entry.score = entry.score.max(1);
+        board.entries.push(entry);
+    }
+    sort_and_trim_leaderboard(&mut board);
+    board.total_players = capacity as u32;
+    board
+}
+
+/// Measure one settled game written to a full leaderboard (developer builds only)
+///
+/// Only compiled with the `debug-logs` feature. Builds the fixture board,
+/// applies game `seed` of the fixture through either the in-place write or
+/// `legacy_apply_game`, and logs the compute units the write took. The
+/// resulting board is serialized into `board` so both paths can be compared
+/// byte for byte.
+///
+/// # Arguments
+/// * `ctx` - The context containing the scratch board account
+/// * `aggregation` - Aggregation strategy of the fixture board
+/// * `legacy` - Use the pre-refactor clone-and-resort write
+/// * `seed` - Fixture seed of the game to write
+///
+/// # Notes
+/// - One game per transaction: every legacy re-sort allocates a scratch
+///   buffer, and the 32 KiB heap never frees
+#[cfg(feature = "debug-logs")]
+pub fn bench_leaderboard_write(
+    ctx: Context<BenchLeaderboardWrite>,
+    aggregation: AggregationStrategy,
+    legacy: bool,
+    seed: u64,
+) -> Result<()> {
+    let mut leaderboard = fixture_leaderboard(aggregation);
+    let game = GameFixture::new(seed).next_game();
+
+    let mut meter = ComputeMeter::start("bench_leaderboard_write");
+    if legacy {
+        legacy_apply_game(&mut leaderboard, &game);
+    } else {
+        apply_game_to_leaderboard(&mut leaderboard, &game);
+    }
+    meter.checkpoint("write entry");
+
+    let mut data = ctx.accounts.board.try_borrow_mut_data()?;
+    leaderboard.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
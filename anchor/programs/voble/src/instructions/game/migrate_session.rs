@@ -0,0 +1,214 @@
+use crate::{contexts::*, errors::VobleError, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Guess layout before results were derived on read
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyGuessData {
+    pub guess: String,
+    pub result: [LetterResult; 6],
+}
+
+/// SessionAccount layout with the old `[Option<LegacyGuessData>; 7]` guesses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacySessionAccount {
+    pub player: Pubkey,
+    pub session_id: String,
+    pub target_word_hash: [u8; 32],
+    pub word_index: u32,
+    pub target_word: String,
+    pub guesses: [Option<LegacyGuessData>; 7],
+    pub is_solved: bool,
+    pub guesses_used: u8,
+    pub time_ms: u64,
+    pub score: u32,
+    pub completed: bool,
+    pub period_id: String,
+    pub vrf_request_timestamp: i64,
+    pub keystrokes: Vec<KeystrokeData>,
+    pub current_input: String,
+    pub version: u8,
+    pub game_epoch: u64,
+    pub state: SessionState,
+    pub score_multiplier_bps: u16,
+}
+
+/// Rewrite a session created before guesses were compacted
+///
+/// Old sessions stored every guess as a `String` plus its `[LetterResult; 6]`
+/// result. The current layout keeps only the guessed letters, so those
+/// accounts no longer deserialize as `SessionAccount` until rewritten.
+///
+/// Also stores the canonical PDA bump on sessions created before bumps were
+/// kept on the account; the gameplay contexts verify the session with
+/// `bump = session.bump` and reject a session whose bump is missing.
+///
+/// # Arguments
+/// * `ctx` - The context containing the player and their session
+///
+/// # Validation
+/// - Session must be owned by this program and carry the SessionAccount discriminator
+///
+/// # Notes
+/// - Sessions already in the current layout with a stored bump are left untouched
+/// - Runs wherever the session currently lives (base layer or ER)
+/// - The account keeps its original size; the freed tail is zeroed
+pub fn migrate_session(ctx: Context<MigrateSession>) -> Result<()> {
+    let session_info = ctx.accounts.session.to_account_info();
+
+    // ========== VALIDATION ==========
+    require!(session_info.owner == &crate::ID, VobleError::Unauthorized);
+
+    let mut data = session_info.try_borrow_mut_data()?;
+    require!(
+        data.starts_with(SessionAccount::DISCRIMINATOR),
+        VobleError::InvalidSessionState
+    );
+
+    // ========== MIGRATE ==========
+    let mut session = match migrate_legacy_session(&data[8..]) {
+        Some(session) => session,
+        None => {
+            let session = SessionAccount::try_deserialize(&mut &data[..])?;
+            if session.bump == ctx.bumps.session {
+                msg!("✅ Session already uses the current layout");
+                return Ok(());
+            }
+            session
+        }
+    };
+    session.bump = ctx.bumps.session;
+    require!(
+        session.player == ctx.accounts.player.key(),
+        VobleError::Unauthorized
+    );
+
+    let mut writer: &mut [u8] = &mut data[..];
+    session.try_serialize(&mut writer)?;
+    writer.fill(0);
+
+    msg!("✅ Session migrated for {}", session.player);
+
+    Ok(())
+}
+
+/// Parse legacy session bytes (after the discriminator) into the current layout
+///
+/// # Returns
+/// `None` if the bytes are not a legacy session. A stored guess in the new
+/// layout starts with ASCII letters, which read as an impossible legacy
+/// string length, so current accounts never parse as legacy ones.
+pub fn migrate_legacy_session(mut data: &[u8]) -> Option<SessionAccount> {
+    let legacy = LegacySessionAccount::deserialize(&mut data).ok()?;
+
+    let guesses = legacy.guesses.map(|guess| {
+        guess.and_then(|guess| GuessData::new(&guess.guess).ok())
+    });
+
+    Some(SessionAccount {
+        player: legacy.player,
+        session_id: legacy.session_id,
+        target_word_hash: legacy.target_word_hash,
+        word_index: legacy.word_index,
+        target_word: legacy.target_word,
+        guesses,
+        is_solved: legacy.is_solved,
+        guesses_used: legacy.guesses_used,
+        time_ms: legacy.time_ms,
+        score: legacy.score,
+        completed: legacy.completed,
+        period_id: legacy.period_id,
+        vrf_request_timestamp: legacy.vrf_request_timestamp,
+        keystrokes: legacy.keystrokes,
+        current_input: legacy.current_input,
+        version: legacy.version,
+        game_epoch: legacy.game_epoch,
+        state: legacy.state,
+        score_multiplier_bps: legacy.score_multiplier_bps,
+        commit_frequency_ms: 0, // Not recorded before migration
+        bump: 0,                // Set by migrate_session
+        word_commitment: [0; 32],
+        word_difficulty: 0,
+        input_guess_index: 0,
+        rejected_keystrokes: 0,
+        session_nonce: 0,
+        delegated: false,
+        hole_in_one_bonus: 0,
+        deadline_rule: ScoreDeadlineRule::OnTime,
+        program_version: 0, // Stamped on the next reset
+        transcript_hash: [0; 32],
+        last_action_slots: [0; 3],
+        mulligan_used: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn to_bytes<T: AnchorSerialize>(value: &T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn legacy_session() -> LegacySessionAccount {
+        let mut guesses: [Option<LegacyGuessData>; 7] = Default::default();
+        guesses[0] = Some(LegacyGuessData {
+            guess: "ANCHOR".to_string(),
+            result: [LetterResult::Absent; 6],
+        });
+        guesses[1] = Some(LegacyGuessData {
+            guess: "SOLANA".to_string(),
+            result: [LetterResult::Correct; 6],
+        });
+
+        LegacySessionAccount {
+            player: Pubkey::new_unique(),
+            session_id: "S1".to_string(),
+            target_word_hash: [7; 32],
+            word_index: 18,
+            target_word: "SOLANA".to_string(),
+            guesses,
+            is_solved: true,
+            guesses_used: 2,
+            time_ms: 4_200,
+            score: 900,
+            completed: true,
+            period_id: "D1".to_string(),
+            vrf_request_timestamp: 1,
+            keystrokes: Vec::new(),
+            current_input: String::new(),
+            version: SESSION_ACCOUNT_VERSION,
+            game_epoch: 3,
+            state: SessionState::CompletedUnsettled,
+            score_multiplier_bps: SCORE_MULTIPLIER_NONE,
+        }
+    }
+
+    #[test]
+    fn test_migrate_legacy_session_keeps_guesses() {
+        let legacy = legacy_session();
+        let mut bytes = to_bytes(&legacy);
+        bytes.resize(bytes.len() + 64, 0); // Unused account tail
+
+        let session = migrate_legacy_session(&bytes).unwrap();
+
+        assert_eq!(session.player, legacy.player);
+        assert_eq!(session.guesses_used, 2);
+        assert_eq!(session.guesses[0].as_ref().unwrap().word(), "ANCHOR");
+        assert_eq!(session.guesses[1].as_ref().unwrap().word(), "SOLANA");
+        assert!(session.guesses[2].is_none());
+        assert_eq!(session.game_epoch, 3);
+        assert_eq!(session.state, SessionState::CompletedUnsettled);
+    }
+
+    #[test]
+    fn test_migrate_ignores_current_layout() {
+        let migrated = migrate_legacy_session(&to_bytes(&legacy_session())).unwrap();
+        let bytes = to_bytes(&migrated);
+
+        assert!(migrate_legacy_session(&bytes).is_none());
+    }
+}
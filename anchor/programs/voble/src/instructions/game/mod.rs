@@ -3,11 +3,23 @@ pub mod start_game;
 pub mod submit_guess;
 pub mod update_player_stats;
 pub mod record_keystroke;
+pub mod retype_guess;
 pub mod reset_session;
+pub mod share_board;
+pub mod period_word;
+pub mod migrate_session;
+pub mod stale_session;
+#[cfg(feature = "debug-logs")]
+pub mod dump_session;
+#[cfg(any(test, feature = "debug-logs"))]
+pub mod leaderboard_bench;
 
 // Helper modules
 pub mod achievements;
+pub mod score_deadline;
 pub mod scoring;
+pub mod session_state;
+pub mod transcript;
 pub mod word_selection;
 
 // Re-export all public functions for easy access
@@ -15,9 +27,25 @@ pub use start_game::*;
 pub use submit_guess::*;
 pub use update_player_stats::*;
 pub use record_keystroke::*;
+pub use retype_guess::*;
 pub use reset_session::*;
+pub use share_board::*;
+pub use period_word::*;
+pub use migrate_session::*;
+pub use stale_session::*;
+#[cfg(feature = "debug-logs")]
+pub use dump_session::*;
+#[cfg(feature = "debug-logs")]
+pub use leaderboard_bench::*;
 
 // Re-export helper functions that might be needed externally
-pub use achievements::{check_and_unlock_achievements, get_unlocked_count};
-pub use scoring::{calculate_final_score, evaluate_guess};
-pub use word_selection::{get_word_by_index, select_word_for_session};
+pub use achievements::{
+    check_and_unlock_achievements, get_unlocked_count, locked_achievement_progress,
+};
+pub use scoring::{
+    active_multiplier_bps, apply_score_multiplier, calculate_final_score, is_hole_in_one,
+};
+pub use word_selection::{
+    next_unused_word_index, require_word_source_playable, select_word_for_session, session_word,
+    session_word_difficulty, word_index_for_period,
+};
@@ -0,0 +1,110 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::PeriodState};
+use anchor_lang::prelude::*;
+
+use crate::utils::words::get_word_by_index;
+
+/// Emit the word a finalized daily period was played against
+///
+/// Only commit-reveal periods have one: `reset_session` starts every game of
+/// such a period on its `SharedWord`, `reveal_daily_word` accepts only a
+/// reveal that opens the commitment to that same index, and `finalize_daily`
+/// records it as `revealed_word_index`. Players can compare it with the word
+/// their own session ended on and with the commitment published before the
+/// period started.
+///
+/// # Arguments
+/// * `ctx` - The context containing the period state
+/// * `period_id` - Finalized daily period (e.g., "D123")
+///
+/// # Validation
+/// - Period must be finalized
+/// - Period must have been finalized under commit-reveal
+///   (`PerPlayerWords` otherwise: each game had its own word, so there is
+///   no period word to report)
+///
+/// # Event
+/// Emits `PeriodWordRevealed` with the word index and the word.
+///
+/// # Notes
+/// - `word_strategy` is read at each reset, so a switch in the middle of a
+///   period leaves the games reset before it on their own words; switch
+///   between periods
+pub fn reveal_period_word(ctx: Context<RevealPeriodWord>, period_id: String) -> Result<()> {
+    let (word_index, word) = period_word(&ctx.accounts.period_state)?;
+
+    msg!("🔤 Word for {}: {} (index {})", period_id, word, word_index);
+
+    emit!(PeriodWordRevealed {
+        period_id,
+        word_index,
+        word: word.to_string(),
+    });
+
+    Ok(())
+}
+
+/// Shared word of a finalized period
+///
+/// # Returns
+/// The revealed word index and its word, or `PerPlayerWords` if the period
+/// wasn't finalized under commit-reveal
+pub fn period_word(period_state: &PeriodState) -> Result<(u32, &'static str)> {
+    require!(period_state.finalized, VobleError::InvalidPeriodState);
+
+    let word_index = period_state
+        .revealed_word_index
+        .ok_or(VobleError::PerPlayerWords)?;
+    Ok((word_index, get_word_by_index(word_index)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::PROGRAM_VERSION;
+    use crate::state::RemainderPolicy;
+
+    fn period_state(revealed_word_index: Option<u32>) -> PeriodState {
+        PeriodState {
+            period_type: "daily".to_string(),
+            period_id: "D42".to_string(),
+            finalized: true,
+            total_participants: 3,
+            vault_balance_at_finalization: 1_000_000,
+            winners: Vec::new(),
+            bump: 255,
+            revealed_word_index,
+            remainder: 0,
+            remainder_policy: RemainderPolicy::ToFirstPlace,
+            program_version: PROGRAM_VERSION,
+            claimed_count: 0,
+            closed_winners: 0,
+        }
+    }
+
+    #[test]
+    fn test_shared_word_revealed() {
+        let (word_index, word) = period_word(&period_state(Some(18))).unwrap();
+
+        assert_eq!(word_index, 18);
+        assert_eq!(word, get_word_by_index(18).unwrap());
+    }
+
+    #[test]
+    fn test_per_player_words_rejected() {
+        assert_eq!(
+            period_word(&period_state(None)).unwrap_err(),
+            VobleError::PerPlayerWords.into()
+        );
+    }
+
+    #[test]
+    fn test_unfinalized_period_rejected() {
+        let mut state = period_state(Some(18));
+        state.finalized = false;
+
+        assert_eq!(
+            period_word(&state).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+    }
+}
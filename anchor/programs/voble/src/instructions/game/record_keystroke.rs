@@ -1,51 +1,69 @@
 use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
 use anchor_lang::prelude::*;
 
+use super::session_state;
+
 /// Record a single keystroke during gameplay
+///
+/// # Validation
+/// - Signer must be the player or their session key
+/// - Game must be in progress with guesses remaining
+/// - Key must be a character of the config's `char_class`, "Backspace" or "Enter"
+///
+/// # Notes
+/// - At most MAX_KEYSTROKES_PER_GUESS per guess and MAX_KEYSTROKES_PER_SESSION
+///   per game are recorded. Keystrokes over a limit are dropped, counted in
+///   `rejected_keystrokes` and reported with `KeystrokeRejected`; the
+///   instruction still succeeds so the counter survives
 pub fn record_keystroke(
     ctx: Context<RecordKeystroke>,
     key: String,
 ) -> Result<()> {
-    let session = &mut ctx.accounts.session;
+    session_state::require_session_authority(
+        &ctx.accounts.user_profile,
+        &ctx.accounts.signer.key(),
+    )?;
+
+    let char_class = ctx.accounts.global_config.char_class;
+    // Reborrow the session once so its fields can be borrowed separately
+    let session: &mut SessionAccount = &mut ctx.accounts.session;
     let now = Clock::get()?.unix_timestamp;
     
     // Validate game is active
-    require!(!session.completed, VobleError::AlreadyClaimed);
+    session_state::require_state(session, SessionState::InProgress)?;
     require!(
         session.guesses_used < MAX_GUESSES,
         VobleError::InvalidGuessCount
     );
-    
+
+    // Read value before the key moves the buffer to the next guess
+    let guess_index = session.input_guess_index;
+
     // Prevent account bloat
-    require!(
-        session.keystrokes.len() < 200,
-        VobleError::TooManyKeystrokes
-    );
+    if let Err(err) = check_keystroke_limits(&session.keystrokes, guess_index) {
+        session.rejected_keystrokes = session.rejected_keystrokes.saturating_add(1);
+        msg!("🚫 Keystroke rejected: {} ({} so far)", err, session.rejected_keystrokes);
+
+        emit!(KeystrokeRejected {
+            player: session.player,
+            session_id: session.session_id.clone(),
+            guess_index,
+            rejected_keystrokes: session.rejected_keystrokes,
+            game_epoch: session.game_epoch,
+        });
+
+        return Ok(());
+    }
     
     // Calculate relative timestamp
     let timestamp_ms = ((now - session.vrf_request_timestamp) * 1000) as u64;
     
-    // Handle different key types
-    match key.as_str() {
-        "Backspace" => {
-            if !session.current_input.is_empty() {
-                session.current_input.pop();
-            }
-        }
-        "Enter" => {
-            // Enter is handled by submit_guess, just record it
-        }
-        _ if key.len() == 1 && key.chars().next().unwrap().is_alphabetic() => {
-            // Only allow letters
-            if session.current_input.len() < 6 {
-                session.current_input.push_str(&key.to_uppercase());
-            }
-        }
-        _ => return Err(VobleError::InvalidInput.into()),
-    }
-
-    // Read value before mutable borrow
-    let guess_index = session.guesses_used; 
+    apply_key(
+        &mut session.current_input,
+        &mut session.input_guess_index,
+        &key,
+        char_class,
+    )?;
     
     // Record keystroke
     session.keystrokes.push(KeystrokeData {
@@ -63,10 +81,198 @@ pub fn record_keystroke(
         key,
         timestamp_ms,
         current_input: session.current_input.clone(),
-        guess_index: session.guesses_used,
+        guess_index,
+        game_epoch: session.game_epoch,
     });
     
     Ok(())
 }
 
+/// Check the per-guess and per-game keystroke limits
+///
+/// # Arguments
+/// * `keystrokes` - Keystrokes recorded so far this game
+/// * `guess_index` - Guess the next keystroke belongs to
+pub fn check_keystroke_limits(keystrokes: &[KeystrokeData], guess_index: u8) -> Result<()> {
+    require!(
+        keystrokes.len() < MAX_KEYSTROKES_PER_SESSION,
+        VobleError::KeystrokeLimitExceeded
+    );
+
+    let this_guess = keystrokes
+        .iter()
+        .filter(|keystroke| keystroke.guess_index == guess_index)
+        .count();
+    require!(
+        this_guess < MAX_KEYSTROKES_PER_GUESS,
+        VobleError::KeystrokeLimitExceeded
+    );
+
+    Ok(())
+}
+
+/// Apply one key to the typing buffer
+///
+/// # Arguments
+/// * `input` - Current typing buffer (at most WORD_LENGTH characters)
+/// * `guess_index` - Guess the buffer belongs to
+/// * `key` - A single character of `char_class`, "Backspace" or "Enter"
+/// * `char_class` - The config's character class
+///
+/// # Notes
+/// - Letters are uppercased; once the buffer is full further characters are ignored
+/// - Backspace on an empty buffer does nothing
+/// - Enter on a full buffer clears it and moves to the next guess; on a
+///   partial buffer (or after the last guess) it does nothing, so Enter
+///   can't be used to reset the per-guess limit
+pub fn apply_key(
+    input: &mut String,
+    guess_index: &mut u8,
+    key: &str,
+    char_class: CharClass,
+) -> Result<()> {
+    match key {
+        "Backspace" => {
+            input.pop();
+        }
+        "Enter" => {
+            if input.len() == WORD_LENGTH && *guess_index < MAX_GUESSES {
+                input.clear();
+                *guess_index += 1;
+            }
+        }
+        _ if key.len() == 1 && key.chars().all(|c| char_class.allows(c)) => {
+            if input.len() < WORD_LENGTH {
+                input.push_str(&key.to_ascii_uppercase());
+            }
+        }
+        _ => return Err(VobleError::InvalidInput.into()),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_keys(input: &mut String, guess_index: &mut u8, keys: &[&str]) {
+        for key in keys {
+            apply_key(input, guess_index, key, CharClass::Alphabetic).unwrap();
+        }
+    }
+
+    fn keystrokes(count: usize, guess_index: u8) -> Vec<KeystrokeData> {
+        (0..count)
+            .map(|i| KeystrokeData {
+                key: "A".to_string(),
+                timestamp_ms: i as u64,
+                guess_index,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_letters_fill_buffer_up_to_word_length() {
+        let (mut input, mut guess_index) = (String::new(), 0);
+
+        type_keys(&mut input, &mut guess_index, &["p", "l", "a", "n", "e", "t", "s"]);
+
+        assert_eq!(input, "PLANET");
+        assert_eq!(guess_index, 0);
+    }
+
+    #[test]
+    fn test_backspace_removes_last_letter() {
+        let (mut input, mut guess_index) = (String::new(), 0);
+
+        type_keys(&mut input, &mut guess_index, &["A", "B", "Backspace", "C"]);
+        assert_eq!(input, "AC");
+
+        type_keys(&mut input, &mut guess_index, &["Backspace", "Backspace", "Backspace"]);
+        assert_eq!(input, "");
+        assert_eq!(guess_index, 0);
+    }
+
+    #[test]
+    fn test_enter_submits_only_full_buffer() {
+        let (mut input, mut guess_index) = (String::new(), 0);
+
+        type_keys(&mut input, &mut guess_index, &["S", "T", "Enter"]);
+        assert_eq!((input.as_str(), guess_index), ("ST", 0));
+
+        type_keys(&mut input, &mut guess_index, &["R", "E", "A", "M", "Enter"]);
+        assert_eq!((input.as_str(), guess_index), ("", 1));
+
+        type_keys(&mut input, &mut guess_index, &["Enter"]);
+        assert_eq!(guess_index, 1);
+    }
 
+    #[test]
+    fn test_enter_stops_after_last_guess() {
+        let (mut input, mut guess_index) = ("PLANET".to_string(), MAX_GUESSES);
+
+        apply_key(&mut input, &mut guess_index, "Enter", CharClass::Alphabetic).unwrap();
+
+        assert_eq!((input.as_str(), guess_index), ("PLANET", MAX_GUESSES));
+    }
+
+    #[test]
+    fn test_invalid_keys_rejected() {
+        let (mut input, mut guess_index) = (String::new(), 0);
+
+        for key in ["1", "AB", "Shift", "é", ""] {
+            assert_eq!(
+                apply_key(&mut input, &mut guess_index, key, CharClass::Alphabetic).unwrap_err(),
+                VobleError::InvalidInput.into()
+            );
+        }
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_numeric_keys_follow_char_class() {
+        let (mut input, mut guess_index) = (String::new(), 0);
+
+        for key in ["1", "0", "4", "7", "2", "9"] {
+            apply_key(&mut input, &mut guess_index, key, CharClass::Numeric).unwrap();
+        }
+        assert_eq!(input, "104729");
+
+        input.clear();
+        assert_eq!(
+            apply_key(&mut input, &mut guess_index, "A", CharClass::Numeric).unwrap_err(),
+            VobleError::InvalidInput.into()
+        );
+        apply_key(&mut input, &mut guess_index, "a", CharClass::Alphanumeric).unwrap();
+        apply_key(&mut input, &mut guess_index, "1", CharClass::Alphanumeric).unwrap();
+        assert_eq!(input, "A1");
+    }
+
+    #[test]
+    fn test_per_guess_limit() {
+        let recorded = keystrokes(MAX_KEYSTROKES_PER_GUESS, 0);
+
+        assert_eq!(
+            check_keystroke_limits(&recorded, 0).unwrap_err(),
+            VobleError::KeystrokeLimitExceeded.into()
+        );
+        assert!(check_keystroke_limits(&recorded, 1).is_ok());
+        assert!(check_keystroke_limits(&recorded[1..], 0).is_ok());
+    }
+
+    #[test]
+    fn test_per_session_limit() {
+        let mut recorded = Vec::new();
+        for guess_index in 0..MAX_GUESSES {
+            recorded.extend(keystrokes(30, guess_index));
+        }
+        recorded.truncate(MAX_KEYSTROKES_PER_SESSION);
+
+        assert_eq!(
+            check_keystroke_limits(&recorded, MAX_GUESSES - 1).unwrap_err(),
+            VobleError::KeystrokeLimitExceeded.into()
+        );
+        assert!(check_keystroke_limits(&recorded[1..], MAX_GUESSES - 1).is_ok());
+    }
+}
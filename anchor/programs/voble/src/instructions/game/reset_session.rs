@@ -1,11 +1,19 @@
 use crate::contexts::*;
 use crate::constants::*;
 use crate::errors::VobleError;
-use crate::instructions::game::word_selection;
+use crate::events::VobleGameStarted;
+use crate::instructions::game::{session_state, word_selection};
+use crate::instructions::admin::session_word_commitment;
+use crate::state::{SessionAccount, SessionState, UserProfile};
+use crate::utils::ratelimit::{self, SessionAction};
 use anchor_lang::prelude::*;
 
 /// Reset session state after session delegated on ER (useful for 2nd, 3rd, time of playing)
 /// Now includes payment verification via TicketReceipt
+///
+/// Under the commit-reveal word strategy the period's daily word commitment
+/// and shared word are required: the session plays the shared word and
+/// records the commitment that `reveal_daily_word` must open to it.
 pub fn reset_session(ctx: Context<ResetSession>, period_id: String) -> Result<()> {
     let session = &mut ctx.accounts.session;
     let user_profile = &ctx.accounts.user_profile;
@@ -24,20 +32,59 @@ pub fn reset_session(ctx: Context<ResetSession>, period_id: String) -> Result<()
         VobleError::Unauthorized
     );
 
-    // 2. Prevent Replay (Session period_id must NOT match receipt period_id yet)
-    // If they match, it means this payment was already used to initialize this session
+    // 2. Prevent Replay - one game per ticket (game epoch)
+    require_ticket_unused(session, user_profile)?;
+    // Only the player's current session generation starts games
     require!(
-        session.period_id != period_id,
-        VobleError::TicketAlreadyUsed
+        session.session_nonce == user_profile.session_nonce,
+        VobleError::SessionRetired
     );
 
-    // 3. Word Selection (Moved from start_game)
-    // Note: We use 0 for total_games as we can't access profile on ER easily
+    // Cool-down between resets (ER slots)
+    ratelimit::record_session_action(
+        session,
+        SessionAction::Reset,
+        Clock::get()?.slot,
+        ratelimit::session_action_gap(&ctx.accounts.global_config, SessionAction::Reset),
+    )?;
+
+    // Commit-reveal games are only played against a committed word
+    let word_strategy = ctx.accounts.global_config.word_strategy;
+    let word_commitment =
+        session_word_commitment(word_strategy, ctx.accounts.daily_word_commitment.as_deref())?;
+
+    // 3. Lifecycle - a completed game must be settled before a new one starts
+    session_state::transition(session, SessionState::InProgress)?;
+
+    // 4. Word Selection (Moved from start_game)
+    // Commit-reveal periods play the period's assigned shared word.
+    // Otherwise: we use 0 for total_games as we can't access profile on ER easily
     // For demo mode with deterministic selection, this is acceptable
-    let word_data = word_selection::select_word_for_session(session.player, &period_id, 0)?;
+    // The word list was chosen at ticket purchase and stamped on the profile
+    let word_data = word_selection::session_word(
+        word_strategy,
+        ctx.accounts.shared_word.as_deref(),
+        session.player,
+        &period_id,
+        user_profile.word_source,
+    )?;
 
-    // 4. Reset Session State
+    // 5. Reset Session State
     session.period_id = period_id.clone();
+    session.game_epoch = user_profile.game_epoch;
+    session.score_multiplier_bps = match user_profile.score_multiplier_bps {
+        0 => SCORE_MULTIPLIER_NONE, // Ticket bought before happy hours existed
+        bps => bps,
+    };
+    // The played word's difficulty: the shared word's under commit-reveal
+    session.word_difficulty = word_selection::session_word_difficulty(
+        word_strategy,
+        ctx.accounts.shared_word.as_deref(),
+        user_profile.word_difficulty,
+    );
+    session.hole_in_one_bonus = user_profile.hole_in_one_bonus;
+    session.program_version = PROGRAM_VERSION; // Ruleset this game is played under
+    session.transcript_hash = [0; 32];
     session.target_word_hash = word_data.word_hash;
     session.word_index = word_data.word_index;
     session.target_word = String::new(); // Hidden
@@ -50,9 +97,55 @@ pub fn reset_session(ctx: Context<ResetSession>, period_id: String) -> Result<()
     session.vrf_request_timestamp = now;
     session.keystrokes.clear();
     session.current_input.clear();
+    session.input_guess_index = 0;
+    session.rejected_keystrokes = 0;
+    session.mulligan_used = false;
+
+    // 6. Record the daily word commitment (commit-reveal periods only)
+    session.word_commitment = word_commitment;
 
     msg!("✅ Session reset and initialized for new game!");
     msg!("   Word Hash: {:x?}", word_data.word_hash);
+    msg!("   Game epoch: {}", session.game_epoch);
+    msg!("   Score multiplier: {} bps", session.score_multiplier_bps);
+    msg!("   Word commitment: {:x?}", &session.word_commitment[..8]);
+    msg!("   Word difficulty: {}", session.word_difficulty);
+    msg!("   Hole in one bonus: {}", session.hole_in_one_bonus);
+
+    emit!(VobleGameStarted {
+        player: session.player,
+        session_id: session.session_id.clone(),
+        period_id,
+        target_word_hash: word_data
+            .word_hash
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect(),
+        timestamp: now,
+        difficulty: session.word_difficulty,
+        current_entrants: user_profile.entrants_at_purchase,
+        prize_pool: user_profile.prize_pool_at_purchase,
+    });
+
+    Ok(())
+}
 
+/// Check that the latest ticket hasn't started a game yet
+///
+/// # Validation
+/// - The session must not have started a game with it already
+///   (`TicketAlreadyUsed`)
+/// - It must not have been forfeited by `recover_stale_session`
+///   (`TicketAlreadyUsed`): a ticket that reached a recovered session's ER
+///   may have started a game there, which a new session can't see
+pub fn require_ticket_unused(session: &SessionAccount, profile: &UserProfile) -> Result<()> {
+    require!(
+        session.game_epoch != profile.game_epoch,
+        VobleError::TicketAlreadyUsed
+    );
+    require!(
+        profile.game_epoch != profile.forfeited_epoch,
+        VobleError::TicketAlreadyUsed
+    );
     Ok(())
 }
@@ -0,0 +1,136 @@
+use crate::utils::validation::{normalize_guess, validate_guess};
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+use super::{require_guess_allowed, session_state};
+
+/// Overwrite the typing buffer with a whole word (mulligan)
+///
+/// Lets a player who fat-fingered a guess replace `current_input` before
+/// calling `submit_guess` without it, without consuming a guess.
+///
+/// # Arguments
+/// * `ctx` - The context containing the session, config and user profile
+/// * `word` - The 6-character word to put in the buffer (will be converted to uppercase)
+///
+/// # Validation
+/// - `allow_mulligan` must be set in GlobalConfig
+/// - Signer must be the player or their session key
+/// - Game must be in progress with guesses remaining
+/// - Only one mulligan per game
+/// - Word must be exactly 6 ASCII characters of the config's `char_class`
+///
+/// # Notes
+/// - No keystrokes are recorded; `GuessRetyped` marks the overwrite in the
+///   audit trail instead
+pub fn retype_current_guess(ctx: Context<RetypeCurrentGuess>, word: String) -> Result<()> {
+    require!(
+        ctx.accounts.global_config.allow_mulligan,
+        VobleError::MulliganDisabled
+    );
+    session_state::require_session_authority(
+        &ctx.accounts.user_profile,
+        &ctx.accounts.signer.key(),
+    )?;
+
+    let char_class = ctx.accounts.global_config.char_class;
+    let session = &mut ctx.accounts.session;
+    retype_buffer(session, &word, char_class)?;
+
+    msg!("🔁 Mulligan used (buffer: {})", session.current_input);
+
+    emit!(GuessRetyped {
+        player: session.player,
+        session_id: session.session_id.clone(),
+        current_input: session.current_input.clone(),
+        guess_index: session.input_guess_index,
+        game_epoch: session.game_epoch,
+    });
+
+    Ok(())
+}
+
+/// Replace the typing buffer and spend the game's mulligan
+///
+/// # Validation
+/// - Same game state checks as `submit_guess`
+/// - `MulliganUsed` if this game already had one
+pub fn retype_buffer(
+    session: &mut SessionAccount,
+    word: &str,
+    char_class: CharClass,
+) -> Result<()> {
+    require_guess_allowed(session)?;
+    require!(!session.mulligan_used, VobleError::MulliganUsed);
+    validate_guess(word, char_class)?;
+
+    session.current_input = normalize_guess(word);
+    session.mulligan_used = true;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::SESSION_ACCOUNT_VERSION;
+    use crate::instructions::game::{advance_input_buffer, resolve_guess};
+
+    fn typing_session() -> SessionAccount {
+        let mut session = SessionAccount::default_for_test(Pubkey::default());
+        session.version = SESSION_ACCOUNT_VERSION;
+        session.state = SessionState::InProgress;
+        session.guesses_used = 2;
+        session.input_guess_index = 2;
+        session.current_input = "CRANSE".to_string();
+        session
+    }
+
+    #[test]
+    fn test_retype_keeps_guesses_used() {
+        let mut session = typing_session();
+
+        retype_buffer(&mut session, "cranes", CharClass::Alphabetic).unwrap();
+        assert_eq!(session.current_input, "CRANES");
+        assert_eq!(session.guesses_used, 2);
+        assert_eq!(session.input_guess_index, 2);
+        assert!(session.mulligan_used);
+
+        // The retyped word is what a buffer submission evaluates
+        assert_eq!(resolve_guess(None, &session.current_input).unwrap(), "CRANES");
+        session.guesses_used += 1;
+        advance_input_buffer(&mut session);
+        assert_eq!(session.current_input, "");
+        assert_eq!(session.input_guess_index, 3);
+    }
+
+    #[test]
+    fn test_one_mulligan_per_game() {
+        let mut session = typing_session();
+        retype_buffer(&mut session, "CRANES", CharClass::Alphabetic).unwrap();
+
+        assert_eq!(
+            retype_buffer(&mut session, "GARDEN", CharClass::Alphabetic).unwrap_err(),
+            VobleError::MulliganUsed.into()
+        );
+        assert_eq!(session.current_input, "CRANES");
+    }
+
+    #[test]
+    fn test_retype_rejected_outside_a_game() {
+        let mut session = typing_session();
+        session.state = SessionState::CompletedUnsettled;
+        assert_eq!(
+            retype_buffer(&mut session, "CRANES", CharClass::Alphabetic).unwrap_err(),
+            VobleError::InvalidSessionState.into()
+        );
+
+        // A bad word doesn't spend the mulligan
+        let mut session = typing_session();
+        assert_eq!(
+            retype_buffer(&mut session, "CRAN", CharClass::Alphabetic).unwrap_err(),
+            VobleError::InvalidGuessLength.into()
+        );
+        assert!(!session.mulligan_used);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::errors::VobleError;
+use crate::state::ScoreDeadlineRule;
+use crate::utils::period::{
+    get_current_period_id, get_period_end_timestamp, get_period_start_timestamp, parse_period_id,
+    PeriodType,
+};
+use anchor_lang::prelude::*;
+
+/// Leaderboard periods a game belongs to
+///
+/// A score always counts for the period its ticket was bought in
+/// (`session.period_id`), no matter when the game finishes or is committed.
+/// The weekly and monthly boards are the ones containing that day.
+///
+/// # Arguments
+/// * `daily_period_id` - The session's period ID (e.g. "D123")
+/// * `epoch_start` - Timestamp of period 0
+///
+/// # Returns
+/// `[daily, weekly, monthly]` period IDs, or `None` if `daily_period_id`
+/// is not a daily period ID
+pub fn settlement_period_ids(daily_period_id: &str, epoch_start: i64) -> Option<[String; 3]> {
+    let (period_type, _) = parse_period_id(daily_period_id)?;
+    if period_type != PeriodType::Daily {
+        return None;
+    }
+
+    let day_start = get_period_start_timestamp(daily_period_id, epoch_start)?;
+    Some([
+        daily_period_id.to_string(),
+        get_current_period_id(PeriodType::Weekly, day_start, epoch_start),
+        get_current_period_id(PeriodType::Monthly, day_start, epoch_start),
+    ])
+}
+
+/// Check the leaderboard periods passed to a settlement commit
+///
+/// # Arguments
+/// * `session_period_id` - Period the ticket was bought in
+/// * `epoch_start` - Timestamp of period 0
+/// * `period_ids` - Daily, weekly and monthly period IDs passed by the caller
+///
+/// # Validation
+/// - All three must match `settlement_period_ids`; sessions with a free-form
+///   period ID (bought before IDs were validated) only check the daily one
+pub fn verify_settlement_periods(
+    session_period_id: &str,
+    epoch_start: i64,
+    period_ids: [&str; 3],
+) -> Result<()> {
+    let Some(expected) = settlement_period_ids(session_period_id, epoch_start) else {
+        require!(
+            period_ids[0] == session_period_id,
+            VobleError::SettlementPeriodMismatch
+        );
+        return Ok(());
+    };
+
+    require!(
+        expected.iter().zip(period_ids).all(|(expected, given)| expected == given),
+        VobleError::SettlementPeriodMismatch
+    );
+
+    Ok(())
+}
+
+/// Decide how a game committed at `now` is scored against its period's end
+///
+/// # Arguments
+/// * `period_id` - Period the ticket was bought in
+/// * `now` - Time of the settlement commit
+/// * `epoch_start` - Timestamp of period 0
+/// * `grace_window` - Seconds after the period ends that commits still count
+///
+/// # Returns
+/// - `OnTime` before the period ends (and for free-form period IDs, which
+///   have no deadline)
+/// - `GraceWindow` within `grace_window` seconds after it ends
+/// - `Late` afterwards; the game updates the profile but no leaderboard
+pub fn score_deadline_rule(
+    period_id: &str,
+    now: i64,
+    epoch_start: i64,
+    grace_window: i64,
+) -> ScoreDeadlineRule {
+    let Some(ends_at) = get_period_end_timestamp(period_id, epoch_start) else {
+        return ScoreDeadlineRule::OnTime;
+    };
+
+    if now < ends_at {
+        ScoreDeadlineRule::OnTime
+    } else if now < ends_at.saturating_add(grace_window) {
+        ScoreDeadlineRule::GraceWindow
+    } else {
+        ScoreDeadlineRule::Late
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_SCORE_GRACE_WINDOW, PERIOD_DAILY_DURATION, PERIOD_EPOCH_START};
+
+    const EPOCH: i64 = PERIOD_EPOCH_START;
+    const GRACE: i64 = DEFAULT_SCORE_GRACE_WINDOW;
+
+    /// Midnight between day 9 and day 10
+    const MIDNIGHT: i64 = EPOCH + 10 * PERIOD_DAILY_DURATION;
+
+    #[test]
+    fn test_late_night_game_committed_after_midnight() {
+        // Ticket bought at 23:59 of D9, committed at 00:05 of D10
+        let period_id = get_current_period_id(PeriodType::Daily, MIDNIGHT - 60, EPOCH);
+        let committed_at = MIDNIGHT + 5 * 60;
+        assert_eq!(period_id, "D9");
+        assert_eq!(
+            get_current_period_id(PeriodType::Daily, committed_at, EPOCH),
+            "D10"
+        );
+
+        // The score still belongs to D9, within the grace window
+        assert_eq!(
+            score_deadline_rule(&period_id, committed_at, EPOCH, GRACE),
+            ScoreDeadlineRule::GraceWindow
+        );
+        assert!(verify_settlement_periods(&period_id, EPOCH, ["D9", "W1", "M0"]).is_ok());
+
+        // Passing the wall-clock day is rejected
+        assert_eq!(
+            verify_settlement_periods(&period_id, EPOCH, ["D10", "W1", "M0"]).unwrap_err(),
+            VobleError::SettlementPeriodMismatch.into()
+        );
+
+        // Past the grace window the commit is late
+        assert_eq!(
+            score_deadline_rule(&period_id, MIDNIGHT + GRACE, EPOCH, GRACE),
+            ScoreDeadlineRule::Late
+        );
+        assert_eq!(
+            score_deadline_rule(&period_id, MIDNIGHT + GRACE - 1, EPOCH, GRACE),
+            ScoreDeadlineRule::GraceWindow
+        );
+    }
+
+    #[test]
+    fn test_game_bought_just_after_midnight() {
+        // Ticket bought at 00:00:30 of D10, committed a few minutes later
+        let period_id = get_current_period_id(PeriodType::Daily, MIDNIGHT + 30, EPOCH);
+        let committed_at = MIDNIGHT + 4 * 60;
+        assert_eq!(period_id, "D10");
+
+        assert_eq!(
+            score_deadline_rule(&period_id, committed_at, EPOCH, GRACE),
+            ScoreDeadlineRule::OnTime
+        );
+        assert!(verify_settlement_periods(&period_id, EPOCH, ["D10", "W1", "M0"]).is_ok());
+
+        // The previous day's board can't be targeted even inside its grace window
+        assert_eq!(
+            verify_settlement_periods(&period_id, EPOCH, ["D9", "W1", "M0"]).unwrap_err(),
+            VobleError::SettlementPeriodMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_week_boundary_follows_ticket_day() {
+        // D13 is the last day of W1; committing in W2 still settles into W1
+        let ids = settlement_period_ids("D13", EPOCH).unwrap();
+        assert_eq!(ids, ["D13".to_string(), "W1".to_string(), "M0".to_string()]);
+        assert_eq!(
+            verify_settlement_periods("D13", EPOCH, ["D13", "W2", "M0"]).unwrap_err(),
+            VobleError::SettlementPeriodMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_free_form_period_ids() {
+        assert!(settlement_period_ids("W3", EPOCH).is_none());
+        assert!(settlement_period_ids("legacy", EPOCH).is_none());
+
+        // Only the daily board is checked and there is no deadline
+        assert!(verify_settlement_periods("legacy", EPOCH, ["legacy", "W9", "M9"]).is_ok());
+        assert_eq!(
+            score_deadline_rule("legacy", i64::MAX, EPOCH, GRACE),
+            ScoreDeadlineRule::OnTime
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants::*;
-use crate::state::LetterResult;
+use crate::state::MultiplierWindow;
 
 /// Calculate the final score for a completed game
 ///
@@ -11,6 +11,8 @@ use crate::state::LetterResult;
 /// * `is_solved` - Whether the player successfully guessed the word
 /// * `guesses_used` - Number of guesses taken (1-7)
 /// * `time_ms` - Time taken to complete in milliseconds
+/// * `difficulty` - Word difficulty snapshotted on the session (scales the
+///   time bonus windows, see `calculate_time_bonus`)
 ///
 /// # Returns
 /// Total score (base + time bonus), or 0 if not solved
@@ -31,13 +33,16 @@ use crate::state::LetterResult;
 /// - Under 2 minutes: +150 points (quick!)
 /// - Under 5 minutes: +50 points (decent)
 /// - Over 5 minutes: +0 points
-pub fn calculate_final_score(is_solved: bool, guesses_used: u8, time_ms: u64) -> u32 {
+///
+/// These windows are for a neutral word (difficulty 3 or untagged); harder
+/// words get proportionally longer ones and easier words shorter ones.
+pub fn calculate_final_score(is_solved: bool, guesses_used: u8, time_ms: u64, difficulty: u8) -> u32 {
     if !is_solved {
         return 0; // No points for unsolved games
     }
 
     let base_score = calculate_base_score(guesses_used);
-    let time_bonus = calculate_time_bonus(time_ms);
+    let time_bonus = calculate_time_bonus(time_ms, difficulty);
 
     base_score + time_bonus
 }
@@ -66,76 +71,152 @@ fn calculate_base_score(guesses_used: u8) -> u32 {
 ///
 /// # Arguments
 /// * `time_ms` - Time taken in milliseconds
+/// * `difficulty` - Word difficulty; each `TIME_BONUS_TIER_*` threshold is
+///   scaled by `difficulty_factor_bps` (1.4x windows for the hardest words,
+///   unchanged for difficulty 3 and untagged sessions)
 ///
 /// # Returns
 /// Bonus points based on speed
-fn calculate_time_bonus(time_ms: u64) -> u32 {
-    if time_ms < TIME_BONUS_TIER_1 {
+fn calculate_time_bonus(time_ms: u64, difficulty: u8) -> u32 {
+    let factor = difficulty_factor_bps(difficulty) as u64;
+    let threshold = |tier_ms: u64| tier_ms * factor / SCORE_MULTIPLIER_NONE as u64;
+
+    if time_ms < threshold(TIME_BONUS_TIER_1) {
         BONUS_TIER_1 // 500 - Under 30 seconds (speed demon!)
-    } else if time_ms < TIME_BONUS_TIER_2 {
+    } else if time_ms < threshold(TIME_BONUS_TIER_2) {
         BONUS_TIER_2 // 300 - Under 1 minute (fast solver!)
-    } else if time_ms < TIME_BONUS_TIER_3 {
+    } else if time_ms < threshold(TIME_BONUS_TIER_3) {
         BONUS_TIER_3 // 150 - Under 2 minutes (quick!)
-    } else if time_ms < TIME_BONUS_TIER_4 {
+    } else if time_ms < threshold(TIME_BONUS_TIER_4) {
         BONUS_TIER_4 // 50 - Under 5 minutes (decent)
     } else {
         0 // No bonus for slow solvers
     }
 }
 
-/// Evaluate a guess against the target word (Wordle/Voble logic)
-///
-/// This implements the classic Wordle color-coding system:
-/// - Green (Correct): Letter is in the word and in the correct position
-/// - Yellow (Present): Letter is in the word but in wrong position
-/// - Gray (Absent): Letter is not in the word
+/// Apply a happy hour multiplier to a final score
 ///
 /// # Arguments
-/// * `guess` - The player's guessed word (must be uppercase)
-/// * `target` - The target word to compare against (uppercase)
+/// * `score` - Final score from `calculate_final_score`
+/// * `multiplier_bps` - Multiplier in basis points (10000 = 1x)
 ///
 /// # Returns
-/// Array of 6 LetterResult indicating the status of each letter
-///
-/// # Algorithm
-/// 1. First pass: Mark all exact matches (correct position) as Green
-/// 2. Second pass: For remaining letters, check if they exist elsewhere (Yellow)
-/// 3. Letters marked in previous passes are consumed and won't be reused
-///
-/// # Example
-/// ```
-/// Target: "CRANE"
-/// Guess:  "ANGER"
-/// Result: [Present, Correct, Absent, Correct, Correct]
-///         (A is in word but wrong pos, N is correct, G not in word, E & R correct)
-/// ```
-pub fn evaluate_guess(guess: &str, target: &str) -> [LetterResult; WORD_LENGTH] {
-    let mut result = [LetterResult::Absent; WORD_LENGTH];
-    let mut target_chars: Vec<char> = target.chars().collect();
-    let guess_chars: Vec<char> = guess.to_uppercase().chars().collect();
-
-    // First pass: Mark correct positions (Green)
-    for i in 0..WORD_LENGTH {
-        if guess_chars[i] == target_chars[i] {
-            result[i] = LetterResult::Correct;
-            target_chars[i] = '\0'; // Mark as used
-        }
+/// Scaled score. A multiplier of 0 (sessions stamped before happy hours
+/// existed) leaves the score unchanged, and anything above 3x is capped.
+pub fn apply_score_multiplier(score: u32, multiplier_bps: u16) -> u32 {
+    if multiplier_bps == 0 {
+        return score;
     }
 
-    // Second pass: Mark present letters in wrong positions (Yellow)
-    for i in 0..WORD_LENGTH {
-        if matches!(result[i], LetterResult::Absent) {
-            if let Some(pos) = target_chars
-                .iter()
-                .position(|&c| c == guess_chars[i] && c != '\0')
-            {
-                result[i] = LetterResult::Present;
-                target_chars[pos] = '\0'; // Mark as used
-            }
+    let multiplier = multiplier_bps.min(MAX_SCORE_MULTIPLIER_BPS) as u64;
+    ((score as u64 * multiplier) / SCORE_MULTIPLIER_NONE as u64) as u32
+}
+
+/// Score factor per word difficulty in basis points (index = difficulty - 1)
+///
+/// Difficulty 3 is neutral so untagged words keep their flat score.
+const DIFFICULTY_FACTOR_BPS: [u16; MAX_WORD_DIFFICULTY as usize] =
+    [8_000, 9_000, 10_000, 12_000, 14_000];
+
+/// Score factor for a word difficulty
+///
+/// # Returns
+/// The factor from `DIFFICULTY_FACTOR_BPS`, or 1x for 0 (sessions started
+/// before words were tagged) and anything out of range
+pub fn difficulty_factor_bps(difficulty: u8) -> u16 {
+    match difficulty {
+        MIN_WORD_DIFFICULTY..=MAX_WORD_DIFFICULTY => {
+            DIFFICULTY_FACTOR_BPS[(difficulty - MIN_WORD_DIFFICULTY) as usize]
         }
+        _ => SCORE_MULTIPLIER_NONE,
+    }
+}
+
+/// Scale a final score by the difficulty of the word it was played against
+///
+/// # Arguments
+/// * `score` - Final score from `calculate_final_score`
+/// * `difficulty` - Word difficulty snapshotted on the session (1-5)
+///
+/// # Returns
+/// `score * difficulty_factor_bps(difficulty) / 10000`
+pub fn apply_difficulty(score: u32, difficulty: u8) -> u32 {
+    let factor = difficulty_factor_bps(difficulty) as u64;
+    ((score as u64 * factor) / SCORE_MULTIPLIER_NONE as u64) as u32
+}
+
+/// Whether a game was solved with its very first guess
+pub fn is_hole_in_one(is_solved: bool, guesses_used: u8) -> bool {
+    is_solved && guesses_used == 1
+}
+
+/// Add the hole in one bonus to a first-guess solve
+///
+/// # Arguments
+/// * `score` - Final score after difficulty and happy hour multiplier
+/// * `is_solved` - Whether the player guessed the word
+/// * `guesses_used` - Number of guesses taken
+/// * `bonus` - Flat bonus snapshotted on the session (0 = off)
+///
+/// # Returns
+/// `score + bonus` for a hole in one, otherwise `score` unchanged. The bonus
+/// is added after the multipliers so it is the same for every word.
+pub fn apply_hole_in_one_bonus(score: u32, is_solved: bool, guesses_used: u8, bonus: u32) -> u32 {
+    if is_hole_in_one(is_solved, guesses_used) {
+        score.saturating_add(bonus.min(MAX_HOLE_IN_ONE_BONUS))
+    } else {
+        score
     }
+}
+
+/// Score a finished game with difficulty, multiplier and hole in one bonus applied
+///
+/// # Arguments
+/// * `is_solved` - Whether the player guessed the word
+/// * `guesses_used` - Number of guesses taken (1-7)
+/// * `time_ms` - Time taken to complete in milliseconds
+/// * `difficulty` - Word difficulty snapshotted on the session
+/// * `multiplier_bps` - Happy hour multiplier snapshotted on the session
+/// * `hole_in_one_bonus` - Hole in one bonus snapshotted on the session
+///
+/// # Returns
+/// The score `complete_session` records on the session
+pub fn score_game(
+    is_solved: bool,
+    guesses_used: u8,
+    time_ms: u64,
+    difficulty: u8,
+    multiplier_bps: u16,
+    hole_in_one_bonus: u32,
+) -> u32 {
+    let score = calculate_final_score(is_solved, guesses_used, time_ms, difficulty);
+    let score = apply_difficulty(score, difficulty);
+    let score = apply_score_multiplier(score, multiplier_bps);
+    apply_hole_in_one_bonus(score, is_solved, guesses_used, hole_in_one_bonus)
+}
 
-    result
+/// Find the happy hour multiplier active at a given time
+///
+/// # Arguments
+/// * `windows` - Happy hour windows configured for the period
+/// * `now` - Timestamp to check (ticket purchase time)
+///
+/// # Returns
+/// The multiplier of the window where `start <= now < end`, or 1x if none
+pub fn active_multiplier_bps(windows: &[MultiplierWindow], now: i64) -> u16 {
+    windows
+        .iter()
+        .find(|w| now >= w.start && now < w.end)
+        .map(|w| w.multiplier_bps)
+        .unwrap_or(SCORE_MULTIPLIER_NONE)
+}
+
+/// Check whether `[start, end)` overlaps any existing window
+///
+/// Back-to-back windows (one ending exactly when the next starts) do not
+/// overlap.
+pub fn window_overlaps(windows: &[MultiplierWindow], start: i64, end: i64) -> bool {
+    windows.iter().any(|w| start < w.end && w.start < end)
 }
 
 #[cfg(test)]
@@ -145,62 +226,145 @@ mod tests {
     #[test]
     fn test_calculate_final_score_solved() {
         // Perfect game: 1 guess in under 30 seconds
-        assert_eq!(calculate_final_score(true, 1, 25_000), 1500); // 1000 + 500
+        assert_eq!(calculate_final_score(true, 1, 25_000, 0), 1500); // 1000 + 500
 
         // Good game: 3 guesses in 45 seconds
-        assert_eq!(calculate_final_score(true, 3, 45_000), 900); // 600 + 300
+        assert_eq!(calculate_final_score(true, 3, 45_000, 0), 900); // 600 + 300
 
         // Slow game: 7 guesses in 10 minutes
-        assert_eq!(calculate_final_score(true, 7, 600_000), 100); // 100 + 0
+        assert_eq!(calculate_final_score(true, 7, 600_000, 0), 100); // 100 + 0
     }
 
     #[test]
     fn test_calculate_final_score_unsolved() {
-        assert_eq!(calculate_final_score(false, 7, 60_000), 0);
-        assert_eq!(calculate_final_score(false, 3, 30_000), 0);
+        assert_eq!(calculate_final_score(false, 7, 60_000, 0), 0);
+        assert_eq!(calculate_final_score(false, 3, 30_000, 5), 0);
+    }
+
+    #[test]
+    fn test_hard_word_widens_time_bonus_windows() {
+        // 35s: past the 30s tier 1 window of a common word, inside the 42s
+        // window of a hardest word
+        assert_eq!(calculate_time_bonus(35_000, DEFAULT_WORD_DIFFICULTY), BONUS_TIER_2);
+        assert_eq!(calculate_time_bonus(35_000, MAX_WORD_DIFFICULTY), BONUS_TIER_1);
+        assert_eq!(calculate_final_score(true, 3, 35_000, MAX_WORD_DIFFICULTY), 1100); // 600 + 500
+
+        // Easiest words get 24s for tier 1
+        assert_eq!(calculate_time_bonus(25_000, MIN_WORD_DIFFICULTY), BONUS_TIER_2);
+    }
+
+    #[test]
+    fn test_neutral_difficulty_keeps_flat_windows() {
+        for difficulty in [0, DEFAULT_WORD_DIFFICULTY] {
+            assert_eq!(calculate_time_bonus(TIME_BONUS_TIER_1 - 1, difficulty), BONUS_TIER_1);
+            assert_eq!(calculate_time_bonus(TIME_BONUS_TIER_1, difficulty), BONUS_TIER_2);
+            assert_eq!(calculate_time_bonus(TIME_BONUS_TIER_4 - 1, difficulty), BONUS_TIER_4);
+            assert_eq!(calculate_time_bonus(TIME_BONUS_TIER_4, difficulty), 0);
+        }
     }
 
     #[test]
-    fn test_evaluate_guess_all_correct() {
-        let result = evaluate_guess("CRANE", "CRANE");
-        assert!(result
-            .iter()
-            .all(|&r| matches!(r, LetterResult::Correct)));
+    fn test_apply_score_multiplier() {
+        assert_eq!(apply_score_multiplier(900, SCORE_MULTIPLIER_NONE), 900);
+        assert_eq!(apply_score_multiplier(900, 20_000), 1800); // 2x
+        assert_eq!(apply_score_multiplier(900, 15_000), 1350); // 1.5x
+        assert_eq!(apply_score_multiplier(900, 0), 900); // Legacy session
+        assert_eq!(apply_score_multiplier(900, 50_000), 2700); // Capped at 3x
+        assert_eq!(apply_score_multiplier(0, 30_000), 0); // Unsolved stays 0
     }
 
     #[test]
-    fn test_evaluate_guess_all_absent() {
-        let result = evaluate_guess("ABCDE", "FGHIJ");
-        assert!(result.iter().all(|&r| matches!(r, LetterResult::Absent)));
+    fn test_difficulty_tier_1() {
+        assert_eq!(apply_difficulty(1500, 1), 1200); // 0.8x
     }
 
     #[test]
-    fn test_evaluate_guess_mixed() {
-        let result = evaluate_guess("ANGER", "CRANE");
-        // A - Present (in word but wrong position)
-        // N - Correct (right position)
-        // G - Absent (not in word)
-        // E - Correct (right position)
-        // R - Correct (right position)
-        assert!(matches!(result[0], LetterResult::Present)); // A
-        assert!(matches!(result[1], LetterResult::Correct)); // N
-        assert!(matches!(result[2], LetterResult::Absent)); // G
-        assert!(matches!(result[3], LetterResult::Correct)); // E
-        assert!(matches!(result[4], LetterResult::Correct)); // R
+    fn test_difficulty_tier_2() {
+        assert_eq!(apply_difficulty(1500, 2), 1350); // 0.9x
     }
 
     #[test]
-    fn test_evaluate_guess_duplicate_letters() {
-        let result = evaluate_guess("SPEED", "ERASE");
-        // S - Correct
-        // P - Absent
-        // E - Present (one E is correct position, this one is wrong position)
-        // E - Correct
-        // D - Absent
-        assert!(matches!(result[0], LetterResult::Correct)); // S
-        assert!(matches!(result[1], LetterResult::Absent)); // P
-        assert!(matches!(result[2], LetterResult::Present)); // E
-        assert!(matches!(result[3], LetterResult::Correct)); // E
-        assert!(matches!(result[4], LetterResult::Absent)); // D
+    fn test_difficulty_tier_3() {
+        assert_eq!(apply_difficulty(1500, 3), 1500); // 1x
+    }
+
+    #[test]
+    fn test_difficulty_tier_4() {
+        assert_eq!(apply_difficulty(1500, 4), 1800); // 1.2x
+    }
+
+    #[test]
+    fn test_difficulty_tier_5() {
+        assert_eq!(apply_difficulty(1500, 5), 2100); // 1.4x
+    }
+
+    #[test]
+    fn test_difficulty_untagged_is_neutral() {
+        assert_eq!(apply_difficulty(1500, 0), 1500); // Legacy session
+        assert_eq!(apply_difficulty(1500, 6), 1500);
+        assert_eq!(apply_difficulty(0, 5), 0); // Unsolved stays 0
+    }
+
+    #[test]
+    fn test_difficulty_then_happy_hour() {
+        // 3 guesses in 45s on a hardest word during a 2x happy hour
+        let score = calculate_final_score(true, 3, 45_000, 5);
+        let score = apply_score_multiplier(apply_difficulty(score, 5), 20_000);
+        assert_eq!(score, 2520); // 900 * 1.4 * 2
+    }
+
+    #[test]
+    fn test_hole_in_one_only_on_first_guess_win() {
+        assert!(is_hole_in_one(true, 1));
+        assert!(!is_hole_in_one(true, 2));
+        assert!(!is_hole_in_one(false, 1)); // Wrong first guess on a 1-guess session
+        assert!(!is_hole_in_one(false, 0));
+    }
+
+    #[test]
+    fn test_hole_in_one_bonus() {
+        assert_eq!(apply_hole_in_one_bonus(1500, true, 1, 250), 1750);
+        assert_eq!(apply_hole_in_one_bonus(1100, true, 2, 250), 1100);
+        assert_eq!(apply_hole_in_one_bonus(0, false, 1, 250), 0);
+        assert_eq!(apply_hole_in_one_bonus(1500, true, 1, 0), 1500); // Off by default
+        assert_eq!(
+            apply_hole_in_one_bonus(1500, true, 1, u32::MAX),
+            1500 + MAX_HOLE_IN_ONE_BONUS
+        );
+    }
+
+    #[test]
+    fn test_active_multiplier_boundaries() {
+        let windows = [MultiplierWindow {
+            start: 1_000,
+            end: 2_000,
+            multiplier_bps: 20_000,
+        }];
+
+        assert_eq!(active_multiplier_bps(&windows, 999), SCORE_MULTIPLIER_NONE);
+        assert_eq!(active_multiplier_bps(&windows, 1_000), 20_000); // Start inclusive
+        assert_eq!(active_multiplier_bps(&windows, 1_999), 20_000);
+        assert_eq!(active_multiplier_bps(&windows, 2_000), SCORE_MULTIPLIER_NONE); // End exclusive
+    }
+
+    #[test]
+    fn test_active_multiplier_no_window() {
+        assert_eq!(active_multiplier_bps(&[], 1_500), SCORE_MULTIPLIER_NONE);
+    }
+
+    #[test]
+    fn test_window_overlaps() {
+        let windows = [MultiplierWindow {
+            start: 1_000,
+            end: 2_000,
+            multiplier_bps: 20_000,
+        }];
+
+        assert!(window_overlaps(&windows, 1_500, 2_500));
+        assert!(window_overlaps(&windows, 500, 1_001));
+        assert!(window_overlaps(&windows, 1_200, 1_800));
+        assert!(!window_overlaps(&windows, 2_000, 3_000)); // Back-to-back
+        assert!(!window_overlaps(&windows, 0, 1_000));
+        assert!(!window_overlaps(&[], 0, 1_000));
     }
 }
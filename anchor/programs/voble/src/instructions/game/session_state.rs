@@ -0,0 +1,262 @@
+use crate::errors::VobleError;
+use crate::state::{SessionAccount, SessionState, UserProfile};
+use anchor_lang::prelude::*;
+
+/// Check whether a session may move from one lifecycle state to another
+///
+/// # Allowed Transitions
+/// - **Idle → InProgress**: first game on a fresh (or legacy) session
+/// - **Settled → InProgress**: next game after the previous one settled
+/// - **InProgress → InProgress**: abandoned game replaced by a new period's game
+/// - **InProgress → CompletedUnsettled**: last guess submitted on the ER
+/// - **CompletedUnsettled → Settled**: settlement commit issued
+/// - **InProgress → Abandoned**: a stuck session was force-closed
+/// - **Abandoned → InProgress**: next game after a force-close
+///
+/// Everything else is rejected. In particular a completed game cannot be
+/// reset before it has been settled, so its score is never lost.
+pub fn can_transition(from: SessionState, to: SessionState) -> bool {
+    matches!(
+        (from, to),
+        (SessionState::Idle, SessionState::InProgress)
+            | (SessionState::Settled, SessionState::InProgress)
+            | (SessionState::InProgress, SessionState::InProgress)
+            | (SessionState::InProgress, SessionState::CompletedUnsettled)
+            | (SessionState::CompletedUnsettled, SessionState::Settled)
+            | (SessionState::InProgress, SessionState::Abandoned)
+            | (SessionState::Abandoned, SessionState::InProgress)
+    )
+}
+
+/// Move a session to a new lifecycle state
+///
+/// # Arguments
+/// * `session` - The session to update
+/// * `to` - The target state
+///
+/// # Returns
+/// `Ok(())` if the transition is allowed, `InvalidSessionState` otherwise
+///
+/// # Notes
+/// Also stamps the current layout version, so legacy sessions are upgraded
+/// the first time they go through a transition.
+pub fn transition(session: &mut SessionAccount, to: SessionState) -> Result<()> {
+    let from = session.current_state();
+
+    require!(can_transition(from, to), VobleError::InvalidSessionState);
+
+    msg!("🔀 Session state: {:?} -> {:?}", from, to);
+
+    session.state = to;
+    session.version = crate::constants::SESSION_ACCOUNT_VERSION;
+
+    Ok(())
+}
+
+/// Mark a running game abandoned so the session can start the next one
+///
+/// # Returns
+/// The state the session was in before the call
+///
+/// # Notes
+/// - An in-progress game becomes Abandoned and never reaches the
+///   leaderboard or profile stats
+/// - A finished game (CompletedUnsettled) is left for settlement, so its
+///   score is never lost
+/// - Idle, settled and already abandoned sessions are left unchanged
+pub fn abandon(session: &mut SessionAccount) -> Result<SessionState> {
+    let previous_state = session.current_state();
+
+    if previous_state == SessionState::InProgress {
+        transition(session, SessionState::Abandoned)?;
+        session.completed = true;
+    }
+
+    Ok(previous_state)
+}
+
+/// Require the session to be in a given state
+pub fn require_state(session: &SessionAccount, expected: SessionState) -> Result<()> {
+    require!(
+        session.current_state() == expected,
+        VobleError::InvalidSessionState
+    );
+    Ok(())
+}
+
+/// Require the signer to be allowed to play on this profile's session
+///
+/// # Arguments
+/// * `profile` - Profile of the session's player
+/// * `signer` - The transaction signer
+///
+/// # Notes
+/// Accepts the player's wallet, or the ephemeral session signer the player
+/// registered when buying the ticket (used to sign ER transactions without a
+/// wallet prompt per keystroke). Anyone else gets `Unauthorized`.
+pub fn require_session_authority(profile: &UserProfile, signer: &Pubkey) -> Result<()> {
+    let is_player = *signer == profile.player;
+    let is_session_signer =
+        profile.session_signer != Pubkey::default() && *signer == profile.session_signer;
+
+    require!(is_player || is_session_signer, VobleError::Unauthorized);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{PROGRAM_VERSION, SESSION_ACCOUNT_VERSION};
+
+    fn create_test_session(version: u8, state: SessionState) -> SessionAccount {
+        let mut session = SessionAccount::default_for_test(Pubkey::new_unique());
+        session.version = version;
+        session.state = state;
+        session
+    }
+
+    fn create_test_profile(player: Pubkey, session_signer: Pubkey) -> UserProfile {
+        let mut profile = UserProfile::default_for_test(player);
+        profile.session_signer = session_signer;
+        profile
+    }
+
+    #[test]
+    fn test_legacy_session_reads_as_idle() {
+        let session = create_test_session(0, SessionState::CompletedUnsettled);
+        assert_eq!(session.current_state(), SessionState::Idle);
+    }
+
+    #[test]
+    fn test_session_round_trips_program_version() {
+        let mut session = create_test_session(SESSION_ACCOUNT_VERSION, SessionState::InProgress);
+        session.program_version = PROGRAM_VERSION;
+
+        let mut data = Vec::new();
+        session.try_serialize(&mut data).unwrap();
+
+        // After the deadline rule, followed by the transcript hash (32),
+        // last action slots (24) and mulligan flag (1)
+        let end = data.len() - 57;
+        assert_eq!(data[end - 2..end], PROGRAM_VERSION.to_le_bytes());
+        let decoded = SessionAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(decoded.program_version, PROGRAM_VERSION);
+        assert_eq!(decoded.current_state(), SessionState::InProgress);
+    }
+
+    #[test]
+    fn test_full_lifecycle() {
+        let mut session = create_test_session(SESSION_ACCOUNT_VERSION, SessionState::Idle);
+
+        assert!(transition(&mut session, SessionState::InProgress).is_ok());
+        assert!(transition(&mut session, SessionState::CompletedUnsettled).is_ok());
+        assert!(transition(&mut session, SessionState::Settled).is_ok());
+        assert!(transition(&mut session, SessionState::InProgress).is_ok());
+
+        assert_eq!(session.current_state(), SessionState::InProgress);
+    }
+
+    #[test]
+    fn test_legacy_session_upgraded_on_start() {
+        let mut session = create_test_session(0, SessionState::Idle);
+
+        assert!(transition(&mut session, SessionState::InProgress).is_ok());
+        assert_eq!(session.version, SESSION_ACCOUNT_VERSION);
+        assert_eq!(session.current_state(), SessionState::InProgress);
+    }
+
+    #[test]
+    fn test_abandoned_game_can_restart() {
+        assert!(can_transition(SessionState::InProgress, SessionState::InProgress));
+    }
+
+    #[test]
+    fn test_force_closed_game_can_restart() {
+        let mut session = create_test_session(SESSION_ACCOUNT_VERSION, SessionState::InProgress);
+
+        assert!(transition(&mut session, SessionState::Abandoned).is_ok());
+        assert!(transition(&mut session, SessionState::InProgress).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_transitions() {
+        // Cannot reset a completed game before settling it
+        assert!(!can_transition(SessionState::CompletedUnsettled, SessionState::InProgress));
+        // Cannot complete or settle a game that never started
+        assert!(!can_transition(SessionState::Idle, SessionState::CompletedUnsettled));
+        assert!(!can_transition(SessionState::Idle, SessionState::Settled));
+        // Cannot settle a game still in progress
+        assert!(!can_transition(SessionState::InProgress, SessionState::Settled));
+        // Cannot settle twice
+        assert!(!can_transition(SessionState::Settled, SessionState::Settled));
+        // Nothing goes back to Idle
+        assert!(!can_transition(SessionState::Settled, SessionState::Idle));
+        assert!(!can_transition(SessionState::InProgress, SessionState::Idle));
+        // Settled or idle sessions are never stuck, so never abandoned
+        assert!(!can_transition(SessionState::Settled, SessionState::Abandoned));
+        assert!(!can_transition(SessionState::Idle, SessionState::Abandoned));
+        // A finished game is settled, never abandoned
+        assert!(!can_transition(SessionState::CompletedUnsettled, SessionState::Abandoned));
+        // An abandoned game is never settled
+        assert!(!can_transition(SessionState::Abandoned, SessionState::Settled));
+    }
+
+    #[test]
+    fn test_require_state() {
+        let session = create_test_session(SESSION_ACCOUNT_VERSION, SessionState::InProgress);
+
+        assert!(require_state(&session, SessionState::InProgress).is_ok());
+        assert!(require_state(&session, SessionState::Settled).is_err());
+    }
+
+    #[test]
+    fn test_player_and_session_signer_authorized() {
+        let player = Pubkey::new_unique();
+        let session_signer = Pubkey::new_unique();
+        let profile = create_test_profile(player, session_signer);
+
+        assert!(require_session_authority(&profile, &player).is_ok());
+        assert!(require_session_authority(&profile, &session_signer).is_ok());
+    }
+
+    #[test]
+    fn test_stranger_rejected() {
+        let profile = create_test_profile(Pubkey::new_unique(), Pubkey::new_unique());
+        let stranger = Pubkey::new_unique();
+
+        assert_eq!(
+            require_session_authority(&profile, &stranger).unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+
+    #[test]
+    fn test_new_period_clears_previous_play() {
+        let mut profile = create_test_profile(Pubkey::new_unique(), Pubkey::default());
+        profile.last_paid_period = "D1".to_string();
+        profile.last_played_period = "D1".to_string();
+        profile.has_played_this_period = true;
+        profile.plays_this_period = 1;
+
+        // Same period: tracking is kept
+        profile.ensure_period("D1");
+        assert!(profile.has_played_this_period);
+        assert_eq!(profile.plays_this_period, 1);
+
+        // Next period: the player is free to buy a ticket again
+        profile.ensure_period("D2");
+        assert!(!profile.has_played_this_period);
+        assert_eq!(profile.plays_this_period, 0);
+        assert_ne!(profile.last_played_period, "D2");
+    }
+
+    #[test]
+    fn test_unset_session_signer_not_a_wildcard() {
+        let profile = create_test_profile(Pubkey::new_unique(), Pubkey::default());
+
+        assert_eq!(
+            require_session_authority(&profile, &Pubkey::default()).unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+}
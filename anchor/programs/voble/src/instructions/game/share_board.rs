@@ -0,0 +1,105 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Emit a spectator-safe version of a finished board
+///
+/// Mirrors Wordle's emoji-grid share: only the colour results of each guess
+/// are emitted, never the guessed letters or the target word, so a player
+/// can share their result without spoiling the word for anyone who has not
+/// played yet.
+///
+/// # Arguments
+/// * `ctx` - The context containing the session account
+///
+/// # Validation
+/// - Game must be completed (solved or out of guesses)
+///
+/// # Event
+/// Emits `ShareableBoard` with one `[LetterResult; 6]` row per guess used,
+/// plus `guesses_used` and `is_solved`.
+pub fn get_shareable_board(ctx: Context<GetShareableBoard>) -> Result<()> {
+    let session = &ctx.accounts.session;
+
+    // ========== VALIDATION ==========
+    require!(session.completed, VobleError::InvalidSessionState);
+
+    let board = build_shareable_board(session)?;
+
+    msg!("🟩 Shareable board for {}", session.player);
+    msg!("   Rows: {}", board.len());
+    msg!("   Solved: {}", session.is_solved);
+
+    emit!(ShareableBoard {
+        player: session.player,
+        period_id: session.period_id.clone(),
+        game_epoch: session.game_epoch,
+        board,
+        guesses_used: session.guesses_used,
+        is_solved: session.is_solved,
+    });
+
+    Ok(())
+}
+
+/// Strip a session's guesses down to their colour results
+///
+/// # Arguments
+/// * `session` - The session to derive the board from
+///
+/// # Returns
+/// One result row per submitted guess, in guess order, re-derived from the
+/// session's `word_index`
+pub fn build_shareable_board(session: &SessionAccount) -> Result<Vec<[LetterResult; WORD_LENGTH]>> {
+    session
+        .guesses
+        .iter()
+        .take(session.guesses_used as usize)
+        .flatten()
+        .map(|guess| guess.results(session.word_index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::words::{evaluate_guess, get_word_count};
+
+    #[test]
+    fn test_build_shareable_board_hides_letters() {
+        let mut guesses: [Option<GuessData>; 7] = Default::default();
+        guesses[0] = Some(GuessData::new("ANCHOR").unwrap());
+        guesses[1] = Some(GuessData::new("SOLANA").unwrap());
+
+        let mut session = SessionAccount::default_for_test(Pubkey::new_unique());
+        session.word_index = 18; // SOLANA
+        session.target_word = "SOLANA".to_string();
+        session.guesses = guesses;
+        session.is_solved = true;
+        session.guesses_used = 2;
+        session.completed = true;
+        session.period_id = "D1".to_string();
+        session.version = SESSION_ACCOUNT_VERSION;
+        session.game_epoch = 1;
+        session.state = SessionState::CompletedUnsettled;
+        session.score_multiplier_bps = SCORE_MULTIPLIER_NONE;
+        session.commit_frequency_ms = DEFAULT_COMMIT_FREQUENCY_MS;
+        session.bump = 255;
+
+        let board = build_shareable_board(&session).unwrap();
+
+        assert_eq!(board.len(), 2);
+        assert_eq!(board[0], evaluate_guess("ANCHOR", "SOLANA"));
+        assert_eq!(board[1], [LetterResult::Correct; 6]);
+    }
+
+    #[test]
+    fn test_guess_data_results_derived_from_word_index() {
+        let guess = GuessData::new("ROCKET").unwrap();
+
+        assert_eq!(guess.word(), "ROCKET");
+        assert_eq!(guess.results(17).unwrap(), [LetterResult::Correct; 6]);
+        assert_eq!(guess.results(3).unwrap(), evaluate_guess("ROCKET", "DRAGON"));
+        assert!(guess.results(get_word_count() as u32).is_err());
+        assert!(GuessData::new("SHORT").is_err());
+    }
+}
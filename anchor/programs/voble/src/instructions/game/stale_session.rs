@@ -0,0 +1,485 @@
+use crate::instructions::game::{
+    release_profile_slot, release_slot_call_handler, session_state, settlement_call_handlers,
+};
+use crate::state::{SessionAccount, SessionState, UserProfile};
+use crate::{contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::cpi::DELEGATION_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::{
+    CommitAndUndelegate, CommitType, MagicAction, MagicInstructionBuilder, UndelegateType,
+};
+
+/// Undelegate a session that was left on the ER past the staleness window
+///
+/// If a client crashes after `delegate_session`, the session stays owned by
+/// the delegation program and the player cannot start the next game. Once
+/// the last game has been running longer than the configured window, anyone
+/// may close it the same way `force_undelegate_session` does.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, cranker, player, session,
+///   and the leaderboards and profile a finished game settles to
+/// * `daily_period_id` / `weekly_period_id` / `monthly_period_id` - Periods a
+///   finished game settles to, as for `commit_and_update_stats`
+///
+/// # Validation
+/// - The session's current game must have started at least
+///   `session_stale_after_secs` ago (`vrf_request_timestamp`, stamped by
+///   `reset_session`)
+///
+/// # Notes
+/// - Runs on the ER like every other undelegation: the session is owned by
+///   the delegation program on the base layer, so only the ER validator can
+///   write the abandoned state and hand the account back. When the ER is
+///   unreachable, use `recover_stale_session` on the base layer instead
+/// - An in-progress game is marked Abandoned, counts for nothing, and the
+///   session can start a new game right away
+/// - A finished but unsettled game is settled on the way out, with the same
+///   handler `commit_and_update_stats` runs
+/// - `release_session_slot` frees the player's active session slot once the
+///   session is back on the base layer
+pub fn force_undelegate_stale_session(
+    ctx: Context<ForceUndelegateStaleSession>,
+    daily_period_id: String,
+    weekly_period_id: String,
+    monthly_period_id: String,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stale_after = ctx.accounts.global_config.session_stale_after();
+    let session = &mut ctx.accounts.session;
+    let started_at = session.vrf_request_timestamp;
+
+    // ========== VALIDATION ==========
+    require!(
+        is_session_stale(started_at, now, stale_after),
+        VobleError::SessionNotStale
+    );
+
+    msg!("⌛ Undelegating stale session for {}", session.player);
+    msg!(
+        "   Started: {} ({}s ago, window {}s)",
+        started_at,
+        now.saturating_sub(started_at),
+        stale_after
+    );
+
+    // ========== MARK ABANDONED ==========
+    let previous_state = session_state::abandon(session)?;
+    if previous_state != session.current_state() {
+        session.exit(&crate::ID)?;
+    }
+
+    emit!(StaleSessionUndelegated {
+        player: session.player,
+        cranker: ctx.accounts.cranker.key(),
+        period_id: session.period_id.clone(),
+        game_epoch: session.game_epoch,
+        previous_state,
+        started_at,
+    });
+
+    // ========== SETTLE AND UNDELEGATE ==========
+    let mut call_handlers = Vec::with_capacity(3);
+    if previous_state == SessionState::CompletedUnsettled {
+        call_handlers.extend(settlement_call_handlers(
+            &mut ctx.accounts.session,
+            &ctx.accounts.global_config,
+            [
+                daily_period_id.as_str(),
+                weekly_period_id.as_str(),
+                monthly_period_id.as_str(),
+            ],
+            [
+                ctx.accounts.daily_leaderboard.key(),
+                ctx.accounts.weekly_leaderboard.key(),
+                ctx.accounts.monthly_leaderboard.key(),
+            ],
+            ctx.accounts.user_profile.key(),
+            &ctx.accounts.cranker,
+        )?);
+    }
+    call_handlers.push(release_slot_call_handler(
+        &mut ctx.accounts.session,
+        ctx.accounts.global_config.handler_escrow_index,
+        &ctx.accounts.cranker,
+    )?);
+
+    MagicInstructionBuilder {
+        payer: ctx.accounts.cranker.to_account_info(),
+        magic_context: ctx.accounts.magic_context.to_account_info(),
+        magic_program: ctx.accounts.magic_program.to_account_info(),
+        magic_action: MagicAction::CommitAndUndelegate(CommitAndUndelegate {
+            commit_type: CommitType::WithHandler {
+                commited_accounts: vec![ctx.accounts.session.to_account_info()],
+                call_handlers,
+            },
+            undelegate_type: UndelegateType::Standalone,
+        }),
+    }
+    .build_and_invoke()?;
+
+    msg!("✅ Stale session undelegated");
+
+    Ok(())
+}
+
+/// Retire a session stuck on an unreachable ER so the player can play again
+///
+/// `force_undelegate_stale_session` needs the ER validator. When it is
+/// unreachable, the session stays owned by the delegation program on the
+/// base layer and nothing can write it. This base-layer fallback leaves it
+/// there and moves the player to a new session generation instead: the next
+/// `initialize_session` creates a fresh session PDA
+/// (`SessionAccount::nonce_seed`) that can be delegated and played.
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, cranker, player, profile
+///   and the stuck session
+///
+/// # Validation
+/// - The session must be owned by the delegation program (`SessionNotStuck`)
+/// - Its last committed copy must belong to the player (`Unauthorized`)
+/// - The session must have been delegated, and the latest ticket bought, at
+///   least `session_stale_after_secs` ago (`SessionNotStale`). Both are
+///   base-layer stamps on the profile: a game started on the ER only reaches
+///   the committed copy with the next commit, so its timestamp there can be
+///   arbitrarily old
+///
+/// # Notes
+/// - Permissionless, like `force_undelegate_stale_session`
+/// - The stuck game expires as a loss, as does a ticket the ER could have
+///   started a game with (see `retire_stuck_session`)
+/// - The retired session can't start new games (`SessionRetired`); if the ER
+///   comes back it can still be undelegated
+/// - The player's active session slot is freed; delegating the new session
+///   takes one again
+pub fn recover_stale_session(ctx: Context<RecoverStaleSession>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let session_info = ctx.accounts.session.to_account_info();
+
+    // ========== VALIDATION ==========
+    require_keys_eq!(*session_info.owner, DELEGATION_PROGRAM_ID, VobleError::SessionNotStuck);
+
+    let stuck = {
+        let data = session_info.try_borrow_data()?;
+        SessionAccount::try_deserialize(&mut &data[..])?
+    };
+    require_keys_eq!(stuck.player, ctx.accounts.player.key(), VobleError::Unauthorized);
+
+    let profile = &mut ctx.accounts.user_profile;
+    let stale_after = ctx.accounts.global_config.session_stale_after();
+    require!(
+        is_session_stale(last_base_layer_start(profile), now, stale_after),
+        VobleError::SessionNotStale
+    );
+
+    // ========== RETIRE ==========
+    let previous_nonce = profile.session_nonce;
+    let forfeited = retire_stuck_session(profile, &stuck);
+    release_profile_slot(&ctx.accounts.session_counter, profile)?;
+
+    emit!(StaleSessionRecovered {
+        player: stuck.player,
+        cranker: ctx.accounts.cranker.key(),
+        retired_session: session_info.key(),
+        session_nonce: profile.session_nonce,
+        game_epoch: if forfeited { profile.forfeited_epoch } else { stuck.game_epoch },
+        previous_state: stuck.current_state(),
+        forfeited,
+    });
+
+    msg!("🛟 Session {} retired for {}", previous_nonce, stuck.player);
+    msg!("   New session generation: {}", profile.session_nonce);
+    if forfeited {
+        msg!("   Game epoch {} expired as a loss", profile.forfeited_epoch);
+    }
+
+    Ok(())
+}
+
+/// Move a player off a session stuck on the ER
+///
+/// # Returns
+/// `true` if the stuck session may hold the player's latest game, which then
+/// expires as a loss
+///
+/// # Notes
+/// - The profile moves to the next session generation
+/// - The latest ticket is lost with the session if its committed copy shows
+///   the game running or finished but never settled, or if the ticket reached
+///   the ER unsettled (`started_epoch`): the session was delegated after it
+///   was bought, or it was bought while the session was delegated. The
+///   committed copy lags the ER, so a game it doesn't show may still have
+///   started there
+/// - A lost ticket's epoch is recorded as `forfeited_epoch`, so
+///   `reset_session` won't replay it on the new session, and the win streak
+///   resets
+/// - A ticket that never reached the stuck session's ER is kept and can be
+///   played on the new session
+pub fn retire_stuck_session(profile: &mut UserProfile, stuck: &SessionAccount) -> bool {
+    profile.session_nonce = profile.session_nonce.saturating_add(1);
+    profile.delegated_at = 0;
+
+    let holds_latest_game = stuck.game_epoch == profile.game_epoch
+        && matches!(
+            stuck.current_state(),
+            SessionState::InProgress | SessionState::CompletedUnsettled
+        );
+    let latest_ticket_reached_er = profile.started_epoch == profile.game_epoch
+        && profile.game_epoch > profile.last_settled_epoch;
+    if !holds_latest_game && !latest_ticket_reached_er {
+        return false;
+    }
+
+    profile.forfeited_epoch = profile.game_epoch;
+    profile.current_streak = 0;
+    true
+}
+
+/// Latest base-layer time the player's delegated session could have started
+/// a game: its delegation or the latest ticket, whichever came last
+pub fn last_base_layer_start(profile: &UserProfile) -> i64 {
+    profile.delegated_at.max(profile.last_ticket_at)
+}
+
+/// Whether a game started at `started_at` has outlived the staleness window
+pub fn is_session_stale(started_at: i64, now: i64, stale_after: i64) -> bool {
+    now >= started_at.saturating_add(stale_after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_SESSION_STALE_AFTER, SESSION_ACCOUNT_VERSION};
+    use crate::instructions::game::{check_slot_release, require_ticket_unused};
+    use crate::utils::pda::{derive_session_pda, derive_session_pda_with_nonce};
+
+    const STARTED_AT: i64 = 1_700_000_000;
+
+    fn in_progress_session() -> SessionAccount {
+        let mut session = SessionAccount::default_for_test(Pubkey::new_unique());
+        session.guesses_used = 2;
+        session.period_id = "D700".to_string();
+        session.vrf_request_timestamp = STARTED_AT;
+        session.version = SESSION_ACCOUNT_VERSION;
+        session.game_epoch = 4;
+        session.state = SessionState::InProgress;
+        session.input_guess_index = 2;
+        session
+    }
+
+    #[test]
+    fn test_staleness_window() {
+        let window = DEFAULT_SESSION_STALE_AFTER;
+
+        assert!(!is_session_stale(STARTED_AT, STARTED_AT, window));
+        assert!(!is_session_stale(STARTED_AT, STARTED_AT + window - 1, window));
+        assert!(is_session_stale(STARTED_AT, STARTED_AT + window, window));
+        // A session that never started a game is always recoverable
+        assert!(is_session_stale(0, STARTED_AT, window));
+    }
+
+    #[test]
+    fn test_crashed_client_recovers_next_game() {
+        let mut session = in_progress_session();
+        let now = STARTED_AT + DEFAULT_SESSION_STALE_AFTER;
+        assert!(is_session_stale(session.vrf_request_timestamp, now, DEFAULT_SESSION_STALE_AFTER));
+
+        let previous = session_state::abandon(&mut session).unwrap();
+
+        assert_eq!(previous, SessionState::InProgress);
+        assert_eq!(session.current_state(), SessionState::Abandoned);
+        assert!(session.completed);
+        // The abandoned game is never settled, but the next one can start
+        assert!(!session_state::can_transition(session.current_state(), SessionState::Settled));
+        assert!(session_state::transition(&mut session, SessionState::InProgress).is_ok());
+    }
+
+    #[test]
+    fn test_unsettled_game_kept_for_settlement() {
+        let mut session = in_progress_session();
+        session.state = SessionState::CompletedUnsettled;
+
+        let previous = session_state::abandon(&mut session).unwrap();
+
+        assert_eq!(previous, SessionState::CompletedUnsettled);
+        assert_eq!(session.current_state(), SessionState::CompletedUnsettled);
+        assert!(session_state::transition(&mut session, SessionState::Settled).is_ok());
+    }
+
+    /// Profile whose latest ticket was bought, then delegated, before `session` started
+    fn profile_for(session: &SessionAccount) -> UserProfile {
+        let mut profile = UserProfile::default_for_test(session.player);
+        profile.game_epoch = session.game_epoch;
+        profile.last_settled_epoch = session.game_epoch - 1;
+        profile.last_paid_period = session.period_id.clone();
+        profile.current_streak = 3;
+        profile.last_ticket_at = STARTED_AT - 60;
+        profile.delegated_at = STARTED_AT - 30;
+        profile.started_epoch = session.game_epoch;
+        profile
+    }
+
+    #[test]
+    fn test_unreachable_er_lockout_recovery() {
+        let stuck = in_progress_session();
+        let mut profile = profile_for(&stuck);
+        let now = profile.delegated_at + DEFAULT_SESSION_STALE_AFTER;
+        assert!(is_session_stale(last_base_layer_start(&profile), now, DEFAULT_SESSION_STALE_AFTER));
+
+        // The stuck game expires as a loss and the player moves to a new session
+        assert!(retire_stuck_session(&mut profile, &stuck));
+        assert_eq!(profile.session_nonce, 1);
+        assert_eq!(profile.forfeited_epoch, stuck.game_epoch);
+        assert_eq!(profile.current_streak, 0);
+
+        let (stuck_key, _) = derive_session_pda_with_nonce(&stuck.player, 0, &crate::ID);
+        let (new_key, _) =
+            derive_session_pda_with_nonce(&stuck.player, profile.session_nonce, &crate::ID);
+        assert_ne!(new_key, stuck_key);
+
+        // The forfeited ticket can't be replayed; the next ticket can be played
+        assert_eq!(profile.game_epoch, profile.forfeited_epoch);
+        profile.game_epoch += 1;
+        assert_ne!(profile.game_epoch, profile.forfeited_epoch);
+    }
+
+    #[test]
+    fn test_recovery_keeps_unused_ticket() {
+        // The ticket was bought after the stuck session's last (settled) game,
+        // and the session's delegation predates the profile's stamps, so the
+        // ticket never reached its ER
+        let mut stuck = in_progress_session();
+        stuck.state = SessionState::Settled;
+        let mut profile = profile_for(&stuck);
+        profile.last_settled_epoch = stuck.game_epoch;
+        profile.game_epoch = stuck.game_epoch + 1;
+        profile.delegated_at = 0;
+        profile.started_epoch = 0;
+
+        assert!(!retire_stuck_session(&mut profile, &stuck));
+        assert_eq!(profile.session_nonce, 1);
+        assert_eq!(profile.forfeited_epoch, 0);
+        assert_eq!(profile.current_streak, 3);
+    }
+
+    #[test]
+    fn test_game_started_on_er_cannot_be_replayed() {
+        // Ticket bought and the session delegated; the game starts on the ER,
+        // but the committed copy still shows the previous, settled game
+        let mut stuck = in_progress_session();
+        stuck.state = SessionState::Settled;
+        stuck.vrf_request_timestamp = 0;
+        let mut profile = profile_for(&stuck);
+        profile.game_epoch = stuck.game_epoch + 1;
+        profile.last_settled_epoch = stuck.game_epoch;
+        profile.last_ticket_at = STARTED_AT;
+        profile.delegated_at = STARTED_AT + 10;
+        profile.started_epoch = profile.game_epoch;
+        let window = DEFAULT_SESSION_STALE_AFTER;
+
+        // The stale clock runs from the delegation, not the committed copy
+        let started_on_er = STARTED_AT + 20;
+        assert!(is_session_stale(stuck.vrf_request_timestamp, started_on_er, window));
+        assert!(!is_session_stale(last_base_layer_start(&profile), started_on_er, window));
+
+        // Once stale, the ticket that reached the ER is forfeited with the session
+        let now = profile.delegated_at + window;
+        assert!(is_session_stale(last_base_layer_start(&profile), now, window));
+        assert!(retire_stuck_session(&mut profile, &stuck));
+        assert_eq!(profile.forfeited_epoch, profile.game_epoch);
+        assert_eq!(profile.current_streak, 0);
+        assert_eq!(profile.delegated_at, 0);
+
+        // The new session can't replay it
+        let mut replay = SessionAccount::default_for_test(stuck.player);
+        replay.session_nonce = profile.session_nonce;
+        assert_eq!(
+            require_ticket_unused(&replay, &profile).unwrap_err(),
+            VobleError::TicketAlreadyUsed.into()
+        );
+
+        // The next ticket plays normally
+        profile.game_epoch += 1;
+        assert!(require_ticket_unused(&replay, &profile).is_ok());
+    }
+
+    #[test]
+    fn test_ticket_bought_on_er_forfeited_with_session() {
+        // The session stays delegated between games; a ticket bought since
+        // can start a game on the ER without the base layer knowing
+        let mut stuck = in_progress_session();
+        stuck.state = SessionState::Settled;
+        let mut profile = profile_for(&stuck);
+        profile.last_settled_epoch = stuck.game_epoch;
+        profile.game_epoch = stuck.game_epoch + 1;
+        profile.last_ticket_at = STARTED_AT + 600;
+        profile.started_epoch = profile.game_epoch;
+
+        // A fresh ticket restarts the stale clock
+        let window = DEFAULT_SESSION_STALE_AFTER;
+        assert!(!is_session_stale(last_base_layer_start(&profile), profile.delegated_at + window, window));
+
+        assert!(retire_stuck_session(&mut profile, &stuck));
+        assert_eq!(profile.forfeited_epoch, profile.game_epoch);
+    }
+
+    #[test]
+    fn test_same_session_cannot_replay_ticket() {
+        let session = in_progress_session();
+        let mut profile = profile_for(&session);
+
+        assert_eq!(
+            require_ticket_unused(&session, &profile).unwrap_err(),
+            VobleError::TicketAlreadyUsed.into()
+        );
+        profile.game_epoch += 1;
+        assert!(require_ticket_unused(&session, &profile).is_ok());
+    }
+
+    #[test]
+    fn test_retired_session_cannot_release_new_slot() {
+        let mut stuck = in_progress_session();
+        let mut profile = profile_for(&stuck);
+        profile.holds_session_slot = true;
+        retire_stuck_session(&mut profile, &stuck);
+
+        // The ER comes back and undelegates the retired session after the
+        // player already took a slot for the new one
+        stuck.delegated = false;
+        assert_eq!(
+            check_slot_release(&stuck, profile.session_nonce).unwrap_err(),
+            VobleError::SessionRetired.into()
+        );
+
+        // The live generation releases normally once it left the ER
+        let mut live = in_progress_session();
+        live.session_nonce = profile.session_nonce;
+        live.delegated = true;
+        assert_eq!(
+            check_slot_release(&live, profile.session_nonce).unwrap_err(),
+            VobleError::InvalidSessionState.into()
+        );
+        live.delegated = false;
+        assert!(check_slot_release(&live, profile.session_nonce).is_ok());
+    }
+
+    #[test]
+    fn test_first_generation_keeps_original_pda() {
+        let player = Pubkey::new_unique();
+        assert_eq!(
+            derive_session_pda_with_nonce(&player, 0, &crate::ID),
+            derive_session_pda(&player, &crate::ID)
+        );
+    }
+
+    #[test]
+    fn test_finished_session_left_unchanged() {
+        let mut session = in_progress_session();
+        session.state = SessionState::Settled;
+
+        assert_eq!(session_state::abandon(&mut session).unwrap(), SessionState::Settled);
+        assert_eq!(session.current_state(), SessionState::Settled);
+        assert!(!session.completed);
+    }
+}
@@ -1,13 +1,30 @@
 use crate::{constants::*, contexts::*, errors::VobleError, events::*};
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+use anchor_lang::Discriminator;
 use ephemeral_rollups_sdk::cpi::DelegateConfig;
-use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
-use ephemeral_rollups_sdk::ephem::{MagicInstructionBuilder, MagicAction, CallHandler, CommitType};
+use ephemeral_rollups_sdk::ephem::{
+    CallHandler, CommitAndUndelegate, CommitType, MagicAction, MagicInstructionBuilder,
+    UndelegateType,
+};
 use ephemeral_rollups_sdk::{ActionArgs, ShortAccountMeta};
 
 // Import helper modules
-use super::word_selection;
+use super::{
+    reset_streak, score_deadline, scoring, session_state, word_selection,
+    HANDLER_LEADERBOARD_ORDER,
+};
+use crate::instructions::admin::{
+    record_inflow, record_pool_inflow, require_not_blocked, require_period_open, ticket_pool_splits,
+    validate_schedule_splits, word_difficulty,
+};
+use crate::instructions::leaderboard::{fill_new_leaderboard, growth_steps_remaining};
+use crate::instructions::prize::distribution;
+use crate::state::{
+    AggregationStrategy, GlobalConfig, PeriodType, PlayerParticipation, SessionAccount,
+    SessionCounter, SessionState, TicketReceipt, UserProfile,
+};
+use crate::utils::period::{self, get_current_period_id, parse_period_id};
+use crate::utils::ratelimit::{self, SessionAction};
 use solana_address::Address;
 
 /// Buy ticket and start a new Voble game in one transaction
@@ -32,23 +49,42 @@ use solana_address::Address;
 /// - W% to platform revenue
 /// (All percentages from config, must total 100%)
 ///
+/// A `PrizeScheduleOverride` passed for the period replaces X, Y and Z; the
+/// split set used is stamped on the profile (`ticket_pool_splits`).
+///
 /// # Validation
 /// - Game must not be paused
+/// - Period ID must be the current daily period (`PeriodNotCurrent`)
+/// - Player must not be on the blocklist (`PlayerBlocked`)
 /// - Player must not have already played this period
+/// - A win streak resets once more than `streak_decay_periods` periods were
+///   skipped since the player's last game
+/// - Player's ticket cooldown (`ticket_cooldown_secs`) must have elapsed
+/// - New entrants are rejected once the period reaches `max_participants_per_period`
+/// - Period's daily leaderboard must not be paused (`PeriodPaused`)
+/// - Under the commit-reveal word strategy the config must sell daily words
+///   (`CommitRevealNeedsDailyWords`)
 /// - Ticket price must be paid in full
 /// - Payment splits must add up exactly to ticket price
 ///
 /// # Session Creation
 /// Creates a new SessionAccount with:
-/// - Randomly selected word (hidden via hash)
+/// - Randomly selected word (hidden via hash) from the config's
+///   `ticket_word_source` list
 /// - Empty guess array
 /// - Game state tracking
 /// - Rent automatically reclaimed on completion
 ///
+/// # First Purchase
+/// - A payer without a USDC associated token account gets an empty one, so
+///   the balance check fails with `InsufficientTicketFunds`
+/// - The period's first ticket creates its daily leaderboard with the
+///   `initialize_period_leaderboard` defaults, paid for by the payer
+///
 /// # Security Notes
 /// - Uses PDA for session (prevents duplicate sessions per player per period)
 /// - Word is hashed until game completion (anti-cheat)
-/// - Payment validation ensures no lamport loss
+/// - Payment validation ensures no token loss
 ///
 /// # Flow After This
 /// 1. This instruction completes ✅
@@ -73,11 +109,51 @@ pub fn buy_ticket_and_start_game(
         VobleError::PeriodIdTooLong
     );
     require!(period_id.len() > 0, VobleError::SessionIdEmpty);
+    // Boards are created on demand, so only today's can be
+    require!(
+        period_id == get_current_period_id(period::PeriodType::Daily, now, config.period_epoch()),
+        VobleError::PeriodNotCurrent
+    );
+
+    let blocked_player = ctx.accounts.blocked_player.to_account_info();
+    require_not_blocked(blocked_player.owner, &blocked_player.try_borrow_data()?)?;
+
+    // The authority picks the list (weekly and monthly are harder); the
+    // ticket feeds all three pools either way
+    let word_source = config.ticket_word_source;
+    // Commit-reveal games play the shared daily word, whatever list is sold
+    word_selection::require_word_source_playable(config.word_strategy, word_source)?;
 
     msg!("🎮 Starting new Voble game");
     msg!("   Period: {}", period_id);
     msg!("   Player: {}", ctx.accounts.payer.key());
 
+    // ========== PERIOD ROLLOVER ==========
+    // Clear last period's play tracking before any period checks read it
+    ctx.accounts.user_profile.ensure_period(&period_id);
+
+    // ========== STREAK DECAY ==========
+    // A streak shouldn't survive weeks of inactivity just because it was never lost
+    if is_streak_decayed(
+        &ctx.accounts.user_profile.last_played_period,
+        &period_id,
+        config.streak_decay_periods,
+    ) {
+        msg!(
+            "📉 Streak of {} reset after inactivity since {}",
+            ctx.accounts.user_profile.current_streak,
+            ctx.accounts.user_profile.last_played_period
+        );
+        let lost = reset_streak(&mut ctx.accounts.user_profile);
+        if lost > 0 {
+            emit!(StreakBroken {
+                player: ctx.accounts.user_profile.player,
+                streak: lost,
+                period_id: period_id.clone(),
+            });
+        }
+    }
+
     // Period limit is now enforced via profile check (see below)
 
     // Get player key for word selection
@@ -90,23 +166,42 @@ pub fn buy_ticket_and_start_game(
 
     // ========== PAYMENT PROCESSING ==========
     let ticket_price = config.ticket_price;
-    let decimals = ctx.accounts.mint.decimals;
-
-    msg!("💰 Processing ticket payment: {} USDC ", ticket_price);
-
-    // Calculate prize distribution splits (basis points -> lamports)
-    let daily_amount = 
-        (ticket_price * config.prize_split_daily as u64) / BASIS_POINTS_TOTAL as u64;
-    let weekly_amount =
-        (ticket_price * config.prize_split_weekly as u64) / BASIS_POINTS_TOTAL as u64;
-    let monthly_amount =
-        (ticket_price * config.prize_split_monthly as u64) / BASIS_POINTS_TOTAL as u64;
-    let platform_amount =
-        (ticket_price * config.platform_revenue_split as u64) / BASIS_POINTS_TOTAL as u64;
-    let lucky_draw_amount =
-        (ticket_price * config.lucky_draw_split as u64) / BASIS_POINTS_TOTAL as u64;
-
-    // CRITICAL: Validate splits add up exactly to ticket price (prevent lamport loss)
+
+    msg!(
+        "💰 Processing ticket payment: {} {} ({} base units)",
+        config.ticket_price_ui(ctx.accounts.mint.decimals),
+        config.currency_symbol(),
+        ticket_price
+    );
+
+    // Fail with a clear error before any share is transferred
+    distribution::validate_payer_balance(ctx.accounts.payer_token_account.amount, ticket_price)?;
+
+    // A schedule override for this period replaces the three pool splits
+    let schedule = ctx.accounts.prize_schedule.as_deref().map(|schedule| &**schedule);
+    let schedule_override = schedule.is_some();
+    let pool_splits = ticket_pool_splits(config, schedule);
+    if schedule_override {
+        // Platform or lucky draw splits may have changed since it was created
+        validate_schedule_splits(config, pool_splits)?;
+        msg!("📅 Prize schedule override in effect");
+    }
+
+    // Calculate prize distribution splits (basis points -> base units)
+    let [daily_split, weekly_split, monthly_split] = pool_splits;
+    let [daily_amount, weekly_amount, monthly_amount, platform_amount, lucky_draw_amount] =
+        distribution::ticket_share_amounts(
+            ticket_price,
+            [
+                daily_split,
+                weekly_split,
+                monthly_split,
+                config.platform_revenue_split,
+                config.lucky_draw_split,
+            ],
+        );
+
+    // CRITICAL: Validate splits add up exactly to ticket price (prevent token loss)
     let total_distributed = daily_amount + weekly_amount + monthly_amount + platform_amount + lucky_draw_amount;
     require!(
         total_distributed == ticket_price,
@@ -122,90 +217,70 @@ pub fn buy_ticket_and_start_game(
         lucky_draw_amount
     );
 
-
-    // Transfer to daily prize vault
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_token_account.to_account_info(),
-                to: ctx.accounts.daily_prize_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info()
-            },
-        ),
-        daily_amount,
-        decimals
-    )?;
-
-    // Transfer to weekly prize vault
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_token_account.to_account_info(),
-                to: ctx.accounts.weekly_prize_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info()
-            },
-        ),
-        weekly_amount,
-        decimals
-    )?;
-
-    // Transfer to monthly prize vault
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_token_account.to_account_info(),
-                to: ctx.accounts.monthly_prize_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info()
-            },
-        ),
-        monthly_amount,
-        decimals
+    // Transfer each share into its vault (zero shares are skipped)
+    let transferred = distribution::execute_ticket_payment(
+        &distribution::TicketPaymentAccounts {
+            payer: &ctx.accounts.payer,
+            payer_token_account: &ctx.accounts.payer_token_account,
+            vaults: [
+                &*ctx.accounts.daily_prize_vault,
+                &*ctx.accounts.weekly_prize_vault,
+                &*ctx.accounts.monthly_prize_vault,
+                &*ctx.accounts.platform_vault,
+                &*ctx.accounts.lucky_draw_vault,
+            ],
+            mint: &ctx.accounts.mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        [
+            daily_amount,
+            weekly_amount,
+            monthly_amount,
+            platform_amount,
+            lucky_draw_amount,
+        ],
     )?;
+    require!(transferred == ticket_price, VobleError::InvalidPrizeSplits);
 
-    // Transfer to platform vault
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_token_account.to_account_info(),
-                to: ctx.accounts.platform_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info()
-            },
-        ),
-        platform_amount,
-        decimals
-    )?;
+    // Keep the per-vault ledgers in step with the transfers above
+    for (ledger, amount) in [
+        (&mut ctx.accounts.daily_vault_ledger, daily_amount),
+        (&mut ctx.accounts.weekly_vault_ledger, weekly_amount),
+        (&mut ctx.accounts.monthly_vault_ledger, monthly_amount),
+        (&mut ctx.accounts.platform_vault_ledger, platform_amount),
+        (&mut ctx.accounts.lucky_draw_vault_ledger, lucky_draw_amount),
+    ] {
+        record_inflow(ledger, amount, now)?;
+    }
 
-    // Transfer to lucky draw vault
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_token_account.to_account_info(),
-                to: ctx.accounts.lucky_draw_vault.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info()
-            },
-        ),
-        lucky_draw_amount,
-        decimals
-    )?;
-    
+    // Track each prize share against its period's pool so a canceled period
+    // knows how much of the shared vault it owes back
+    let pool_periods = score_deadline::settlement_period_ids(&period_id, config.period_epoch())
+        .ok_or(VobleError::InvalidPeriodState)?;
+    for (ledger, pool_period, amount) in [
+        (&mut ctx.accounts.daily_vault_ledger, &pool_periods[0], daily_amount),
+        (&mut ctx.accounts.weekly_vault_ledger, &pool_periods[1], weekly_amount),
+        (&mut ctx.accounts.monthly_vault_ledger, &pool_periods[2], monthly_amount),
+    ] {
+        record_pool_inflow(ledger, pool_period, amount)?;
+    }
 
     msg!("✅ Payment distributed to all vaults");
 
+    // Keep the pool shares refundable if one of the ticket's periods is canceled
+    record_ticket_receipt(
+        &mut ctx.accounts.ticket_receipt,
+        player_key,
+        &period_id,
+        [daily_amount, weekly_amount, monthly_amount],
+        ctx.bumps.ticket_receipt,
+    );
+
     // ========== WORD SELECTION ==========
     // Select a word for this game session
     // ⚠️ Currently uses deterministic selection (DEMO MODE)
     // ⚠️ Replace with VRF for production!
-    let _word_data = word_selection::select_word_for_session(player_key, &period_id, total_games)?;
+    let word_data = word_selection::select_word_for_session(player_key, &period_id, total_games, word_source)?;
 
     msg!("📝 Word selected for session");
 
@@ -219,13 +294,134 @@ pub fn buy_ticket_and_start_game(
 
     msg!("✅ Period limit enforced: Player hasn't played period {}", period_id);
 
+    // ========== TICKET COOLDOWN ==========
+    // Throttle rapid repeat purchases so bursts don't flood the ER with delegations
+    require!(
+        !is_ticket_cooldown_active(
+            ctx.accounts.user_profile.last_ticket_at,
+            now,
+            config.ticket_cooldown_secs
+        ),
+        VobleError::TicketCooldown
+    );
+
+    // ========== PERIOD PAUSE & PARTICIPANT CAP ==========
+    // Only a player's first ticket in the period counts; players already in
+    // the period can keep playing and settling after the cap is reached
+    let is_new_entrant = record_participation(
+        &mut ctx.accounts.participation,
+        player_key,
+        &period_id,
+        now,
+        ctx.bumps.participation,
+    );
+    let max_participants = config.max_participants_per_period;
+    let leaderboard = &mut ctx.accounts.period_leaderboard;
+    if leaderboard.period_id.is_empty() {
+        // First ticket of the day: the board was just allocated
+        fill_new_leaderboard(
+            leaderboard,
+            &period_id,
+            PeriodType::Daily,
+            AggregationStrategy::default_for(PeriodType::Daily),
+            config,
+            ctx.bumps.period_leaderboard,
+            now,
+        );
+        msg!("📊 Daily leaderboard created for {}", period_id);
+
+        let remaining = growth_steps_remaining(
+            leaderboard.to_account_info().data_len(),
+            leaderboard.capacity(),
+        );
+        if remaining > 0 {
+            msg!("   ⚠️  Call grow_leaderboard {} more time(s)", remaining);
+        }
+
+        emit!(LeaderboardInitialized {
+            period_id: period_id.clone(),
+            period_type: PeriodType::Daily,
+            aggregation: leaderboard.aggregation,
+            created_at: now,
+        });
+    }
+    require_period_open(leaderboard)?;
+    admit_to_period(&mut leaderboard.entrants, is_new_entrant, max_participants)?;
+    msg!("👥 Entrants: {} (cap {})", leaderboard.entrants, max_participants);
+    let entrants = leaderboard.entrants;
+    // The vault was read before the transfer above
+    let daily_pool = ctx.accounts.daily_prize_vault.amount.saturating_add(daily_amount);
+
     // ========== PAYMENT TRACKING ==========
     // Update user profile to reflect payment for this period
     // This allows ER to verify payment without needing a separate receipt account
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.last_paid_period = period_id.clone();
+    user_profile.plays_this_period = user_profile.plays_this_period.saturating_add(1);
+    user_profile.game_epoch = user_profile.game_epoch.saturating_add(1);
+    user_profile.last_ticket_at = now;
+    // A ticket bought while the session is on the ER can start a game there
+    // right away (see recover_stale_session)
+    if user_profile.delegated_at != 0 {
+        user_profile.started_epoch = user_profile.game_epoch;
+    }
+    user_profile.word_source = word_source; // reset_session picks the word from this list
+    // reset_session reports these in VobleGameStarted, so clients don't have
+    // to fetch the board and vault separately
+    user_profile.entrants_at_purchase = entrants;
+    user_profile.prize_pool_at_purchase = daily_pool;
+
+    // Register the ephemeral key that signs gameplay on the ER for this player
+    if let Some(session_signer) = &ctx.accounts.session_signer {
+        user_profile.session_signer = session_signer.key();
+        msg!("🔑 Session signer registered: {}", user_profile.session_signer);
+    }
+
+    // ========== HAPPY HOUR ==========
+    // Snapshot the multiplier at purchase time - the ER can't read the
+    // multiplier account mid-game, so reset_session copies it from the profile
+    let multiplier_bps = match &ctx.accounts.score_multiplier {
+        Some(score_multiplier) => scoring::active_multiplier_bps(&score_multiplier.windows, now),
+        None => SCORE_MULTIPLIER_NONE,
+    };
+    user_profile.score_multiplier_bps = multiplier_bps;
+
+    // ========== WORD DIFFICULTY ==========
+    // Deterministic games: reset_session picks the same word, so the
+    // difficulty looked up here is the one the session is scored with.
+    // Commit-reveal games are scored with the shared word's difficulty
+    // instead (see session_word_difficulty)
+    let word_bank = ctx.accounts.word_bank.to_account_info();
+    let word_difficulty = word_difficulty(
+        word_bank.owner,
+        &word_bank.try_borrow_data()?,
+        word_data.word_index,
+    )?;
+    user_profile.word_difficulty = word_difficulty;
+
+    // ========== HOLE IN ONE BONUS ==========
+    // Stamped like the multiplier so a config change mid-game can't alter the score
+    user_profile.hole_in_one_bonus = ctx.accounts.global_config.hole_in_one_bonus;
+
+    // ========== PRIZE SCHEDULE ==========
+    // Record which split set paid for this ticket
+    user_profile.ticket_pool_splits = pool_splits;
+
+    // ========== ACTIVE SESSION SLOT ==========
+    // The game holds a slot until it settles (or its session leaves the ER);
+    // purchases are rejected while the ER validator is full
+    take_session_slot(
+        &mut ctx.accounts.session_counter,
+        ctx.bumps.session_counter,
+        user_profile,
+        ctx.accounts.global_config.active_session_cap(),
+    )?;
 
     msg!("✅ Payment recorded for period: {}", period_id);
+    msg!("   Game epoch: {}", user_profile.game_epoch);
+    msg!("   Score multiplier: {} bps", multiplier_bps);
+    msg!("   Word difficulty: {}", word_difficulty);
+    msg!("   Hole in one bonus: {}", user_profile.hole_in_one_bonus);
     
     // Note: Session initialization/reset now happens on ER in reset_session
     // This avoids writing to the delegated session account from Base layer
@@ -239,6 +435,10 @@ pub fn buy_ticket_and_start_game(
         monthly_amount,
         platform_amount,
         lucky_draw_amount, 
+        period_id,
+        new_participant: is_new_entrant,
+        schedule_override,
+        currency_symbol: ctx.accounts.global_config.currency_symbol().to_string(),
     });
 
     Ok(())
@@ -251,6 +451,21 @@ pub fn initialize_session(ctx: Context<InitializeSession>) -> Result<()> {
     session.player = ctx.accounts.payer.key();
     session.keystrokes = Vec::new();  
     session.current_input = String::new(); 
+    session.version = SESSION_ACCOUNT_VERSION;
+    session.game_epoch = 0;
+    session.state = SessionState::Idle;
+    session.score_multiplier_bps = SCORE_MULTIPLIER_NONE;
+    session.commit_frequency_ms = 0; // Set on delegation
+    session.delegated = false;
+    session.bump = ctx.bumps.session;
+    session.session_nonce = ctx
+        .accounts
+        .user_profile
+        .as_ref()
+        .map_or(0, |profile| profile.session_nonce);
+    session.program_version = PROGRAM_VERSION;
+    // `init` already makes this once per player; recorded for a complete action history
+    ratelimit::record_session_action(session, SessionAction::Initialize, Clock::get()?.slot, 0)?;
     
     msg!("✅ Session initialized for player: {}", session.player);
     
@@ -258,94 +473,298 @@ pub fn initialize_session(ctx: Context<InitializeSession>) -> Result<()> {
 }
 
 /// Delegate session to Ephemeral Rollup
-pub fn delegate_session(ctx: Context<DelegateSession>) -> Result<()> {
-    
+///
+/// # Arguments
+/// * `ctx` - The context containing the payer and session PDA
+/// * `commit_frequency_ms` - How often the ER commits the session to the base
+///   layer (5_000..=60_000, defaults to 30_000). More frequent commits are
+///   more durable but cost more.
+///
+/// # Validation
+/// - The session must have been created by `initialize_session` for the
+///   payer (`SessionNotInitialized` otherwise)
+/// - Unless the player already holds one, an active session slot must be
+///   free (`TooManyActiveSessions` otherwise)
+/// - The delegate cool-down (`session_action_gaps`) must have passed since
+///   the last delegation (`RateLimited`)
+///
+/// # Notes
+/// The chosen frequency and the `delegated` flag are stored on the session.
+/// They are written before delegation, while the program still owns the
+/// account. The slot is freed when the game settles or the session is
+/// undelegated (see `release_session_slot`).
+/// The delegation time and the latest ticket's epoch are stamped on the
+/// profile for `recover_stale_session`.
+pub fn delegate_session(ctx: Context<DelegateSession>, commit_frequency_ms: Option<u32>) -> Result<()> {
+    let commit_frequency_ms = resolve_commit_frequency(commit_frequency_ms)?;
+    let min_gap =
+        ratelimit::session_action_gap(&ctx.accounts.global_config, SessionAction::Delegate);
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+
+    take_session_slot(
+        &mut ctx.accounts.session_counter,
+        ctx.bumps.session_counter,
+        &mut ctx.accounts.user_profile,
+        ctx.accounts.global_config.active_session_cap(),
+    )?;
+
+    // From here the latest ticket can start a game on the ER; the ER's own
+    // timestamps never reach recover_stale_session, so it goes by these
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.delegated_at = clock.unix_timestamp;
+    user_profile.started_epoch = user_profile.game_epoch;
+
+    let session_nonce = {
+        let mut data = ctx.accounts.pda.try_borrow_mut_data()?;
+        let mut session =
+            load_initialized_session(ctx.accounts.pda.owner, &data, &ctx.accounts.payer.key())?;
+        ratelimit::record_session_action(&mut session, SessionAction::Delegate, slot, min_gap)?;
+        session.commit_frequency_ms = commit_frequency_ms;
+        session.delegated = true;
+        session.try_serialize(&mut &mut data[..])?;
+        session.session_nonce
+    };
+
+    // Generation 0 keeps the original two-seed PDA
+    let payer_key = ctx.accounts.payer.key();
+    let nonce_seed = SessionAccount::nonce_seed(session_nonce);
+    let mut seeds: Vec<&[u8]> = vec![SEED_SESSION, payer_key.as_ref()];
+    if !nonce_seed.is_empty() {
+        seeds.push(&nonce_seed);
+    }
     ctx.accounts.delegate_pda(
         &ctx.accounts.payer,
-        &[SEED_SESSION, ctx.accounts.payer.key().as_ref()],
+        &seeds,
         DelegateConfig {
-            commit_frequency_ms: 30_000,
+            commit_frequency_ms,
             validator: Some(pubkey!("MAS1Dt9qreoRMQ14YQuhg8UTZMMzDdKhmkZMECCzk57")),
         },
     )?;
     
-    msg!("✅ Session delegated to ER");
+    msg!("✅ Session delegated to ER (commit every {} ms)", commit_frequency_ms);
 
     Ok(())
 }
 
+/// Read the session about to be delegated, refusing one that doesn't exist yet
+///
+/// # Arguments
+/// * `owner` - Owner of the session PDA
+/// * `data` - The PDA's account data
+/// * `payer` - Player delegating the session
+///
+/// # Returns
+/// The deserialized session
+///
+/// # Validation
+/// - The PDA must be a program-owned `SessionAccount` whose `player` is the
+///   payer (`SessionNotInitialized` otherwise), so a client that delegates
+///   before `initialize_session` gets a clear error instead of delegating an
+///   empty account
+pub fn load_initialized_session(
+    owner: &Pubkey,
+    data: &[u8],
+    payer: &Pubkey,
+) -> Result<SessionAccount> {
+    require!(
+        owner == &crate::ID && data.starts_with(SessionAccount::DISCRIMINATOR),
+        VobleError::SessionNotInitialized
+    );
+    let session = SessionAccount::try_deserialize(&mut &data[..])?;
+    require_keys_eq!(session.player, *payer, VobleError::SessionNotInitialized);
+    Ok(session)
+}
 
 /// Undelegate session from Ephemeral Rollup
 /// This instruction ONLY commits the session from ER to base layer
 /// It does NOT update leaderboard or profile (those accounts are not on ER)
+///
+/// # Notes
+/// `release_session_slot` runs as a handler once the session is back on the
+/// base layer, freeing the player's active session slot
 pub fn undelegate_session(ctx: Context<UndelegateSession>) -> Result<()> {
     msg!("🔄 Committing session from ER to base layer");
-    
-    // Commit and undelegate session from ER to base layer
-    commit_and_undelegate_accounts(
+
+    let release_handler = release_slot_call_handler(
+        &mut ctx.accounts.session,
+        ctx.accounts.global_config.handler_escrow_index,
         &ctx.accounts.payer,
-        vec![&ctx.accounts.session.to_account_info()],
-        &ctx.accounts.magic_context,
-        &ctx.accounts.magic_program,
     )?;
+
+    // Commit and undelegate session from ER to base layer
+    let magic_builder = MagicInstructionBuilder {
+        payer: ctx.accounts.payer.to_account_info(),
+        magic_context: ctx.accounts.magic_context.to_account_info(),
+        magic_program: ctx.accounts.magic_program.to_account_info(),
+        magic_action: MagicAction::CommitAndUndelegate(CommitAndUndelegate {
+            commit_type: CommitType::WithHandler {
+                commited_accounts: vec![ctx.accounts.session.to_account_info()],
+                call_handlers: vec![release_handler],
+            },
+            undelegate_type: UndelegateType::Standalone,
+        }),
+    };
+    magic_builder.build_and_invoke()?;
     
     msg!("✅ Session committed successfully");
     
     Ok(())
 }
 
+/// Magic Actions handler - frees the player's active session slot once
+/// their session has left the ER
+///
+/// Scheduled by every undelegation. Anyone may also call it directly for a
+/// session that is back on the base layer.
+///
+/// # Arguments
+/// * `ctx` - The context containing the session counter, profile and session
+///
+/// # Validation
+/// - The session must be the profile's session PDA (`ProfileSessionMismatch`)
+/// - See `check_slot_release`
+///
+/// # Notes
+/// Releasing a slot the player doesn't hold is a no-op, so a repeated
+/// undelegation (or a release after the game already settled) can't
+/// decrement twice
+pub fn release_session_slot(ctx: Context<ReleaseSessionSlot>) -> Result<()> {
+    let session_info = &ctx.accounts.committed_session.to_account_info();
+    let mut data: &[u8] = &session_info.try_borrow_data()?;
+    let session = SessionAccount::try_deserialize(&mut data)?;
+
+    let profile = &mut ctx.accounts.user_profile;
+    crate::instructions::game::verify_session_for_profile(
+        session_info.key,
+        &session,
+        &profile.player,
+    )?;
+    check_slot_release(&session, profile.session_nonce)?;
+    // Back on the base layer: later tickets can't reach the ER until the next delegation
+    profile.delegated_at = 0;
 
-/// Commit and update stats when undelegate
-pub fn commit_and_update_stats(
-    ctx: Context<CommitAndUpdateStats>,
-    daily_period_id: String,
-    weekly_period_id: String,
-    monthly_period_id: String,
-) -> Result<()> {
-    msg!("🔄 Committing session from ER to base layer with handler");
-    msg!(
-        "   Period IDs → daily: {}, weekly: {}, monthly: {}",
-        daily_period_id,
-        weekly_period_id,
-        monthly_period_id
+    if !release_profile_slot(&ctx.accounts.session_counter, profile)? {
+        msg!("ℹ️  {} holds no active session slot", profile.player);
+    }
+
+    Ok(())
+}
+
+/// Check that a committed session may give back its player's slot
+///
+/// # Arguments
+/// * `session` - The committed session
+/// * `profile_session_nonce` - The profile's live session generation
+///
+/// # Validation
+/// - The session must be the player's live generation; a session retired by
+///   `recover_stale_session` already gave its slot back, and the slot the
+///   player holds now belongs to the new session (`SessionRetired`)
+/// - The session must have been undelegated (`InvalidSessionState`)
+pub fn check_slot_release(session: &SessionAccount, profile_session_nonce: u32) -> Result<()> {
+    require!(
+        session.session_nonce == profile_session_nonce,
+        VobleError::SessionRetired
     );
-    
-    // Build handler instruction data
+    require!(!session.delegated, VobleError::InvalidSessionState);
+    Ok(())
+}
+
+/// Mark a session as leaving the ER and build the handler that frees its slot
+///
+/// Shared by every instruction that undelegates a session.
+///
+/// # Arguments
+/// * `session` - The session about to be committed and undelegated
+/// * `escrow_index` - Magic Actions escrow that pays for the handler
+/// * `escrow_authority` - Signer whose escrow pays for the handler
+///
+/// # Notes
+/// `delegated` is cleared before the commit so the handler can tell the
+/// session really left the ER
+pub fn release_slot_call_handler<'info>(
+    session: &mut Account<'info, SessionAccount>,
+    escrow_index: u8,
+    escrow_authority: &Signer<'info>,
+) -> Result<CallHandler<'info>> {
+    session.delegated = false;
+    session.exit(&crate::ID)?;
+
+    let (user_profile, _) = Pubkey::find_program_address(
+        &[SEED_USER_PROFILE, session.player.as_ref()],
+        &crate::ID,
+    );
+    let (session_counter, _) = Pubkey::find_program_address(&[SEED_SESSION_COUNTER], &crate::ID);
+
     let instruction_data = anchor_lang::InstructionData::data(
-        &crate::instruction::UpdatePlayerStats {}
+        &crate::instruction::ReleaseSessionSlot {}
     );
 
-    let call_handler = CallHandler {
+    Ok(CallHandler {
         args: ActionArgs {
-            escrow_index: 0,
+            escrow_index,
             data: instruction_data,
         },
-        compute_units: 400_000,
-        escrow_authority: ctx.accounts.payer.to_account_info(),
+        compute_units: 100_000,
+        escrow_authority: escrow_authority.to_account_info(),
         destination_program: crate::ID,
         accounts: vec![
             ShortAccountMeta {
-                pubkey: Address::new_from_array(ctx.accounts.daily_leaderboard.key().to_bytes()),
+                pubkey: Address::new_from_array(session_counter.to_bytes()),
                 is_writable: true,
             },
             ShortAccountMeta {
-                pubkey: Address::new_from_array(ctx.accounts.weekly_leaderboard.key().to_bytes()),
+                pubkey: Address::new_from_array(user_profile.to_bytes()),
                 is_writable: true,
             },
             ShortAccountMeta {
-                pubkey: Address::new_from_array(ctx.accounts.monthly_leaderboard.key().to_bytes()),
-                is_writable: true,
-            },
-            ShortAccountMeta {
-                pubkey: Address::new_from_array(ctx.accounts.user_profile.key().to_bytes()),
-                is_writable: true,
-            },
-            ShortAccountMeta {
-                pubkey: Address::new_from_array(ctx.accounts.session.key().to_bytes()),
+                pubkey: Address::new_from_array(session.key().to_bytes()),
                 is_writable: false,
             },
         ],
-    };
+    })
+}
+
+
+/// Commit and update stats when undelegate
+///
+/// # Notes
+/// - The handler compute budget and escrow index come from GlobalConfig
+/// - With `split_stats_handlers` set, leaderboards and profile are settled
+///   by `update_leaderboard_stats` and `update_profile_stats`, each with the
+///   full budget; otherwise `update_player_stats` does both
+/// - Emits `CommitScheduled` with the values used, for debugging failed handlers
+/// - A finished game counts for the period its ticket was bought in
+///   (`session.period_id`): the period IDs must be that day and the week and
+///   month containing it, even if the wall clock has rolled over
+/// - The commit time decides `session.deadline_rule`: games committed more
+///   than `grace_window_seconds` after their period ended only update the
+///   profile, and the handler emits `LateScore` instead of writing the boards
+pub fn commit_and_update_stats(
+    ctx: Context<CommitAndUpdateStats>,
+    daily_period_id: String,
+    weekly_period_id: String,
+    monthly_period_id: String,
+) -> Result<()> {
+    msg!("🔄 Committing session from ER to base layer with handler");
+
+    let call_handlers = settlement_call_handlers(
+        &mut ctx.accounts.session,
+        &ctx.accounts.global_config,
+        [
+            daily_period_id.as_str(),
+            weekly_period_id.as_str(),
+            monthly_period_id.as_str(),
+        ],
+        [
+            ctx.accounts.daily_leaderboard.key(),
+            ctx.accounts.weekly_leaderboard.key(),
+            ctx.accounts.monthly_leaderboard.key(),
+        ],
+        ctx.accounts.user_profile.key(),
+        &ctx.accounts.payer,
+    )?;
 
     let magic_builder = MagicInstructionBuilder {
         payer: ctx.accounts.payer.to_account_info(),
@@ -353,7 +772,7 @@ pub fn commit_and_update_stats(
         magic_program: ctx.accounts.magic_program.to_account_info(),
         magic_action: MagicAction::Commit(CommitType::WithHandler {
             commited_accounts: vec![ctx.accounts.session.to_account_info()],
-            call_handlers: vec![call_handler],
+            call_handlers,
         }),
     };
 
@@ -363,3 +782,710 @@ pub fn commit_and_update_stats(
     
     Ok(())
 }
+
+/// Settle a finished game and lay out the handlers that apply it
+///
+/// Shared by `commit_and_update_stats` and `force_undelegate_stale_session`,
+/// so a game settles the same way whoever commits it.
+///
+/// # Arguments
+/// * `session` - The session about to be committed
+/// * `config` - Supplies the epoch, grace window and handler settings
+/// * `period_ids` - Daily, weekly and monthly period IDs of the settlement
+/// * `leaderboards` - Daily, weekly and monthly leaderboard addresses
+/// * `user_profile` - The player's profile
+/// * `escrow_authority` - Signer whose escrow pays for the handlers
+///
+/// # Validation
+/// - A finished or settled game's period IDs must match its ticket's period
+///   (`verify_settlement_periods`)
+///
+/// # Notes
+/// - A CompletedUnsettled game is stamped with its deadline rule and moved
+///   to Settled, so the handler applies it exactly once
+/// - Emits `CommitScheduled`
+pub fn settlement_call_handlers<'info>(
+    session: &mut Account<'info, SessionAccount>,
+    config: &GlobalConfig,
+    period_ids: [&str; 3],
+    leaderboards: [Pubkey; 3],
+    user_profile: Pubkey,
+    escrow_authority: &Signer<'info>,
+) -> Result<Vec<CallHandler<'info>>> {
+    let epoch_start = config.period_epoch();
+    let [daily_period_id, weekly_period_id, monthly_period_id] = period_ids;
+
+    // ========== VALIDATION: Settlement Periods ==========
+    if matches!(
+        session.current_state(),
+        SessionState::CompletedUnsettled | SessionState::Settled
+    ) {
+        score_deadline::verify_settlement_periods(&session.period_id, epoch_start, period_ids)?;
+    }
+
+    // Mark a finished game as settled so the handler applies it exactly once
+    if session.current_state() == SessionState::CompletedUnsettled {
+        session.deadline_rule = score_deadline::score_deadline_rule(
+            &session.period_id,
+            Clock::get()?.unix_timestamp,
+            epoch_start,
+            config.score_grace_window(),
+        );
+        session_state::transition(session, SessionState::Settled)?;
+        session.exit(&crate::ID)?;
+
+        msg!("   Deadline rule: {:?}", session.deadline_rule);
+    }
+
+    msg!(
+        "   Period IDs → daily: {}, weekly: {}, monthly: {}",
+        daily_period_id,
+        weekly_period_id,
+        monthly_period_id
+    );
+
+    // ========== PLAN HANDLERS ==========
+    let (session_counter, _) = Pubkey::find_program_address(&[SEED_SESSION_COUNTER], &crate::ID);
+    let plans = plan_stats_handlers(
+        config.handler_compute_budget(),
+        config.handler_escrow_index,
+        config.split_stats_handlers,
+        leaderboards,
+        user_profile,
+        session.key(),
+        session_counter,
+    );
+
+    msg!(
+        "   Handlers: {} ({} CU each, escrow {})",
+        plans.len(),
+        config.handler_compute_budget(),
+        config.handler_escrow_index
+    );
+
+    emit!(CommitScheduled {
+        player: session.player,
+        game_epoch: session.game_epoch,
+        state: session.current_state(),
+        handlers: plans.len() as u8,
+        compute_units: config.handler_compute_budget(),
+        escrow_index: config.handler_escrow_index,
+    });
+
+    Ok(plans
+        .into_iter()
+        .map(|plan| CallHandler {
+            args: ActionArgs {
+                escrow_index: plan.escrow_index,
+                data: plan.data,
+            },
+            compute_units: plan.compute_units,
+            escrow_authority: escrow_authority.to_account_info(),
+            destination_program: crate::ID,
+            accounts: plan
+                .accounts
+                .iter()
+                .map(|(pubkey, is_writable)| ShortAccountMeta {
+                    pubkey: Address::new_from_array(pubkey.to_bytes()),
+                    is_writable: *is_writable,
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+/// One settlement call handler, before it is attached to the magic builder
+pub struct StatsHandlerPlan {
+    pub data: Vec<u8>,
+    pub compute_units: u32,
+    pub escrow_index: u8,
+    pub accounts: Vec<(Pubkey, bool)>, // (address, is_writable) after the injected escrow accounts
+}
+
+// `plan_stats_handlers` destructures its leaderboards as daily, weekly, monthly
+const _: () = assert!(matches!(
+    HANDLER_LEADERBOARD_ORDER,
+    [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly]
+));
+
+/// Lay out the call handlers that settle a committed session
+///
+/// # Arguments
+/// * `compute_units` - Budget for each handler
+/// * `escrow_index` - Escrow paying for the handlers
+/// * `split` - Settle leaderboards and profile in separate handlers
+/// * `leaderboards` - Daily, weekly and monthly leaderboard addresses
+///   (`HANDLER_LEADERBOARD_ORDER`)
+/// * `profile` - The player's profile
+/// * `session` - The committed session
+/// * `session_counter` - Active session counter, freed by the profile update
+///
+/// # Returns
+/// One `update_player_stats` handler, or `update_leaderboard_stats` followed
+/// by `update_profile_stats` when split
+///
+/// # Notes
+/// - The handlers reject leaderboards out of this order with
+///   `HandlerAccountOrderInvalid`, see `verify_handler_leaderboards`
+pub fn plan_stats_handlers(
+    compute_units: u32,
+    escrow_index: u8,
+    split: bool,
+    leaderboards: [Pubkey; 3],
+    profile: Pubkey,
+    session: Pubkey,
+    session_counter: Pubkey,
+) -> Vec<StatsHandlerPlan> {
+    let [daily, weekly, monthly] = leaderboards;
+    let plan = |data: Vec<u8>, accounts: Vec<(Pubkey, bool)>| StatsHandlerPlan {
+        data,
+        compute_units,
+        escrow_index,
+        accounts,
+    };
+
+    if !split {
+        return vec![plan(
+            anchor_lang::InstructionData::data(&crate::instruction::UpdatePlayerStats {}),
+            vec![
+                (daily, true),
+                (weekly, true),
+                (monthly, true),
+                (profile, true),
+                (session, false),
+                (session_counter, true),
+            ],
+        )];
+    }
+
+    vec![
+        plan(
+            anchor_lang::InstructionData::data(&crate::instruction::UpdateLeaderboardStats {}),
+            vec![
+                (daily, true),
+                (weekly, true),
+                (monthly, true),
+                (profile, false),
+                (session, false),
+            ],
+        ),
+        plan(
+            anchor_lang::InstructionData::data(&crate::instruction::UpdateProfileStats {}),
+            vec![(profile, true), (session, false), (session_counter, true)],
+        ),
+    ]
+}
+
+/// Check whether a player is still inside their ticket purchase cooldown
+///
+/// # Arguments
+/// * `last_ticket_at` - Timestamp of the player's previous purchase (0 if never)
+/// * `now` - Current Unix timestamp
+/// * `cooldown_secs` - Configured cooldown (0 disables the check)
+pub fn is_ticket_cooldown_active(last_ticket_at: i64, now: i64, cooldown_secs: i64) -> bool {
+    cooldown_secs > 0 && now.saturating_sub(last_ticket_at) < cooldown_secs
+}
+
+/// Whether a player skipped too many periods to keep their win streak
+///
+/// # Arguments
+/// * `last_played_period` - Period of the player's last settled game
+/// * `current_period` - Period the new ticket is for
+/// * `decay_periods` - Skipped periods a streak survives (0 = unlimited)
+///
+/// # Returns
+/// `true` if more than `decay_periods` periods lie between the two; free-form
+/// period IDs or IDs of different period types never decay
+pub fn is_streak_decayed(last_played_period: &str, current_period: &str, decay_periods: u32) -> bool {
+    if decay_periods == 0 {
+        return false;
+    }
+
+    let (Some((last_type, last)), Some((current_type, current))) = (
+        parse_period_id(last_played_period),
+        parse_period_id(current_period),
+    ) else {
+        return false;
+    };
+    if last_type != current_type {
+        return false;
+    }
+
+    let skipped = current.saturating_sub(last).saturating_sub(1);
+    skipped > decay_periods as u64
+}
+
+/// Whether a participant cap is in force (u32::MAX and legacy 0 mean uncapped)
+pub fn is_participant_cap_set(max_participants: u32) -> bool {
+    max_participants != 0 && max_participants != u32::MAX
+}
+
+/// Check that a period has room for one more entrant
+///
+/// # Arguments
+/// * `entrants` - Distinct players who already bought a ticket for the period
+/// * `max_participants` - Configured `max_participants_per_period`
+pub fn check_period_capacity(entrants: u32, max_participants: u32) -> Result<()> {
+    require!(
+        !is_participant_cap_set(max_participants) || entrants < max_participants,
+        VobleError::PeriodFull
+    );
+    Ok(())
+}
+
+/// Count a ticket buyer against the period's participant cap
+///
+/// # Arguments
+/// * `entrants` - The period leaderboard's entrant counter
+/// * `is_new_entrant` - Whether this is the player's first ticket in the period
+/// * `max_participants` - Configured `max_participants_per_period`
+///
+/// # Returns
+/// `PeriodFull` if a new entrant would exceed the cap; players already in
+/// the period are always admitted and not counted again
+pub fn admit_to_period(entrants: &mut u32, is_new_entrant: bool, max_participants: u32) -> Result<()> {
+    if is_new_entrant {
+        check_period_capacity(*entrants, max_participants)?;
+        *entrants = entrants.saturating_add(1);
+    }
+    Ok(())
+}
+
+/// Take a player's active session slot
+///
+/// # Arguments
+/// * `active_sessions` - The session counter
+/// * `max_active_sessions` - Configured cap
+/// * `holds_slot` - The profile's `holds_session_slot` flag; a player who
+///   already holds a slot keeps it without being counted again
+///
+/// # Returns
+/// `TooManyActiveSessions` if a new slot would exceed the cap
+pub fn reserve_active_session(
+    active_sessions: &mut u32,
+    max_active_sessions: u32,
+    holds_slot: &mut bool,
+) -> Result<()> {
+    if *holds_slot {
+        return Ok(());
+    }
+
+    require!(
+        *active_sessions < max_active_sessions,
+        VobleError::TooManyActiveSessions
+    );
+    *active_sessions = active_sessions.saturating_add(1);
+    *holds_slot = true;
+    Ok(())
+}
+
+/// Give back a player's slot
+///
+/// # Returns
+/// Whether the counter was decremented (false if the player held no slot)
+pub fn release_active_session(active_sessions: &mut u32, holds_slot: &mut bool) -> bool {
+    if !*holds_slot {
+        return false;
+    }
+
+    *active_sessions = active_sessions.saturating_sub(1);
+    *holds_slot = false;
+    true
+}
+
+/// Take the player's slot in the session counter and log the new count
+///
+/// # Arguments
+/// * `counter` - The session counter (created on first use)
+/// * `counter_bump` - Canonical bump of the counter PDA
+/// * `profile` - The player's profile
+/// * `max_active_sessions` - Configured cap
+pub fn take_session_slot(
+    counter: &mut SessionCounter,
+    counter_bump: u8,
+    profile: &mut UserProfile,
+    max_active_sessions: u32,
+) -> Result<()> {
+    counter.bump = counter_bump;
+    if profile.holds_session_slot {
+        return Ok(());
+    }
+
+    reserve_active_session(
+        &mut counter.active_sessions,
+        max_active_sessions,
+        &mut profile.holds_session_slot,
+    )?;
+
+    msg!("🚦 Active sessions: {}/{}", counter.active_sessions, max_active_sessions);
+
+    emit!(ActiveSessionsChanged {
+        player: profile.player,
+        holds_slot: true,
+        active_sessions: counter.active_sessions,
+    });
+
+    Ok(())
+}
+
+/// Give back the player's slot in a counter passed to a handler unchecked
+///
+/// # Arguments
+/// * `counter` - The session counter PDA (seeds checked by the context)
+/// * `profile` - The player's profile
+///
+/// # Returns
+/// Whether a slot was freed; a counter that doesn't exist yet means no slot
+/// was ever taken
+pub fn release_profile_slot(counter: &AccountInfo, profile: &mut UserProfile) -> Result<bool> {
+    if counter.owner != &crate::ID || counter.data_is_empty() {
+        return Ok(false);
+    }
+
+    let mut data = counter.try_borrow_mut_data()?;
+    let mut session_counter = SessionCounter::try_deserialize(&mut &data[..])?;
+    if !release_active_session(&mut session_counter.active_sessions, &mut profile.holds_session_slot) {
+        return Ok(false);
+    }
+    session_counter.try_serialize(&mut &mut data[..])?;
+
+    msg!("🚦 Session slot released for {}", profile.player);
+    msg!("   Active sessions: {}", session_counter.active_sessions);
+
+    emit!(ActiveSessionsChanged {
+        player: profile.player,
+        holds_slot: false,
+        active_sessions: session_counter.active_sessions,
+    });
+
+    Ok(true)
+}
+
+/// Record a ticket on the player's participation marker for the period
+///
+/// # Returns
+/// `true` for the player's first ticket in the period (the marker was just
+/// created), `false` for every later one
+pub fn record_participation(
+    participation: &mut PlayerParticipation,
+    player: Pubkey,
+    period_id: &str,
+    now: i64,
+    bump: u8,
+) -> bool {
+    let is_first = participation.tickets == 0;
+    if is_first {
+        participation.player = player;
+        participation.period_id = period_id.to_string();
+        participation.first_ticket_at = now;
+        participation.bump = bump;
+    }
+    participation.tickets = participation.tickets.saturating_add(1);
+    is_first
+}
+
+/// Add a ticket's pool shares to the player's receipt for the period
+///
+/// # Arguments
+/// * `receipt` - The player's receipt for `period_id` (zeroed when just created)
+/// * `pool_amounts` - The ticket's daily, weekly and monthly shares
+pub fn record_ticket_receipt(
+    receipt: &mut TicketReceipt,
+    player: Pubkey,
+    period_id: &str,
+    pool_amounts: [u64; 3],
+    bump: u8,
+) {
+    if receipt.bump == 0 {
+        receipt.player = player;
+        receipt.period_id = period_id.to_string();
+        receipt.bump = bump;
+    }
+    for (total, amount) in receipt.contributions.iter_mut().zip(pool_amounts) {
+        *total = total.saturating_add(amount);
+    }
+}
+
+/// Resolve the requested ER commit frequency
+///
+/// # Returns
+/// The requested value, DEFAULT_COMMIT_FREQUENCY_MS if none was given, or
+/// `InvalidCommitFrequency` outside MIN..=MAX_COMMIT_FREQUENCY_MS
+pub fn resolve_commit_frequency(commit_frequency_ms: Option<u32>) -> Result<u32> {
+    let commit_frequency_ms = commit_frequency_ms.unwrap_or(DEFAULT_COMMIT_FREQUENCY_MS);
+    require!(
+        (MIN_COMMIT_FREQUENCY_MS..=MAX_COMMIT_FREQUENCY_MS).contains(&commit_frequency_ms),
+        VobleError::InvalidCommitFrequency
+    );
+    Ok(commit_frequency_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_data(player: Pubkey) -> Vec<u8> {
+        let session = SessionAccount::default_for_test(player);
+
+        let mut data = Vec::new();
+        session.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_delegate_before_initialize_rejected() {
+        let payer = Pubkey::new_unique();
+        let system_program = anchor_lang::system_program::ID;
+
+        // PDA not created yet: system-owned, no data
+        assert_eq!(
+            load_initialized_session(&system_program, &[], &payer).err().unwrap(),
+            VobleError::SessionNotInitialized.into()
+        );
+        // Program-owned but never written
+        assert_eq!(
+            load_initialized_session(&crate::ID, &[0; 64], &payer).err().unwrap(),
+            VobleError::SessionNotInitialized.into()
+        );
+    }
+
+    #[test]
+    fn test_delegate_initialized_session() {
+        let payer = Pubkey::new_unique();
+        let data = session_data(payer);
+
+        assert_eq!(
+            load_initialized_session(&crate::ID, &data, &payer).unwrap().player,
+            payer
+        );
+        assert_eq!(
+            load_initialized_session(&crate::ID, &data, &Pubkey::new_unique()).err().unwrap(),
+            VobleError::SessionNotInitialized.into()
+        );
+    }
+
+    #[test]
+    fn test_commit_frequency_defaults_and_bounds() {
+        assert_eq!(resolve_commit_frequency(None).unwrap(), DEFAULT_COMMIT_FREQUENCY_MS);
+        assert_eq!(resolve_commit_frequency(Some(5_000)).unwrap(), 5_000);
+        assert_eq!(resolve_commit_frequency(Some(60_000)).unwrap(), 60_000);
+        assert!(resolve_commit_frequency(Some(4_999)).is_err());
+        assert!(resolve_commit_frequency(Some(60_001)).is_err());
+    }
+
+    #[test]
+    fn test_purchase_within_cooldown_rejected() {
+        assert!(is_ticket_cooldown_active(1_000, 1_000, 60));
+        assert!(is_ticket_cooldown_active(1_000, 1_059, 60));
+    }
+
+    #[test]
+    fn test_purchase_after_cooldown_allowed() {
+        assert!(!is_ticket_cooldown_active(1_000, 1_060, 60)); // Exactly at boundary
+        assert!(!is_ticket_cooldown_active(1_000, 5_000, 60));
+        assert!(!is_ticket_cooldown_active(0, 1_700_000_000, 60)); // First purchase
+    }
+
+    #[test]
+    fn test_entrant_over_cap_rejected() {
+        let cap = 3;
+        let mut entrants = 0;
+
+        for _ in 0..cap {
+            admit_to_period(&mut entrants, true, cap).unwrap();
+        }
+        assert_eq!(entrants, cap);
+
+        assert_eq!(
+            admit_to_period(&mut entrants, true, cap).unwrap_err(),
+            VobleError::PeriodFull.into()
+        );
+        assert_eq!(entrants, cap);
+    }
+
+    #[test]
+    fn test_existing_players_unaffected_by_full_period() {
+        // Settlement never reads the cap; a player already in a full
+        // period can still buy their next game
+        let mut entrants = 3;
+
+        admit_to_period(&mut entrants, false, 3).unwrap();
+        assert_eq!(entrants, 3);
+    }
+
+    #[test]
+    fn test_uncapped_by_default() {
+        assert!(check_period_capacity(u32::MAX - 1, u32::MAX).is_ok());
+        assert!(check_period_capacity(1_000_000, 0).is_ok()); // Legacy config
+        assert!(!is_participant_cap_set(u32::MAX));
+        assert!(is_participant_cap_set(100));
+    }
+
+    #[test]
+    fn test_cooldown_disabled_by_default() {
+        assert!(!is_ticket_cooldown_active(1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn test_active_sessions_balance() {
+        let mut active_sessions = 0;
+        let mut holds_slot = [false; 5];
+
+        // Delegating and buying the first ticket both reserve; only one counts
+        for flag in holds_slot.iter_mut() {
+            reserve_active_session(&mut active_sessions, 5, flag).unwrap();
+            reserve_active_session(&mut active_sessions, 5, flag).unwrap();
+        }
+        assert_eq!(active_sessions, 5);
+
+        // Settling the game frees the slot, then undelegation finds nothing to free
+        for flag in holds_slot.iter_mut() {
+            assert!(release_active_session(&mut active_sessions, flag));
+            assert!(!release_active_session(&mut active_sessions, flag));
+        }
+        assert_eq!(active_sessions, 0);
+    }
+
+    #[test]
+    fn test_full_validator_rejects_delegation() {
+        let mut active_sessions = 2;
+        let mut holds_slot = false;
+
+        assert_eq!(
+            reserve_active_session(&mut active_sessions, 2, &mut holds_slot).unwrap_err(),
+            VobleError::TooManyActiveSessions.into()
+        );
+        assert_eq!(active_sessions, 2);
+        assert!(!holds_slot);
+
+        // A player who still holds their slot can play on when full
+        holds_slot = true;
+        reserve_active_session(&mut active_sessions, 2, &mut holds_slot).unwrap();
+        assert_eq!(active_sessions, 2);
+    }
+
+    #[test]
+    fn test_next_game_retakes_settled_slot() {
+        let mut active_sessions = 1;
+        let mut holds_slot = true;
+
+        // The session stays delegated between games, but a settled game
+        // no longer counts against the cap
+        assert!(release_active_session(&mut active_sessions, &mut holds_slot));
+        assert_eq!(active_sessions, 0);
+
+        reserve_active_session(&mut active_sessions, 1, &mut holds_slot).unwrap();
+        assert_eq!(active_sessions, 1);
+        assert!(holds_slot);
+    }
+
+    #[test]
+    fn test_single_handler_uses_configured_budget() {
+        let leaderboards = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let (profile, session, counter) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let plans = plan_stats_handlers(650_000, 2, false, leaderboards, profile, session, counter);
+
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].compute_units, 650_000);
+        assert_eq!(plans[0].escrow_index, 2);
+        assert_eq!(
+            plans[0].data,
+            anchor_lang::InstructionData::data(&crate::instruction::UpdatePlayerStats {})
+        );
+        assert_eq!(
+            plans[0].accounts,
+            vec![
+                (leaderboards[0], true),
+                (leaderboards[1], true),
+                (leaderboards[2], true),
+                (profile, true),
+                (session, false),
+                (counter, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_handlers_share_configured_budget() {
+        let leaderboards = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let (profile, session, counter) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+
+        let plans = plan_stats_handlers(300_000, 1, true, leaderboards, profile, session, counter);
+
+        assert_eq!(plans.len(), 2);
+        for plan in &plans {
+            assert_eq!(plan.compute_units, 300_000);
+            assert_eq!(plan.escrow_index, 1);
+        }
+        assert_eq!(
+            plans[0].data,
+            anchor_lang::InstructionData::data(&crate::instruction::UpdateLeaderboardStats {})
+        );
+        // Leaderboard handler only reads the profile
+        assert_eq!(plans[0].accounts[3], (profile, false));
+        assert_eq!(
+            plans[1].data,
+            anchor_lang::InstructionData::data(&crate::instruction::UpdateProfileStats {})
+        );
+        assert_eq!(plans[1].accounts, vec![(profile, true), (session, false), (counter, true)]);
+    }
+
+    #[test]
+    fn test_repeat_tickets_count_player_once() {
+        let player = Pubkey::new_unique();
+        let mut participation = PlayerParticipation {
+            player: Pubkey::default(),
+            period_id: String::new(),
+            first_ticket_at: 0,
+            tickets: 0,
+            bump: 0,
+        };
+        let mut entrants = 0;
+
+        for (i, now) in [1_000, 2_000, 3_000].into_iter().enumerate() {
+            let is_new = record_participation(&mut participation, player, "D700", now, 254);
+            assert_eq!(is_new, i == 0);
+            admit_to_period(&mut entrants, is_new, u32::MAX).unwrap();
+        }
+
+        assert_eq!(entrants, 1);
+        assert_eq!(participation.tickets, 3);
+        assert_eq!(participation.first_ticket_at, 1_000);
+        assert_eq!(participation.player, player);
+        assert_eq!(participation.period_id, "D700");
+    }
+
+    #[test]
+    fn test_double_undelegation_decrements_once() {
+        let mut active_sessions = 3;
+        let mut holds_slot = true;
+
+        assert!(release_active_session(&mut active_sessions, &mut holds_slot));
+        assert!(!release_active_session(&mut active_sessions, &mut holds_slot));
+        assert_eq!(active_sessions, 2);
+    }
+
+    #[test]
+    fn test_streak_resets_after_skipped_periods() {
+        // Last played D10; D11..D13 skipped when buying for D14
+        assert!(!is_streak_decayed("D10", "D14", 3));
+        assert!(is_streak_decayed("D10", "D15", 3));
+
+        // Consecutive days never decay
+        assert!(!is_streak_decayed("D10", "D11", 1));
+        assert!(is_streak_decayed("D10", "D13", 1));
+    }
+
+    #[test]
+    fn test_streak_decay_unlimited_by_default() {
+        assert!(!is_streak_decayed("D10", "D400", 0));
+
+        // Never played, free-form or mismatched IDs keep the streak
+        assert!(!is_streak_decayed("", "D400", 1));
+        assert!(!is_streak_decayed("legacy", "D400", 1));
+        assert!(!is_streak_decayed("W1", "D400", 1));
+    }
+}
@@ -1,8 +1,25 @@
+use crate::utils::debug::{self, ComputeMeter};
+use crate::utils::validate_guess;
+use crate::utils::words;
 use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
 use anchor_lang::prelude::*;
 
 // Import helper modules
-use super::{scoring, word_selection};
+use super::{scoring, session_state, transcript};
+use crate::instructions::admin::require_period_open;
+
+/// Outcome of a single guess, returned to the caller as instruction return data
+///
+/// Borsh layout (9 bytes): the 6 letter results in order (one byte each,
+/// Correct = 0, Present = 1, Absent = 2), then `is_solved`, `guesses_used`
+/// and `game_over` (one byte each).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GuessOutcome {
+    pub result: [LetterResult; WORD_LENGTH],
+    pub is_solved: bool,
+    pub guesses_used: u8, // Including this guess
+    pub game_over: bool,  // Solved or out of guesses; the session was auto-completed
+}
 
 /// Submit a guess for the current Voble game
 ///
@@ -15,10 +32,12 @@ use super::{scoring, word_selection};
 /// # Arguments
 /// * `ctx` - The context containing session and user profile
 /// * `_period_id` - Period ID (used for PDA derivation, prefixed with _ as not used in logic)
-/// * `guess` - The 6-letter word guess (will be converted to uppercase)
+/// * `guess` - The 6-letter word guess (will be converted to uppercase), or
+///   `None` to submit the typing buffer (`current_input`)
 ///
 /// # Validation
-/// - Guess must be exactly 6 characters
+/// - Guess must be exactly 6 ASCII letters (`NonAsciiGuess` for accented or non-Latin letters)
+/// - Without an explicit guess the typing buffer must be full (`InvalidGuessLength`)
 /// - Game must not be completed
 /// - Must have guesses remaining (< 7 guesses used)
 /// - Word must have been selected (word_index valid)
@@ -29,6 +48,11 @@ use super::{scoring, word_selection};
 /// - If no session token: Requires player signature
 /// - Enables smooth UX without constant wallet popups
 ///
+/// # Returns
+/// The guess's `GuessOutcome`, also set as return data so a client reads the
+/// colours straight from the transaction (or its simulation) without parsing
+/// logs. `GuessSubmitted` is still emitted for indexers.
+///
 /// # Game Flow
 /// 1. Validate guess format and game state
 /// 2. Get target word from word list
@@ -56,41 +80,64 @@ use super::{scoring, word_selection};
 ///
 /// # Notes
 /// - Guesses are stored in a fixed-size array (no Vec reallocation)
+/// - Only the guessed letters are stored; results come from `GuessData::results`
 /// - Session account holds up to 7 guesses
-/// - Game doesn't auto-complete - player must call complete_voble_game
-pub fn submit_guess(ctx: Context<SubmitGuess>, _period_id: String, guess: String) -> Result<()> {
-    // ========== VALIDATION: Guess Format ==========
-    require!(guess.len() == WORD_LENGTH, VobleError::InvalidScore);
+/// - Rejected with `PeriodPaused` while the period's daily leaderboard is paused
+/// - The guess that ends the game completes it here (see `complete_session`);
+///   there is no separate completion instruction
+///
+/// # Typing Buffer
+/// `current_input` is built by `record_keystroke` (or replaced by
+/// `retype_current_guess`) and belongs to guess `input_guess_index`.
+/// Keystrokes are an audit log only; the buffer is never rebuilt from them.
+/// Storing a guess moves the buffer on to the next guess (see
+/// `advance_input_buffer`), whichever way the guess was passed.
+pub fn submit_guess(
+    ctx: Context<SubmitGuess>,
+    _period_id: String,
+    guess: Option<String>,
+) -> Result<GuessOutcome> {
+    let mut meter = ComputeMeter::start("submit_guess");
+
+    // ========== VALIDATION: Ownership ==========
+    session_state::require_session_authority(
+        &ctx.accounts.user_profile,
+        &ctx.accounts.signer.key(),
+    )?;
 
+    let char_class = ctx.accounts.global_config.char_class;
     let session = &mut ctx.accounts.session;
 
+    // ========== VALIDATION: Guess Format ==========
+    // Six ASCII characters of the config's class - nothing else is in the word list
+    let guess = resolve_guess(guess, &session.current_input)?;
+    validate_guess(&guess, char_class)?;
+
     msg!("📝 Submitting guess for session: {}", session.session_id);
     msg!("   Guess: {}", guess);
     msg!("   Attempt: {}/{}", session.guesses_used + 1, MAX_GUESSES);
 
     // ========== VALIDATION: Game State ==========
-    require!(!session.completed, VobleError::AlreadyClaimed);
-    require!(
-        session.guesses_used < MAX_GUESSES,
-        VobleError::InvalidGuessCount
-    );
+    require_guess_allowed(session)?;
+    require_period_open(&ctx.accounts.daily_leaderboard)?;
     require!(
-        session.word_index < word_selection::get_word_count() as u32,
+        session.word_index < words::get_word_count() as u32,
         VobleError::InvalidPeriodState
     );
 
     // ========== GET TARGET WORD ==========
-    let target_word = word_selection::get_word_by_index(session.word_index)?;
+    let target_word = words::get_word_by_index(session.word_index)?;
     let target_word_string = target_word.to_string();
 
     msg!("🎯 Evaluating guess against target");
 
     // ========== EVALUATE GUESS ==========
     let guess_upper = guess.to_uppercase();
-    let result = scoring::evaluate_guess(&guess_upper, target_word);
+    let result = words::evaluate_guess(&guess_upper, target_word);
 
     // Check if all letters are correct (word is solved)
     let is_correct = result.iter().all(|&r| matches!(r, LetterResult::Correct));
+    meter.checkpoint("evaluate");
 
     if is_correct {
         session.is_solved = true;
@@ -98,14 +145,13 @@ pub fn submit_guess(ctx: Context<SubmitGuess>, _period_id: String, guess: String
     }
 
     // ========== STORE GUESS ==========
-    let guess_data = GuessData {
-        guess: guess_upper.clone(),
-        result,
-    };
+    // Only the letters are kept; the result is re-derived from word_index on read
+    let guess_data = GuessData::new(&guess_upper)?;
 
     let guess_index = session.guesses_used as usize;
     session.guesses[guess_index] = Some(guess_data);
     session.guesses_used += 1;
+    advance_input_buffer(session);
 
     msg!(
         "✅ Guess stored (attempt {}/{})",
@@ -122,6 +168,7 @@ pub fn submit_guess(ctx: Context<SubmitGuess>, _period_id: String, guess: String
         guess_number: session.guesses_used,
         is_correct,
         result,
+        game_epoch: session.game_epoch,
     });
 
     // ========== AUTO-COMPLETE GAME ==========
@@ -130,25 +177,15 @@ pub fn submit_guess(ctx: Context<SubmitGuess>, _period_id: String, guess: String
     if game_ended {
         msg!("🏁 Game ended - auto-completing on ER");
         
-        // Calculate final score
         let now = Clock::get()?.unix_timestamp;
-        let time_elapsed = (now - session.vrf_request_timestamp) as u64 * 1000; // Convert to milliseconds
-        session.time_ms = time_elapsed;
-        
-        // Use the scoring module to calculate final score
-        let final_score = super::scoring::calculate_final_score(
-            session.is_solved,
-            session.guesses_used,
-            session.time_ms
-        );
-        session.score = final_score;
-        session.completed = true;
-        session.target_word = target_word_string;
+        let final_score = complete_session(session, now, target_word_string)?;
         
         msg!("   Final score: {}", final_score);
-        msg!("   Time: {}ms", time_elapsed);
+        msg!("   Time: {}ms", session.time_ms);
         msg!("   ✅ Game auto-completed on ER");
+        meter.checkpoint("complete session");
     }
+    debug::log_session(session);
 
     // ========== GAME STATUS LOGGING ==========
     if is_correct {
@@ -169,5 +206,292 @@ pub fn submit_guess(ctx: Context<SubmitGuess>, _period_id: String, guess: String
     msg!("   🟨 Present: Letter in word, wrong position");
     msg!("   ⬜ Absent: Letter not in word");
 
+    Ok(GuessOutcome {
+        result,
+        is_solved: is_correct,
+        guesses_used: session.guesses_used,
+        game_over: game_ended,
+    })
+}
+
+/// Pick the word a `submit_guess` call evaluates
+///
+/// # Arguments
+/// * `guess` - Guess passed to the instruction, if any
+/// * `current_input` - The session's typing buffer
+///
+/// # Returns
+/// The explicit guess if given, otherwise the buffer once it holds a full
+/// word (`InvalidGuessLength` while it's still being typed)
+pub fn resolve_guess(guess: Option<String>, current_input: &str) -> Result<String> {
+    match guess {
+        Some(guess) => Ok(guess),
+        None => {
+            require!(
+                current_input.len() == WORD_LENGTH,
+                VobleError::InvalidGuessLength
+            );
+            Ok(current_input.to_string())
+        }
+    }
+}
+
+/// Move the typing buffer on to the guess after the one just stored
+///
+/// A buffer already advanced by Enter is left alone; otherwise the submitted
+/// word (or a partly typed one, when the guess was passed explicitly) is
+/// cleared so the next keystrokes start the next guess.
+pub fn advance_input_buffer(session: &mut SessionAccount) {
+    if session.input_guess_index < session.guesses_used {
+        session.current_input.clear();
+        session.input_guess_index = session.guesses_used;
+    }
+}
+
+/// Check that the session's game can still take a guess
+///
+/// # Validation
+/// - The game must be `InProgress`; once the final guess auto-completes it,
+///   the session is `CompletedUnsettled` and further guesses are rejected
+///   with `InvalidSessionState`
+/// - `completed` must not be set (same rule, for sessions whose state and
+///   flag disagree)
+/// - Fewer than MAX_GUESSES guesses may have been used
+pub fn require_guess_allowed(session: &SessionAccount) -> Result<()> {
+    session_state::require_state(session, SessionState::InProgress)?;
+    require!(!session.completed, VobleError::InvalidSessionState);
+    require!(
+        session.guesses_used < MAX_GUESSES,
+        VobleError::InvalidGuessCount
+    );
     Ok(())
 }
+
+/// Score a finished game and hand it over to settlement
+///
+/// Completion only touches the session: it records the time and final
+/// score, reveals the word and moves the session to `CompletedUnsettled`.
+/// Profiles and leaderboards are updated once, at settlement, by the
+/// `update_player_stats` Magic Actions handler after
+/// `commit_and_update_stats` marks the session `Settled`.
+///
+/// # Validation
+/// - At least one guess must have been made (`InvalidGuessCount` otherwise)
+///
+/// # Returns
+/// The final score (difficulty, happy hour multiplier and hole in one bonus applied)
+pub fn complete_session(
+    session: &mut SessionAccount,
+    now: i64,
+    target_word: String,
+) -> Result<u32> {
+    require!(session.guesses_used > 0, VobleError::InvalidGuessCount);
+    session_state::transition(session, SessionState::CompletedUnsettled)?;
+    session.time_ms = (now - session.vrf_request_timestamp) as u64 * 1000; // Convert to milliseconds
+
+    let final_score = scoring::score_game(
+        session.is_solved,
+        session.guesses_used,
+        session.time_ms,
+        session.word_difficulty,
+        session.score_multiplier_bps,
+        session.hole_in_one_bonus,
+    );
+
+    session.score = final_score;
+    session.completed = true;
+    session.target_word = target_word;
+    session.transcript_hash = transcript::transcript_hash(session)?;
+
+    Ok(final_score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STARTED_AT: i64 = 1_700_000_000;
+
+    fn in_progress_session() -> SessionAccount {
+        let mut session = SessionAccount::default_for_test(Pubkey::new_unique());
+        session.session_id = "session".to_string();
+        session.is_solved = true;
+        session.guesses_used = 3;
+        session.period_id = "D42".to_string();
+        session.vrf_request_timestamp = STARTED_AT;
+        session.version = SESSION_ACCOUNT_VERSION;
+        session.game_epoch = 1;
+        session.state = SessionState::InProgress;
+        session.score_multiplier_bps = SCORE_MULTIPLIER_NONE;
+        session.bump = 255;
+        session.word_difficulty = DEFAULT_WORD_DIFFICULTY;
+        session
+    }
+
+    #[test]
+    fn test_completion_scores_and_awaits_settlement() {
+        let mut session = in_progress_session();
+
+        let score = complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+
+        // 3 guesses (600) + under 30 seconds (500), neutral difficulty and multiplier
+        assert_eq!(score, 1_100);
+        assert_eq!(session.score, 1_100);
+        assert_eq!(session.time_ms, 20_000);
+        assert!(session.completed);
+        assert_eq!(session.target_word, "PLANET");
+        assert_eq!(session.current_state(), SessionState::CompletedUnsettled);
+    }
+
+    #[test]
+    fn test_game_completes_once() {
+        let mut session = in_progress_session();
+        complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+
+        assert_eq!(
+            complete_session(&mut session, STARTED_AT + 40, "PLANET".to_string()).unwrap_err(),
+            VobleError::InvalidSessionState.into()
+        );
+        assert_eq!(session.score, 1_100);
+    }
+
+    #[test]
+    fn test_guess_rejected_after_auto_completion() {
+        let mut session = in_progress_session();
+        assert!(require_guess_allowed(&session).is_ok());
+
+        // The solving guess completes the game
+        complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+
+        assert_eq!(
+            require_guess_allowed(&session).unwrap_err(),
+            VobleError::InvalidSessionState.into()
+        );
+    }
+
+    #[test]
+    fn test_guess_rejected_when_flags_disagree() {
+        let mut session = in_progress_session();
+        session.completed = true;
+        assert_eq!(
+            require_guess_allowed(&session).unwrap_err(),
+            VobleError::InvalidSessionState.into()
+        );
+
+        let mut session = in_progress_session();
+        session.guesses_used = MAX_GUESSES;
+        assert_eq!(
+            require_guess_allowed(&session).unwrap_err(),
+            VobleError::InvalidGuessCount.into()
+        );
+    }
+
+    #[test]
+    fn test_completion_requires_a_guess() {
+        let mut session = in_progress_session();
+        session.is_solved = false;
+        session.guesses_used = 0;
+
+        assert_eq!(
+            complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap_err(),
+            VobleError::InvalidGuessCount.into()
+        );
+        assert_eq!(session.current_state(), SessionState::InProgress);
+        assert!(!session.completed);
+    }
+
+    #[test]
+    fn test_hole_in_one_bonus_applied_on_completion() {
+        let mut session = in_progress_session();
+        session.guesses_used = 1;
+        session.hole_in_one_bonus = 500;
+
+        let score = complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+
+        // 1 guess (1000) + under 30 seconds (500) + bonus
+        assert_eq!(score, 2_000);
+
+        let mut session = in_progress_session();
+        session.hole_in_one_bonus = 500;
+        let score = complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+        assert_eq!(score, 1_100); // 3 guesses get nothing extra
+    }
+
+    #[test]
+    fn test_guess_outcome_return_data_layout() {
+        let outcome = GuessOutcome {
+            result: [
+                LetterResult::Correct,
+                LetterResult::Present,
+                LetterResult::Absent,
+                LetterResult::Absent,
+                LetterResult::Present,
+                LetterResult::Correct,
+            ],
+            is_solved: false,
+            guesses_used: 3,
+            game_over: false,
+        };
+
+        let mut data = Vec::new();
+        outcome.serialize(&mut data).unwrap();
+
+        assert_eq!(data, vec![0, 1, 2, 2, 1, 0, 0, 3, 0]);
+        assert_eq!(GuessOutcome::try_from_slice(&data).unwrap(), outcome);
+    }
+
+    #[test]
+    fn test_completion_records_transcript() {
+        let mut session = in_progress_session();
+        session.guesses[0] = Some(GuessData::new("ZZZZZZ").unwrap());
+        session.guesses[1] = Some(GuessData::new("YYYYYY").unwrap());
+        session.guesses[2] = Some(GuessData::new("XXXXXX").unwrap());
+
+        complete_session(&mut session, STARTED_AT + 20, "PLANET".to_string()).unwrap();
+
+        assert_ne!(session.transcript_hash, [0; 32]);
+        assert!(transcript::verify_transcript(&session).is_ok());
+
+        // Rewriting a guess after completion is caught at settlement
+        session.guesses[1] = Some(GuessData::new("WWWWWW").unwrap());
+        assert_eq!(
+            transcript::verify_transcript(&session).unwrap_err(),
+            VobleError::TranscriptMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_buffer_submitted_when_no_guess_passed() {
+        assert_eq!(resolve_guess(None, "CRANES").unwrap(), "CRANES");
+        assert_eq!(resolve_guess(Some("garden".to_string()), "CRANES").unwrap(), "garden");
+
+        // A buffer still being typed can't be submitted
+        assert_eq!(
+            resolve_guess(None, "CRAN").unwrap_err(),
+            VobleError::InvalidGuessLength.into()
+        );
+        assert_eq!(
+            resolve_guess(None, "").unwrap_err(),
+            VobleError::InvalidGuessLength.into()
+        );
+    }
+
+    #[test]
+    fn test_stored_guess_advances_buffer() {
+        // Buffer typed for guess 3 and submitted without Enter
+        let mut session = in_progress_session();
+        session.guesses_used = 3;
+        session.input_guess_index = 2;
+        session.current_input = "CRANES".to_string();
+
+        advance_input_buffer(&mut session);
+        assert_eq!(session.current_input, "");
+        assert_eq!(session.input_guess_index, 3);
+
+        // Enter already moved the buffer on and the next guess is being typed
+        session.current_input = "GA".to_string();
+        advance_input_buffer(&mut session);
+        assert_eq!(session.current_input, "GA");
+        assert_eq!(session.input_guess_index, 3);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::constants::WORD_LENGTH;
+use crate::errors::VobleError;
+use crate::state::{GuessData, SessionAccount};
+use anchor_lang::prelude::*;
+use solana_program::hash::hash;
+
+/// Bytes a single guess contributes to a transcript
+pub const TRANSCRIPT_ENTRY_LEN: usize = WORD_LENGTH * 2;
+
+/// Canonical serialization of a game's guesses
+///
+/// # Arguments
+/// * `guesses` - The session's guess slots
+/// * `guesses_used` - Guesses submitted; later slots are ignored
+/// * `word_index` - Target word the results are derived against
+///
+/// # Returns
+/// For every submitted guess in order, its 6 uppercase ASCII letters
+/// followed by one byte per letter result (Correct = 0, Present = 1,
+/// Absent = 2). Empty slots contribute nothing.
+pub fn transcript_bytes(
+    guesses: &[Option<GuessData>],
+    guesses_used: u8,
+    word_index: u32,
+) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(guesses_used as usize * TRANSCRIPT_ENTRY_LEN);
+    for guess in guesses.iter().take(guesses_used as usize).flatten() {
+        bytes.extend_from_slice(&guess.guess);
+        bytes.extend(guess.results(word_index)?.iter().map(|&result| result as u8));
+    }
+    Ok(bytes)
+}
+
+/// sha256 of a session's canonical transcript
+pub fn transcript_hash(session: &SessionAccount) -> Result<[u8; 32]> {
+    let bytes = transcript_bytes(&session.guesses, session.guesses_used, session.word_index)?;
+    Ok(hash(&bytes).to_bytes())
+}
+
+/// Check a committed session's guesses against the hash stored at completion
+///
+/// # Validation
+/// - The recomputed hash must equal `transcript_hash` (`TranscriptMismatch`);
+///   sessions completed before transcripts were recorded (zero hash) pass
+pub fn verify_transcript(session: &SessionAccount) -> Result<()> {
+    if session.transcript_hash == [0; 32] {
+        return Ok(());
+    }
+
+    require!(
+        transcript_hash(session)? == session.transcript_hash,
+        VobleError::TranscriptMismatch
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::words::get_word_by_index;
+    use crate::state::LetterResult;
+
+    const WORD_INDEX: u32 = 0;
+
+    fn guesses(words: &[&str]) -> [Option<GuessData>; 7] {
+        let mut guesses: [Option<GuessData>; 7] = Default::default();
+        for (slot, word) in guesses.iter_mut().zip(words) {
+            *slot = Some(GuessData::new(word).unwrap());
+        }
+        guesses
+    }
+
+    #[test]
+    fn test_empty_transcript() {
+        let bytes = transcript_bytes(&guesses(&[]), 0, WORD_INDEX).unwrap();
+
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn test_partial_transcript_layout() {
+        let target = get_word_by_index(WORD_INDEX).unwrap();
+        let slots = guesses(&["ZZZZZZ", target]);
+
+        let bytes = transcript_bytes(&slots, 2, WORD_INDEX).unwrap();
+
+        assert_eq!(bytes.len(), 2 * TRANSCRIPT_ENTRY_LEN);
+        assert_eq!(&bytes[..6], b"ZZZZZZ");
+        assert_eq!(&bytes[12..18], target.as_bytes());
+        assert_eq!(&bytes[18..], &[LetterResult::Correct as u8; 6]);
+    }
+
+    #[test]
+    fn test_slots_past_guesses_used_ignored() {
+        let slots = guesses(&["ZZZZZZ", "YYYYYY"]);
+
+        assert_eq!(
+            transcript_bytes(&slots, 1, WORD_INDEX).unwrap(),
+            transcript_bytes(&guesses(&["ZZZZZZ"]), 1, WORD_INDEX).unwrap()
+        );
+        assert_ne!(
+            transcript_bytes(&slots, 2, WORD_INDEX).unwrap(),
+            transcript_bytes(&slots, 1, WORD_INDEX).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_guess_order_matters() {
+        let forward = transcript_bytes(&guesses(&["ZZZZZZ", "YYYYYY"]), 2, WORD_INDEX).unwrap();
+        let reversed = transcript_bytes(&guesses(&["YYYYYY", "ZZZZZZ"]), 2, WORD_INDEX).unwrap();
+
+        assert_ne!(hash(&forward), hash(&reversed));
+    }
+}
@@ -1,168 +1,1255 @@
 use anchor_lang::prelude::*;
+use crate::constants::{
+    MAX_HOLE_IN_ONE_BONUS, PERIOD_DAILY_DURATION, SEED_LEADERBOARD, SEED_SESSION,
+    STREAK_MILESTONES,
+};
 use crate::contexts::*;
+use crate::errors::VobleError;
+use crate::events::*;
+use crate::instructions::game::release_profile_slot;
+use super::{scoring, transcript};
 use crate::state::*;
+use crate::instructions::leaderboard::{insert_sorted, reposition_sorted};
+use crate::utils::debug::{self, ComputeMeter};
+use crate::utils::period::{self, daily_period_mask};
+use crate::utils::validation;
 
 /// Magic Actions handler - runs on base layer after session commit
 /// Updates leaderboard automatically when game is completed
 pub fn update_player_stats(ctx: Context<UpdatePlayerStats>) -> Result<()> {
     msg!("🎮 [Magic Handler] Processing game completion");
+    let mut meter = ComputeMeter::start("update_player_stats");
+
+    let Some(session) =
+        load_settled_session(&ctx.accounts.committed_session, &ctx.accounts.user_profile)?
+    else {
+        return Ok(());
+    };
+    meter.checkpoint("load session");
+    verify_handler_leaderboards(
+        &session,
+        [
+            (ctx.accounts.daily_leaderboard.key(), &*ctx.accounts.daily_leaderboard),
+            (ctx.accounts.weekly_leaderboard.key(), &*ctx.accounts.weekly_leaderboard),
+            (ctx.accounts.monthly_leaderboard.key(), &*ctx.accounts.monthly_leaderboard),
+        ],
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+
+    apply_session_to_leaderboards(
+        &session,
+        &ctx.accounts.user_profile,
+        now,
+        [
+            &mut ctx.accounts.daily_leaderboard,
+            &mut ctx.accounts.weekly_leaderboard,
+            &mut ctx.accounts.monthly_leaderboard,
+        ],
+    );
+    trim_to_allocation(&mut ctx.accounts.daily_leaderboard);
+    trim_to_allocation(&mut ctx.accounts.weekly_leaderboard);
+    trim_to_allocation(&mut ctx.accounts.monthly_leaderboard);
+    meter.checkpoint("leaderboards");
+    log_handler_leaderboards([
+        &*ctx.accounts.daily_leaderboard,
+        &*ctx.accounts.weekly_leaderboard,
+        &*ctx.accounts.monthly_leaderboard,
+    ]);
+    apply_session_to_profile(&session, &mut ctx.accounts.user_profile, now);
+    meter.checkpoint("profile");
+
+    // The settled game no longer needs its active session slot
+    release_profile_slot(&ctx.accounts.session_counter, &mut ctx.accounts.user_profile)?;
+
+    msg!("✅ [Magic Handler] Game completion processed successfully");
     
+    Ok(())
+}
+
+/// Magic Actions handler - leaderboard half of a split settlement
+///
+/// Scheduled before `update_profile_stats` when `split_stats_handlers` is
+/// set, so each half gets its own compute budget.
+///
+/// # Notes
+/// - Reads the profile to skip epochs already applied; the profile handler
+///   records the epoch afterwards
+pub fn update_leaderboard_stats(ctx: Context<UpdateLeaderboardStats>) -> Result<()> {
+    msg!("🎮 [Magic Handler] Processing game completion (leaderboards)");
+    let mut meter = ComputeMeter::start("update_leaderboard_stats");
+
+    let Some(session) =
+        load_settled_session(&ctx.accounts.committed_session, &ctx.accounts.user_profile)?
+    else {
+        return Ok(());
+    };
+    meter.checkpoint("load session");
+    verify_handler_leaderboards(
+        &session,
+        [
+            (ctx.accounts.daily_leaderboard.key(), &*ctx.accounts.daily_leaderboard),
+            (ctx.accounts.weekly_leaderboard.key(), &*ctx.accounts.weekly_leaderboard),
+            (ctx.accounts.monthly_leaderboard.key(), &*ctx.accounts.monthly_leaderboard),
+        ],
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+
+    apply_session_to_leaderboards(
+        &session,
+        &ctx.accounts.user_profile,
+        now,
+        [
+            &mut ctx.accounts.daily_leaderboard,
+            &mut ctx.accounts.weekly_leaderboard,
+            &mut ctx.accounts.monthly_leaderboard,
+        ],
+    );
+    trim_to_allocation(&mut ctx.accounts.daily_leaderboard);
+    trim_to_allocation(&mut ctx.accounts.weekly_leaderboard);
+    trim_to_allocation(&mut ctx.accounts.monthly_leaderboard);
+    meter.checkpoint("leaderboards");
+    log_handler_leaderboards([
+        &*ctx.accounts.daily_leaderboard,
+        &*ctx.accounts.weekly_leaderboard,
+        &*ctx.accounts.monthly_leaderboard,
+    ]);
+
+    msg!("✅ [Magic Handler] Leaderboards updated");
+
+    Ok(())
+}
+
+/// Magic Actions handler - profile half of a split settlement
+pub fn update_profile_stats(ctx: Context<UpdateProfileStats>) -> Result<()> {
+    msg!("🎮 [Magic Handler] Processing game completion (profile)");
+
+    let Some(session) =
+        load_settled_session(&ctx.accounts.committed_session, &ctx.accounts.user_profile)?
+    else {
+        return Ok(());
+    };
+    let now = Clock::get()?.unix_timestamp;
+
+    apply_session_to_profile(&session, &mut ctx.accounts.user_profile, now);
+    release_profile_slot(&ctx.accounts.session_counter, &mut ctx.accounts.user_profile)?;
+
+    msg!("✅ [Magic Handler] Profile updated");
+
+    Ok(())
+}
+
+/// Log the settled boards' sizes (`debug-logs` builds only)
+fn log_handler_leaderboards(leaderboards: [&PeriodLeaderboard; 3]) {
+    for leaderboard in leaderboards {
+        debug::log_leaderboard(leaderboard);
+    }
+}
+
+/// Deserialize a committed session and decide whether it still has a game to apply
+///
+/// # Returns
+/// The session if it holds a settled game whose epoch the profile hasn't
+/// recorded yet, `None` if there is nothing to apply
+fn load_settled_session(
+    committed_session: &UncheckedAccount,
+    profile: &UserProfile,
+) -> Result<Option<SessionAccount>> {
     // Manually deserialize the committed session account
-    let session_info = &ctx.accounts.committed_session.to_account_info();
+    let session_info = committed_session.to_account_info();
     let mut data: &[u8] = &session_info.try_borrow_data()?;
-    let session = crate::state::SessionAccount::try_deserialize(&mut data)?;
+    let session = SessionAccount::try_deserialize(&mut data)?;
+
+    // The handler accounts are only checked here - never credit a game to
+    // a profile other than the session's owner
+    verify_session_for_profile(session_info.key, &session, &profile.player)?;
     
     msg!("   Session: {}", session.session_id);
     msg!("   Completed: {}", session.completed);
     msg!("   Score: {}", session.score);
     
-    // Only process if game is completed
-    if !session.completed {
-        msg!("   ⏭️  Game not completed, skipping");
-        return Ok(());
-    }
-    
-    let final_score = session.score;
-    let player = session.player;
-    let now = Clock::get()?.unix_timestamp;
-    
-    // ========== UPDATE LEADERBOARDS ==========
-    msg!("📊 Updating period leaderboards");
+    msg!("   State: {:?} (epoch {})", session.current_state(), session.game_epoch);
 
-    let mut update_daily = |leaderboard: &mut PeriodLeaderboard| {
-        if leaderboard.finalized || final_score == 0 {
-            return;
+    // Only settlement commits carry a finished game to apply
+    match session.current_state() {
+        SessionState::Settled => {}
+        SessionState::Idle
+        | SessionState::InProgress
+        | SessionState::CompletedUnsettled
+        | SessionState::Abandoned => {
+            msg!("   ⏭️  Game not settled, skipping");
+            return Ok(None);
         }
+    }
 
-        let new_entry = LeaderEntry {
-            player,
-            score: final_score,
+    // Each game epoch is applied to the profile at most once
+    if session.game_epoch == profile.last_settled_epoch {
+        msg!("   ⏭️  Game epoch {} already applied, skipping", session.game_epoch);
+        return Ok(None);
+    }
+
+    // The guesses must be the ones the game was scored on
+    transcript::verify_transcript(&session)?;
+
+    Ok(Some(session))
+}
+
+/// Write a settled game to the daily, weekly and monthly leaderboards
+///
+/// Games committed after their period's grace window (`ScoreDeadlineRule::Late`)
+/// leave the boards untouched and emit `LateScore` instead. Games whose score
+/// or time couldn't have come from their guesses are skipped the same way and
+/// emit `ScoreFlagged`. Every other game counts as participation on each
+/// board, including unsolved ones that don't get a ranked entry.
+///
+/// Must run before `apply_session_to_profile`, which overwrites the
+/// `last_played_period` used to spot the player's first game on a board.
+fn apply_session_to_leaderboards(
+    session: &SessionAccount,
+    profile: &UserProfile,
+    now: i64,
+    leaderboards: [&mut PeriodLeaderboard; 3],
+) {
+    if session.deadline_rule == ScoreDeadlineRule::Late {
+        msg!("   ⏰ Committed after the grace window, leaderboards skipped");
+
+        emit!(LateScore {
+            player: session.player,
+            period_id: session.period_id.clone(),
+            game_epoch: session.game_epoch,
+            score: session.score,
+        });
+        return;
+    }
+
+    if let Err(err) = validate_settled_game(session) {
+        msg!("   🚩 Score failed consistency checks ({}), leaderboards skipped", err);
+
+        emit!(ScoreFlagged {
+            player: session.player,
+            period_id: session.period_id.clone(),
+            game_epoch: session.game_epoch,
+            score: session.score,
             guesses_used: session.guesses_used,
             time_ms: session.time_ms,
-            timestamp: now,
-            username: ctx.accounts.user_profile.username.clone(),
-        };
+        });
+        return;
+    }
 
-        let mut updated_existing = false;
-        for entry in &mut leaderboard.entries {
-            if entry.player == player {
-                if final_score > entry.score {
-                    *entry = new_entry.clone();
-                    updated_existing = true;
-                    msg!("   ✅ Updated daily entry with better score");
-                }
-                return;
-            }
-        }
+    msg!("📊 Updating period leaderboards");
 
-        leaderboard.entries.push(new_entry);
-        leaderboard.total_players += 1;
-        msg!("   ✅ Added daily leaderboard entry");
+    // Built once; each leaderboard copies fields into the player's slot in place
+    let new_entry = LeaderEntry {
+        player: session.player,
+        score: session.score,
+        guesses_used: session.guesses_used,
+        time_ms: session.time_ms,
+        timestamp: now,
+        username: profile.username.clone(),
+        equipped_flair: profile.equipped_flair,
+        games_played: 1,
+        counted_days: daily_period_mask(&session.period_id),
     };
 
-    let mut accumulate_score = |leaderboard: &mut PeriodLeaderboard| {
-        if leaderboard.finalized || final_score == 0 {
-            return;
-        }
+    for leaderboard in leaderboards {
+        record_board_participation(leaderboard, &profile.last_played_period);
+        // Boards stay sorted as the entry is placed - no re-sort needed
+        apply_game_to_leaderboard(leaderboard, &new_entry);
+    }
+}
 
-        let mut updated_existing = false;
-        for entry in &mut leaderboard.entries {
-            if entry.player == player {
-                entry.score = entry.score.saturating_add(final_score);
-                entry.timestamp = now;
-                entry.username = ctx.accounts.user_profile.username.clone();
-                entry.guesses_used = session.guesses_used;
-                entry.time_ms = session.time_ms;
-                updated_existing = true;
-                msg!("   ➕ Aggregated score for existing entry");
-                break;
-            }
-        }
+/// Count a settled game's player on a leaderboard, whatever the score
+///
+/// Ranking ignores zero scores, so participation is tracked separately
+/// from the entries.
+///
+/// # Arguments
+/// * `leaderboard` - Board the game settles into
+/// * `previous_played_period` - The player's `last_played_period` before
+///   this game (a daily period ID, or empty)
+///
+/// # Notes
+/// - Finalized boards are left untouched
+/// - A player is counted once per board: only if their previous game was
+///   in a different period of the board's type
+pub fn record_board_participation(leaderboard: &mut PeriodLeaderboard, previous_played_period: &str) {
+    if leaderboard.finalized {
+        return;
+    }
+    if containing_period_id(previous_played_period, leaderboard.period_type).as_deref()
+        == Some(leaderboard.period_id.as_str())
+    {
+        return;
+    }
 
-        if !updated_existing {
-            leaderboard.entries.push(LeaderEntry {
-                player,
-                score: final_score,
-                guesses_used: session.guesses_used,
-                time_ms: session.time_ms,
-                timestamp: now,
-                username: ctx.accounts.user_profile.username.clone(),
-            });
-            leaderboard.total_players += 1;
-            msg!("   ✅ Added aggregated entry");
-        }
+    leaderboard.participants = leaderboard.participants.saturating_add(1);
+}
+
+/// Period of the given type containing a daily period
+///
+/// Weeks and months are whole numbers of days from the same epoch, so the
+/// day number alone decides them.
+///
+/// # Returns
+/// `None` if `daily_period_id` is not a daily period ID
+pub fn containing_period_id(daily_period_id: &str, period_type: PeriodType) -> Option<String> {
+    let (period::PeriodType::Daily, day) = period::parse_period_id(daily_period_id)? else {
+        return None;
+    };
+    let day_start = (day as i64).saturating_mul(PERIOD_DAILY_DURATION);
+    let kind = match period_type {
+        PeriodType::Daily => period::PeriodType::Daily,
+        PeriodType::Weekly => period::PeriodType::Weekly,
+        PeriodType::Monthly => period::PeriodType::Monthly,
     };
+    Some(period::get_current_period_id(kind, day_start, 0))
+}
 
-    update_daily(&mut ctx.accounts.daily_leaderboard);
-    accumulate_score(&mut ctx.accounts.weekly_leaderboard);
-    accumulate_score(&mut ctx.accounts.monthly_leaderboard);
-
-    for leaderboard in [
-        &mut ctx.accounts.daily_leaderboard,
-        &mut ctx.accounts.weekly_leaderboard,
-        &mut ctx.accounts.monthly_leaderboard,
-    ] {
-        // Sort by score (highest first, tie-breaker by time)
-        leaderboard.entries.sort_by(|a, b| {
-            match b.score.cmp(&a.score) {
-                std::cmp::Ordering::Equal => a.time_ms.cmp(&b.time_ms),
-                other => other,
-            }
-        });
+/// How a settled game changed the player's win streak
+#[derive(Debug, PartialEq, Eq)]
+pub enum StreakChange {
+    /// Win; carries a milestone the streak reached for the first time this run
+    Extended(Option<u32>),
+    /// Loss; carries the length of the streak lost (0 if there was none)
+    Broken(u32),
+}
 
-        // Keep only top 100
-        if leaderboard.entries.len() > 100 {
-            leaderboard.entries.truncate(100);
-        }
+/// Apply a settled game to the win streak
+///
+/// # Notes
+/// - Each milestone is reported once per streak run: `last_milestone_emitted`
+///   remembers the highest one reached and is cleared with the streak
+/// - Profiles that already had a long streak when milestones were added
+///   report only the highest milestone reached, not every one below it
+pub fn record_streak(profile: &mut UserProfile, is_solved: bool) -> StreakChange {
+    if !is_solved {
+        return StreakChange::Broken(reset_streak(profile));
     }
-    
-    // ========== UPDATE USER PROFILE STATS ==========
+
+    profile.current_streak += 1;
+    if profile.current_streak > profile.max_streak {
+        profile.max_streak = profile.current_streak;
+    }
+
+    let milestone = STREAK_MILESTONES
+        .iter()
+        .rev()
+        .copied()
+        .find(|&m| profile.current_streak >= m && m > profile.last_milestone_emitted);
+    if let Some(milestone) = milestone {
+        profile.last_milestone_emitted = milestone;
+    }
+
+    StreakChange::Extended(milestone)
+}
+
+/// Clear the win streak and its milestone progress
+///
+/// # Returns
+/// The length of the streak that was lost
+pub fn reset_streak(profile: &mut UserProfile) -> u32 {
+    let lost = profile.current_streak;
+    profile.current_streak = 0;
+    profile.last_milestone_emitted = 0;
+    lost
+}
+
+/// Check that a settled game's score and time could have come from its guesses
+fn validate_settled_game(session: &SessionAccount) -> Result<()> {
+    validation::validate_score_consistency(
+        session.is_solved,
+        session.guesses_used,
+        session.time_ms,
+        session.score,
+        session.word_difficulty,
+        session.score_multiplier_bps,
+        session.hole_in_one_bonus,
+    )?;
+    validation::validate_time_plausibility(session.guesses_used, session.time_ms)
+}
+
+/// Write a settled game to the player's profile stats and record its epoch
+fn apply_session_to_profile(session: &SessionAccount, profile: &mut UserProfile, now: i64) {
     msg!("📈 Updating user profile stats");
-    
-    let profile = &mut ctx.accounts.user_profile;
+
+    let final_score = session.score;
     profile.total_games_played += 1;
     
     if session.is_solved {
         profile.games_won += 1;
-        profile.current_streak += 1;
-        
-        if profile.current_streak > profile.max_streak {
-            profile.max_streak = profile.current_streak;
+    }
+    match record_streak(profile, session.is_solved) {
+        StreakChange::Extended(milestone) => {
+            msg!("   ✅ Win recorded! Streak: {}", profile.current_streak);
+
+            if let Some(streak) = milestone {
+                msg!("   🔥 Streak milestone: {}", streak);
+                emit!(StreakMilestoneReached {
+                    player: session.player,
+                    streak,
+                    period_id: session.period_id.clone(),
+                });
+            }
+        }
+        StreakChange::Broken(streak) => {
+            msg!("   📊 Loss recorded. Streak reset.");
+
+            if streak > 0 {
+                emit!(StreakBroken {
+                    player: session.player,
+                    streak,
+                    period_id: session.period_id.clone(),
+                });
+            }
         }
-        
-        msg!("   ✅ Win recorded! Streak: {}", profile.current_streak);
-    } else {
-        profile.current_streak = 0;
-        msg!("   📊 Loss recorded. Streak reset.");
     }
 
     profile.total_score += final_score as u64;
+    profile.points_balance = profile.points_balance.saturating_add(final_score as u64);
 
     if final_score > profile.best_score {
         profile.best_score = final_score;
     }
 
-    if session.is_solved && session.guesses_used > 0 && session.guesses_used <= 7 {
-        let idx = (session.guesses_used - 1) as usize;
-        profile.guess_distribution[idx] += 1;
-    }
+    let previous_average_guesses = profile.average_guesses;
+    let distribution_bucket = record_guess_distribution(
+        &mut profile.guess_distribution,
+        session.is_solved,
+        session.guesses_used,
+    );
 
-    if profile.games_won > 0 {
-        let total_guesses: u32 = profile
-            .guess_distribution
-            .iter()
-            .enumerate()
-            .map(|(i, &count)| (i as u32 + 1) * count)
-            .sum();
-        profile.average_guesses = total_guesses as f32 / profile.games_won as f32;
+    if let Some(average) = average_guesses(&profile.guess_distribution, profile.games_won) {
+        profile.average_guesses = average;
     }
 
+    profile.last_settled_epoch = session.game_epoch;
     profile.last_played_period = session.period_id.clone();
     profile.has_played_this_period = true;
     profile.last_played = now;
-    
-    msg!("✅ [Magic Handler] Game completion processed successfully");
-    
+
+    emit!(GameSettled {
+        player: session.player,
+        period_id: session.period_id.clone(),
+        game_epoch: session.game_epoch,
+        is_solved: session.is_solved,
+        guesses_used: session.guesses_used,
+        score: final_score,
+        multiplier_bps: session.score_multiplier_bps,
+        difficulty: session.word_difficulty,
+        distribution_bucket,
+        previous_average_guesses,
+        average_guesses: profile.average_guesses,
+        rejected_keystrokes: session.rejected_keystrokes,
+        deadline_rule: session.deadline_rule,
+        program_version: session.program_version,
+    });
+
+    if scoring::is_hole_in_one(session.is_solved, session.guesses_used) {
+        msg!("   ⛳ Hole in one!");
+
+        emit!(HoleInOne {
+            player: session.player,
+            period_id: session.period_id.clone(),
+            game_epoch: session.game_epoch,
+            score: final_score,
+            bonus: session.hole_in_one_bonus.min(MAX_HOLE_IN_ONE_BONUS),
+        });
+    }
+}
+
+
+/// Check that a committed session is the player's session PDA and matches the profile
+///
+/// # Arguments
+/// * `session_key` - Address of the committed session account
+/// * `session` - Deserialized session
+/// * `profile_player` - Owner of the profile about to be credited
+///
+/// # Validation
+/// - `session_key` must be the session PDA of `session.player` for the
+///   session's generation (`SessionAccount::nonce_seed`) under this program
+/// - `session.player` must equal `profile_player`
+pub fn verify_session_for_profile(
+    session_key: &Pubkey,
+    session: &SessionAccount,
+    profile_player: &Pubkey,
+) -> Result<()> {
+    let (expected_session, _) = Pubkey::find_program_address(
+        &[
+            SEED_SESSION,
+            session.player.as_ref(),
+            &SessionAccount::nonce_seed(session.session_nonce),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(*session_key, expected_session, VobleError::ProfileSessionMismatch);
+    require_keys_eq!(session.player, *profile_player, VobleError::ProfileSessionMismatch);
+
+    Ok(())
+}
+
+/// Leaderboard order every stats handler takes, as laid out by `plan_stats_handlers`
+pub const HANDLER_LEADERBOARD_ORDER: [PeriodType; 3] =
+    [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly];
+
+/// Check that a stats handler was given the session's leaderboards in order
+///
+/// Handler accounts arrive by position and aren't seed-checked by their
+/// context, so a misordered list would otherwise credit the game to the
+/// wrong boards.
+///
+/// # Arguments
+/// * `session` - The settled session being applied
+/// * `leaderboards` - Address and data of each leaderboard, in handler order
+///
+/// # Validation
+/// - Each board's period type must follow `HANDLER_LEADERBOARD_ORDER`
+/// - Each address must be the board's `[SEED_LEADERBOARD, period_id, period_type]` PDA
+/// - The daily board must be for `session.period_id`
+///
+/// # Notes
+/// - Boards without a recorded bump (pre-backfill) are derived with `find_program_address`
+pub fn verify_handler_leaderboards(
+    session: &SessionAccount,
+    leaderboards: [(Pubkey, &PeriodLeaderboard); 3],
+) -> Result<()> {
+    for ((key, leaderboard), period_type) in leaderboards.iter().zip(HANDLER_LEADERBOARD_ORDER) {
+        require!(
+            leaderboard.period_type == period_type,
+            VobleError::HandlerAccountOrderInvalid
+        );
+
+        let seeds = [
+            SEED_LEADERBOARD,
+            leaderboard.period_id.as_bytes(),
+            period_type.seed(),
+        ];
+        let expected = if leaderboard.bump == 0 {
+            Pubkey::find_program_address(&seeds, &crate::ID).0
+        } else {
+            Pubkey::create_program_address(
+                &[seeds[0], seeds[1], seeds[2], &[leaderboard.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| VobleError::HandlerAccountOrderInvalid)?
+        };
+        require_keys_eq!(*key, expected, VobleError::HandlerAccountOrderInvalid);
+    }
+
+    require!(
+        leaderboards[0].1.period_id == session.period_id,
+        VobleError::HandlerAccountOrderInvalid
+    );
+
     Ok(())
 }
 
+/// Count a solved game in the guess distribution
+///
+/// # Returns
+/// Index of the incremented bucket (`guesses_used - 1`), or `None` for
+/// losses and out-of-range guess counts
+pub fn record_guess_distribution(
+    distribution: &mut [u32; 7],
+    is_solved: bool,
+    guesses_used: u8,
+) -> Option<u8> {
+    if !is_solved || guesses_used == 0 || guesses_used as usize > distribution.len() {
+        return None;
+    }
+
+    let bucket = guesses_used - 1;
+    distribution[bucket as usize] += 1;
+    Some(bucket)
+}
+
+/// Mean guesses per win from the guess distribution
+///
+/// # Returns
+/// `None` until the player has won a game
+pub fn average_guesses(distribution: &[u32; 7], games_won: u32) -> Option<f32> {
+    if games_won == 0 {
+        return None;
+    }
+
+    let total_guesses: u32 = distribution
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| (i as u32 + 1) * count)
+        .sum();
+    Some(total_guesses as f32 / games_won as f32)
+}
+
+/// Fold one settled game into a leaderboard using its aggregation strategy
+///
+/// # Arguments
+/// * `leaderboard` - Leaderboard to update
+/// * `new_entry` - Entry describing the settled game (`games_played` = 1)
+///
+/// # Notes
+/// - Boards in `DailyRollup` mode are skipped; `roll_daily_into_weekly`
+///   feeds them from the daily final standings instead
+/// - See `fold_entry_into_leaderboard` for how the entry is merged
+///
+/// # Returns
+/// `true` if any entry was added or changed (the caller should re-sort)
+pub fn apply_game_to_leaderboard(
+    leaderboard: &mut PeriodLeaderboard,
+    new_entry: &LeaderEntry,
+) -> bool {
+    if leaderboard.mode == LeaderboardMode::DailyRollup {
+        return false;
+    }
+
+    fold_entry_into_leaderboard(leaderboard, new_entry)
+}
+
+/// Merge an entry covering `games_played` games into a leaderboard
+///
+/// # Arguments
+/// * `leaderboard` - Leaderboard to update
+/// * `new_entry` - A single game (`games_played` = 1) or a player's daily
+///   final entry being rolled up
+///
+/// # Notes
+/// - Finalized leaderboards and zero scores are left untouched
+/// - An entry whose days were all counted already (a retried commit or a
+///   daily rolled up twice) is ignored, so weekly and monthly totals never
+///   count the same daily game twice; entries with no `counted_days` (legacy
+///   boards) are always merged
+/// - `Best` keeps the highest single game; the entry is only rewritten on improvement
+/// - `Sum` adds the game's score to the player's entry
+/// - `Average` keeps the mean score weighted by games played, rounded down on every merge
+/// - The board must already be sorted and stays sorted: a new entry is
+///   inserted at its binary-searched position (the lowest entry drops off
+///   past the board's capacity) and a changed entry is moved to its new position, instead
+///   of re-sorting the whole board
+/// - The player's slot is located once and written in place; the username is
+///   only copied when it differs from the stored one
+///
+/// # Returns
+/// `true` if any entry was added or changed (the caller should re-sort)
+pub fn fold_entry_into_leaderboard(
+    leaderboard: &mut PeriodLeaderboard,
+    new_entry: &LeaderEntry,
+) -> bool {
+    if leaderboard.finalized || new_entry.score == 0 {
+        return false;
+    }
+
+    let aggregation = leaderboard.aggregation;
+    let Some(index) = leaderboard
+        .entries
+        .iter()
+        .position(|entry| entry.player == new_entry.player)
+    else {
+        insert_sorted(&mut leaderboard.entries, new_entry.clone(), leaderboard_order);
+        let capacity = leaderboard.capacity();
+        leaderboard.entries.truncate(capacity);
+        leaderboard.total_players += 1;
+        msg!("   ✅ Added {:?} leaderboard entry", leaderboard.period_type);
+        return true;
+    };
+    let entry = &mut leaderboard.entries[index];
+
+    if is_already_counted(entry.counted_days, new_entry.counted_days) {
+        msg!("   ⏭️  Day already counted for {:?} entry", leaderboard.period_type);
+        return false;
+    }
+
+    let previous_games = entry.games_played.max(1);
+    let new_games = new_entry.games_played.max(1);
+    let games_played = previous_games.saturating_add(new_games);
+
+    match aggregation {
+        AggregationStrategy::Best => {
+            if new_entry.score > entry.score {
+                write_entry(entry, new_entry, new_entry.score);
+                msg!("   ✅ Updated entry with better score");
+            }
+        }
+        AggregationStrategy::Sum => {
+            let score = entry.score.saturating_add(new_entry.score);
+            write_entry(entry, new_entry, score);
+            msg!("   ➕ Aggregated score for existing entry");
+        }
+        AggregationStrategy::Average => {
+            let total = entry.score as u64 * previous_games as u64
+                + new_entry.score as u64 * new_games as u64;
+            let score = (total / games_played as u64) as u32;
+            write_entry(entry, new_entry, score);
+            msg!("   ➗ Averaged score for existing entry");
+        }
+    }
+
+    entry.games_played = games_played;
+    entry.counted_days |= new_entry.counted_days;
+    reposition_sorted(&mut leaderboard.entries, index, leaderboard_order);
+    true
+}
+
+/// Check whether every day in `new_days` is already in `counted_days`
+///
+/// # Returns
+/// `false` when `new_days` is 0 (the entry's days are unknown)
+pub fn is_already_counted(counted_days: u64, new_days: u64) -> bool {
+    new_days != 0 && counted_days & new_days == new_days
+}
+
+/// Stored leaderboard order: score (highest first), tie-breaker by time
+pub fn leaderboard_order(a: &LeaderEntry, b: &LeaderEntry) -> std::cmp::Ordering {
+    match b.score.cmp(&a.score) {
+        std::cmp::Ordering::Equal => a.time_ms.cmp(&b.time_ms),
+        other => other,
+    }
+}
+
+/// Sort by `leaderboard_order` and keep the top `capacity()` entries
+///
+/// Only needed for boards whose order isn't maintained by
+/// `fold_entry_into_leaderboard` (e.g. written by an older program version).
+pub fn sort_and_trim_leaderboard(leaderboard: &mut PeriodLeaderboard) {
+    leaderboard.entries.sort_by(leaderboard_order);
+
+    let capacity = leaderboard.capacity();
+    if leaderboard.entries.len() > capacity {
+        leaderboard.entries.truncate(capacity);
+    }
+}
+
+/// Drop entries past what the board's account is currently allocated for
+///
+/// `capacity()` may exceed the allocation until `grow_leaderboard` has run
+/// to completion; serializing more entries than fit would fail the exit.
+/// Boards are kept sorted, so this keeps the top entries.
+pub fn trim_to_allocation(leaderboard: &mut Account<PeriodLeaderboard>) {
+    let allocated = leaderboard.allocated_capacity(leaderboard.to_account_info().data_len());
+    if leaderboard.entries.len() > allocated {
+        msg!(
+            "   ⚠️  {:?} board holds {} entries until grown",
+            leaderboard.period_type,
+            allocated
+        );
+        leaderboard.entries.truncate(allocated);
+    }
+}
+
+/// Overwrite an existing entry with a settled game's fields and the aggregated score
+fn write_entry(entry: &mut LeaderEntry, new_entry: &LeaderEntry, score: u32) {
+    entry.score = score;
+    entry.guesses_used = new_entry.guesses_used;
+    entry.time_ms = new_entry.time_ms;
+    entry.timestamp = new_entry.timestamp;
+    entry.equipped_flair = new_entry.equipped_flair;
+    if entry.username != new_entry.username {
+        entry.username.clone_from(&new_entry.username);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::game::leaderboard_bench::{
+        fixture_leaderboard, legacy_apply_game, GameFixture,
+    };
+
+    fn leaderboard(aggregation: AggregationStrategy) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("W1", PeriodType::Weekly);
+        board.aggregation = aggregation;
+        board
+    }
+
+    fn game(player: Pubkey, score: u32, timestamp: i64) -> LeaderEntry {
+        LeaderEntry {
+            player,
+            score,
+            guesses_used: 3,
+            time_ms: 30_000,
+            timestamp,
+            username: "Test".to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    fn play_twice(aggregation: AggregationStrategy, first: u32, second: u32) -> PeriodLeaderboard {
+        let player = Pubkey::new_unique();
+        let mut board = leaderboard(aggregation);
+
+        apply_game_to_leaderboard(&mut board, &game(player, first, 1));
+        apply_game_to_leaderboard(&mut board, &game(player, second, 2));
+
+        assert_eq!(board.entries.len(), 1);
+        assert_eq!(board.total_players, 1);
+        assert_eq!(board.entries[0].games_played, 2);
+        board
+    }
+
+    #[test]
+    fn test_truncated_player_readded_counts_once() {
+        let mut board = leaderboard(AggregationStrategy::Best);
+        let returning = Pubkey::new_unique();
+        board.entrants = 101;
+
+        apply_game_to_leaderboard(&mut board, &game(returning, 10, 1));
+        for i in 0..100 {
+            apply_game_to_leaderboard(&mut board, &game(Pubkey::new_unique(), 500 + i, 2));
+        }
+        sort_and_trim_leaderboard(&mut board);
+        assert!(board.entries.iter().all(|entry| entry.player != returning));
+
+        // A late commit pushes the truncated player back in
+        apply_game_to_leaderboard(&mut board, &game(returning, 900, 3));
+
+        assert_eq!(board.total_players, 102);
+        assert_eq!(board.participants_count(), 101);
+    }
+
+    #[test]
+    fn test_participants_fall_back_to_pushes_without_tickets() {
+        let mut board = leaderboard(AggregationStrategy::Best);
+        apply_game_to_leaderboard(&mut board, &game(Pubkey::new_unique(), 500, 1));
+
+        assert_eq!(board.participants_count(), 1);
+    }
+
+    #[test]
+    fn test_unsolved_game_counts_participation_not_rank() {
+        // Weekly board W1 covers D7-D13
+        let mut board = leaderboard(AggregationStrategy::Best);
+        let solver = Pubkey::new_unique();
+        record_board_participation(&mut board, "");
+        apply_game_to_leaderboard(&mut board, &game(solver, 500, 1));
+
+        let unsolved = Pubkey::new_unique();
+        record_board_participation(&mut board, "D3");
+        assert!(!apply_game_to_leaderboard(&mut board, &game(unsolved, 0, 2)));
+
+        assert_eq!(board.entries.len(), 1);
+        assert_eq!(board.entries[0].player, solver);
+        assert_eq!(crate::instructions::leaderboard::get_player_rank(&board, unsolved), None);
+        assert_eq!(board.total_players, 1);
+        assert_eq!(board.participants, 2);
+        assert_eq!(board.participants_count(), 2);
+    }
+
+    #[test]
+    fn test_participation_counted_once_per_board() {
+        let mut weekly = leaderboard(AggregationStrategy::Sum);
+
+        // First game of the week, then a second day in the same week
+        record_board_participation(&mut weekly, "D6");
+        record_board_participation(&mut weekly, "D8");
+        assert_eq!(weekly.participants, 1);
+
+        assert_eq!(containing_period_id("D13", PeriodType::Weekly).as_deref(), Some("W1"));
+        assert_eq!(containing_period_id("D14", PeriodType::Weekly).as_deref(), Some("W2"));
+        assert_eq!(containing_period_id("D29", PeriodType::Monthly).as_deref(), Some("M0"));
+        assert_eq!(containing_period_id("D8", PeriodType::Daily).as_deref(), Some("D8"));
+        assert_eq!(containing_period_id("legacy", PeriodType::Weekly), None);
+
+        weekly.finalized = true;
+        record_board_participation(&mut weekly, "");
+        assert_eq!(weekly.participants, 1);
+    }
+
+    fn daily_game(player: Pubkey, score: u32, period_id: &str) -> LeaderEntry {
+        LeaderEntry {
+            counted_days: daily_period_mask(period_id),
+            ..game(player, score, 1)
+        }
+    }
+
+    #[test]
+    fn test_same_daily_game_counted_once_on_weekly() {
+        for aggregation in [
+            AggregationStrategy::Best,
+            AggregationStrategy::Sum,
+            AggregationStrategy::Average,
+        ] {
+            let player = Pubkey::new_unique();
+            let mut board = leaderboard(aggregation);
+
+            assert!(apply_game_to_leaderboard(&mut board, &daily_game(player, 900, "D7")));
+            assert!(apply_game_to_leaderboard(&mut board, &daily_game(player, 600, "D8")));
+            let before_score = board.entries[0].score;
+
+            // Committing the D8 game again leaves the entry untouched
+            assert!(!apply_game_to_leaderboard(&mut board, &daily_game(player, 600, "D8")));
+            assert_eq!(board.entries[0].score, before_score);
+            assert_eq!(board.entries[0].games_played, 2);
+            assert_eq!(
+                board.entries[0].counted_days,
+                daily_period_mask("D7") | daily_period_mask("D8")
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum_weekly_total_unchanged_by_retry() {
+        let player = Pubkey::new_unique();
+        let mut weekly = leaderboard(AggregationStrategy::Sum);
+        let mut monthly = leaderboard(AggregationStrategy::Sum);
+        monthly.period_type = PeriodType::Monthly;
+
+        for board in [&mut weekly, &mut monthly] {
+            apply_game_to_leaderboard(board, &daily_game(player, 900, "D7"));
+            apply_game_to_leaderboard(board, &daily_game(player, 600, "D8"));
+            apply_game_to_leaderboard(board, &daily_game(player, 600, "D8"));
+            apply_game_to_leaderboard(board, &daily_game(player, 900, "D7"));
+
+            assert_eq!(board.entries[0].score, 1_500);
+        }
+    }
+
+    #[test]
+    fn test_unknown_days_always_merged() {
+        assert!(!is_already_counted(u64::MAX, 0));
+        assert!(!is_already_counted(0, 1));
+        assert!(is_already_counted(0b11, 0b10));
+        assert!(!is_already_counted(0b01, 0b11));
+    }
+
+    #[test]
+    fn test_best_keeps_highest_game() {
+        let board = play_twice(AggregationStrategy::Best, 900, 600);
+
+        assert_eq!(board.entries[0].score, 900);
+        assert_eq!(board.entries[0].timestamp, 1);
+    }
+
+    #[test]
+    fn test_sum_adds_games() {
+        let board = play_twice(AggregationStrategy::Sum, 900, 600);
+
+        assert_eq!(board.entries[0].score, 1_500);
+        assert_eq!(board.entries[0].timestamp, 2);
+    }
+
+    #[test]
+    fn test_average_takes_mean() {
+        let board = play_twice(AggregationStrategy::Average, 900, 600);
+
+        assert_eq!(board.entries[0].score, 750);
+        assert_eq!(board.entries[0].timestamp, 2);
+    }
+
+    fn serialized(leaderboard: &PeriodLeaderboard) -> Vec<u8> {
+        let mut data = Vec::new();
+        leaderboard.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_in_place_update_matches_legacy_state() {
+        for aggregation in [
+            AggregationStrategy::Best,
+            AggregationStrategy::Sum,
+            AggregationStrategy::Average,
+        ] {
+            let mut current = leaderboard(aggregation);
+            let mut legacy = leaderboard(aggregation);
+            let mut fixture = GameFixture::new(0x5eed);
+
+            for _ in 0..1_000 {
+                let entry = fixture.next_game();
+
+                // No re-sort: insertion keeps the board in legacy order
+                apply_game_to_leaderboard(&mut current, &entry);
+                legacy_apply_game(&mut legacy, &entry);
+
+                assert_eq!(serialized(&current), serialized(&legacy));
+            }
+            assert_eq!(current.entries.len(), 100);
+        }
+    }
+
+    #[test]
+    fn test_benchmark_fixture_matches_legacy_state() {
+        for aggregation in [
+            AggregationStrategy::Best,
+            AggregationStrategy::Sum,
+            AggregationStrategy::Average,
+        ] {
+            for seed in 0..50 {
+                let game = GameFixture::new(seed).next_game();
+                let mut current = fixture_leaderboard(aggregation);
+                let mut legacy = fixture_leaderboard(aggregation);
+
+                apply_game_to_leaderboard(&mut current, &game);
+                legacy_apply_game(&mut legacy, &game);
+
+                assert_eq!(serialized(&current), serialized(&legacy));
+            }
+        }
+    }
+
+    #[test]
+    fn test_unchanged_board_reports_no_write() {
+        let player = Pubkey::new_unique();
+        let mut board = leaderboard(AggregationStrategy::Best);
+
+        assert!(!apply_game_to_leaderboard(&mut board, &game(player, 0, 1)));
+        assert!(apply_game_to_leaderboard(&mut board, &game(player, 900, 1)));
+
+        board.finalized = true;
+        assert!(!apply_game_to_leaderboard(&mut board, &game(player, 950, 2)));
+        assert_eq!(board.entries[0].score, 900);
+    }
+
+    fn session_for(player: Pubkey) -> (Pubkey, SessionAccount) {
+        let (key, bump) =
+            Pubkey::find_program_address(&[SEED_SESSION, player.as_ref()], &crate::ID);
+        let mut session = SessionAccount::default_for_test(player);
+        session.is_solved = true;
+        session.guesses_used = 3;
+        session.time_ms = 30_000;
+        session.score = 900;
+        session.completed = true;
+        session.period_id = "D1".to_string();
+        session.game_epoch = 1;
+        session.state = SessionState::Settled;
+        session.bump = bump;
+        (key, session)
+    }
+
+    #[test]
+    fn test_settled_game_consistency() {
+        let (_, session) = session_for(Pubkey::new_unique());
+        assert!(validate_settled_game(&session).is_ok());
+
+        let mut inflated = session.clone();
+        inflated.score = 1_500;
+        assert_eq!(
+            validate_settled_game(&inflated).unwrap_err(),
+            VobleError::ScoreMismatch.into()
+        );
+
+        // Three guesses in two seconds, scored consistently for that time
+        let mut rushed = session.clone();
+        rushed.time_ms = 2_000;
+        rushed.score = 1_100;
+        assert_eq!(
+            validate_settled_game(&rushed).unwrap_err(),
+            VobleError::ImplausibleTime.into()
+        );
+    }
+
+    #[test]
+    fn test_session_matching_profile_accepted() {
+        let player = Pubkey::new_unique();
+        let (key, session) = session_for(player);
+
+        assert!(verify_session_for_profile(&key, &session, &player).is_ok());
+    }
+
+    #[test]
+    fn test_session_for_other_profile_rejected() {
+        let (key, session) = session_for(Pubkey::new_unique());
+
+        assert_eq!(
+            verify_session_for_profile(&key, &session, &Pubkey::new_unique()).unwrap_err(),
+            VobleError::ProfileSessionMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_session_at_wrong_address_rejected() {
+        let player = Pubkey::new_unique();
+        let (_, session) = session_for(player);
+
+        assert_eq!(
+            verify_session_for_profile(&Pubkey::new_unique(), &session, &player).unwrap_err(),
+            VobleError::ProfileSessionMismatch.into()
+        );
+    }
+
+    fn handler_board(period_id: &str, period_type: PeriodType) -> (Pubkey, PeriodLeaderboard) {
+        let (key, bump) = Pubkey::find_program_address(
+            &[SEED_LEADERBOARD, period_id.as_bytes(), period_type.seed()],
+            &crate::ID,
+        );
+        let mut board = leaderboard(AggregationStrategy::Best);
+        board.period_id = period_id.to_string();
+        board.period_type = period_type;
+        board.bump = bump;
+        (key, board)
+    }
+
+    #[test]
+    fn test_handler_leaderboards_in_order() {
+        let (_, session) = session_for(Pubkey::new_unique());
+        let (daily_key, daily) = handler_board("D1", PeriodType::Daily);
+        let (weekly_key, weekly) = handler_board("W0", PeriodType::Weekly);
+        let (monthly_key, mut monthly) = handler_board("M0", PeriodType::Monthly);
+
+        assert!(verify_handler_leaderboards(
+            &session,
+            [(daily_key, &daily), (weekly_key, &weekly), (monthly_key, &monthly)]
+        )
+        .is_ok());
+
+        // Boards created before bumps were recorded are still derived
+        monthly.bump = 0;
+        assert!(verify_handler_leaderboards(
+            &session,
+            [(daily_key, &daily), (weekly_key, &weekly), (monthly_key, &monthly)]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_handler_leaderboards_misordered_rejected() {
+        let (_, session) = session_for(Pubkey::new_unique());
+        let (daily_key, daily) = handler_board("D1", PeriodType::Daily);
+        let (weekly_key, weekly) = handler_board("W0", PeriodType::Weekly);
+        let (monthly_key, monthly) = handler_board("M0", PeriodType::Monthly);
+
+        for leaderboards in [
+            [(weekly_key, &weekly), (monthly_key, &monthly), (daily_key, &daily)],
+            [(daily_key, &daily), (monthly_key, &monthly), (weekly_key, &weekly)],
+            [(monthly_key, &monthly), (weekly_key, &weekly), (daily_key, &daily)],
+        ] {
+            assert_eq!(
+                verify_handler_leaderboards(&session, leaderboards).unwrap_err(),
+                VobleError::HandlerAccountOrderInvalid.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_handler_leaderboards_wrong_address_or_period_rejected() {
+        let (_, session) = session_for(Pubkey::new_unique());
+        let (_, daily) = handler_board("D1", PeriodType::Daily);
+        let (weekly_key, weekly) = handler_board("W0", PeriodType::Weekly);
+        let (monthly_key, monthly) = handler_board("M0", PeriodType::Monthly);
+
+        // Data that doesn't live at its own PDA
+        assert_eq!(
+            verify_handler_leaderboards(
+                &session,
+                [(Pubkey::new_unique(), &daily), (weekly_key, &weekly), (monthly_key, &monthly)]
+            )
+            .unwrap_err(),
+            VobleError::HandlerAccountOrderInvalid.into()
+        );
+
+        // Another day's board
+        let (other_key, other_day) = handler_board("D2", PeriodType::Daily);
+        assert_eq!(
+            verify_handler_leaderboards(
+                &session,
+                [(other_key, &other_day), (weekly_key, &weekly), (monthly_key, &monthly)]
+            )
+            .unwrap_err(),
+            VobleError::HandlerAccountOrderInvalid.into()
+        );
+    }
+
+    /// Milestones reported while playing `results` in order
+    fn milestones(profile: &mut UserProfile, results: &[bool]) -> Vec<StreakChange> {
+        results
+            .iter()
+            .map(|&won| record_streak(profile, won))
+            .filter(|change| *change != StreakChange::Extended(None))
+            .collect()
+    }
+
+    #[test]
+    fn test_streak_milestones_once_per_run() {
+        let mut profile = UserProfile::default_for_test(Pubkey::default());
+
+        // 30 straight wins hit each milestone exactly once
+        assert_eq!(
+            milestones(&mut profile, &[true; 30]),
+            vec![
+                StreakChange::Extended(Some(3)),
+                StreakChange::Extended(Some(7)),
+                StreakChange::Extended(Some(14)),
+                StreakChange::Extended(Some(30)),
+            ]
+        );
+        // Winning on doesn't re-emit any of them
+        assert!(milestones(&mut profile, &[true; 10]).is_empty());
+        assert_eq!(profile.current_streak, 40);
+        assert_eq!(profile.max_streak, 40);
+    }
+
+    #[test]
+    fn test_streak_broken_and_reachieved() {
+        let mut profile = UserProfile::default_for_test(Pubkey::default());
+        milestones(&mut profile, &[true; 8]);
+        assert_eq!(profile.last_milestone_emitted, 7);
+
+        // Loss reports the streak lost and clears milestone progress
+        assert_eq!(record_streak(&mut profile, false), StreakChange::Broken(8));
+        assert_eq!(profile.current_streak, 0);
+        assert_eq!(profile.last_milestone_emitted, 0);
+        assert_eq!(profile.max_streak, 8);
+
+        // A second loss has no streak to lose
+        assert_eq!(record_streak(&mut profile, false), StreakChange::Broken(0));
+
+        // The new run reaches 3 and 7 again
+        assert_eq!(
+            milestones(&mut profile, &[true; 7]),
+            vec![StreakChange::Extended(Some(3)), StreakChange::Extended(Some(7))]
+        );
+        assert_eq!(profile.max_streak, 8);
+    }
+
+    #[test]
+    fn test_legacy_streak_reports_highest_milestone_only() {
+        let mut profile = UserProfile::default_for_test(Pubkey::default());
+        profile.current_streak = 15;
+        profile.max_streak = 15;
+
+        assert_eq!(
+            record_streak(&mut profile, true),
+            StreakChange::Extended(Some(14))
+        );
+        assert_eq!(record_streak(&mut profile, true), StreakChange::Extended(None));
+    }
+
+    #[test]
+    fn test_distribution_delta_for_win() {
+        // Four wins in 4 guesses, then one in 3
+        let mut distribution = [0, 0, 0, 4, 0, 0, 0];
+        let before = average_guesses(&distribution, 4).unwrap();
+
+        let bucket = record_guess_distribution(&mut distribution, true, 3);
+        let after = average_guesses(&distribution, 5).unwrap();
+
+        assert_eq!(bucket, Some(2));
+        assert_eq!(distribution, [0, 0, 1, 4, 0, 0, 0]);
+        assert_eq!(before, 4.0);
+        assert_eq!(after, 3.8);
+    }
+
+    #[test]
+    fn test_distribution_unchanged_for_loss() {
+        let mut distribution = [0, 1, 0, 0, 0, 0, 0];
+
+        assert_eq!(record_guess_distribution(&mut distribution, false, 7), None);
+        assert_eq!(record_guess_distribution(&mut distribution, true, 0), None);
+        assert_eq!(record_guess_distribution(&mut distribution, true, 8), None);
+        assert_eq!(distribution, [0, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(average_guesses(&[0; 7], 0), None);
+    }
+
+    #[test]
+    fn test_default_strategy_per_period_type() {
+        assert_eq!(
+            AggregationStrategy::default_for(PeriodType::Daily),
+            AggregationStrategy::Best
+        );
+        assert_eq!(
+            AggregationStrategy::default_for(PeriodType::Weekly),
+            AggregationStrategy::Sum
+        );
+        assert_eq!(
+            AggregationStrategy::default_for(PeriodType::Monthly),
+            AggregationStrategy::Sum
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use crate::constants::*;
+use crate::constants::DEFAULT_WORD_DIFFICULTY;
+use crate::errors::VobleError;
+use crate::state::{PeriodType, SharedWord, WordStrategy};
+use crate::utils::words::{get_word_by_index, word_list};
 use anchor_lang::prelude::*;
 use solana_program::hash::hash;
 
 /// Data returned from word selection
+#[derive(Debug)]
 pub struct WordSelectionData {
     pub word_hash: [u8; 32],
     pub word_index: u32,
@@ -16,16 +20,20 @@ pub struct WordSelectionData {
 ///
 /// # Current Implementation (Demo/Testing)
 /// Uses a deterministic hash of player data and game count to select from
-/// the VOBLE_WORDS array. While better than pure blockhash randomness,
+/// the word list of `source`. While better than pure blockhash randomness,
 /// this is still predictable and exploitable.
 ///
 /// # Arguments
 /// * `player` - The player's public key
 /// * `period_id` - The current period ID
 /// * `game_count` - The player's total games played (used as nonce)
+/// * `source` - Which competition's word list to draw from
 ///
 /// # Returns
-/// WordSelectionData containing the word hash and index
+/// WordSelectionData containing the word hash and the global word index
+///
+/// # Errors
+/// `WordListEmpty` if the list for `source` has no words
 ///
 /// # Security Notes
 /// - **Current**: Deterministic selection (can be predicted)
@@ -42,6 +50,7 @@ pub fn select_word_for_session(
     player: Pubkey,
     period_id: &str,
     game_count: u32,
+    source: PeriodType,
 ) -> Result<WordSelectionData> {
     msg!("⚠️  ========== DEMO MODE: WORD SELECTION ========== ⚠️");
     msg!("⚠️  Using deterministic word selection (INSECURE)");
@@ -59,16 +68,20 @@ pub fn select_word_for_session(
 
     let selection_hash = hash(selection_seed.as_bytes()).to_bytes();
 
+    let (words, offset) = word_list(source);
+    require!(!words.is_empty(), VobleError::WordListEmpty);
+
     // Use first 4 bytes to generate index
-    let word_index = (u32::from_le_bytes([
+    let list_index = (u32::from_le_bytes([
         selection_hash[0],
         selection_hash[1],
         selection_hash[2],
         selection_hash[3],
     ]) as usize)
-        % VOBLE_WORDS.len();
+        % words.len();
 
-    let selected_word = VOBLE_WORDS[word_index];
+    let selected_word = words[list_index];
+    let word_index = offset as usize + list_index;
 
     // Create hash of the selected word (hidden until game completion)
     let word_hash = hash(selected_word.as_bytes()).to_bytes();
@@ -85,41 +98,142 @@ pub fn select_word_for_session(
     })
 }
 
-/// Get a word from the word list by index
+/// Map a period ID to a word index, independent of the player
+///
+/// This is the stable `period_id → word_index` mapping used when every
+/// player in a period shares the same word. It is a pure function so that
+/// clients can reproduce historical words off-chain and so that tests can
+/// pin the mapping across program versions.
+///
+/// # Algorithm
+/// `sha256(period_id)`, first 4 bytes read as little-endian `u32`,
+/// modulo `word_count`.
+///
+/// # Arguments
+/// * `period_id` - The period ID (e.g., "D123")
+/// * `word_count` - Number of words in the list (must be > 0)
+///
+/// # Returns
+/// Index into the word list
+///
+/// # Stability
+/// ⚠️ Changing this function changes which word every past period maps to.
+/// The pinned values in the tests below must not be edited casually.
+pub fn word_index_for_period(period_id: &str, word_count: usize) -> u32 {
+    let period_hash = hash(period_id.as_bytes()).to_bytes();
+
+    let value = u32::from_le_bytes([
+        period_hash[0],
+        period_hash[1],
+        period_hash[2],
+        period_hash[3],
+    ]);
+
+    (value as usize % word_count) as u32
+}
+
+/// Map a period ID to a word index that avoids recently used words
+///
+/// Starts at `word_index_for_period` and probes forward one index at a
+/// time (wrapping) until it finds one not in `recent`, so the result is as
+/// reproducible off-chain as the plain mapping.
 ///
 /// # Arguments
-/// * `word_index` - Index of the word in VOBLE_WORDS array
+/// * `period_id` - The period ID (e.g., "D123")
+/// * `word_count` - Number of words in the list (must be > 0)
+/// * `recent` - Indexes the new word must not repeat
 ///
 /// # Returns
-/// The word at the specified index
-///
-/// # Panics
-/// If the index is out of bounds (should never happen in production)
-pub fn get_word_by_index(word_index: u32) -> Result<&'static str> {
-    VOBLE_WORDS
-        .get(word_index as usize)
-        .copied()
-        .ok_or_else(|| error!(crate::errors::VobleError::InvalidPeriodState))
+/// Index into the word list; the unprobed index if `recent` covers every word
+pub fn next_unused_word_index(period_id: &str, word_count: usize, recent: &[u32]) -> u32 {
+    let start = word_index_for_period(period_id, word_count);
+
+    (0..word_count as u32)
+        .map(|step| (start + step) % word_count as u32)
+        .find(|index| !recent.contains(index))
+        .unwrap_or(start)
 }
 
-/// Validate that a word exists in the word list
+/// Pick the word a new game session plays under the config's word strategy
 ///
 /// # Arguments
-/// * `word` - The word to validate
+/// * `strategy` - The config's word strategy
+/// * `shared_word` - The period's assigned shared word, if passed
+/// * `player`, `period_id`, `source` - As for `select_word_for_session`
 ///
 /// # Returns
-/// True if the word exists in VOBLE_WORDS, false otherwise
-pub fn is_valid_word(word: &str) -> bool {
-    let word_upper = word.to_uppercase();
-    VOBLE_WORDS.iter().any(|&w| w == word_upper)
+/// Under `CommitReveal` the period's shared word, the index
+/// `assign_shared_word` recorded; otherwise the player's own word
+///
+/// # Errors
+/// `SharedWordNotAssigned` under `CommitReveal` without a shared word for
+/// `period_id`
+pub fn session_word(
+    strategy: WordStrategy,
+    shared_word: Option<&SharedWord>,
+    player: Pubkey,
+    period_id: &str,
+    source: PeriodType,
+) -> Result<WordSelectionData> {
+    match strategy {
+        WordStrategy::Deterministic => select_word_for_session(player, period_id, 0, source),
+        WordStrategy::CommitReveal => {
+            let shared_word = shared_word
+                .filter(|shared_word| shared_word.period_id == period_id)
+                .ok_or(VobleError::SharedWordNotAssigned)?;
+            let word = get_word_by_index(shared_word.word_index)?;
+
+            msg!("📝 Shared word: index={}", shared_word.word_index);
+
+            Ok(WordSelectionData {
+                word_hash: hash(word.as_bytes()).to_bytes(),
+                word_index: shared_word.word_index,
+            })
+        }
+    }
 }
 
-/// Get the total number of words available
+/// Difficulty a new game session is scored with
+///
+/// # Arguments
+/// * `strategy` - The config's word strategy
+/// * `shared_word` - The period's assigned shared word, if passed
+/// * `ticket_difficulty` - Difficulty `buy_ticket_and_start_game` stamped on
+///   the profile for the ticket's own word
 ///
 /// # Returns
-/// The length of the VOBLE_WORDS array
-pub fn get_word_count() -> usize {
-    VOBLE_WORDS.len()
+/// Under `CommitReveal` the shared word's difficulty, stamped by
+/// `assign_shared_word`; otherwise the ticket's. An unset (0) difficulty
+/// reads as DEFAULT_WORD_DIFFICULTY.
+pub fn session_word_difficulty(
+    strategy: WordStrategy,
+    shared_word: Option<&SharedWord>,
+    ticket_difficulty: u8,
+) -> u8 {
+    let difficulty = match (strategy, shared_word) {
+        (WordStrategy::CommitReveal, Some(shared_word)) => shared_word.word_difficulty,
+        _ => ticket_difficulty,
+    };
+    match difficulty {
+        0 => DEFAULT_WORD_DIFFICULTY,
+        difficulty => difficulty,
+    }
+}
+
+/// Check that tickets are sold from a word list the strategy plays
+///
+/// Commit-reveal sessions all play the period's shared word, which
+/// `assign_shared_word` draws from the daily list, so a weekly or monthly
+/// `ticket_word_source` would be charged for but never played.
+///
+/// # Errors
+/// `CommitRevealNeedsDailyWords` under `CommitReveal` with a non-daily source
+pub fn require_word_source_playable(strategy: WordStrategy, source: PeriodType) -> Result<()> {
+    require!(
+        strategy != WordStrategy::CommitReveal || source == PeriodType::Daily,
+        VobleError::CommitRevealNeedsDailyWords
+    );
+    Ok(())
 }
 
 /* ========== VRF INTEGRATION TEMPLATE ========== */
@@ -189,28 +303,166 @@ pub fn get_word_count() -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::*;
+    use crate::state::UsedWords;
+    use crate::instructions::admin::pick_shared_word;
+    use crate::utils::words::get_word_count;
 
     #[test]
-    fn test_get_word_by_index() {
-        for i in 0..VOBLE_WORDS.len() {
-            let word = get_word_by_index(i as u32).unwrap();
-            assert_eq!(word.len(), 6);
-            assert!(word.chars().all(|c| c.is_ascii_uppercase()));
+    fn test_select_word_from_source_list() {
+        let player = Pubkey::new_unique();
+
+        for source in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            let (words, offset) = word_list(source);
+            assert!(!words.is_empty());
+
+            let result = select_word_for_session(player, "D123", 0, source).unwrap();
+            let word = get_word_by_index(result.word_index).unwrap();
+
+            assert!(result.word_index >= offset);
+            assert!(words.contains(&word));
+            assert_eq!(result.word_hash, hash(word.as_bytes()).to_bytes());
         }
     }
 
     #[test]
-    fn test_is_valid_word() {
-        assert!(is_valid_word("ANCHOR"));
-        assert!(is_valid_word("anchor")); // Case insensitive
-        assert!(is_valid_word("SOLANA"));
-        assert!(!is_valid_word("NOTAWORD"));
-        assert!(!is_valid_word("XYZ"));
+    fn test_word_index_for_period_pinned() {
+        // Pinned mapping - if these change, historical words change too
+        assert_eq!(word_index_for_period("D0", 20), 7);
+        assert_eq!(word_index_for_period("D123", 20), 16);
+        assert_eq!(word_index_for_period("D700", 20), 3);
+        assert_eq!(word_index_for_period("W45", 20), 6);
+        assert_eq!(word_index_for_period("M12", 20), 19);
     }
 
     #[test]
-    fn test_get_word_count() {
-        assert_eq!(get_word_count(), 20);
+    fn test_word_index_for_period_in_range() {
+        for day in 0..100 {
+            let period_id = format!("D{}", day);
+            let index = word_index_for_period(&period_id, get_word_count());
+            assert!(index < get_word_count() as u32);
+            assert_eq!(index, word_index_for_period(&period_id, get_word_count()));
+        }
+    }
+
+    #[test]
+    fn test_consecutive_periods_never_repeat_within_window() {
+        for window in [1, DEFAULT_SHARED_WORD_WINDOW as usize, MAX_SHARED_WORD_WINDOW] {
+            let mut assigned: Vec<u32> = Vec::new();
+
+            for day in 0..200 {
+                let recent: Vec<u32> = assigned.iter().rev().take(window).copied().collect();
+                let index = next_unused_word_index(&format!("D{}", day), VOBLE_WORDS.len(), &recent);
+
+                assert!(index < VOBLE_WORDS.len() as u32);
+                assert!(!recent.contains(&index), "D{} repeats a word within {}", day, window);
+                assigned.push(index);
+            }
+
+            // Any `window + 1` consecutive periods have distinct words
+            for run in assigned.windows(window + 1) {
+                let mut sorted = run.to_vec();
+                sorted.sort_unstable();
+                sorted.dedup();
+                assert_eq!(sorted.len(), run.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unused_index_probes_from_hash() {
+        // No history: same as the plain mapping
+        assert_eq!(next_unused_word_index("D0", 20, &[]), 7);
+        // Hashed index taken: next free one, wrapping past the end
+        assert_eq!(next_unused_word_index("D0", 20, &[7, 8]), 9);
+        assert_eq!(next_unused_word_index("M12", 20, &[19]), 0);
+
+        // Every word used: falls back to the hashed index
+        let all: Vec<u32> = (0..20).collect();
+        assert_eq!(next_unused_word_index("D0", 20, &all), 7);
+    }
+
+    #[test]
+    fn test_used_words_ring_buffer() {
+        let mut used = UsedWords {
+            recent: [0; MAX_SHARED_WORD_WINDOW],
+            len: 0,
+            next: 0,
+            last_period: 0,
+            bump: 255,
+        };
+        for (day, index) in (0..MAX_SHARED_WORD_WINDOW as u64 + 3).zip(100..) {
+            used.record(day, index);
+        }
+
+        assert_eq!(used.len as usize, MAX_SHARED_WORD_WINDOW);
+        assert_eq!(used.last_period, MAX_SHARED_WORD_WINDOW as u64 + 2);
+        let last = 100 + MAX_SHARED_WORD_WINDOW as u32 + 2;
+        assert_eq!(used.recent_window(3), vec![last, last - 1, last - 2]);
+        assert_eq!(used.recent_window(100).len(), MAX_SHARED_WORD_WINDOW);
+    }
+
+    #[test]
+    fn test_sessions_never_play_a_recently_used_shared_word() {
+        let mut used = UsedWords {
+            recent: [0; MAX_SHARED_WORD_WINDOW],
+            len: 0,
+            next: 0,
+            last_period: 0,
+            bump: 255,
+        };
+        // D2-D4 took D5's hashed word and the two after it
+        let count = VOBLE_WORDS.len() as u32;
+        let hashed = word_index_for_period("D5", VOBLE_WORDS.len());
+        let recent = [hashed, (hashed + 1) % count, (hashed + 2) % count];
+        for (day, index) in (2..).zip(recent) {
+            used.record(day, index);
+        }
+
+        let (word_index, hashed_index) = pick_shared_word(&mut used, "D5", 7).unwrap();
+        assert_eq!(hashed_index, hashed);
+        assert_eq!(word_index, (hashed + 3) % count);
+        let shared_word = SharedWord {
+            period_id: "D5".to_string(),
+            word_index,
+            assigned_at: 0,
+            bump: 255,
+            word_difficulty: 4,
+        };
+
+        // Every player of D5 plays the assigned word, whatever their ticket's list
+        for source in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            let session = session_word(
+                WordStrategy::CommitReveal,
+                Some(&shared_word),
+                Pubkey::new_unique(),
+                "D5",
+                source,
+            )
+            .unwrap();
+            assert_eq!(session.word_index, word_index);
+            assert!(!recent.contains(&session.word_index));
+        }
+
+        // A period already assigned can't be re-drawn onto a different word
+        assert_eq!(
+            pick_shared_word(&mut used, "D5", 7).unwrap_err(),
+            VobleError::SharedWordAlreadyAssigned.into()
+        );
+        // Without its shared word, or with another day's, the period can't be played
+        for shared_word in [None, Some(&shared_word)] {
+            assert_eq!(
+                session_word(
+                    WordStrategy::CommitReveal,
+                    shared_word,
+                    Pubkey::new_unique(),
+                    "D6",
+                    PeriodType::Daily,
+                )
+                .unwrap_err(),
+                VobleError::SharedWordNotAssigned.into()
+            );
+        }
     }
 
     #[test]
@@ -219,8 +471,8 @@ mod tests {
         let period_id = "D123";
 
         // Same inputs should give same result
-        let result1 = select_word_for_session(player, period_id, 0).unwrap();
-        let result2 = select_word_for_session(player, period_id, 0).unwrap();
+        let result1 = select_word_for_session(player, period_id, 0, PeriodType::Daily).unwrap();
+        let result2 = select_word_for_session(player, period_id, 0, PeriodType::Daily).unwrap();
 
         assert_eq!(result1.word_index, result2.word_index);
         assert_eq!(result1.word_hash, result2.word_hash);
@@ -232,12 +484,60 @@ mod tests {
         let period_id = "D123";
 
         // Different game counts should give different results
-        let result1 = select_word_for_session(player, period_id, 0).unwrap();
-        let result2 = select_word_for_session(player, period_id, 1).unwrap();
+        let result1 = select_word_for_session(player, period_id, 0, PeriodType::Daily).unwrap();
+        let result2 = select_word_for_session(player, period_id, 1, PeriodType::Daily).unwrap();
 
         // Should be different (though not guaranteed due to modulo)
         // Just check that function executes without error
         assert!(result1.word_index < VOBLE_WORDS.len() as u32);
         assert!(result2.word_index < VOBLE_WORDS.len() as u32);
     }
+
+    #[test]
+    fn test_commit_reveal_scores_shared_word_difficulty() {
+        let shared_word = SharedWord {
+            period_id: "D5".to_string(),
+            word_index: 3,
+            assigned_at: 0,
+            bump: 255,
+            word_difficulty: 5,
+        };
+
+        assert_eq!(
+            session_word_difficulty(WordStrategy::CommitReveal, Some(&shared_word), 2),
+            5
+        );
+        assert_eq!(
+            session_word_difficulty(WordStrategy::Deterministic, Some(&shared_word), 2),
+            2
+        );
+
+        // Words assigned or tickets bought before difficulty was stamped
+        let unstamped = SharedWord {
+            word_difficulty: 0,
+            ..shared_word
+        };
+        assert_eq!(
+            session_word_difficulty(WordStrategy::CommitReveal, Some(&unstamped), 2),
+            DEFAULT_WORD_DIFFICULTY
+        );
+        assert_eq!(
+            session_word_difficulty(WordStrategy::Deterministic, None, 0),
+            DEFAULT_WORD_DIFFICULTY
+        );
+    }
+
+    #[test]
+    fn test_commit_reveal_only_sells_daily_words() {
+        for source in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            assert!(require_word_source_playable(WordStrategy::Deterministic, source).is_ok());
+        }
+        assert!(require_word_source_playable(WordStrategy::CommitReveal, PeriodType::Daily).is_ok());
+        for source in [PeriodType::Weekly, PeriodType::Monthly] {
+            assert_eq!(
+                require_word_source_playable(WordStrategy::CommitReveal, source).unwrap_err(),
+                VobleError::CommitRevealNeedsDailyWords.into()
+            );
+        }
+    }
 }
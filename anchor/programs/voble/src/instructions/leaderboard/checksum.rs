@@ -0,0 +1,124 @@
+use crate::state::LeaderEntry;
+use crate::{contexts::*, events::*};
+use anchor_lang::prelude::*;
+use solana_program::hash::hashv;
+
+/// Bytes hashed per entry: player (32) + score (4) + time_ms (8) + guesses_used (1)
+pub const CHECKSUM_TUPLE_LEN: usize = 32 + 4 + 8 + 1;
+
+/// Emit a checksum of a leaderboard's standings
+///
+/// Off-chain indexers recompute `leaderboard_checksum` over their mirrored
+/// entries and compare it with the `LeaderboardChecksum` event to detect
+/// divergence.
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard
+/// * `period_id` - Period of the leaderboard (e.g., "D123")
+/// * `period_type` - Leaderboard type seed (0 = daily, 1 = weekly, 2 = monthly)
+///
+/// # Notes
+/// - Read-only and permissionless; the result is the event
+pub fn get_leaderboard_checksum(
+    ctx: Context<GetLeaderboardChecksum>,
+    period_id: String,
+    period_type: u8,
+) -> Result<()> {
+    let leaderboard = &ctx.accounts.leaderboard;
+    let checksum = leaderboard_checksum(&leaderboard.entries);
+
+    msg!("🧮 Leaderboard checksum for {} (type {})", period_id, period_type);
+    msg!("   Entries: {}", leaderboard.entries.len());
+    msg!("   Checksum: {:x?}", &checksum[..8]);
+
+    emit!(LeaderboardChecksum {
+        period_id,
+        period_type,
+        entry_count: leaderboard.entries.len() as u32,
+        checksum,
+    });
+
+    Ok(())
+}
+
+/// Hash of the ordered `(player, score, time_ms, guesses_used)` tuples
+///
+/// # Algorithm
+/// `sha256(tuple_0 || tuple_1 || ...)` in board order, where each tuple is
+/// `player || score_le || time_ms_le || guesses_used` (CHECKSUM_TUPLE_LEN
+/// bytes). An empty board hashes the empty string.
+pub fn leaderboard_checksum(entries: &[LeaderEntry]) -> [u8; 32] {
+    let tuples: Vec<[u8; CHECKSUM_TUPLE_LEN]> = entries.iter().map(checksum_tuple).collect();
+    let slices: Vec<&[u8]> = tuples.iter().map(|tuple| tuple.as_slice()).collect();
+    hashv(&slices).to_bytes()
+}
+
+/// Serialize the checksummed fields of one entry
+fn checksum_tuple(entry: &LeaderEntry) -> [u8; CHECKSUM_TUPLE_LEN] {
+    let mut tuple = [0u8; CHECKSUM_TUPLE_LEN];
+    tuple[..32].copy_from_slice(entry.player.as_ref());
+    tuple[32..36].copy_from_slice(&entry.score.to_le_bytes());
+    tuple[36..44].copy_from_slice(&entry.time_ms.to_le_bytes());
+    tuple[44] = entry.guesses_used;
+    tuple
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::hash::hash;
+
+    fn entry(score: u32, time_ms: u64, guesses_used: u8) -> LeaderEntry {
+        LeaderEntry {
+            player: Pubkey::new_unique(),
+            score,
+            guesses_used,
+            time_ms,
+            timestamp: 0,
+            username: "Test".to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    #[test]
+    fn test_checksum_matches_documented_layout() {
+        let entries = vec![entry(1_500, 25_000, 1), entry(900, 45_000, 3)];
+
+        let mut bytes = Vec::new();
+        for e in &entries {
+            bytes.extend_from_slice(e.player.as_ref());
+            bytes.extend_from_slice(&e.score.to_le_bytes());
+            bytes.extend_from_slice(&e.time_ms.to_le_bytes());
+            bytes.push(e.guesses_used);
+        }
+
+        assert_eq!(leaderboard_checksum(&entries), hash(&bytes).to_bytes());
+        assert_eq!(leaderboard_checksum(&[]), hash(&[]).to_bytes());
+    }
+
+    #[test]
+    fn test_checksum_detects_order_and_field_changes() {
+        let entries = vec![entry(1_500, 25_000, 1), entry(900, 45_000, 3)];
+        let checksum = leaderboard_checksum(&entries);
+
+        let swapped = vec![entries[1].clone(), entries[0].clone()];
+        assert_ne!(leaderboard_checksum(&swapped), checksum);
+
+        let mut edited = entries.clone();
+        edited[1].time_ms += 1;
+        assert_ne!(leaderboard_checksum(&edited), checksum);
+    }
+
+    #[test]
+    fn test_checksum_ignores_display_fields() {
+        let entries = vec![entry(1_500, 25_000, 1)];
+        let checksum = leaderboard_checksum(&entries);
+
+        let mut renamed = entries.clone();
+        renamed[0].username = "Renamed".to_string();
+        renamed[0].equipped_flair = 4;
+        assert_eq!(leaderboard_checksum(&renamed), checksum);
+    }
+}
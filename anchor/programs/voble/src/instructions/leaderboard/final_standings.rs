@@ -0,0 +1,180 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+use std::ops::Range;
+
+/// Emit a chunk of a finalized leaderboard's standings for indexers
+///
+/// `finalize_leaderboard` only announces the top 3. This exports every
+/// entry as a `FinalStandingEntry` event, a chunk at a time, so the full
+/// standings are verifiable from transaction history alone.
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard and the cranker
+/// * `period_id` - Period of the leaderboard (e.g., "D123")
+/// * `period_type` - Leaderboard type seed (0 = daily, 1 = weekly, 2 = monthly)
+/// * `start_index` - First entry to emit; must equal `emitted_up_to`
+/// * `count` - Entries to emit (1..=MAX_STANDINGS_PER_CALL)
+///
+/// # Validation
+/// - Leaderboard must be finalized
+/// - Chunks must be contiguous: no entry is skipped or emitted twice
+///
+/// # Notes
+/// - Permissionless; anyone can crank the export
+/// - `emitted_up_to == entries.len()` once the export is complete
+pub fn emit_final_standings(
+    ctx: Context<EmitFinalStandings>,
+    period_id: String,
+    period_type: u8,
+    start_index: u16,
+    count: u16,
+) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    // ========== VALIDATION ==========
+    let range = next_standings_chunk(leaderboard, start_index, count)?;
+
+    // ========== EMIT STANDINGS ==========
+    for (index, entry) in leaderboard.entries[range.clone()].iter().enumerate() {
+        emit!(FinalStandingEntry {
+            period_id: period_id.clone(),
+            period_type,
+            rank: (range.start + index + 1) as u8,
+            player: entry.player,
+            username: entry.username.clone(),
+            score: entry.score,
+        });
+    }
+    leaderboard.emitted_up_to = range.end as u16;
+
+    msg!("📤 Final standings exported for {} (type {})", period_id, period_type);
+    msg!("   Ranks {}..={}", range.start + 1, range.end);
+    msg!(
+        "   Progress: {}/{}",
+        leaderboard.emitted_up_to,
+        leaderboard.entries.len()
+    );
+
+    Ok(())
+}
+
+/// Entries covered by the next export chunk
+///
+/// # Returns
+/// `start_index..start_index + count`, or an error if the leaderboard isn't
+/// finalized (`LeaderboardNotFinalized`) or the chunk doesn't continue from
+/// the cursor, is empty, too large or runs past the end (`InvalidStandingsChunk`)
+pub fn next_standings_chunk(
+    leaderboard: &PeriodLeaderboard,
+    start_index: u16,
+    count: u16,
+) -> Result<Range<usize>> {
+    require!(leaderboard.finalized, VobleError::LeaderboardNotFinalized);
+    require!(
+        start_index == leaderboard.emitted_up_to,
+        VobleError::InvalidStandingsChunk
+    );
+    require!(
+        count > 0 && count as usize <= MAX_STANDINGS_PER_CALL,
+        VobleError::InvalidStandingsChunk
+    );
+
+    let start = start_index as usize;
+    let end = start + count as usize;
+    require!(
+        end <= leaderboard.entries.len(),
+        VobleError::InvalidStandingsChunk
+    );
+
+    Ok(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finalized_board(entries: usize) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("W1", PeriodType::Weekly);
+        board.entries = (0..entries)
+            .map(|i| LeaderEntry {
+                player: Pubkey::new_unique(),
+                score: 10_000 - i as u32,
+                guesses_used: 3,
+                time_ms: 30_000,
+                timestamp: 0,
+                username: format!("player{}", i),
+                equipped_flair: 0,
+                games_played: 1,
+                counted_days: 0,
+            })
+            .collect();
+        board.total_players = entries as u32;
+        board.finalized = true;
+        board.finalized_at = Some(0);
+        board.aggregation = AggregationStrategy::Sum;
+        board.entrants = entries as u32;
+        board
+    }
+
+    #[test]
+    fn test_chunks_cover_board_exactly_once() {
+        let mut board = finalized_board(45);
+        let mut exported = Vec::new();
+
+        while (board.emitted_up_to as usize) < board.entries.len() {
+            let remaining = board.entries.len() - board.emitted_up_to as usize;
+            let count = remaining.min(MAX_STANDINGS_PER_CALL) as u16;
+            let range = next_standings_chunk(&board, board.emitted_up_to, count).unwrap();
+            exported.extend(range.clone());
+            board.emitted_up_to = range.end as u16;
+        }
+
+        assert_eq!(exported, (0..45).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_chunks_cannot_skip_or_repeat() {
+        let mut board = finalized_board(30);
+        board.emitted_up_to = 20;
+
+        for start in [0, 19, 21] {
+            assert_eq!(
+                next_standings_chunk(&board, start, 5).unwrap_err(),
+                VobleError::InvalidStandingsChunk.into()
+            );
+        }
+        assert!(next_standings_chunk(&board, 20, 10).is_ok());
+    }
+
+    #[test]
+    fn test_chunk_size_and_bounds() {
+        let board = finalized_board(30);
+
+        assert_eq!(
+            next_standings_chunk(&board, 0, 0).unwrap_err(),
+            VobleError::InvalidStandingsChunk.into()
+        );
+        assert_eq!(
+            next_standings_chunk(&board, 0, MAX_STANDINGS_PER_CALL as u16 + 1).unwrap_err(),
+            VobleError::InvalidStandingsChunk.into()
+        );
+
+        let mut board = board;
+        board.emitted_up_to = 20;
+        assert_eq!(
+            next_standings_chunk(&board, 20, 11).unwrap_err(),
+            VobleError::InvalidStandingsChunk.into()
+        );
+    }
+
+    #[test]
+    fn test_export_requires_finalized_board() {
+        let mut board = finalized_board(5);
+        board.finalized = false;
+
+        assert_eq!(
+            next_standings_chunk(&board, 0, 5).unwrap_err(),
+            VobleError::LeaderboardNotFinalized.into()
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, utils::validate_period_id_for};
+use crate::state::PeriodLeaderboard;
+use super::require_rollups_complete;
 use anchor_lang::prelude::*;
 
 /// Finalize the period leaderboard and officially determine winners
@@ -13,10 +15,13 @@ use anchor_lang::prelude::*;
 /// * `_period_type` - Period type (prefixed with _ as not used in logic)
 ///
 /// # Validation
+/// - Period ID must be a valid period ID (D/W/M + number, at most 20 bytes)
+///   whose prefix matches the leaderboard's period type (`PeriodTypeMismatch`)
 /// - Only authority can finalize leaderboards
-/// - Leaderboard must not already be finalized
-/// - Leaderboard must have at least 1 entry (at least one player played)
+/// - Leaderboard must not already be finalized or canceled
 /// - Period ID must match leaderboard's period_id
+/// - Rollup-mode boards must have every day of the period rolled up
+///   (`RollupIncomplete`)
 ///
 /// # What Happens
 /// 1. Marks leaderboard as finalized (locked)
@@ -33,8 +38,8 @@ use anchor_lang::prelude::*;
 ///
 /// # After Finalization
 /// - Leaderboard is locked (no more updates)
-/// - Admin can create winner entitlements
-/// - Admin can finalize the period (distribute prizes)
+/// - Admin can finalize the period (compute prizes)
+/// - Admin can then create winner entitlements
 /// - Winners can claim their prizes
 ///
 /// # When to Call
@@ -48,19 +53,23 @@ use anchor_lang::prelude::*;
 /// - Must be called BEFORE prize finalization
 /// - Cannot be undone - leaderboard is permanently locked
 /// - If fewer than 3 players, only available positions get prizes
+/// - A period with no players can still be finalized (0 winners), so the
+///   period's prize finalization is never stuck
 ///
 /// # Example Flow
 /// 1. Initialize leaderboard (period starts)
 /// 2. Players complete games (leaderboard updates)
 /// 3. **Finalize leaderboard (this instruction)** ← You are here
-/// 4. Admin creates winner entitlements
-/// 5. Winners claim prizes
+/// 4. Admin finalizes the period (prize::finalize_*)
+/// 5. Admin creates winner entitlements
+/// 6. Winners claim prizes
 pub fn finalize_leaderboard(
     ctx: Context<FinalizeLeaderboard>,
     period_id: String,
     _period_type: u8,
 ) -> Result<()> {
     let leaderboard = &mut ctx.accounts.leaderboard;
+    validate_period_id_for(&period_id, leaderboard.period_type)?;
     let now = Clock::get()?.unix_timestamp;
 
     msg!("🏁 Finalizing leaderboard");
@@ -68,28 +77,10 @@ pub fn finalize_leaderboard(
     msg!("   Total players: {}", leaderboard.total_players);
     msg!("   Total entries: {}", leaderboard.entries.len());
 
-    // ========== VALIDATION ==========
-    // Must not already be finalized
-    require!(!leaderboard.finalized, VobleError::AlreadyClaimed);
-
-    // Must have at least one player
-    require!(
-        leaderboard.entries.len() > 0,
-        VobleError::InvalidWinnerCount
-    );
-
-    // Validate period ID matches
-    require!(
-        leaderboard.period_id == period_id,
-        VobleError::InvalidPeriodState
-    );
+    // ========== VALIDATION & LOCK ==========
+    lock_leaderboard(leaderboard, &period_id, now)?;
 
     msg!("✅ Validation passed");
-
-    // ========== FINALIZE LEADERBOARD ==========
-    leaderboard.finalized = true;
-    leaderboard.finalized_at = Some(now);
-
     msg!("🔒 Leaderboard locked (finalized)");
     msg!("   Finalized at: {}", now);
     msg!("   No more entries can be added");
@@ -128,6 +119,10 @@ pub fn finalize_leaderboard(
         );
     }
 
+    if winners_count == 0 {
+        msg!("   No players this period - prize pool rolls forward");
+    }
+
     msg!("==========================================");
 
     // ========== EMIT FINALIZATION EVENT ==========
@@ -147,9 +142,36 @@ pub fn finalize_leaderboard(
     msg!("   Total players: {}", leaderboard.total_players);
     msg!("");
     msg!("💡 Next steps:");
-    msg!("   1. Create winner entitlements (admin)");
-    msg!("   2. Finalize period prizes (admin)");
+    msg!("   1. Finalize period prizes (admin)");
+    msg!("   2. Create winner entitlements (admin)");
     msg!("   3. Winners can claim their prizes");
 
     Ok(())
 }
+
+/// Lock a leaderboard so the period's prizes can be finalized from it
+///
+/// # Validation
+/// - Leaderboard must not already be finalized or canceled
+/// - Period ID must match the leaderboard's period_id
+/// - Rollup-mode boards must have every day rolled up, since a locked board
+///   takes no more rollups
+pub fn lock_leaderboard(leaderboard: &mut PeriodLeaderboard, period_id: &str, now: i64) -> Result<()> {
+    // Must not already be finalized
+    require!(!leaderboard.finalized, VobleError::AlreadyClaimed);
+
+    // Canceled periods pay refunds instead of prizes
+    require!(!leaderboard.canceled, VobleError::PeriodCanceled);
+
+    // Validate period ID matches
+    require!(
+        leaderboard.period_id == period_id,
+        VobleError::InvalidPeriodState
+    );
+
+    require_rollups_complete(leaderboard)?;
+
+    leaderboard.finalized = true;
+    leaderboard.finalized_at = Some(now);
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use crate::utils::words::word_list;
+use crate::state::{AggregationStrategy, PeriodLeaderboard, PeriodType};
+use crate::utils::period::{self, get_current_period_id, parse_period_id};
+use crate::utils::pda;
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use super::{fill_new_leaderboard, growth_steps_remaining};
+
+/// Create the leaderboards of the next `count` periods of one type
+///
+/// Lets a cron pre-create upcoming boards (e.g. a week of daily boards) in
+/// one transaction instead of calling `initialize_period_leaderboard` once
+/// per period.
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config and authority
+/// * `period_type` - 0=Daily, 1=Weekly, 2=Monthly
+/// * `count` - Number of upcoming periods, starting after the current one
+///
+/// # Remaining Accounts
+/// One writable leaderboard PDA per period, in period order
+/// (`[SEED_LEADERBOARD, period_id, period_type]`)
+///
+/// # Validation
+/// - Only the authority can call this instruction
+/// - Period type must be 0, 1, or 2
+/// - Count must be 1 to MAX_FUTURE_LEADERBOARDS, with exactly `count`
+///   remaining accounts
+/// - Each account must be the leaderboard PDA of its period
+///
+/// # Notes
+/// - Boards that already exist are skipped, so the cron can rerun the same
+///   window safely
+/// - New boards get the default aggregation and the same snapshots as
+///   `initialize_period_leaderboard`; capacities above the default still
+///   need `grow_leaderboard`
+/// - Emits one `LeaderboardInitialized` per board created
+pub fn initialize_future_leaderboards<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeFutureLeaderboards<'info>>,
+    period_type: u8,
+    count: u8,
+) -> Result<()> {
+    // ========== VALIDATION ==========
+    let period_type = match period_type {
+        0 => PeriodType::Daily,
+        1 => PeriodType::Weekly,
+        2 => PeriodType::Monthly,
+        _ => return Err(VobleError::InvalidPeriodState.into()),
+    };
+    require!(
+        (1..=MAX_FUTURE_LEADERBOARDS).contains(&count)
+            && ctx.remaining_accounts.len() == count as usize,
+        VobleError::FutureLeaderboardCountInvalid
+    );
+    require!(
+        !word_list(period_type).0.is_empty(),
+        VobleError::WordListEmpty
+    );
+
+    let config = &ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
+    let period_ids = future_period_ids(period_type, now, config.period_epoch(), count)
+        .ok_or(VobleError::InvalidPeriodState)?;
+
+    msg!("📊 Initializing {} future {:?} leaderboards", count, period_type);
+
+    // ========== CREATE BOARDS ==========
+    let aggregation = AggregationStrategy::default_for(period_type);
+    let space = PeriodLeaderboard::next_space(0, config.leaderboard_capacity() as usize);
+    let mut created = 0u8;
+
+    for (period_id, info) in period_ids.iter().zip(ctx.remaining_accounts.iter()) {
+        let (address, bump) = pda::derive_leaderboard_pda(period_id, period_type, &crate::ID);
+        require_keys_eq!(address, info.key(), VobleError::Unauthorized);
+
+        if info.owner == &crate::ID {
+            msg!("   ⏭️  {} already exists", period_id);
+            continue;
+        }
+        require!(info.is_writable, VobleError::Unauthorized);
+
+        let seeds: &[&[u8]] = &[
+            SEED_LEADERBOARD,
+            period_id.as_bytes(),
+            period_type.seed(),
+            &[bump],
+        ];
+        create_pda_account(
+            info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            space,
+            seeds,
+        )?;
+
+        let mut data = info.try_borrow_mut_data()?;
+        let mut leaderboard = PeriodLeaderboard::try_deserialize_unchecked(&mut &data[..])?;
+        fill_new_leaderboard(
+            &mut leaderboard,
+            period_id,
+            period_type,
+            aggregation,
+            config,
+            bump,
+            now,
+        );
+        leaderboard.try_serialize(&mut &mut data[..])?;
+        created += 1;
+
+        msg!("   ✅ {} created", period_id);
+        let remaining = growth_steps_remaining(space, leaderboard.capacity());
+        if remaining > 0 {
+            msg!("      ⚠️  Call grow_leaderboard {} more time(s)", remaining);
+        }
+
+        emit!(LeaderboardInitialized {
+            period_id: period_id.clone(),
+            period_type,
+            aggregation,
+            created_at: now,
+        });
+    }
+
+    msg!("📊 {} created, {} skipped", created, count - created);
+
+    Ok(())
+}
+
+/// Period IDs of the `count` periods after the current one
+///
+/// # Arguments
+/// * `period_type` - Daily, weekly or monthly
+/// * `now` - Current Unix timestamp
+/// * `epoch_start` - Timestamp of period 0
+/// * `count` - Number of periods
+///
+/// # Returns
+/// `None` if `now` is before the epoch
+pub fn future_period_ids(
+    period_type: PeriodType,
+    now: i64,
+    epoch_start: i64,
+    count: u8,
+) -> Option<Vec<String>> {
+    let kind = match period_type {
+        PeriodType::Daily => period::PeriodType::Daily,
+        PeriodType::Weekly => period::PeriodType::Weekly,
+        PeriodType::Monthly => period::PeriodType::Monthly,
+    };
+    let (_, current) = parse_period_id(&get_current_period_id(kind, now, epoch_start))?;
+
+    Some(
+        (1..=count as u64)
+            .map(|offset| format!("{}{}", kind.prefix(), current + offset))
+            .collect(),
+    )
+}
+
+/// Create a program-owned PDA account, the way `init` does
+///
+/// An address that was already sent lamports can't go through
+/// `create_account`, so it is topped up to rent exemption, allocated and
+/// assigned instead.
+fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(space);
+    let signer_seeds = &[seeds];
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_exempt,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent_exempt.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH: i64 = PERIOD_EPOCH_START;
+
+    #[test]
+    fn test_future_period_ids_start_after_current() {
+        // Day 10, week 1, month 0
+        let now = EPOCH + 10 * PERIOD_DAILY_DURATION + 60;
+
+        assert_eq!(
+            future_period_ids(PeriodType::Daily, now, EPOCH, 7).unwrap(),
+            ["D11", "D12", "D13", "D14", "D15", "D16", "D17"]
+        );
+        assert_eq!(
+            future_period_ids(PeriodType::Weekly, now, EPOCH, 2).unwrap(),
+            ["W2", "W3"]
+        );
+        assert_eq!(
+            future_period_ids(PeriodType::Monthly, now, EPOCH, 1).unwrap(),
+            ["M1"]
+        );
+    }
+
+    #[test]
+    fn test_future_period_ids_match_period_type() {
+        let now = EPOCH + 40 * PERIOD_DAILY_DURATION;
+        let ids = future_period_ids(PeriodType::Weekly, now, EPOCH, MAX_FUTURE_LEADERBOARDS).unwrap();
+
+        assert_eq!(ids.len(), MAX_FUTURE_LEADERBOARDS as usize);
+        for id in &ids {
+            assert!(crate::utils::validate_period_id_for(id, PeriodType::Weekly).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_future_period_ids_before_epoch() {
+        assert!(future_period_ids(PeriodType::Daily, EPOCH - PERIOD_DAILY_DURATION, EPOCH, 3).is_none());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::state::PeriodType;
+use crate::utils::words::word_list;
+use crate::state::{
+    AggregationStrategy, GlobalConfig, LeaderboardMode, PeriodLeaderboard, PeriodType,
+};
+use crate::utils::validate_period_id_for;
 use crate::{constants::*, contexts::*, errors::VobleError, events::*};
 use anchor_lang::prelude::*;
 
@@ -12,10 +16,15 @@ use anchor_lang::prelude::*;
 /// * `ctx` - The context containing the leaderboard account and authority
 /// * `period_id` - Unique identifier for this period (e.g., "D123", "W45", "M12")
 /// * `period_type` - Type of period: 0=Daily, 1=Weekly, 2=Monthly
+/// * `aggregation` - How repeat games combine: 0=Best, 1=Sum, 2=Average
+///   (None = Best for daily, Sum for weekly/monthly)
 ///
 /// # Validation
 /// - Period ID must be 1-20 characters
 /// - Period type must be 0, 1, or 2
+/// - Period ID prefix must match the period type (`PeriodTypeMismatch`)
+/// - Aggregation strategy (if given) must be 0, 1, or 2
+/// - Word list for the period type must not be empty
 /// - Only authority can initialize leaderboards
 /// - Leaderboard PDA must not already exist (enforced by init constraint)
 ///
@@ -27,6 +36,9 @@ use anchor_lang::prelude::*;
 /// - Prize pool at 0 (updated during finalization)
 /// - Not finalized status
 /// - Creation timestamp
+/// - Snapshot of the current winner splits (payout terms for this period)
+/// - Score aggregation strategy (fixed for the life of the leaderboard)
+/// - Snapshot of the configured capacity (entries kept before the lowest drops off)
 ///
 /// # When to Call
 /// This should be called at the START of each new period:
@@ -39,6 +51,9 @@ use anchor_lang::prelude::*;
 /// - Must be initialized BEFORE players can play that period
 /// - Leaderboard is automatically updated when players complete games
 /// - Leaderboard is finalized at the end of the period
+/// - Capacities above DEFAULT_LEADERBOARD_CAPACITY don't fit one `init`;
+///   call `grow_leaderboard` until it reports the board fully allocated,
+///   before the board fills past what has been allocated
 ///
 /// # Example Flow
 /// 1. **Initialize leaderboard (this instruction)** ← You are here
@@ -49,14 +64,8 @@ pub fn initialize_period_leaderboard(
     ctx: Context<InitializePeriodLeaderboard>,
     period_id: String,
     period_type: u8,
+    aggregation: Option<u8>,
 ) -> Result<()> {
-    // ========== VALIDATION: Period ID ==========
-    require!(
-        period_id.len() <= MAX_PERIOD_ID_LENGTH,
-        VobleError::PeriodIdTooLong
-    );
-    require!(period_id.len() > 0, VobleError::SessionIdEmpty);
-
     msg!("📊 Initializing leaderboard");
     msg!("   Period ID: {}", period_id);
     msg!("   Period type: {}", period_type);
@@ -77,36 +86,69 @@ pub fn initialize_period_leaderboard(
 
     msg!("   Period type enum: {:?}", period_type_enum);
 
-    // ========== INITIALIZE LEADERBOARD ==========
-    let leaderboard = &mut ctx.accounts.leaderboard;
-    let now = Clock::get()?.unix_timestamp;
+    // ========== VALIDATION: Period ID ==========
+    validate_period_id_for(&period_id, period_type_enum)?;
 
-    // Set period identification
-    leaderboard.period_id = period_id.clone();
-    leaderboard.period_type = period_type_enum;
+    // ========== VALIDATION: Word List ==========
+    // Sessions can draw their word from this period type's list
+    require!(
+        !word_list(period_type_enum).0.is_empty(),
+        VobleError::WordListEmpty
+    );
 
-    // Initialize empty state
-    leaderboard.entries = Vec::new();
-    leaderboard.total_players = 0;
-    leaderboard.prize_pool = 0;
+    // ========== VALIDATION: Aggregation Strategy ==========
+    let aggregation = match aggregation {
+        None => AggregationStrategy::default_for(period_type_enum),
+        Some(0) => AggregationStrategy::Best,
+        Some(1) => AggregationStrategy::Sum,
+        Some(2) => AggregationStrategy::Average,
+        Some(other) => {
+            msg!(
+                "❌ Invalid aggregation strategy: {} (must be 0, 1, or 2)",
+                other
+            );
+            return Err(VobleError::InvalidAggregationStrategy.into());
+        }
+    };
 
-    // Set status flags
-    leaderboard.finalized = false;
+    msg!("   Aggregation: {:?}", aggregation);
 
-    // Set timestamps
-    leaderboard.created_at = now;
-    leaderboard.finalized_at = None;
+    // ========== INITIALIZE LEADERBOARD ==========
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let now = Clock::get()?.unix_timestamp;
+
+    fill_new_leaderboard(
+        leaderboard,
+        &period_id,
+        period_type_enum,
+        aggregation,
+        &ctx.accounts.global_config,
+        ctx.bumps.leaderboard,
+        now,
+    );
 
     msg!("✅ Leaderboard data initialized");
     msg!("   Entries: {} (empty)", leaderboard.entries.len());
     msg!("   Total players: {}", leaderboard.total_players);
     msg!("   Finalized: {}", leaderboard.finalized);
     msg!("   Created at: {}", now);
+    msg!("   Winner splits: {:?}", leaderboard.winner_splits);
+    msg!("   Mode: {:?}", leaderboard.mode);
+    msg!("   Capacity: {} entries", leaderboard.capacity);
+
+    let remaining = growth_steps_remaining(
+        leaderboard.to_account_info().data_len(),
+        leaderboard.capacity(),
+    );
+    if remaining > 0 {
+        msg!("   ⚠️  Call grow_leaderboard {} more time(s)", remaining);
+    }
 
     // ========== EMIT EVENT ==========
     emit!(LeaderboardInitialized {
         period_id: period_id.clone(),
         period_type: period_type_enum,
+        aggregation,
         created_at: now,
     });
 
@@ -121,3 +163,244 @@ pub fn initialize_period_leaderboard(
 
     Ok(())
 }
+
+/// Write the initial state of a just-created leaderboard
+///
+/// Shared by `initialize_period_leaderboard` and the daily board created by
+/// a period's first `buy_ticket_and_start_game`.
+///
+/// # Arguments
+/// * `leaderboard` - The freshly allocated board
+/// * `period_id` - Period the board is for (e.g., "D123")
+/// * `period_type` - Daily, weekly or monthly
+/// * `aggregation` - How repeat games combine
+/// * `config` - Source of the winner splits, mode and capacity snapshots
+/// * `bump` - Canonical PDA bump
+/// * `now` - Creation timestamp
+pub fn fill_new_leaderboard(
+    leaderboard: &mut PeriodLeaderboard,
+    period_id: &str,
+    period_type: PeriodType,
+    aggregation: AggregationStrategy,
+    config: &GlobalConfig,
+    bump: u8,
+    now: i64,
+) {
+    // Set period identification
+    leaderboard.period_id = period_id.to_string();
+    leaderboard.period_type = period_type;
+
+    // Initialize empty state
+    leaderboard.entries = Vec::new();
+    leaderboard.total_players = 0;
+    leaderboard.prize_pool = 0;
+
+    // Set status flags
+    leaderboard.finalized = false;
+
+    // Set timestamps
+    leaderboard.created_at = now;
+    leaderboard.finalized_at = None;
+
+    // Snapshot payout terms so set_config can't change them mid-period
+    leaderboard.winner_splits = config.winner_splits.clone();
+    leaderboard.bump = bump;
+    leaderboard.aggregation = aggregation;
+    leaderboard.emitted_up_to = 0;
+    leaderboard.entrants = 0;
+    leaderboard.mode = match period_type {
+        PeriodType::Daily => LeaderboardMode::LivePerGame, // The rollup source is always live
+        PeriodType::Weekly | PeriodType::Monthly => config.leaderboard_mode,
+    };
+    leaderboard.rolled_up = false;
+    leaderboard.rolled_up_days = 0;
+    leaderboard.paused = false;
+    leaderboard.capacity = config.leaderboard_capacity();
+    leaderboard.canceled = false;
+    leaderboard.participants = 0;
+}
+
+/// Allocate the next 10 KiB of a leaderboard whose capacity doesn't fit one `init`
+///
+/// The allocation itself happens in the `GrowLeaderboard` realloc
+/// constraint; the payer covers the extra rent.
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard and payer
+/// * `period_id` - Period of the leaderboard (e.g., "W45")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+///
+/// # Notes
+/// - Anyone may pay to grow a board; it never grows past `space(capacity)`
+/// - Calling it on a fully allocated board changes nothing
+pub fn grow_leaderboard(
+    ctx: Context<GrowLeaderboard>,
+    period_id: String,
+    period_type: u8,
+) -> Result<()> {
+    let leaderboard = &ctx.accounts.leaderboard;
+    let data_len = leaderboard.to_account_info().data_len();
+    let remaining = growth_steps_remaining(data_len, leaderboard.capacity());
+
+    msg!("📐 Leaderboard {} ({}) allocated: {} bytes", period_id, period_type, data_len);
+    if remaining == 0 {
+        msg!("   ✅ Fully allocated for {} entries", leaderboard.capacity());
+    } else {
+        msg!("   Call grow_leaderboard {} more time(s)", remaining);
+    }
+
+    emit!(LeaderboardGrown {
+        period_id,
+        period_type: leaderboard.period_type,
+        data_len: data_len as u32,
+        capacity: leaderboard.capacity() as u16,
+        fully_allocated: remaining == 0,
+    });
+
+    Ok(())
+}
+
+/// `grow_leaderboard` calls still needed to fit `capacity` entries
+pub fn growth_steps_remaining(data_len: usize, capacity: usize) -> usize {
+    let mut len = data_len;
+    let mut steps = 0;
+    while len < PeriodLeaderboard::space(capacity) {
+        len = PeriodLeaderboard::next_space(len, capacity);
+        steps += 1;
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::game::apply_game_to_leaderboard;
+    use crate::state::LeaderEntry;
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+    fn board(capacity: u16) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("W45", PeriodType::Weekly);
+        board.capacity = capacity;
+        board
+    }
+
+    fn entry(score: u32) -> LeaderEntry {
+        LeaderEntry {
+            player: Pubkey::new_unique(),
+            score,
+            guesses_used: 3,
+            time_ms: 30_000,
+            timestamp: 0,
+            // Longest username, so the board is at its largest serialized size
+            username: "x".repeat(32),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    #[test]
+    fn test_ticket_created_daily_board_defaults() {
+        let mut config =
+            GlobalConfig::try_deserialize_unchecked(&mut &vec![0u8; 8 + GlobalConfig::INIT_SPACE][..])
+                .unwrap();
+        config.winner_splits = vec![6000, 3000, 1000];
+        config.leaderboard_mode = LeaderboardMode::DailyRollup;
+
+        // A zeroed board, as init_if_needed hands it to buy_ticket_and_start_game
+        let mut leaderboard = board(0);
+        leaderboard.period_id = String::new();
+        fill_new_leaderboard(
+            &mut leaderboard,
+            "D700",
+            PeriodType::Daily,
+            AggregationStrategy::default_for(PeriodType::Daily),
+            &config,
+            254,
+            1_000,
+        );
+
+        assert_eq!(leaderboard.period_id, "D700");
+        assert_eq!(leaderboard.period_type, PeriodType::Daily);
+        assert_eq!(leaderboard.winner_splits, vec![6000, 3000, 1000]);
+        assert_eq!(leaderboard.aggregation, AggregationStrategy::Best);
+        // Daily boards stay live whatever the configured mode
+        assert_eq!(leaderboard.mode, LeaderboardMode::LivePerGame);
+        assert_eq!(leaderboard.capacity(), DEFAULT_LEADERBOARD_CAPACITY as usize);
+        assert_eq!((leaderboard.bump, leaderboard.created_at), (254, 1_000));
+    }
+
+    #[test]
+    fn test_default_capacity_needs_no_growth() {
+        let space = PeriodLeaderboard::space(DEFAULT_LEADERBOARD_CAPACITY as usize);
+        assert_eq!(PeriodLeaderboard::next_space(0, DEFAULT_LEADERBOARD_CAPACITY as usize), space);
+        assert_eq!(growth_steps_remaining(space, DEFAULT_LEADERBOARD_CAPACITY as usize), 0);
+    }
+
+    #[test]
+    fn test_max_capacity_board_fills_and_fits() {
+        let capacity = MAX_LEADERBOARD_CAPACITY as usize;
+        let mut leaderboard = board(MAX_LEADERBOARD_CAPACITY);
+
+        // init allocates 10 KiB, grow_leaderboard the rest in 10 KiB steps
+        let init_len = PeriodLeaderboard::next_space(0, capacity);
+        assert_eq!(init_len, MAX_PERMITTED_DATA_INCREASE);
+        assert_eq!(growth_steps_remaining(init_len, capacity), 2);
+
+        let mut len = init_len;
+        for _ in 0..2 {
+            let next = PeriodLeaderboard::next_space(len, capacity);
+            assert!(next - len <= MAX_PERMITTED_DATA_INCREASE);
+            len = next;
+        }
+        assert_eq!(len, PeriodLeaderboard::space(capacity));
+        assert_eq!(PeriodLeaderboard::next_space(len, capacity), len);
+
+        // More players than spots: only the top `capacity` are kept
+        for score in 1..=300 {
+            apply_game_to_leaderboard(&mut leaderboard, &entry(score));
+        }
+        assert_eq!(leaderboard.entries.len(), capacity);
+        assert_eq!(leaderboard.entries[0].score, 300);
+        assert_eq!(leaderboard.entries[capacity - 1].score, 51);
+
+        // The full board serializes into the grown account
+        let mut data = Vec::new();
+        leaderboard.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= len);
+    }
+
+    #[test]
+    fn test_legacy_board_keeps_default_capacity() {
+        let mut leaderboard = board(0);
+        for score in 1..=150 {
+            apply_game_to_leaderboard(&mut leaderboard, &entry(score));
+        }
+        assert_eq!(leaderboard.entries.len(), DEFAULT_LEADERBOARD_CAPACITY as usize);
+    }
+
+    #[test]
+    fn test_partially_grown_board_fits_its_allocation() {
+        let capacity = 250;
+        let mut leaderboard = board(capacity as u16);
+
+        // Only the first 10 KiB step exists until grow_leaderboard runs
+        let init_len = PeriodLeaderboard::next_space(0, capacity);
+        let allocated = leaderboard.allocated_capacity(init_len);
+        assert!(allocated < capacity);
+
+        for score in 1..=300 {
+            apply_game_to_leaderboard(&mut leaderboard, &entry(score));
+        }
+        leaderboard.entries.truncate(allocated);
+        assert_eq!(leaderboard.entries[0].score, 300);
+
+        let mut data = Vec::new();
+        leaderboard.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= init_len);
+
+        // Fully grown boards hold their whole capacity
+        let full_len = PeriodLeaderboard::space(capacity);
+        assert_eq!(leaderboard.allocated_capacity(full_len), capacity);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::state::{
+    AggregationStrategy, LeaderEntry, LeaderboardMode, PeriodLeaderboard, PeriodType,
+};
+use crate::{contexts::*, errors::VobleError};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+/// Entry layout before entries carried flair and game counts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LegacyLeaderEntry {
+    pub player: Pubkey,
+    pub score: u32,
+    pub guesses_used: u8,
+    pub time_ms: u64,
+    pub timestamp: i64,
+    #[max_len(32)]
+    pub username: String,
+}
+
+/// PeriodLeaderboard layout with `LegacyLeaderEntry` entries
+///
+/// Every board of this layout was allocated at `SPACE` bytes for 100 entries.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LegacyPeriodLeaderboard {
+    #[max_len(20)]
+    pub period_id: String,
+    pub period_type: PeriodType,
+    #[max_len(100)]
+    pub entries: Vec<LegacyLeaderEntry>,
+    pub total_players: u32,
+    pub prize_pool: u64,
+    pub finalized: bool,
+    pub created_at: i64,
+    pub finalized_at: Option<i64>,
+}
+
+impl LegacyPeriodLeaderboard {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+/// Rewrite a leaderboard created before entries carried flair and game counts
+///
+/// Each appended `LeaderEntry` field shifts every entry after the first, so
+/// those boards no longer deserialize as `PeriodLeaderboard` (or parse as
+/// garbage) until rewritten. The board is grown to the current layout and
+/// its fields added since are set to what the old board implied.
+///
+/// Also stores the canonical PDA bump, which boards of that layout predate.
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard and payer
+/// * `period_id` - Period of the leaderboard (e.g., "W45")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+///
+/// # Validation
+/// - Leaderboard must be owned by this program and carry the PeriodLeaderboard discriminator
+///
+/// # Notes
+/// - Anyone may pay to migrate a board; the payer covers the extra rent
+/// - Boards already in the current layout are left untouched
+/// - `backfill_account_bumps` rejects old-layout boards, so run this first
+pub fn migrate_leaderboard(
+    ctx: Context<MigrateLeaderboard>,
+    period_id: String,
+    period_type: u8,
+) -> Result<()> {
+    let leaderboard_info = ctx.accounts.leaderboard.to_account_info();
+
+    // ========== VALIDATION ==========
+    require!(leaderboard_info.owner == &crate::ID, VobleError::Unauthorized);
+
+    let mut leaderboard = {
+        let data = leaderboard_info.try_borrow_data()?;
+        require!(
+            data.starts_with(PeriodLeaderboard::DISCRIMINATOR),
+            VobleError::InvalidPeriodState
+        );
+        match migrate_legacy_leaderboard(&data) {
+            Some(leaderboard) => leaderboard,
+            None => {
+                msg!("✅ Leaderboard already uses the current layout");
+                return Ok(());
+            }
+        }
+    };
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    // ========== GROW ==========
+    let new_len = PeriodLeaderboard::space(leaderboard.capacity());
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(leaderboard_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: leaderboard_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    leaderboard_info.resize(new_len)?;
+
+    // ========== MIGRATE ==========
+    let mut data = leaderboard_info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    leaderboard.try_serialize(&mut writer)?;
+    writer.fill(0);
+
+    msg!("✅ Leaderboard {} ({}) migrated", period_id, period_type);
+    msg!("   Entries: {}", leaderboard.entries.len());
+
+    Ok(())
+}
+
+/// Whether account bytes hold a leaderboard in the `LegacyPeriodLeaderboard` layout
+///
+/// Current boards are sized by `PeriodLeaderboard::space` or in 10 KiB
+/// allocation steps, never at `LegacyPeriodLeaderboard::SPACE`, so the size
+/// tells the layouts apart.
+pub fn is_legacy_leaderboard(data: &[u8]) -> bool {
+    data.len() == LegacyPeriodLeaderboard::SPACE
+        && data.starts_with(PeriodLeaderboard::DISCRIMINATOR)
+}
+
+/// Parse legacy leaderboard account bytes into the current layout
+///
+/// # Returns
+/// `None` if the bytes are not a legacy leaderboard
+pub fn migrate_legacy_leaderboard(data: &[u8]) -> Option<PeriodLeaderboard> {
+    if !is_legacy_leaderboard(data) {
+        return None;
+    }
+    let legacy = LegacyPeriodLeaderboard::deserialize(&mut &data[8..]).ok()?;
+
+    let entries = legacy
+        .entries
+        .into_iter()
+        .map(|entry| LeaderEntry {
+            player: entry.player,
+            score: entry.score,
+            guesses_used: entry.guesses_used,
+            time_ms: entry.time_ms,
+            timestamp: entry.timestamp,
+            username: entry.username,
+            equipped_flair: 0, // Not recorded before migration
+            games_played: 1,
+            counted_days: 0, // Unknown
+        })
+        .collect();
+
+    Some(PeriodLeaderboard {
+        period_id: legacy.period_id,
+        period_type: legacy.period_type,
+        entries,
+        total_players: legacy.total_players,
+        prize_pool: legacy.prize_pool,
+        finalized: legacy.finalized,
+        created_at: legacy.created_at,
+        finalized_at: legacy.finalized_at,
+        winner_splits: Vec::new(), // Not snapshotted before migration
+        bump: 0,                   // Set by migrate_leaderboard
+        aggregation: AggregationStrategy::Best, // What the missing byte reads as
+        emitted_up_to: 0,
+        entrants: 0,
+        mode: LeaderboardMode::LivePerGame,
+        rolled_up: false,
+        paused: false,
+        capacity: 0, // Legacy boards hold the default capacity
+        canceled: false,
+        participants: 0,
+        rolled_up_days: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn legacy_entry(score: u32, username: &str) -> LegacyLeaderEntry {
+        LegacyLeaderEntry {
+            player: Pubkey::new_unique(),
+            score,
+            guesses_used: 3,
+            time_ms: 30_000,
+            timestamp: 1_700_000_000,
+            username: username.to_string(),
+        }
+    }
+
+    fn legacy_board(entries: Vec<LegacyLeaderEntry>) -> Vec<u8> {
+        let board = LegacyPeriodLeaderboard {
+            period_id: "W12".to_string(),
+            period_type: PeriodType::Weekly,
+            total_players: entries.len() as u32,
+            entries,
+            prize_pool: 0,
+            finalized: false,
+            created_at: 1_700_000_000,
+            finalized_at: None,
+        };
+        let mut data = PeriodLeaderboard::DISCRIMINATOR.to_vec();
+        board.serialize(&mut data).unwrap();
+        data.resize(LegacyPeriodLeaderboard::SPACE, 0);
+        data
+    }
+
+    #[test]
+    fn test_legacy_entries_survive_migration() {
+        let data = legacy_board(vec![legacy_entry(900, "Alice"), legacy_entry(700, "Bob")]);
+
+        let board = migrate_legacy_leaderboard(&data).unwrap();
+        assert_eq!(board.period_id, "W12");
+        assert_eq!(board.period_type, PeriodType::Weekly);
+        assert_eq!(board.total_players, 2);
+        let standings: Vec<(u32, &str, u16)> = board
+            .entries
+            .iter()
+            .map(|e| (e.score, e.username.as_str(), e.games_played))
+            .collect();
+        assert_eq!(standings, vec![(900, "Alice", 1), (700, "Bob", 1)]);
+        assert_eq!(board.capacity(), DEFAULT_LEADERBOARD_CAPACITY as usize);
+
+        // The rewritten board parses as a current one
+        let mut migrated = Vec::new();
+        board.try_serialize(&mut migrated).unwrap();
+        migrated.resize(PeriodLeaderboard::space(board.capacity()), 0);
+        let parsed = PeriodLeaderboard::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(parsed.entries[1].username, "Bob");
+        assert!(migrate_legacy_leaderboard(&migrated).is_none());
+    }
+
+    #[test]
+    fn test_current_boards_are_never_legacy_sized() {
+        for capacity in 0..=MAX_LEADERBOARD_CAPACITY as usize {
+            let mut len = 0;
+            while len < PeriodLeaderboard::space(capacity) {
+                len = PeriodLeaderboard::next_space(len, capacity);
+                assert_ne!(len, LegacyPeriodLeaderboard::SPACE, "capacity {}", capacity);
+            }
+        }
+    }
+}
@@ -3,16 +3,33 @@
 // ================================
 // Business logic for leaderboard management and winner determination
 
+pub mod checksum;
+pub mod final_standings;
 pub mod finalize_leaderboard;
+pub mod future_leaderboards;
 pub mod init_leaderboard;
+pub mod migrate_leaderboard;
+pub mod period_timing;
+pub mod player_ranks;
 pub mod ranking;
+pub mod rollup;
+pub mod usernames;
 
 // Re-export all public functions for easy access
+pub use checksum::*;
+pub use final_standings::*;
 pub use finalize_leaderboard::*;
+pub use future_leaderboards::*;
 pub use init_leaderboard::*;
+pub use migrate_leaderboard::*;
+pub use period_timing::*;
+pub use player_ranks::*;
+pub use rollup::*;
+pub use usernames::*;
 
 // Re-export helper functions that might be needed externally
 pub use ranking::{
-    calculate_rank_change, compare_entries, get_player_rank, get_score_threshold_for_top_n,
-    get_top_n_entries, is_in_top_n, sort_leaderboard, would_make_top_n,
+    calculate_rank_change, compare_entries, compute_standings, get_player_rank,
+    get_score_threshold_for_top_n, get_top_n_entries, insert_sorted, is_in_top_n,
+    reposition_sorted, sort_leaderboard, would_make_top_n,
 };
@@ -0,0 +1,78 @@
+use crate::utils::period::{get_current_period_id, get_period_end_timestamp, PeriodType};
+use crate::{contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+/// Emit the current daily, weekly and monthly periods and when they end
+///
+/// Clients should derive period IDs from this (or from the config's
+/// `period_epoch_start`) rather than a hardcoded epoch, since each
+/// deployment numbers its periods from its own start.
+///
+/// # Arguments
+/// * `ctx` - The context containing the global config
+///
+/// # Notes
+/// - Read-only and permissionless; the result is the `PeriodTiming` event
+pub fn get_period_timing(ctx: Context<GetPeriodTiming>) -> Result<()> {
+    let epoch_start = ctx.accounts.global_config.period_epoch();
+    let now = Clock::get()?.unix_timestamp;
+
+    let [daily, weekly, monthly] = [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly]
+        .map(|period_type| current_period_bounds(period_type, now, epoch_start));
+    let (daily_period_id, daily_ends_at) = daily?;
+    let (weekly_period_id, weekly_ends_at) = weekly?;
+    let (monthly_period_id, monthly_ends_at) = monthly?;
+
+    msg!("🕒 Period timing (epoch {})", epoch_start);
+    msg!("   Daily: {} ends {}", daily_period_id, daily_ends_at);
+    msg!("   Weekly: {} ends {}", weekly_period_id, weekly_ends_at);
+    msg!("   Monthly: {} ends {}", monthly_period_id, monthly_ends_at);
+
+    emit!(PeriodTiming {
+        epoch_start,
+        now,
+        daily_period_id,
+        daily_ends_at,
+        weekly_period_id,
+        weekly_ends_at,
+        monthly_period_id,
+        monthly_ends_at,
+    });
+
+    Ok(())
+}
+
+/// Current period ID of a type and the timestamp it ends at
+pub fn current_period_bounds(
+    period_type: PeriodType,
+    now: i64,
+    epoch_start: i64,
+) -> Result<(String, i64)> {
+    let period_id = get_current_period_id(period_type, now, epoch_start);
+    let ends_at =
+        get_period_end_timestamp(&period_id, epoch_start).ok_or(VobleError::InvalidPeriodState)?;
+    Ok((period_id, ends_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{PERIOD_DAILY_DURATION, PERIOD_EPOCH_START, PERIOD_WEEKLY_DURATION};
+
+    #[test]
+    fn test_bounds_follow_deployment_epoch() {
+        let epoch_start = PERIOD_EPOCH_START + 1_000;
+        let now = epoch_start + 2 * PERIOD_DAILY_DURATION + 5;
+
+        assert_eq!(
+            current_period_bounds(PeriodType::Daily, now, epoch_start).unwrap(),
+            ("D2".to_string(), epoch_start + 3 * PERIOD_DAILY_DURATION)
+        );
+
+        let (weekly_id, weekly_end) =
+            current_period_bounds(PeriodType::Weekly, now, epoch_start).unwrap();
+        let week = (2 * PERIOD_DAILY_DURATION + 5) / PERIOD_WEEKLY_DURATION;
+        assert_eq!(weekly_id, format!("W{}", week));
+        assert_eq!(weekly_end, epoch_start + (week + 1) * PERIOD_WEEKLY_DURATION);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::state::{PeriodLeaderboard, PeriodType};
+use crate::{contexts::*, events::*};
+use anchor_lang::prelude::*;
+
+use super::ranking;
+
+/// Look up a player's rank on all three period leaderboards in one call
+///
+/// Emits a `PlayerRanks` event with the player's 1-based rank on the daily,
+/// weekly and monthly boards. Clients read the event from the transaction
+/// logs (or simulate the transaction) instead of fetching and sorting three
+/// leaderboard accounts themselves.
+///
+/// # Arguments
+/// * `ctx` - The context containing the three leaderboard accounts
+/// * `player` - The player whose ranks are being looked up
+/// * `daily_period_id` - Daily period of the daily board (e.g. "D123")
+/// * `weekly_period_id` - Weekly period of the weekly board (e.g. "W17")
+/// * `monthly_period_id` - Monthly period of the monthly board (e.g. "M4")
+///
+/// # Validation
+/// - Each board must sit at the leaderboard PDA of its period id and type,
+///   so the event only ever describes the boards it names
+///
+/// # Uninitialized Boards
+/// A board that has not been initialized yet (no data, not owned by this
+/// program, or holding a leaderboard of a different period type) is treated
+/// as "not ranked" and reported as `None` rather than failing the call.
+pub fn get_player_ranks(
+    ctx: Context<GetPlayerRanks>,
+    player: Pubkey,
+    daily_period_id: String,
+    weekly_period_id: String,
+    monthly_period_id: String,
+) -> Result<()> {
+    let daily = rank_on_board(&ctx.accounts.daily_leaderboard, PeriodType::Daily, player);
+    let weekly = rank_on_board(&ctx.accounts.weekly_leaderboard, PeriodType::Weekly, player);
+    let monthly = rank_on_board(&ctx.accounts.monthly_leaderboard, PeriodType::Monthly, player);
+
+    msg!("📊 Player ranks for {}", player);
+    msg!("   Daily {}: {:?}", daily_period_id, daily);
+    msg!("   Weekly {}: {:?}", weekly_period_id, weekly);
+    msg!("   Monthly {}: {:?}", monthly_period_id, monthly);
+
+    emit!(PlayerRanks {
+        player,
+        daily_period_id,
+        weekly_period_id,
+        monthly_period_id,
+        daily,
+        weekly,
+        monthly,
+    });
+
+    Ok(())
+}
+
+/// Resolve a player's rank on a single board, `None` if the board is unusable
+fn rank_on_board(
+    account: &AccountInfo,
+    expected_type: PeriodType,
+    player: Pubkey,
+) -> Option<u8> {
+    let leaderboard = load_leaderboard(account)?;
+
+    if leaderboard.period_type != expected_type {
+        msg!(
+            "⚠️  Expected {:?} leaderboard, got {:?} - skipping",
+            expected_type,
+            leaderboard.period_type
+        );
+        return None;
+    }
+
+    ranking::get_player_rank(&leaderboard, player)
+}
+
+/// Deserialize a leaderboard account if it exists and belongs to this program
+fn load_leaderboard(info: &AccountInfo) -> Option<PeriodLeaderboard> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        msg!("⚠️  Leaderboard {} not initialized", info.key());
+        return None;
+    }
+
+    let data = info.try_borrow_data().ok()?;
+    let mut slice: &[u8] = &data;
+    PeriodLeaderboard::try_deserialize(&mut slice).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LeaderEntry;
+    use crate::utils::test_accounts::{account_infos, TestAccount};
+
+    fn entry(player: Pubkey, score: u32) -> LeaderEntry {
+        LeaderEntry {
+            player,
+            score,
+            guesses_used: 3,
+            time_ms: 30_000,
+            timestamp: 0,
+            username: "Test".to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    fn board(period_type: PeriodType, entries: Vec<LeaderEntry>) -> TestAccount {
+        let mut leaderboard = PeriodLeaderboard::default_for_test("D10", period_type);
+        leaderboard.entries = entries;
+        TestAccount::program_account(&leaderboard)
+    }
+
+    #[test]
+    fn test_rank_on_board() {
+        let (first, second, absent) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let entries = vec![entry(first, 900), entry(second, 700)];
+        let mut accounts = [
+            board(PeriodType::Daily, entries.clone()),
+            board(PeriodType::Weekly, entries),
+            TestAccount::wallet(Pubkey::new_unique()),
+        ];
+        let infos = account_infos(&mut accounts);
+        let (daily, weekly, uninitialized) = (&infos[0], &infos[1], &infos[2]);
+
+        // Ranked and unranked players
+        assert_eq!(rank_on_board(daily, PeriodType::Daily, first), Some(1));
+        assert_eq!(rank_on_board(daily, PeriodType::Daily, second), Some(2));
+        assert_eq!(rank_on_board(daily, PeriodType::Daily, absent), None);
+
+        // A board that doesn't exist yet ranks nobody
+        assert_eq!(rank_on_board(uninitialized, PeriodType::Daily, first), None);
+
+        // A board of another period type is skipped
+        assert_eq!(rank_on_board(weekly, PeriodType::Daily, first), None);
+        assert_eq!(rank_on_board(weekly, PeriodType::Weekly, first), Some(1));
+    }
+}
@@ -62,6 +62,50 @@ pub fn sort_leaderboard(leaderboard: &mut PeriodLeaderboard) {
     leaderboard.entries.sort_by(|a, b| compare_entries(a, b));
 }
 
+/// Insert an entry into entries already sorted by `cmp`
+///
+/// Binary-searches the insertion point instead of re-sorting, so adding an
+/// entry costs O(log n) comparisons plus one shift of the tail.
+///
+/// # Notes
+/// The entry goes after every entry it compares equal to, where a stable
+/// sort of the entries with it appended would put it.
+///
+/// # Returns
+/// The index the entry was inserted at
+pub fn insert_sorted<T>(
+    entries: &mut Vec<T>,
+    new_entry: T,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> usize {
+    let index = entries.partition_point(|entry| cmp(entry, &new_entry) != Ordering::Greater);
+    entries.insert(index, new_entry);
+    index
+}
+
+/// Move the entry at `index` back into sorted position after it changed
+///
+/// # Notes
+/// - Every other entry must already be sorted by `cmp`
+/// - Among entries it now compares equal to, the entry keeps its previous
+///   relative position, where a stable sort would leave it
+///
+/// # Returns
+/// The entry's new index
+pub fn reposition_sorted<T>(
+    entries: &mut Vec<T>,
+    index: usize,
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> usize {
+    let entry = entries.remove(index);
+    let first_equal = entries.partition_point(|other| cmp(other, &entry) == Ordering::Less);
+    let after_equal = entries.partition_point(|other| cmp(other, &entry) != Ordering::Greater);
+
+    let new_index = index.clamp(first_equal, after_equal);
+    entries.insert(new_index, entry);
+    new_index
+}
+
 /// Get a player's current rank on the leaderboard
 ///
 /// # Arguments
@@ -88,6 +132,36 @@ pub fn get_player_rank(leaderboard: &PeriodLeaderboard, player: Pubkey) -> Optio
         .map(|pos| (pos + 1) as u8) // Convert 0-based index to 1-based rank
 }
 
+/// Rank of every entry with ties sharing a rank
+///
+/// Entries tie when `compare_entries` finds them equal and they share the
+/// same timestamp. Tied entries take the best rank of their group and the
+/// next entry skips the ranks they used ("1, 1, 3").
+///
+/// # Arguments
+/// * `entries` - Entries in leaderboard order (best first)
+///
+/// # Returns
+/// 1-based rank per entry, in the same order
+pub fn compute_standings(entries: &[LeaderEntry]) -> Vec<u8> {
+    let mut ranks: Vec<u8> = Vec::with_capacity(entries.len());
+
+    for (i, entry) in entries.iter().enumerate() {
+        let rank = match i.checked_sub(1) {
+            Some(prev) if is_tie(&entries[prev], entry) => ranks[prev],
+            _ => (i + 1) as u8,
+        };
+        ranks.push(rank);
+    }
+
+    ranks
+}
+
+/// Whether two entries are indistinguishable for ranking
+fn is_tie(a: &LeaderEntry, b: &LeaderEntry) -> bool {
+    compare_entries(a, b) == Ordering::Equal && a.timestamp == b.timestamp
+}
+
 /// Check if a player is in the top N positions
 ///
 /// # Arguments
@@ -270,6 +344,9 @@ mod tests {
             time_ms,
             timestamp: 0,
             username: "Test".to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
         }
     }
 
@@ -299,23 +376,87 @@ mod tests {
         assert_eq!(compare_entries(&efficient, &inefficient), Ordering::Greater);
     }
 
+    fn by_score(a: &LeaderEntry, b: &LeaderEntry) -> Ordering {
+        b.score.cmp(&a.score)
+    }
+
+    fn scores(entries: &[LeaderEntry]) -> Vec<u32> {
+        entries.iter().map(|entry| entry.score).collect()
+    }
+
     #[test]
-    fn test_would_make_top_n() {
-        let mut leaderboard = PeriodLeaderboard {
-            period_id: "D123".to_string(),
-            period_type: crate::state::PeriodType::Daily,
-            entries: vec![
-                create_test_entry(1000, 30000, 3),
-                create_test_entry(800, 40000, 4),
-                create_test_entry(600, 50000, 5),
-            ],
-            total_players: 3,
-            prize_pool: 0,
-            finalized: false,
-            created_at: 0,
-            finalized_at: None,
+    fn test_insert_sorted_matches_stable_sort() {
+        let mut entries: Vec<LeaderEntry> = Vec::new();
+        let mut sorted: Vec<LeaderEntry> = Vec::new();
+
+        for (i, score) in [500, 900, 500, 100, 900, 700].into_iter().enumerate() {
+            let mut entry = create_test_entry(score, 30000, 3);
+            entry.timestamp = i as i64;
+
+            insert_sorted(&mut entries, entry.clone(), by_score);
+            sorted.push(entry);
+            sorted.sort_by(by_score);
+
+            let order = |list: &[LeaderEntry]| list.iter().map(|e| e.timestamp).collect::<Vec<_>>();
+            assert_eq!(order(&entries), order(&sorted));
+        }
+        assert_eq!(scores(&entries), vec![900, 900, 700, 500, 500, 100]);
+    }
+
+    #[test]
+    fn test_reposition_sorted_moves_changed_entry() {
+        let mut entries: Vec<LeaderEntry> =
+            [900, 700, 500, 300].iter().map(|&s| create_test_entry(s, 30000, 3)).collect();
+
+        // A summed score climbs two places
+        entries[3].score = 800;
+        assert_eq!(reposition_sorted(&mut entries, 3, by_score), 1);
+        assert_eq!(scores(&entries), vec![900, 800, 700, 500]);
+
+        // An averaged score drops to the bottom
+        entries[0].score = 100;
+        assert_eq!(reposition_sorted(&mut entries, 0, by_score), 3);
+        assert_eq!(scores(&entries), vec![800, 700, 500, 100]);
+
+        // A tie keeps its old position relative to equal entries
+        let player = entries[2].player;
+        entries[2].score = 700;
+        assert_eq!(reposition_sorted(&mut entries, 2, by_score), 2);
+        assert_eq!(entries[2].player, player);
+    }
+
+    #[test]
+    fn test_insert_sorted_uses_logarithmic_comparisons() {
+        use std::cell::Cell;
+
+        let mut entries: Vec<LeaderEntry> =
+            (0..100).map(|i| create_test_entry(10_000 - i * 10, 30000, 3)).collect();
+        let comparisons = Cell::new(0u32);
+        let counting = |a: &LeaderEntry, b: &LeaderEntry| {
+            comparisons.set(comparisons.get() + 1);
+            by_score(a, b)
         };
 
+        insert_sorted(&mut entries, create_test_entry(5_005, 30000, 3), counting);
+        assert!(comparisons.get() <= 8);
+
+        // A full re-sort of the same board compares every neighbour at least once
+        comparisons.set(0);
+        entries.sort_by(counting);
+        assert!(comparisons.get() >= 100);
+    }
+
+    #[test]
+    fn test_would_make_top_n() {
+        let mut leaderboard =
+            PeriodLeaderboard::default_for_test("D123", crate::state::PeriodType::Daily);
+        leaderboard.entries = vec![
+            create_test_entry(1000, 30000, 3),
+            create_test_entry(800, 40000, 4),
+            create_test_entry(600, 50000, 5),
+        ];
+        leaderboard.total_players = 3;
+
         // Score 700 would make top 3
         assert!(would_make_top_n(&leaderboard, 700, 3));
 
@@ -323,6 +464,21 @@ mod tests {
         assert!(!would_make_top_n(&leaderboard, 500, 3));
     }
 
+    #[test]
+    fn test_compute_standings_shares_tied_ranks() {
+        let first = create_test_entry(1000, 30000, 3);
+        let tied = create_test_entry(1000, 30000, 3);
+        let third = create_test_entry(800, 30000, 3);
+        let mut later = create_test_entry(800, 30000, 3);
+        later.timestamp = 60;
+
+        assert_eq!(
+            compute_standings(&[first, tied, third, later]),
+            vec![1, 1, 3, 4]
+        );
+        assert!(compute_standings(&[]).is_empty());
+    }
+
     #[test]
     fn test_calculate_rank_change() {
         // Improved from rank 10 to rank 5
@@ -340,4 +496,13 @@ mod tests {
         // Fell off leaderboard
         assert_eq!(calculate_rank_change(Some(10), None), -1);
     }
+
+    #[test]
+    fn test_leaderboard_fits_single_allocation() {
+        use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+        // Default-capacity leaderboards are created with `init`, which is capped at 10 KiB
+        let capacity = crate::constants::DEFAULT_LEADERBOARD_CAPACITY as usize;
+        assert!(PeriodLeaderboard::space(capacity) <= MAX_PERMITTED_DATA_INCREASE);
+    }
 }
@@ -0,0 +1,333 @@
+use crate::instructions::game::{fold_entry_into_leaderboard, trim_to_allocation};
+use crate::state::{LeaderboardMode, PeriodLeaderboard, PeriodType};
+use crate::utils::period::{self, get_current_period_id, get_period_start_timestamp};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+/// Fold a finalized daily leaderboard into its week's and month's boards
+///
+/// In `DailyRollup` mode the Magic handler only writes the daily board, which
+/// keeps settlement cheap. This crank carries each day's final standings
+/// into the weekly and monthly boards using their aggregation strategy.
+///
+/// # Arguments
+/// * `ctx` - The context containing the daily, weekly and monthly leaderboards
+/// * `daily_period_id` - Finalized daily period to roll up (e.g., "D123")
+///
+/// # Validation
+/// - Daily leaderboard must be finalized and not rolled up yet
+/// - Weekly/monthly boards must be the periods containing the day
+/// - Rollup-mode target boards must not be finalized yet
+///
+/// # Notes
+/// - Permissionless; run once per finalized daily period
+/// - Boards created in `LivePerGame` mode already saw every game and are
+///   left untouched, so switching modes mid-week never double counts
+/// - Only the daily board's top 100 entries are carried over
+/// - Rollup-mode boards can't be finalized until every day of their period
+///   is rolled up, so a day nobody played still needs its (empty) daily
+///   board initialized, finalized and rolled up
+pub fn roll_daily_into_weekly(
+    ctx: Context<RollDailyIntoWeekly>,
+    daily_period_id: String,
+) -> Result<()> {
+    // ========== VALIDATION ==========
+    let (weekly_period_id, monthly_period_id) = rollup_targets(&daily_period_id)?;
+    for (board, expected_type, expected_id) in [
+        (&ctx.accounts.weekly_leaderboard, PeriodType::Weekly, &weekly_period_id),
+        (&ctx.accounts.monthly_leaderboard, PeriodType::Monthly, &monthly_period_id),
+    ] {
+        require!(
+            board.period_type == expected_type && &board.period_id == expected_id,
+            VobleError::InvalidPeriodState
+        );
+    }
+
+    // ========== ROLL UP ==========
+    let accounts = &mut *ctx.accounts;
+    roll_daily_standings(
+        &mut accounts.daily_leaderboard,
+        &mut [
+            &mut *accounts.weekly_leaderboard,
+            &mut *accounts.monthly_leaderboard,
+        ],
+    )?;
+    trim_to_allocation(&mut accounts.weekly_leaderboard);
+    trim_to_allocation(&mut accounts.monthly_leaderboard);
+    let entries = accounts.daily_leaderboard.entries.len() as u32;
+
+    msg!("🔁 Daily {} rolled up", daily_period_id);
+    msg!("   Weekly: {} | Monthly: {}", weekly_period_id, monthly_period_id);
+    msg!("   Entries: {}", entries);
+
+    emit!(DailyRolledUp {
+        daily_period_id,
+        weekly_period_id,
+        monthly_period_id,
+        entries,
+    });
+
+    Ok(())
+}
+
+/// Weekly and monthly period IDs containing a daily period
+///
+/// # Notes
+/// Period numbers only depend on the offset from the epoch, so the targets
+/// are the same for every deployment and are computed from epoch 0.
+pub fn rollup_targets(daily_period_id: &str) -> Result<(String, String)> {
+    let is_daily = matches!(
+        period::parse_period_id(daily_period_id),
+        Some((period::PeriodType::Daily, _))
+    );
+    require!(is_daily, VobleError::InvalidPeriodState);
+
+    let offset =
+        get_period_start_timestamp(daily_period_id, 0).ok_or(VobleError::InvalidPeriodState)?;
+    Ok((
+        get_current_period_id(period::PeriodType::Weekly, offset, 0),
+        get_current_period_id(period::PeriodType::Monthly, offset, 0),
+    ))
+}
+
+/// Fold a daily board's final entries into every rollup-mode target
+///
+/// # Arguments
+/// * `daily` - Finalized daily leaderboard (marked rolled up on success)
+/// * `targets` - Weekly and monthly leaderboards containing the day
+pub fn roll_daily_standings(
+    daily: &mut PeriodLeaderboard,
+    targets: &mut [&mut PeriodLeaderboard],
+) -> Result<()> {
+    require!(daily.finalized, VobleError::LeaderboardNotFinalized);
+    require!(!daily.rolled_up, VobleError::DailyAlreadyRolledUp);
+
+    for target in targets.iter_mut() {
+        fold_daily_entries(daily, target)?;
+    }
+
+    daily.rolled_up = true;
+    Ok(())
+}
+
+/// Fold a daily board's entries into one target, if it is fed by rollups
+fn fold_daily_entries(daily: &PeriodLeaderboard, target: &mut PeriodLeaderboard) -> Result<()> {
+    if target.mode != LeaderboardMode::DailyRollup {
+        return Ok(());
+    }
+    require!(!target.finalized, VobleError::PeriodAlreadyFinalized);
+
+    // Each fold keeps the target sorted
+    for entry in &daily.entries {
+        fold_entry_into_leaderboard(target, entry);
+    }
+    target.rolled_up_days += 1;
+
+    Ok(())
+}
+
+/// Daily periods in a period of the given type
+pub fn days_in_period(period_type: PeriodType) -> u8 {
+    let duration = match period_type {
+        PeriodType::Daily => PERIOD_DAILY_DURATION,
+        PeriodType::Weekly => PERIOD_WEEKLY_DURATION,
+        PeriodType::Monthly => PERIOD_MONTHLY_DURATION,
+    };
+    (duration / PERIOD_DAILY_DURATION) as u8
+}
+
+/// Require a rollup-mode board to hold every day of its period
+///
+/// # Returns
+/// `RollupIncomplete` if a day of the period hasn't been rolled up yet;
+/// live boards always pass
+pub fn require_rollups_complete(board: &PeriodLeaderboard) -> Result<()> {
+    if board.mode == LeaderboardMode::DailyRollup {
+        require!(
+            board.rolled_up_days >= days_in_period(board.period_type),
+            VobleError::RollupIncomplete
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::game::{apply_game_to_leaderboard, sort_and_trim_leaderboard};
+    use crate::instructions::leaderboard::lock_leaderboard;
+    use crate::state::{AggregationStrategy, LeaderEntry};
+
+    fn board(period_type: PeriodType, mode: LeaderboardMode) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("", period_type);
+        board.aggregation = AggregationStrategy::Sum;
+        board.mode = mode;
+        board
+    }
+
+    fn game(player: Pubkey, score: u32, time_ms: u64) -> LeaderEntry {
+        LeaderEntry {
+            player,
+            score,
+            guesses_used: 3,
+            time_ms,
+            timestamp: 0,
+            username: "Test".to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    fn standings(board: &PeriodLeaderboard) -> Vec<(Pubkey, u32, u16, u64)> {
+        board
+            .entries
+            .iter()
+            .map(|e| (e.player, e.score, e.games_played, e.time_ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_seven_daily_rollups_match_live_sum() {
+        let players: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut live = board(PeriodType::Weekly, LeaderboardMode::LivePerGame);
+        let mut rollup = board(PeriodType::Weekly, LeaderboardMode::DailyRollup);
+
+        for day in 0..7u32 {
+            let mut daily = board(PeriodType::Daily, LeaderboardMode::LivePerGame);
+
+            // Up to three games per player per day, some players skip days
+            for (i, player) in players.iter().enumerate() {
+                let games = (day as usize + i) % 4;
+                for g in 0..games {
+                    let score = 100 + day * 37 + i as u32 * 11 + g as u32 * 5;
+                    let entry = game(*player, score, 20_000 + (day * 3 + g as u32) as u64);
+
+                    for target in [&mut live, &mut rollup, &mut daily] {
+                        if apply_game_to_leaderboard(target, &entry) {
+                            sort_and_trim_leaderboard(target);
+                        }
+                    }
+                }
+            }
+
+            daily.finalized = true;
+            fold_daily_entries(&daily, &mut rollup).unwrap();
+            daily.rolled_up = true;
+        }
+
+        assert!(!standings(&live).is_empty());
+        assert_eq!(standings(&rollup), standings(&live));
+        assert_eq!(rollup.total_players, live.total_players);
+    }
+
+    #[test]
+    fn test_games_skip_rollup_mode_boards() {
+        let mut weekly = board(PeriodType::Weekly, LeaderboardMode::DailyRollup);
+
+        assert!(!apply_game_to_leaderboard(&mut weekly, &game(Pubkey::new_unique(), 500, 30_000)));
+        assert!(weekly.entries.is_empty());
+    }
+
+    #[test]
+    fn test_live_mode_targets_are_not_double_counted() {
+        let player = Pubkey::new_unique();
+        let mut weekly = board(PeriodType::Weekly, LeaderboardMode::LivePerGame);
+        let mut daily = board(PeriodType::Daily, LeaderboardMode::LivePerGame);
+
+        for target in [&mut weekly, &mut daily] {
+            apply_game_to_leaderboard(target, &game(player, 500, 30_000));
+        }
+        daily.finalized = true;
+        fold_daily_entries(&daily, &mut weekly).unwrap();
+
+        assert_eq!(weekly.entries[0].score, 500);
+        assert_eq!(weekly.entries[0].games_played, 1);
+    }
+
+    #[test]
+    fn test_daily_rolls_up_once_after_finalization() {
+        let mut daily = board(PeriodType::Daily, LeaderboardMode::LivePerGame);
+        apply_game_to_leaderboard(&mut daily, &game(Pubkey::new_unique(), 500, 30_000));
+        let mut weekly = board(PeriodType::Weekly, LeaderboardMode::DailyRollup);
+        let mut monthly = board(PeriodType::Monthly, LeaderboardMode::DailyRollup);
+
+        assert_eq!(
+            roll_daily_standings(&mut daily, &mut [&mut weekly, &mut monthly]).unwrap_err(),
+            VobleError::LeaderboardNotFinalized.into()
+        );
+
+        daily.finalized = true;
+        roll_daily_standings(&mut daily, &mut [&mut weekly, &mut monthly]).unwrap();
+        assert!(daily.rolled_up);
+        assert_eq!(weekly.entries[0].score, 500);
+        assert_eq!(monthly.entries[0].score, 500);
+
+        assert_eq!(
+            roll_daily_standings(&mut daily, &mut [&mut weekly, &mut monthly]).unwrap_err(),
+            VobleError::DailyAlreadyRolledUp.into()
+        );
+        assert_eq!(weekly.entries[0].score, 500);
+    }
+
+    #[test]
+    fn test_rollup_targets() {
+        let (weekly, monthly) = rollup_targets("D0").unwrap();
+        assert_eq!((weekly.as_str(), monthly.as_str()), ("W0", "M0"));
+
+        let (weekly, monthly) = rollup_targets("D30").unwrap();
+        assert_eq!((weekly.as_str(), monthly.as_str()), ("W4", "M1"));
+
+        assert_eq!(
+            rollup_targets("W3").unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+    }
+
+    #[test]
+    fn test_rollup_board_locks_after_every_day() {
+        let mut weekly = board(PeriodType::Weekly, LeaderboardMode::DailyRollup);
+        weekly.period_id = "W0".to_string();
+        let mut monthly = board(PeriodType::Monthly, LeaderboardMode::DailyRollup);
+        assert_eq!(days_in_period(PeriodType::Weekly), 7);
+        assert_eq!(days_in_period(PeriodType::Monthly), 30);
+
+        for day in 0..7 {
+            assert_eq!(
+                lock_leaderboard(&mut weekly, "W0", 1).unwrap_err(),
+                VobleError::RollupIncomplete.into()
+            );
+            let mut daily = board(PeriodType::Daily, LeaderboardMode::LivePerGame);
+            // Days nobody played still count once their empty board is rolled up
+            if day % 2 == 0 {
+                apply_game_to_leaderboard(&mut daily, &game(Pubkey::new_unique(), 500, 30_000));
+            }
+            daily.finalized = true;
+            roll_daily_standings(&mut daily, &mut [&mut weekly, &mut monthly]).unwrap();
+        }
+
+        assert_eq!(weekly.rolled_up_days, 7);
+        lock_leaderboard(&mut weekly, "W0", 1).unwrap();
+        assert_eq!(
+            require_rollups_complete(&monthly).unwrap_err(),
+            VobleError::RollupIncomplete.into()
+        );
+
+        // Live boards saw every game already
+        let live = board(PeriodType::Weekly, LeaderboardMode::LivePerGame);
+        require_rollups_complete(&live).unwrap();
+    }
+
+    #[test]
+    fn test_rollup_target_must_be_open() {
+        let mut daily = board(PeriodType::Daily, LeaderboardMode::LivePerGame);
+        apply_game_to_leaderboard(&mut daily, &game(Pubkey::new_unique(), 500, 30_000));
+        let mut weekly = board(PeriodType::Weekly, LeaderboardMode::DailyRollup);
+        weekly.finalized = true;
+
+        assert_eq!(
+            fold_daily_entries(&daily, &mut weekly).unwrap_err(),
+            VobleError::PeriodAlreadyFinalized.into()
+        );
+    }
+}
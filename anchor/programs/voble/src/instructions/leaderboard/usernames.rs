@@ -0,0 +1,145 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use crate::utils::pda::derive_user_profile_pda;
+use anchor_lang::prelude::*;
+
+/// Refresh the caller's leaderboard entry with their current username
+///
+/// Leaderboard entries copy the username when they are written, so after a
+/// rename the old name stays on the board (and could later be claimed by a
+/// different account). Entries are always keyed by `player`; this lets a
+/// player bring the displayed name back in line with their profile.
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard, the caller's profile and the caller
+/// * `period_id` - Period of the leaderboard (e.g., "D123")
+/// * `period_type` - Leaderboard type seed (0 = daily, 1 = weekly, 2 = monthly)
+///
+/// # Validation
+/// - Caller must have an entry on the leaderboard
+///
+/// # Notes
+/// - Permissionless, but only ever touches the signer's own entry
+/// - Allowed on finalized leaderboards; it changes nothing but the display name
+pub fn update_leaderboard_username(
+    ctx: Context<UpdateLeaderboardUsername>,
+    period_id: String,
+    period_type: u8,
+) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    let username = ctx.accounts.user_profile.username.clone();
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    let entry = leaderboard
+        .entries
+        .iter_mut()
+        .find(|entry| entry.player == player)
+        .ok_or(VobleError::NotOnLeaderboard)?;
+    entry.username = username.clone();
+
+    msg!("🏷️ Leaderboard username refreshed for {}", player);
+    msg!("   Period: {} (type {})", period_id, period_type);
+    msg!("   Username: {}", username);
+
+    emit!(LeaderboardUsernameUpdated {
+        player,
+        period_id,
+        period_type,
+        username,
+    });
+
+    Ok(())
+}
+
+/// Read `(player, username)` pairs from UserProfile accounts
+///
+/// # Arguments
+/// * `accounts` - Candidate profile accounts (typically `remaining_accounts`)
+///
+/// # Validation
+/// - Each account must be a UserProfile owned by this program
+/// - Each account must sit at its player's profile PDA, so a profile can
+///   only ever vouch for its own player
+pub fn load_profile_usernames(accounts: &[AccountInfo]) -> Result<Vec<(Pubkey, String)>> {
+    accounts
+        .iter()
+        .map(|info| {
+            require!(info.owner == &crate::ID, VobleError::Unauthorized);
+            let data = info.try_borrow_data()?;
+            let profile = UserProfile::try_deserialize(&mut &data[..])?;
+            require!(
+                info.key() == derive_user_profile_pda(&profile.player, &crate::ID).0,
+                VobleError::Unauthorized
+            );
+            Ok((profile.player, profile.username))
+        })
+        .collect()
+}
+
+/// Overwrite entry usernames with the current ones from profiles
+///
+/// Matching is by player pubkey only, never by name.
+///
+/// # Returns
+/// Number of entries whose username changed
+pub fn refresh_usernames(entries: &mut [LeaderEntry], profiles: &[(Pubkey, String)]) -> usize {
+    let mut refreshed = 0;
+    for entry in entries.iter_mut() {
+        if let Some((_, username)) = profiles.iter().find(|(player, _)| *player == entry.player) {
+            if entry.username != *username {
+                entry.username = username.clone();
+                refreshed += 1;
+            }
+        }
+    }
+    refreshed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(player: Pubkey, username: &str, score: u32) -> LeaderEntry {
+        LeaderEntry {
+            player,
+            score,
+            guesses_used: 3,
+            time_ms: 30_000,
+            timestamp: 0,
+            username: username.to_string(),
+            equipped_flair: 0,
+            games_played: 1,
+            counted_days: 0,
+        }
+    }
+
+    #[test]
+    fn test_rename_then_finalize_uses_current_name() {
+        let alice = Pubkey::new_unique();
+        let squatter = Pubkey::new_unique();
+        let mut entries = vec![entry(alice, "alice", 1_000), entry(squatter, "bob", 500)];
+
+        // Alice renamed; a new account took her old name
+        let profiles = vec![
+            (alice, "alice_v2".to_string()),
+            (squatter, "alice".to_string()),
+        ];
+
+        assert_eq!(refresh_usernames(&mut entries, &profiles), 2);
+        assert_eq!(entries[0].player, alice);
+        assert_eq!(entries[0].username, "alice_v2");
+        assert_eq!(entries[1].username, "alice");
+    }
+
+    #[test]
+    fn test_refresh_leaves_entries_without_profile() {
+        let player = Pubkey::new_unique();
+        let mut entries = vec![entry(player, "carol", 700)];
+
+        assert_eq!(refresh_usernames(&mut entries, &[]), 0);
+        assert_eq!(
+            refresh_usernames(&mut entries, &[(player, "carol".to_string())]),
+            0
+        );
+        assert_eq!(entries[0].username, "carol");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod admin;
+pub mod duel; // Escrowed head-to-head wagers
 pub mod game; // Now a directory with profile.rs and voble.rs
 pub mod leaderboard;
 pub mod prize; // Now a directory with finalize.rs, claim.rs, entitlement.rs
@@ -1,5 +1,16 @@
-use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use crate::instructions::admin::record_outflow;
+use crate::utils::validation::{validate_payout_account, PayoutAccount};
+use crate::utils::{calculate_bps, pda, validate_bps_sum_equals_100};
+use crate::{
+    constants::*,
+    contexts::*,
+    errors::VobleError,
+    events::*,
+    state::{GlobalConfig, PeriodType, PrizeDestination, VaultLedger, WinnerEntitlement},
+};
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 
 /// Claim a prize for a finalized period
@@ -22,6 +33,9 @@ use anchor_lang::prelude::*;
 /// - Entitlement must not be claimed already
 /// - Vault must have sufficient balance (prize amount + rent)
 /// - Only the winner can claim (enforced by PDA seeds)
+/// - Mint must be the configured USDC mint and the receiving account an
+///   unfrozen account of it owned by the winner (`PayoutMintMismatch`,
+///   `PayoutAccountFrozen`, `PayoutOwnerMismatch`)
 ///
 /// # Security
 /// - Uses PDA signer seeds for vault transfer
@@ -37,8 +51,10 @@ use anchor_lang::prelude::*;
 /// - Vault stays healthy for future periods
 ///
 /// # Notes
-/// - Winner receives lamports directly to their account
-/// - Entitlement account is NOT closed (kept for record)
+/// - Winner receives the prize tokens directly to their token account; an
+///   empty account is created as the winner's associated token account first
+/// - The entitlement stays open after the claim; the winner or the authority
+///   may then close it with `close_claimed_entitlement` to reclaim its rent
 /// - No time limit on claiming (winners can claim anytime)
 /// - Gas fees paid by winner (normal transaction cost)
 ///
@@ -46,67 +62,649 @@ use anchor_lang::prelude::*;
 /// 1. Period finalized → prizes calculated
 /// 2. Admin creates entitlements
 /// 3. **Winner calls this instruction** ← You are here
-/// 4. Winner receives lamports
+/// 4. Winner receives the prize tokens
 /// 5. Entitlement marked as claimed
 pub fn claim_daily(ctx: Context<ClaimDaily>) -> Result<()> {
-    claim_prize_internal(
-        &mut ctx.accounts.winner_entitlement,
-        &ctx.accounts.daily_prize_vault,
-        &ctx.accounts.winner,
+    init_winner_token_account(
         &ctx.accounts.winner_token_account,
-        &ctx.accounts.token_program,
+        &ctx.accounts.winner,
         &ctx.accounts.usdc_mint,
-        ctx.bumps.daily_prize_vault,
-        SEED_DAILY_PRIZE_VAULT,
-        "daily",
+        &ctx.accounts.token_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.daily_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Daily,
+        ),
+        &accounts.winner,
+        vec![PrizeShare::whole(
+            accounts.winner.key(),
+            accounts.winner_token_account.to_account_info(),
+        )],
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
     )
 }
 
 pub fn claim_weekly(ctx: Context<ClaimWeekly>) -> Result<()> {
-    claim_prize_internal(
-        &mut ctx.accounts.winner_entitlement,
-        &ctx.accounts.weekly_prize_vault,
-        &ctx.accounts.winner,
+    init_winner_token_account(
         &ctx.accounts.winner_token_account,
-        &ctx.accounts.token_program,
+        &ctx.accounts.winner,
         &ctx.accounts.usdc_mint,
-        ctx.bumps.weekly_prize_vault,
-        SEED_WEEKLY_PRIZE_VAULT,
-        "weekly",
+        &ctx.accounts.token_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.weekly_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Weekly,
+        ),
+        &accounts.winner,
+        vec![PrizeShare::whole(
+            accounts.winner.key(),
+            accounts.winner_token_account.to_account_info(),
+        )],
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
     )
 }
 
 pub fn claim_monthly(ctx: Context<ClaimMonthly>) -> Result<()> {
-    claim_prize_internal(
-        &mut ctx.accounts.winner_entitlement,
-        &ctx.accounts.monthly_prize_vault,
-        &ctx.accounts.winner,
+    init_winner_token_account(
         &ctx.accounts.winner_token_account,
-        &ctx.accounts.token_program,
+        &ctx.accounts.winner,
         &ctx.accounts.usdc_mint,
-        ctx.bumps.monthly_prize_vault,
-        SEED_MONTHLY_PRIZE_VAULT,
-        "monthly",
+        &ctx.accounts.token_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.monthly_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Monthly,
+        ),
+        &accounts.winner,
+        vec![PrizeShare::whole(
+            accounts.winner.key(),
+            accounts.winner_token_account.to_account_info(),
+        )],
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
+    )
+}
+
+/// Claim a daily prize split between up to three wallets
+///
+/// # Arguments
+/// * `ctx` - Same accounts as `claim_daily`
+/// * `destinations` - Wallets and their shares in basis points
+///
+/// # Remaining Accounts
+/// One writable associated token account (of the prize mint) per
+/// destination, in the same order
+///
+/// # Validation
+/// - Same as `claim_daily`
+/// - 1 to MAX_PRIZE_DESTINATIONS destinations with non-zero shares summing
+///   to 10000 bps (`InvalidPrizeSplits`)
+/// - Each remaining account must be its destination's associated token
+///   account
+///
+/// # Notes
+/// - Each share is rounded down; the rounding remainder goes to the first
+///   destination, so the whole prize is paid out
+/// - The entitlement is marked claimed once; one `PrizeClaimed` is emitted
+///   per destination
+pub fn claim_daily_with_split<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimDaily<'info>>,
+    destinations: Vec<PrizeDestination>,
+) -> Result<()> {
+    let shares = destination_shares(
+        &destinations,
+        ctx.remaining_accounts,
+        &ctx.accounts.usdc_mint.key(),
+        &ctx.accounts.token_program.key(),
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.daily_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Daily,
+        ),
+        &accounts.winner,
+        shares,
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
     )
 }
 
+/// Claim a weekly prize split between up to three wallets (see `claim_daily_with_split`)
+pub fn claim_weekly_with_split<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimWeekly<'info>>,
+    destinations: Vec<PrizeDestination>,
+) -> Result<()> {
+    let shares = destination_shares(
+        &destinations,
+        ctx.remaining_accounts,
+        &ctx.accounts.usdc_mint.key(),
+        &ctx.accounts.token_program.key(),
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.weekly_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Weekly,
+        ),
+        &accounts.winner,
+        shares,
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
+    )
+}
+
+/// Claim a monthly prize split between up to three wallets (see `claim_daily_with_split`)
+pub fn claim_monthly_with_split<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimMonthly<'info>>,
+    destinations: Vec<PrizeDestination>,
+) -> Result<()> {
+    let shares = destination_shares(
+        &destinations,
+        ctx.remaining_accounts,
+        &ctx.accounts.usdc_mint.key(),
+        &ctx.accounts.token_program.key(),
+    )?;
+    let accounts = &mut *ctx.accounts;
+    claim_prize_internal(
+        &mut accounts.winner_entitlement,
+        ClaimVault::new(
+            &accounts.monthly_prize_vault,
+            &mut accounts.vault_ledger,
+            &accounts.global_config,
+            PeriodType::Monthly,
+        ),
+        &accounts.winner,
+        shares,
+        &accounts.token_program,
+        &accounts.usdc_mint,
+        &accounts.global_config,
+    )
+}
+
+/// Token account receiving part of a claimed prize
+pub struct PrizeShare<'info> {
+    pub owner: Pubkey,
+    pub token_account: AccountInfo<'info>,
+    pub bps: u16,
+}
+
+impl<'info> PrizeShare<'info> {
+    /// The whole prize to one account (the unsplit claims)
+    pub fn whole(owner: Pubkey, token_account: AccountInfo<'info>) -> Self {
+        Self {
+            owner,
+            token_account,
+            bps: BASIS_POINTS_TOTAL,
+        }
+    }
+}
+
+/// Create the winner's associated token account if the claim pays into an empty one
+///
+/// # Validation
+/// - An empty account must be the winner's associated token account for
+///   `usdc_mint` (`AccountNotInitialized` otherwise)
+///
+/// # Notes
+/// - The winner pays the rent
+/// - An existing account is left as is; the claim validates it like any
+///   payout account
+fn init_winner_token_account<'info>(
+    winner_token_account: &AccountInfo<'info>,
+    winner: &Signer<'info>,
+    usdc_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if !winner_token_account.data_is_empty() {
+        return Ok(());
+    }
+
+    let expected = get_associated_token_address_with_program_id(
+        &winner.key(),
+        &usdc_mint.key(),
+        &token_program.key(),
+    );
+    require_keys_eq!(
+        winner_token_account.key(),
+        expected,
+        ErrorCode::AccountNotInitialized
+    );
+
+    anchor_spl::associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: winner.to_account_info(),
+            associated_token: winner_token_account.clone(),
+            authority: winner.to_account_info(),
+            mint: usdc_mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))
+}
+
+/// Read the fields `validate_payout_account` checks from a token account
+///
+/// # Returns
+/// `AccountNotInitialized` if the account isn't a token account of
+/// `token_program` (e.g. a split destination's ATA that was never created)
+fn load_payout_account(info: &AccountInfo, token_program: &Pubkey) -> Result<PayoutAccount> {
+    require!(
+        info.owner == token_program && !info.data_is_empty(),
+        ErrorCode::AccountNotInitialized
+    );
+    let account = TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    Ok(PayoutAccount::from(&account))
+}
+
+/// Pair split destinations with their token accounts from remaining accounts
+///
+/// # Validation
+/// - Destinations must pass `validate_prize_destinations`
+/// - Exactly one writable account per destination, each the destination's
+///   associated token account for `mint` (`Unauthorized` otherwise)
+fn destination_shares<'info>(
+    destinations: &[PrizeDestination],
+    remaining_accounts: &[AccountInfo<'info>],
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<Vec<PrizeShare<'info>>> {
+    validate_prize_destinations(destinations)?;
+    require!(
+        remaining_accounts.len() == destinations.len(),
+        VobleError::InvalidInput
+    );
+
+    destinations
+        .iter()
+        .zip(remaining_accounts)
+        .map(|(destination, info)| {
+            let expected = get_associated_token_address_with_program_id(
+                &destination.owner,
+                mint,
+                token_program,
+            );
+            require_keys_eq!(info.key(), expected, VobleError::Unauthorized);
+            require!(info.is_writable, VobleError::Unauthorized);
+            Ok(PrizeShare {
+                owner: destination.owner,
+                token_account: info.clone(),
+                bps: destination.bps,
+            })
+        })
+        .collect()
+}
+
+/// Validate the wallets a prize is split between
+///
+/// # Validation
+/// - 1 to MAX_PRIZE_DESTINATIONS destinations
+/// - Every share non-zero, all shares summing to 10000 bps
+///
+/// # Returns
+/// `InvalidPrizeSplits` if any check fails
+pub fn validate_prize_destinations(destinations: &[PrizeDestination]) -> Result<()> {
+    let splits: Vec<u16> = destinations.iter().map(|destination| destination.bps).collect();
+    require!(
+        (1..=MAX_PRIZE_DESTINATIONS).contains(&destinations.len())
+            && splits.iter().all(|&bps| bps > 0)
+            && validate_bps_sum_equals_100(&splits),
+        VobleError::InvalidPrizeSplits
+    );
+    Ok(())
+}
+
+/// Split a prize by basis points without losing any base units
+///
+/// # Arguments
+/// * `amount` - The entitlement amount
+/// * `splits` - Each destination's share, summing to 10000 bps
+///
+/// # Returns
+/// One amount per split, rounded down, with the rounding remainder added to
+/// the first; they always sum to `amount`
+pub fn split_prize_amount(amount: u64, splits: &[u16]) -> Vec<u64> {
+    let mut amounts: Vec<u64> = splits.iter().map(|&bps| calculate_bps(amount, bps)).collect();
+    let paid: u64 = amounts.iter().sum();
+    if let Some(first) = amounts.first_mut() {
+        *first += amount - paid;
+    }
+    amounts
+}
+
+/// Claim several prizes (e.g. daily, weekly and monthly wins) in one transaction
+///
+/// # Arguments
+/// * `ctx` - Context with the winner, their token account and the config
+///
+/// # Remaining Accounts
+/// Up to MAX_BATCH_CLAIMS groups, each in this order:
+/// 1. Writable WinnerEntitlement
+/// 2. Writable prize vault for that entitlement's period type
+/// 3. Writable VaultLedger of that vault
+///
+/// # Validation
+/// - Between 1 and MAX_BATCH_CLAIMS groups, no entitlement passed twice
+/// - Every entitlement must belong to the signer and sit at its PDA
+/// - No entitlement may be claimed already
+/// - Every vault must be the prize vault for its entitlement's period type
+///   and cover the total claimed from it
+/// - Every ledger must be the one tracking its vault
+/// - The winner's token account must be an unfrozen account of the USDC mint
+///   owned by the winner (`PayoutMintMismatch`, `PayoutAccountFrozen`,
+///   `PayoutOwnerMismatch`); an empty one is created as their associated
+///   token account
+///
+/// # Notes
+/// - All-or-nothing: every group is validated before any transfer, so one bad
+///   entitlement aborts the whole batch
+/// - Emits one `PrizeClaimed` per entitlement, same as the single claims
+pub fn claim_all<'info>(ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>) -> Result<()> {
+    let winner = ctx.accounts.winner.key();
+    let config = &ctx.accounts.global_config;
+
+    msg!("🎁 Claiming prizes in batch");
+    msg!("   Winner: {}", winner);
+
+    // ========== PARSE REMAINING ACCOUNTS ==========
+    let groups = ctx.remaining_accounts.chunks_exact(3);
+    require!(groups.remainder().is_empty(), VobleError::InvalidInput);
+
+    let mut requests = Vec::with_capacity(groups.len());
+    for group in groups {
+        let (entitlement_info, vault_info, ledger_info) = (&group[0], &group[1], &group[2]);
+        // Closed entitlements are handed back to the system program
+        require!(
+            entitlement_info.owner != &anchor_lang::system_program::ID,
+            VobleError::EntitlementClosed
+        );
+        require!(
+            entitlement_info.owner == &crate::ID && ledger_info.owner == &crate::ID,
+            VobleError::Unauthorized
+        );
+        require!(
+            entitlement_info.is_writable && vault_info.is_writable && ledger_info.is_writable,
+            VobleError::Unauthorized
+        );
+
+        let entitlement =
+            WinnerEntitlement::try_deserialize(&mut &entitlement_info.try_borrow_data()?[..])?;
+        let vault = TokenAccount::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+        let ledger = VaultLedger::try_deserialize(&mut &ledger_info.try_borrow_data()?[..])?;
+        require_keys_eq!(ledger.vault, vault_info.key(), VobleError::Unauthorized);
+
+        requests.push(ClaimRequest {
+            entitlement_key: entitlement_info.key(),
+            entitlement,
+            vault_key: vault_info.key(),
+            vault_balance: vault.amount,
+        });
+    }
+
+    // ========== VALIDATION ==========
+    validate_claim_batch(&winner, &requests, |period_type| {
+        let (seed, bump) = prize_vault_seed_and_bump(config, period_type).ok()?;
+        Pubkey::create_program_address(&[seed, &[bump]], &crate::ID).ok()
+    })?;
+
+    init_winner_token_account(
+        &ctx.accounts.winner_token_account,
+        &ctx.accounts.winner,
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
+    let account = load_payout_account(
+        &ctx.accounts.winner_token_account,
+        &ctx.accounts.token_program.key(),
+    )?;
+    validate_payout_account(&account, &config.usdc_mint, Some(&winner))?;
+
+    msg!("✅ {} entitlements validated", requests.len());
+
+    // ========== TRANSFER PRIZES ==========
+    // Decimals stored at vault init; configs created before that fall back to the mint
+    let decimals = if config.usdc_decimals == 0 {
+        ctx.accounts.usdc_mint.decimals
+    } else {
+        config.usdc_decimals
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    for (request, group) in requests.iter_mut().zip(ctx.remaining_accounts.chunks(3)) {
+        let (entitlement_info, vault_info, ledger_info) = (&group[0], &group[1], &group[2]);
+        let entitlement = &mut request.entitlement;
+        let (seed, bump) = prize_vault_seed_and_bump(config, &entitlement.period_type)?;
+        let vault_seeds = &[seed, &[bump]];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: vault_info.clone(),
+                    to: ctx.accounts.winner_token_account.to_account_info(),
+                    authority: vault_info.clone(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            entitlement.amount,
+            decimals,
+        )?;
+
+        entitlement.claimed = true;
+        entitlement.try_serialize(&mut &mut entitlement_info.try_borrow_mut_data()?[..])?;
+
+        // Re-read the ledger each time - two entitlements may share a vault
+        {
+            let mut data = ledger_info.try_borrow_mut_data()?;
+            let mut ledger = VaultLedger::try_deserialize(&mut &data[..])?;
+            record_outflow(&mut ledger, entitlement.amount, now)?;
+            ledger.try_serialize(&mut &mut data[..])?;
+        }
+
+        msg!(
+            "   💸 {} {} rank #{}: {} base units",
+            entitlement.period_type,
+            entitlement.period_id,
+            entitlement.rank,
+            entitlement.amount
+        );
+
+        emit!(PrizeClaimed {
+            winner,
+            period_type: entitlement.period_type.clone(),
+            period_id: entitlement.period_id.clone(),
+            rank: entitlement.rank,
+            amount: entitlement.amount,
+            destination: winner,
+            split_bps: BASIS_POINTS_TOTAL,
+        });
+    }
+
+    msg!("🎉 Batch claim complete");
+
+    Ok(())
+}
+
+/// Entitlement/vault pair parsed from `claim_all` remaining accounts
+pub struct ClaimRequest {
+    pub entitlement_key: Pubkey,
+    pub entitlement: WinnerEntitlement,
+    pub vault_key: Pubkey,
+    pub vault_balance: u64,
+}
+
+/// Validate a whole claim batch before anything is transferred
+///
+/// # Arguments
+/// * `winner` - Signer claiming the prizes
+/// * `requests` - Parsed entitlement/vault pairs
+/// * `prize_vault` - Expected prize vault address for a period type string
+pub fn validate_claim_batch(
+    winner: &Pubkey,
+    requests: &[ClaimRequest],
+    prize_vault: impl Fn(&str) -> Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        !requests.is_empty() && requests.len() <= MAX_BATCH_CLAIMS,
+        VobleError::InvalidInput
+    );
+
+    // (vault, balance, total claimed from it) - two entitlements may share a vault
+    let mut vault_totals: Vec<(Pubkey, u64, u64)> = Vec::with_capacity(requests.len());
+
+    for (i, request) in requests.iter().enumerate() {
+        let entitlement = &request.entitlement;
+
+        require!(
+            requests[..i]
+                .iter()
+                .all(|other| other.entitlement_key != request.entitlement_key),
+            VobleError::InvalidInput
+        );
+        require_keys_eq!(entitlement.player, *winner, VobleError::Unauthorized);
+
+        let (expected_entitlement, _) = pda::derive_winner_entitlement_pda(
+            winner,
+            &entitlement.period_type,
+            &entitlement.period_id,
+            &crate::ID,
+        );
+        require_keys_eq!(
+            request.entitlement_key,
+            expected_entitlement,
+            VobleError::Unauthorized
+        );
+        require!(!entitlement.claimed, VobleError::AlreadyClaimed);
+
+        let expected_vault =
+            prize_vault(&entitlement.period_type).ok_or(VobleError::InvalidPeriodType)?;
+        require_keys_eq!(request.vault_key, expected_vault, VobleError::Unauthorized);
+
+        match vault_totals.iter_mut().find(|(key, _, _)| *key == request.vault_key) {
+            Some((_, _, total)) => *total = total.saturating_add(entitlement.amount),
+            None => vault_totals.push((
+                request.vault_key,
+                request.vault_balance,
+                entitlement.amount,
+            )),
+        }
+    }
+
+    for (_, balance, total) in vault_totals {
+        require!(balance >= total, VobleError::InsufficientVaultBalance);
+    }
+
+    Ok(())
+}
+
+/// Prize vault seed and cached bump for a period type string
+///
+/// # Returns
+/// `InvalidPeriodType` for anything but a canonical period type string
+fn prize_vault_seed_and_bump(
+    config: &GlobalConfig,
+    period_type: &str,
+) -> Result<(&'static [u8], u8)> {
+    let period_type = PeriodType::try_from(period_type)?;
+    Ok((period_type.vault_seed(), config.prize_vault_bump(period_type)))
+}
+
+/// Prize vault a single claim pays out of, with the seeds that sign its transfers
+struct ClaimVault<'a, 'info> {
+    account: &'a InterfaceAccount<'info, TokenAccount>,
+    ledger: &'a mut Account<'info, VaultLedger>,
+    seed: &'static [u8],
+    bump: u8,
+    period_type: PeriodType,
+}
+
+impl<'a, 'info> ClaimVault<'a, 'info> {
+    /// The prize vault of `period_type`, signed for with the config's cached bump
+    fn new(
+        account: &'a InterfaceAccount<'info, TokenAccount>,
+        ledger: &'a mut Account<'info, VaultLedger>,
+        config: &GlobalConfig,
+        period_type: PeriodType,
+    ) -> Self {
+        Self {
+            account,
+            ledger,
+            seed: period_type.vault_seed(),
+            bump: config.prize_vault_bump(period_type),
+            period_type,
+        }
+    }
+}
+
 /// Internal function to claim prize for any period type
 ///
 /// This consolidates the logic for daily, weekly, and monthly prize claims
-/// to avoid code duplication. The only differences are the vault account,
-/// vault seeds, and period type.
+/// to avoid code duplication. The only difference is the vault (see
+/// `ClaimVault`). `shares` is the winner's own token account
+/// for a plain claim, or the split destinations.
+///
+/// Every receiving account is checked up front (mint, frozen, owner) so a
+/// bad one fails with a specific error instead of inside `transfer_checked`.
 fn claim_prize_internal<'info>(
-    entitlement: &mut Account<'info, crate::state::WinnerEntitlement>,
-    vault: &InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>,
+    entitlement: &mut Account<'info, WinnerEntitlement>,
+    vault: ClaimVault<'_, 'info>,
     winner: &Signer<'info>,
-    winner_token_account: &InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>,
-    token_program: &Interface<'info, anchor_spl::token_interface::TokenInterface>,
-    usdc_mint: &InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
-    _vault_bump: u8,
-    _vault_seed: &[u8],
-    period_type: &str,
+    shares: Vec<PrizeShare<'info>>,
+    token_program: &Interface<'info, TokenInterface>,
+    usdc_mint: &InterfaceAccount<'info, Mint>,
+    config: &GlobalConfig,
 ) -> Result<()> {
-    msg!("🎁 Claiming {} prize", period_type);
+    let ClaimVault {
+        account: vault,
+        ledger: vault_ledger,
+        seed: vault_seed,
+        bump: vault_bump,
+        period_type,
+    } = vault;
+    let config_mint = config.usdc_mint;
+
+    msg!("🎁 Claiming {} prize", period_type.to_str());
     msg!("   Winner: {}", winner.key());
     msg!("   Period: {}", entitlement.period_id);
     msg!("   Rank: #{}", entitlement.rank);
@@ -118,8 +716,8 @@ fn claim_prize_internal<'info>(
     let vault_balance = vault.amount;
 
     msg!("💰 Prize details:");
-    msg!("   Amount: {} USDC", amount);
-    msg!("   Vault balance: {} USDC", vault_balance);
+    msg!("   Amount: {} base units", amount);
+    msg!("   Vault balance: {} base units", vault_balance);
 
     // ========== VALIDATION: Vault Balance ==========
     // Ensure vault has enough for prize
@@ -130,56 +728,79 @@ fn claim_prize_internal<'info>(
 
     msg!("✅ Validation passed - vault has sufficient balance");
 
+    // ========== VALIDATION: Receiving Accounts ==========
+    require_keys_eq!(usdc_mint.key(), config_mint, VobleError::PayoutMintMismatch);
+    for share in &shares {
+        let account = load_payout_account(&share.token_account, &token_program.key())?;
+        validate_payout_account(&account, &config_mint, Some(&share.owner))?;
+    }
+
     // ========== TRANSFER PRIZE ==========
-    msg!("💸 Transferring {} USDC to winner", amount);
+    msg!("💸 Transferring {} base units to {} destination(s)", amount, shares.len());
 
-    let vault_seeds = &[_vault_seed, &[_vault_bump]];
+    let vault_seeds = &[vault_seed, &[vault_bump]];
     let signer_seeds = &[&vault_seeds[..]];
 
-    let decimals = usdc_mint.decimals;
+    // Decimals stored at vault init; configs created before that fall back to the mint
+    let decimals = if config.usdc_decimals == 0 {
+        usdc_mint.decimals
+    } else {
+        config.usdc_decimals
+    };
 
-    anchor_spl::token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            anchor_spl::token_interface::TransferChecked {
-                from: vault.to_account_info(),
-                to: winner_token_account.to_account_info(),
-                authority: vault.to_account_info(),
-                mint: usdc_mint.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount,
-        decimals,
-    )?;
+    let splits: Vec<u16> = shares.iter().map(|share| share.bps).collect();
+    let share_amounts = split_prize_amount(amount, &splits);
+
+    for (share, &share_amount) in shares.iter().zip(&share_amounts) {
+        anchor_spl::token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                anchor_spl::token_interface::TransferChecked {
+                    from: vault.to_account_info(),
+                    to: share.token_account.clone(),
+                    authority: vault.to_account_info(),
+                    mint: usdc_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            share_amount,
+            decimals,
+        )?;
+    }
+
+    record_outflow(vault_ledger, amount, Clock::get()?.unix_timestamp)?;
 
     let remaining_balance = vault_balance - amount;
 
     msg!("✅ Transfer successful");
-    msg!("   Transferred: {} USDC", amount);
-    msg!("   Remaining vault balance: {} USDC", remaining_balance);
+    msg!("   Transferred: {} base units", amount);
+    msg!("   Remaining vault balance: {} base units", remaining_balance);
 
     // ========== MARK AS CLAIMED ==========
     entitlement.claimed = true;
 
     msg!("✅ Entitlement marked as claimed");
 
-    // ========== EMIT EVENT ==========
-    emit!(PrizeClaimed {
-        winner: winner.key(),
-        period_type: period_type.to_string(),
-        period_id: entitlement.period_id.clone(),
-        rank: entitlement.rank,
-        amount,
-    });
+    // ========== EMIT EVENTS ==========
+    for (share, &share_amount) in shares.iter().zip(&share_amounts) {
+        emit!(PrizeClaimed {
+            winner: winner.key(),
+            period_type: period_type.to_string(),
+            period_id: entitlement.period_id.clone(),
+            rank: entitlement.rank,
+            amount: share_amount,
+            destination: share.owner,
+            split_bps: share.bps,
+        });
+    }
 
     // ========== FINAL LOGGING ==========
     msg!("");
     msg!("✅ ========== PRIZE CLAIMED ========== ✅");
     msg!("   Winner: {}", winner.key());
-    msg!("   Period: {} ({})", entitlement.period_id, period_type);
+    msg!("   Period: {} ({})", entitlement.period_id, period_type.to_str());
     msg!("   Rank: #{}", entitlement.rank);
-    msg!("   Amount: {} USDC", amount);
+    msg!("   Amount: {} base units", amount);
     msg!("   Status: Successfully claimed");
     msg!("");
     msg!("🎉 Congratulations on your win!");
@@ -187,3 +808,311 @@ fn claim_prize_internal<'info>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PoolPeriod, VaultKind};
+    use crate::utils::test_accounts::{account_infos, TestAccount};
+    use anchor_spl::token::spl_token;
+    use std::collections::BTreeSet;
+
+    fn prize_vault(period_type: &str) -> Option<Pubkey> {
+        pda::derive_vault_pda_for_period(period_type, &crate::ID).map(|(key, _)| key)
+    }
+
+    fn request(winner: Pubkey, period_type: &str, period_id: &str, claimed: bool) -> ClaimRequest {
+        let (entitlement_key, bump) =
+            pda::derive_winner_entitlement_pda(&winner, period_type, period_id, &crate::ID);
+        ClaimRequest {
+            entitlement_key,
+            entitlement: WinnerEntitlement {
+                player: winner,
+                period_type: period_type.to_string(),
+                period_id: period_id.to_string(),
+                rank: 1,
+                amount: 1_000_000,
+                claimed,
+                bump,
+            },
+            vault_key: prize_vault(period_type).unwrap(),
+            vault_balance: 5_000_000,
+        }
+    }
+
+    #[test]
+    fn test_claim_all_three_vaults() {
+        let winner = Pubkey::new_unique();
+        let requests = [
+            request(winner, "daily", "D100", false),
+            request(winner, "weekly", "W14", false),
+            request(winner, "monthly", "M3", false),
+        ];
+
+        assert!(validate_claim_batch(&winner, &requests, prize_vault).is_ok());
+    }
+
+    #[test]
+    fn test_claim_all_rejects_mixed_claimed() {
+        let winner = Pubkey::new_unique();
+        let requests = [
+            request(winner, "daily", "D100", false),
+            request(winner, "weekly", "W14", true),
+        ];
+
+        assert_eq!(
+            validate_claim_batch(&winner, &requests, prize_vault).unwrap_err(),
+            VobleError::AlreadyClaimed.into()
+        );
+    }
+
+    #[test]
+    fn test_claim_all_rejects_other_players_entitlement() {
+        let winner = Pubkey::new_unique();
+        let requests = [
+            request(winner, "daily", "D100", false),
+            request(Pubkey::new_unique(), "weekly", "W14", false),
+        ];
+
+        assert_eq!(
+            validate_claim_batch(&winner, &requests, prize_vault).unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+
+    #[test]
+    fn test_claim_all_rejects_wrong_vault() {
+        let winner = Pubkey::new_unique();
+        let mut daily = request(winner, "daily", "D100", false);
+        daily.vault_key = prize_vault("monthly").unwrap();
+
+        assert_eq!(
+            validate_claim_batch(&winner, &[daily], prize_vault).unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+
+    #[test]
+    fn test_claim_all_rejects_duplicates_and_bad_counts() {
+        let winner = Pubkey::new_unique();
+        let duplicate = [
+            request(winner, "daily", "D100", false),
+            request(winner, "daily", "D100", false),
+        ];
+        let too_many = [
+            request(winner, "daily", "D100", false),
+            request(winner, "daily", "D101", false),
+            request(winner, "weekly", "W14", false),
+            request(winner, "monthly", "M3", false),
+        ];
+
+        for requests in [&duplicate[..], &too_many[..], &[]] {
+            assert_eq!(
+                validate_claim_batch(&winner, requests, prize_vault).unwrap_err(),
+                VobleError::InvalidInput.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_claim_all_checks_shared_vault_total() {
+        let winner = Pubkey::new_unique();
+        let mut first = request(winner, "daily", "D100", false);
+        let mut second = request(winner, "daily", "D101", false);
+        first.vault_balance = 1_500_000;
+        second.vault_balance = 1_500_000;
+
+        assert_eq!(
+            validate_claim_batch(&winner, &[first, second], prize_vault).unwrap_err(),
+            VobleError::InsufficientVaultBalance.into()
+        );
+    }
+
+    fn destinations(splits: &[u16]) -> Vec<PrizeDestination> {
+        splits
+            .iter()
+            .map(|&bps| PrizeDestination {
+                owner: Pubkey::new_unique(),
+                bps,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_destinations_validated() {
+        assert!(validate_prize_destinations(&destinations(&[10_000])).is_ok());
+        assert!(validate_prize_destinations(&destinations(&[5_000, 3_000, 2_000])).is_ok());
+
+        // Not summing to 100%, a zero share, none, or too many
+        for splits in [
+            &[6_000, 3_000][..],
+            &[6_000, 5_000],
+            &[10_000, 0],
+            &[],
+            &[2_500, 2_500, 2_500, 2_500],
+        ] {
+            assert_eq!(
+                validate_prize_destinations(&destinations(splits)).unwrap_err(),
+                VobleError::InvalidPrizeSplits.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_amounts_pay_out_whole_prize() {
+        assert_eq!(
+            split_prize_amount(1_000_000, &[5_000, 3_000, 2_000]),
+            vec![500_000, 300_000, 200_000]
+        );
+        assert_eq!(split_prize_amount(1_000_000, &[10_000]), vec![1_000_000]);
+
+        // Rounding remainder goes to the first destination
+        assert_eq!(split_prize_amount(10, &[3_334, 3_333, 3_333]), vec![4, 3, 3]);
+        for amount in [1, 7, 999_999, u64::MAX] {
+            let amounts = split_prize_amount(amount, &[3_334, 3_333, 3_333]);
+            assert_eq!(amounts.iter().map(|&a| a as u128).sum::<u128>(), amount as u128);
+        }
+    }
+
+    /// Accounts of a W12 `claim_weekly` for 1 USDC out of a 5 USDC vault
+    struct WeeklyClaim {
+        winner: Pubkey,
+        usdc_mint: Pubkey,
+        entitlement: WinnerEntitlement,
+        winner_token_account: TestAccount,
+    }
+
+    impl WeeklyClaim {
+        fn new() -> Self {
+            let winner = Pubkey::new_unique();
+            let usdc_mint = Pubkey::new_unique();
+            let (_, bump) =
+                pda::derive_winner_entitlement_pda(&winner, "weekly", "W12", &crate::ID);
+            Self {
+                winner,
+                usdc_mint,
+                entitlement: WinnerEntitlement {
+                    player: winner,
+                    period_type: "weekly".to_string(),
+                    period_id: "W12".to_string(),
+                    rank: 1,
+                    amount: 1_000_000,
+                    claimed: false,
+                    bump,
+                },
+                winner_token_account: TestAccount::token_account(usdc_mint, winner, 0),
+            }
+        }
+
+        /// The accounts in `ClaimWeekly` order, each at its PDA
+        fn accounts(self) -> Vec<TestAccount> {
+            let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID);
+            let (config_key, config_bump) = pda(&[SEED_GLOBAL_CONFIG]);
+            let (vault_key, vault_bump) = pda(&[SEED_WEEKLY_PRIZE_VAULT]);
+            let (ledger_key, ledger_bump) = pda(&[SEED_VAULT_LEDGER, VaultKind::Weekly.seed()]);
+            let (entitlement_key, _) =
+                pda::derive_winner_entitlement_pda(&self.winner, "weekly", "W12", &crate::ID);
+
+            let zeroed = [0u8; 8 + GlobalConfig::INIT_SPACE];
+            let mut config = GlobalConfig::try_deserialize_unchecked(&mut &zeroed[..]).unwrap();
+            config.usdc_mint = self.usdc_mint;
+            config.usdc_decimals = 6;
+            config.bumps.global_config = config_bump;
+            config.bumps.weekly_prize_vault = vault_bump;
+            let ledger = VaultLedger {
+                kind: VaultKind::Weekly,
+                vault: vault_key,
+                opening_balance: 0,
+                total_in: 0,
+                total_out: 0,
+                last_updated: 0,
+                bump: ledger_bump,
+                refund_liability: 0,
+                current_pool: PoolPeriod::default(),
+                previous_pool: PoolPeriod::default(),
+            };
+
+            vec![
+                TestAccount::program_account(&self.entitlement).at(entitlement_key),
+                TestAccount::token_account(self.usdc_mint, vault_key, 5_000_000).at(vault_key),
+                TestAccount::program_account(&ledger).at(ledger_key),
+                TestAccount::signer(self.winner),
+                self.winner_token_account,
+                TestAccount::program_account(&config).at(config_key),
+                TestAccount::program(anchor_lang::system_program::ID),
+                TestAccount::program(spl_token::ID),
+                TestAccount::program(anchor_spl::associated_token::ID),
+                TestAccount::mint(6).at(self.usdc_mint),
+            ]
+        }
+
+        /// Run `ClaimWeekly`'s account constraints, then `claim_weekly` up to
+        /// its transfer (the first CPI)
+        fn run(self) -> Result<()> {
+            let mut accounts = self.accounts();
+            let infos = account_infos(&mut accounts);
+
+            let mut bumps = ClaimWeeklyBumps::default();
+            let mut claim = ClaimWeekly::try_accounts(
+                &crate::ID,
+                &mut &infos[..],
+                &[],
+                &mut bumps,
+                &mut BTreeSet::new(),
+            )?;
+            claim_weekly(Context::new(&crate::ID, &mut claim, &[], bumps))
+        }
+    }
+
+    #[test]
+    fn test_claim_rejects_wrong_payout_account() {
+        // Both get past the account constraints and fail the handler's checks
+        // before the transfer
+        let mut claim = WeeklyClaim::new();
+        claim.winner_token_account =
+            TestAccount::token_account(Pubkey::new_unique(), claim.winner, 0);
+        assert_eq!(claim.run().unwrap_err(), VobleError::PayoutMintMismatch.into());
+
+        let mut claim = WeeklyClaim::new();
+        claim.winner_token_account =
+            TestAccount::token_account(claim.usdc_mint, Pubkey::new_unique(), 0);
+        assert_eq!(claim.run().unwrap_err(), VobleError::PayoutOwnerMismatch.into());
+    }
+
+    #[test]
+    fn test_weekly_claim_creates_only_the_winners_ata() {
+        // An empty receiving account goes through the same ATA creation as
+        // claim_daily, which only accepts the winner's associated token account
+        let mut claim = WeeklyClaim::new();
+        claim.winner_token_account = TestAccount::wallet(Pubkey::new_unique());
+        assert_eq!(
+            claim.run().unwrap_err(),
+            ErrorCode::AccountNotInitialized.into()
+        );
+    }
+
+    #[test]
+    fn test_stored_entitlement_bump_verifies_claim() {
+        // `bump = winner_entitlement.bump` accepts the stored canonical bump
+        let mut accounts = WeeklyClaim::new().accounts();
+        let infos = account_infos(&mut accounts);
+
+        assert!(ClaimWeekly::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut ClaimWeeklyBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_wrong_stored_bump_rejected() {
+        for offset in [1, 2, u8::MAX] {
+            let mut claim = WeeklyClaim::new();
+            claim.entitlement.bump = claim.entitlement.bump.wrapping_add(offset);
+            assert_eq!(claim.run().unwrap_err(), ErrorCode::ConstraintSeeds.into());
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Close a claimed winner entitlement and return its rent to the authority
+///
+/// The claim itself stays on record through the `PrizeClaimed` event emitted
+/// at claim time and the period's `claimed_count`/`closed_winners`.
+///
+/// # Arguments
+/// * `ctx` - The context containing the entitlement, its period state and the authority
+///
+/// # Validation
+/// - Signer must be the entitlement's winner or the config authority
+/// - The entitlement must already be claimed (`EntitlementNotClaimed`)
+/// - The winner must be in the period's finalized winners list
+///
+/// # Notes
+/// - The rent goes to the authority, who paid for the entitlement
+/// - The period remembers the closed winner, so the entitlement can't be
+///   created (and claimed) a second time
+pub fn close_claimed_entitlement(ctx: Context<CloseClaimedEntitlement>) -> Result<()> {
+    let entitlement = &ctx.accounts.winner_entitlement;
+    let signer = ctx.accounts.signer.key();
+
+    // ========== VALIDATION ==========
+    require!(
+        signer == entitlement.player || signer == ctx.accounts.global_config.authority,
+        VobleError::Unauthorized
+    );
+
+    // ========== RECORD ==========
+    let period_state = &mut ctx.accounts.period_state;
+    record_closed_entitlement(period_state, entitlement)?;
+
+    msg!(
+        "🧾 Closed claimed {} entitlement for {} (rank #{})",
+        entitlement.period_type,
+        entitlement.period_id,
+        entitlement.rank
+    );
+    msg!("   Claims closed this period: {}", period_state.claimed_count);
+
+    emit!(EntitlementClosed {
+        player: entitlement.player,
+        period_type: entitlement.period_type.clone(),
+        period_id: entitlement.period_id.clone(),
+        rank: entitlement.rank,
+        amount: entitlement.amount,
+        closed_by: signer,
+        claimed_count: period_state.claimed_count,
+    });
+
+    Ok(())
+}
+
+/// Note a claimed entitlement's closure on its period
+///
+/// # Validation
+/// - The entitlement must be claimed (`EntitlementNotClaimed`)
+/// - Its player must be one of the period's winners (`Unauthorized`)
+/// - The winner's entitlement must not have been closed already (`EntitlementClosed`)
+pub fn record_closed_entitlement(
+    period_state: &mut PeriodState,
+    entitlement: &WinnerEntitlement,
+) -> Result<()> {
+    require!(entitlement.claimed, VobleError::EntitlementNotClaimed);
+
+    let bit = winner_bit(period_state, &entitlement.player).ok_or(VobleError::Unauthorized)?;
+    require!(period_state.closed_winners & bit == 0, VobleError::EntitlementClosed);
+
+    period_state.closed_winners |= bit;
+    period_state.claimed_count = period_state.claimed_count.saturating_add(1);
+    Ok(())
+}
+
+/// Reject re-creating an entitlement that was claimed and closed
+pub fn require_entitlement_not_closed(period_state: &PeriodState, player: &Pubkey) -> Result<()> {
+    let closed = winner_bit(period_state, player)
+        .is_some_and(|bit| period_state.closed_winners & bit != 0);
+    require!(!closed, VobleError::EntitlementClosed);
+    Ok(())
+}
+
+/// Bit of `closed_winners` belonging to a winner
+fn winner_bit(period_state: &PeriodState, player: &Pubkey) -> Option<u8> {
+    let index = period_state.winners.iter().position(|winner| winner == player)?;
+    Some(1 << index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::PROGRAM_VERSION;
+
+    fn period_state(winners: Vec<Pubkey>) -> PeriodState {
+        PeriodState {
+            period_type: "daily".to_string(),
+            period_id: "D42".to_string(),
+            finalized: true,
+            total_participants: 3,
+            vault_balance_at_finalization: 1_000_000,
+            winners,
+            bump: 255,
+            revealed_word_index: None,
+            remainder: 0,
+            remainder_policy: RemainderPolicy::ToFirstPlace,
+            program_version: PROGRAM_VERSION,
+            claimed_count: 0,
+            closed_winners: 0,
+        }
+    }
+
+    fn entitlement(player: Pubkey, claimed: bool) -> WinnerEntitlement {
+        WinnerEntitlement {
+            player,
+            period_type: "daily".to_string(),
+            period_id: "D42".to_string(),
+            rank: 2,
+            amount: 300_000,
+            claimed,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_close_before_claim_rejected() {
+        let winner = Pubkey::new_unique();
+        let mut state = period_state(vec![Pubkey::new_unique(), winner]);
+
+        assert_eq!(
+            record_closed_entitlement(&mut state, &entitlement(winner, false)).unwrap_err(),
+            VobleError::EntitlementNotClaimed.into()
+        );
+        assert_eq!(state.claimed_count, 0);
+        assert_eq!(state.closed_winners, 0);
+    }
+
+    #[test]
+    fn test_close_after_claim_recorded() {
+        let winner = Pubkey::new_unique();
+        let mut state = period_state(vec![Pubkey::new_unique(), winner]);
+        assert!(require_entitlement_not_closed(&state, &winner).is_ok());
+
+        record_closed_entitlement(&mut state, &entitlement(winner, true)).unwrap();
+
+        assert_eq!(state.claimed_count, 1);
+        assert_eq!(state.closed_winners, 0b10);
+
+        // The entitlement can't be created again for a second payout
+        assert_eq!(
+            require_entitlement_not_closed(&state, &winner).unwrap_err(),
+            VobleError::EntitlementClosed.into()
+        );
+        assert_eq!(
+            record_closed_entitlement(&mut state, &entitlement(winner, true)).unwrap_err(),
+            VobleError::EntitlementClosed.into()
+        );
+        assert_eq!(state.claimed_count, 1);
+    }
+
+    #[test]
+    fn test_close_requires_period_winner() {
+        let mut state = period_state(vec![Pubkey::new_unique()]);
+
+        assert_eq!(
+            record_closed_entitlement(&mut state, &entitlement(Pubkey::new_unique(), true))
+                .unwrap_err(),
+            VobleError::Unauthorized.into()
+        );
+    }
+}
@@ -1,5 +1,8 @@
-use crate::{constants::*, contexts::*, errors::VobleError, state::*};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use super::close_entitlement::require_entitlement_not_closed;
 
 /// Create a winner entitlement for a specific period
 ///
@@ -14,28 +17,32 @@ use anchor_lang::prelude::*;
 /// 4. Creates WinnerEntitlement account
 /// 5. Sets entitlement data (player, period, rank, amount)
 /// 6. Marks as not claimed
+/// 7. Sends the winner a small SOL nudge if `notify_lamports` is set
 ///
 /// # Arguments
 /// * `ctx` - Context with config, period state, and entitlement account
 /// * `period_id` - Period identifier (e.g., "D123", "W45", "M12")
 /// * `rank` - Winner's rank (1 = first place, 2 = second, 3 = third)
-/// * `amount` - Prize amount in lamports
+/// * `amount` - Prize amount in the mint's base units
 ///
 /// # Validation
 /// - Period must be finalized
 /// - Rank must be 1, 2, or 3
-/// - Period ID must be valid length
+/// - Period ID must be a valid period ID (D/W/M + number, at most 20 bytes)
+///   whose prefix matches the entitlement's period type (`PeriodTypeMismatch`),
+///   checked by the account seeds before any address is derived from it
 /// - Amount must match calculated prize for that rank
 ///
 /// # Security
 /// - Only authority can create entitlements
 /// - Entitlement is a PDA (derived from player + period + type)
-/// - Cannot create duplicate entitlements (enforced by init constraint)
+/// - Cannot create duplicate entitlements (enforced by init constraint, and
+///   by the period's `closed_winners` once a claimed entitlement is closed)
 /// - Winner must be in the finalized winners list
 ///
 /// # After Creation
 /// Winner can:
-/// - Call claim_prize() to receive their lamports
+/// - Call claim_prize() to receive their tokens
 /// - Entitlement is marked as claimed
 /// - Cannot claim twice (enforced)
 ///
@@ -44,6 +51,9 @@ use anchor_lang::prelude::*;
 /// - This does NOT transfer funds - only creates the entitlement
 /// - Actual transfer happens when winner claims
 /// - Winners have unlimited time to claim (no expiry)
+/// - The `notify_lamports` nudge is paid by the authority and is best
+///   effort: it is skipped (never failing the instruction) when the
+///   authority can't afford it or the winner wouldn't end up rent-exempt
 ///
 /// # Example Flow
 /// 1. Period finalized → prize amounts calculated
@@ -57,7 +67,14 @@ pub fn create_daily_winner_entitlement(
     rank: u8,
     amount: u64,
 ) -> Result<()> {
-    create_entitlement_internal(ctx.accounts, period_id, rank, amount, "daily")
+    create_entitlement_internal(
+        ctx.accounts,
+        period_id,
+        rank,
+        amount,
+        PeriodType::Daily,
+        ctx.bumps.winner_entitlement,
+    )
 }
 
 pub fn create_weekly_winner_entitlement(
@@ -66,7 +83,14 @@ pub fn create_weekly_winner_entitlement(
     rank: u8,
     amount: u64,
 ) -> Result<()> {
-    create_entitlement_internal(ctx.accounts, period_id, rank, amount, "weekly")
+    create_entitlement_internal(
+        ctx.accounts,
+        period_id,
+        rank,
+        amount,
+        PeriodType::Weekly,
+        ctx.bumps.winner_entitlement,
+    )
 }
 
 pub fn create_monthly_winner_entitlement(
@@ -75,7 +99,14 @@ pub fn create_monthly_winner_entitlement(
     rank: u8,
     amount: u64,
 ) -> Result<()> {
-    create_entitlement_internal(ctx.accounts, period_id, rank, amount, "monthly")
+    create_entitlement_internal(
+        ctx.accounts,
+        period_id,
+        rank,
+        amount,
+        PeriodType::Monthly,
+        ctx.bumps.winner_entitlement,
+    )
 }
 
 /// Internal function to create entitlement for any period type
@@ -87,16 +118,17 @@ fn create_entitlement_internal<'info>(
     period_id: String,
     rank: u8,
     amount: u64,
-    period_type: &str,
+    period_type: PeriodType,
+    entitlement_bump: u8,
 ) -> Result<()> {
     // Get winner pubkey first (immutable borrow)
     let winner_pubkey = accounts.get_winner_key();
 
-    msg!("🎁 Creating {} winner entitlement", period_type);
+    msg!("🎁 Creating {} winner entitlement", period_type.to_str());
     msg!("   Period: {}", period_id);
     msg!("   Rank: {}", rank);
     msg!("   Winner: {}", winner_pubkey);
-    msg!("   Amount: {} lamports", amount);
+    msg!("   Amount: {} base units", amount);
 
     // ========== VALIDATION: Period ID ==========
     require!(
@@ -111,35 +143,18 @@ fn create_entitlement_internal<'info>(
         VobleError::InvalidWinnerSplits
     );
 
-    // ========== VALIDATION: Period Finalized ==========
+    // ========== VALIDATION: Period Finalized, Winner in List ==========
     // Scope the immutable borrow of period_state
-    let (finalized, total_participants, period_id_matches, is_winner) = {
+    let total_participants = {
         let period_state = accounts.get_period_state();
-        let finalized = period_state.finalized;
-        let total_participants = period_state.total_participants;
-        let period_id_matches = period_state.period_id == period_id;
-        let is_winner = period_state.winners.iter().any(|&w| w == winner_pubkey);
-        (finalized, total_participants, period_id_matches, is_winner)
+        validate_entitlement_request(period_state, &period_id, &winner_pubkey, amount)?;
+        period_state.total_participants
     };
 
-    require!(finalized, VobleError::InvalidPeriodState);
-    require!(period_id_matches, VobleError::PeriodNotFound);
-
     msg!("✅ Validation passed");
-    msg!("   Period finalized: {}", finalized);
     msg!("   Total participants: {}", total_participants);
-
-    // ========== VALIDATION: Winner in List ==========
-    // Verify winner is actually in the finalized winners list
-    require!(is_winner, VobleError::Unauthorized);
-
     msg!("✅ Winner verified in finalized winners list");
 
-    // ========== VALIDATION: Amount Matches Rank ==========
-    // Optional: Could add validation that amount matches expected prize for rank
-    // This would require storing prize amounts in PeriodState
-    require!(amount > 0, VobleError::InvalidPrizeAmount);
-
     // ========== CREATE ENTITLEMENT ==========
     // Now we can safely get mutable borrow of entitlement
     let entitlement = accounts.get_entitlement();
@@ -149,13 +164,17 @@ fn create_entitlement_internal<'info>(
     entitlement.rank = rank;
     entitlement.amount = amount;
     entitlement.claimed = false;
+    entitlement.bump = entitlement_bump;
+
+    // ========== NOTIFY WINNER ==========
+    notify_winner(&accounts, &period_id, period_type, rank)?;
 
     msg!("");
     msg!("✅ ========== ENTITLEMENT CREATED ========== ✅");
     msg!("   Winner: {}", winner_pubkey);
-    msg!("   Period: {} ({})", period_id, period_type);
+    msg!("   Period: {} ({})", period_id, period_type.to_str());
     msg!("   Rank: #{}", rank);
-    msg!("   Prize amount: {} lamports", amount);
+    msg!("   Prize amount: {} base units", amount);
     msg!("   Status: Ready to claim");
     msg!("");
     msg!("💡 Winner can now call claim_prize() to receive funds");
@@ -164,13 +183,117 @@ fn create_entitlement_internal<'info>(
     Ok(())
 }
 
+/// Send the configured "you won" SOL nudge from the authority to the winner
+///
+/// Skipped with a logged warning whenever the transfer could fail, so the
+/// entitlement is created either way.
+fn notify_winner<'info>(
+    accounts: &impl CreateEntitlementAccounts<'info>,
+    period_id: &str,
+    period_type: PeriodType,
+    rank: u8,
+) -> Result<()> {
+    let notify_lamports = accounts.get_notify_lamports();
+    if notify_lamports == 0 {
+        return Ok(());
+    }
+
+    let (authority, winner, system_program) = accounts.get_notification_accounts();
+    if authority.key() == winner.key() {
+        msg!("⚠️  Winner notification skipped (winner is the authority)");
+        return Ok(());
+    }
+
+    let rent = Rent::get()?;
+    let Some(lamports) = notification_lamports(
+        notify_lamports,
+        authority.lamports(),
+        rent.minimum_balance(authority.data_len()),
+        winner.lamports(),
+        rent.minimum_balance(winner.data_len()),
+    ) else {
+        msg!("⚠️  Winner notification skipped (balance too low for {} lamports)", notify_lamports);
+        return Ok(());
+    };
+
+    let player = winner.key();
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: authority,
+                to: winner,
+            },
+        ),
+        lamports,
+    )?;
+
+    msg!("🔔 Winner notified with {} lamports", lamports);
+
+    emit!(WinnerNotified {
+        player,
+        period_id: period_id.to_string(),
+        period_type: period_type.to_string(),
+        rank,
+        lamports,
+        memo: format!(
+            "You placed #{} in Voble {} period {}! Claim your prize.",
+            rank,
+            period_type.to_str(),
+            period_id
+        ),
+    });
+
+    Ok(())
+}
+
+/// Amount to send as a winner notification, if it can't fail
+///
+/// # Arguments
+/// * `notify_lamports` - Configured nudge (`GlobalConfig.notify_lamports`)
+/// * `payer_balance` / `payer_min_balance` - Authority lamports and the
+///   balance it must keep to stay rent-exempt
+/// * `winner_balance` / `winner_min_balance` - Winner lamports and the
+///   balance the account needs to be rent-exempt after the transfer
+///
+/// # Returns
+/// `Some(notify_lamports)` if the transfer should be made, `None` if the
+/// nudge is off or either side can't take it
+pub fn notification_lamports(
+    notify_lamports: u64,
+    payer_balance: u64,
+    payer_min_balance: u64,
+    winner_balance: u64,
+    winner_min_balance: u64,
+) -> Option<u64> {
+    if notify_lamports == 0 {
+        return None;
+    }
+
+    let payer_needs = notify_lamports.checked_add(payer_min_balance)?;
+    if payer_balance < payer_needs {
+        return None;
+    }
+
+    if winner_balance.saturating_add(notify_lamports) < winner_min_balance {
+        return None;
+    }
+
+    Some(notify_lamports)
+}
+
 /// Trait to abstract over different entitlement creation contexts
 trait CreateEntitlementAccounts<'info> {
     fn get_period_state(&self) -> &Account<'info, PeriodState>;
     fn get_entitlement(&mut self) -> &mut Account<'info, WinnerEntitlement>;
     fn get_winner_key(&self) -> Pubkey;
+    fn get_notify_lamports(&self) -> u64;
+    fn get_notification_accounts(&self) -> NotificationAccounts<'info>;
 }
 
+/// Authority (payer), winner and system program for the notification transfer
+type NotificationAccounts<'info> = (AccountInfo<'info>, AccountInfo<'info>, AccountInfo<'info>);
+
 impl<'info> CreateEntitlementAccounts<'info> for &mut CreateDailyWinnerEntitlement<'info> {
     fn get_period_state(&self) -> &Account<'info, PeriodState> {
         &self.period_state
@@ -181,6 +304,16 @@ impl<'info> CreateEntitlementAccounts<'info> for &mut CreateDailyWinnerEntitleme
     fn get_winner_key(&self) -> Pubkey {
         self.winner.key()
     }
+    fn get_notify_lamports(&self) -> u64 {
+        self.global_config.notify_lamports
+    }
+    fn get_notification_accounts(&self) -> NotificationAccounts<'info> {
+        (
+            self.authority.to_account_info(),
+            self.winner.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
 }
 
 impl<'info> CreateEntitlementAccounts<'info> for &mut CreateWeeklyWinnerEntitlement<'info> {
@@ -193,6 +326,16 @@ impl<'info> CreateEntitlementAccounts<'info> for &mut CreateWeeklyWinnerEntitlem
     fn get_winner_key(&self) -> Pubkey {
         self.winner.key()
     }
+    fn get_notify_lamports(&self) -> u64 {
+        self.global_config.notify_lamports
+    }
+    fn get_notification_accounts(&self) -> NotificationAccounts<'info> {
+        (
+            self.authority.to_account_info(),
+            self.winner.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
 }
 
 impl<'info> CreateEntitlementAccounts<'info> for &mut CreateMonthlyWinnerEntitlement<'info> {
@@ -205,4 +348,145 @@ impl<'info> CreateEntitlementAccounts<'info> for &mut CreateMonthlyWinnerEntitle
     fn get_winner_key(&self) -> Pubkey {
         self.winner.key()
     }
+    fn get_notify_lamports(&self) -> u64 {
+        self.global_config.notify_lamports
+    }
+    fn get_notification_accounts(&self) -> NotificationAccounts<'info> {
+        (
+            self.authority.to_account_info(),
+            self.winner.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+}
+
+/// Check that a finalized period owes `winner` the entitlement being created
+///
+/// # Validation
+/// - A claimed entitlement that was closed must not be paid out again
+/// - The period must be finalized by `finalize_*` (`InvalidPeriodState`)
+/// - Period ID must match the PeriodState (`PeriodNotFound`)
+/// - The winner must be in the finalized winners list (`Unauthorized`)
+/// - Amount must be positive (`InvalidPrizeAmount`); matching it to the
+///   rank would require storing prize amounts in PeriodState
+pub fn validate_entitlement_request(
+    period_state: &PeriodState,
+    period_id: &str,
+    winner: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require_entitlement_not_closed(period_state, winner)?;
+    require!(period_state.finalized, VobleError::InvalidPeriodState);
+    require!(period_state.period_id == period_id, VobleError::PeriodNotFound);
+    require!(
+        period_state.winners.iter().any(|w| w == winner),
+        VobleError::Unauthorized
+    );
+    require!(amount > 0, VobleError::InvalidPrizeAmount);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_accounts::{account_infos, ix_args, TestAccount};
+    use std::collections::BTreeSet;
+
+    /// Rent-exempt minimum of a 0-byte system account
+    const WALLET_MIN_BALANCE: u64 = 890_880;
+    const NUDGE: u64 = 1_000_000; // 0.001 SOL
+
+    #[test]
+    fn test_notification_off_by_default() {
+        assert_eq!(
+            notification_lamports(0, 10 * NUDGE, WALLET_MIN_BALANCE, 0, WALLET_MIN_BALANCE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_funded_notification_sent() {
+        // Fresh winner wallet: the nudge alone makes it rent-exempt
+        assert_eq!(
+            notification_lamports(NUDGE, 10 * NUDGE, WALLET_MIN_BALANCE, 0, WALLET_MIN_BALANCE),
+            Some(NUDGE)
+        );
+        // Authority left exactly at its rent-exempt minimum
+        assert_eq!(
+            notification_lamports(
+                NUDGE,
+                NUDGE + WALLET_MIN_BALANCE,
+                WALLET_MIN_BALANCE,
+                5 * NUDGE,
+                WALLET_MIN_BALANCE
+            ),
+            Some(NUDGE)
+        );
+    }
+
+    #[test]
+    fn test_notification_skipped_instead_of_failing() {
+        // Authority can't cover the nudge and stay rent-exempt
+        assert_eq!(
+            notification_lamports(
+                NUDGE,
+                NUDGE + WALLET_MIN_BALANCE - 1,
+                WALLET_MIN_BALANCE,
+                0,
+                WALLET_MIN_BALANCE
+            ),
+            None
+        );
+        // Nudge too small to make a fresh wallet rent-exempt
+        assert_eq!(
+            notification_lamports(1_000, 10 * NUDGE, WALLET_MIN_BALANCE, 0, WALLET_MIN_BALANCE),
+            None
+        );
+        assert_eq!(notification_lamports(u64::MAX, u64::MAX, 1, 0, 0), None);
+    }
+
+    /// Run `CreateDailyWinnerEntitlement`'s account constraints for a period ID
+    fn try_create_daily_accounts(period_id: &str) -> Result<()> {
+        let authority = Pubkey::new_unique();
+        let zeroed = [0u8; 8 + GlobalConfig::INIT_SPACE];
+        let mut config = GlobalConfig::try_deserialize_unchecked(&mut &zeroed[..]).unwrap();
+        config.authority = authority;
+        let zeroed = [0u8; 8 + PeriodState::INIT_SPACE];
+        let mut period_state = PeriodState::try_deserialize_unchecked(&mut &zeroed[..]).unwrap();
+        period_state.finalized = true;
+
+        let mut accounts = [
+            TestAccount::program_account(&config),
+            TestAccount::program_account(&period_state),
+            TestAccount::wallet(Pubkey::new_unique()),
+            TestAccount::wallet(Pubkey::new_unique()),
+            TestAccount::signer(authority),
+            TestAccount::program(system_program::ID),
+        ];
+        let infos = account_infos(&mut accounts);
+
+        CreateDailyWinnerEntitlement::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &ix_args((period_id.to_string(), 1u8)),
+            &mut CreateDailyWinnerEntitlementBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_bad_period_id_rejected_before_pda_derivation() {
+        // 41 bytes: deriving the entitlement PDA from it would panic
+        let oversized = format!("D{}", "1".repeat(40));
+
+        assert_eq!(
+            try_create_daily_accounts(&oversized).unwrap_err(),
+            VobleError::PeriodIdTooLong.into()
+        );
+        assert_eq!(
+            try_create_daily_accounts("W12").unwrap_err(),
+            VobleError::PeriodTypeMismatch.into()
+        );
+    }
 }
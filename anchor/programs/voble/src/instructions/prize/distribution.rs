@@ -1,6 +1,10 @@
 use crate::constants::*;
 use crate::errors::VobleError;
+use crate::state::{PeriodType, RemainderPolicy};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 /// Payment accounts for ticket distribution
 pub struct PaymentAccounts<'a, 'info> {
@@ -12,6 +16,18 @@ pub struct PaymentAccounts<'a, 'info> {
     pub system_program: &'a AccountInfo<'info>,
 }
 
+/// Token accounts for a USDC ticket payment
+///
+/// Vaults are ordered like the config splits:
+/// [daily, weekly, monthly, platform, lucky draw]
+pub struct TicketPaymentAccounts<'a, 'info> {
+    pub payer: &'a Signer<'info>,
+    pub payer_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub vaults: [&'a InterfaceAccount<'info, TokenAccount>; 5],
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
 /// Prize split amounts for winners
 pub struct PrizeSplit {
     pub first_place: u64,
@@ -25,7 +41,7 @@ pub struct PrizeSplit {
 /// based on the current vault balance and the configured winner split percentages.
 ///
 /// # Arguments
-/// * `vault_balance` - Total lamports in the prize vault
+/// * `vault_balance` - Prize vault balance in the mint's base units
 /// * `winner_splits` - Array of 3 percentages in basis points [1st, 2nd, 3rd]
 ///
 /// # Returns
@@ -34,7 +50,7 @@ pub struct PrizeSplit {
 /// # Important: Remainder Handling
 /// Due to integer division, there may be a small remainder after calculating
 /// all three splits. This remainder is added to the first place prize to ensure
-/// ALL lamports are distributed (no lamports left behind).
+/// ALL base units are distributed (nothing left behind).
 ///
 /// # Example
 /// ```
@@ -45,7 +61,7 @@ pub struct PrizeSplit {
 /// // First place: 500,000 + remainder
 /// // Second place: 300,000
 /// // Third place: 200,000
-/// // Total: exactly 1,000,000 (no lamports lost)
+/// // Total: exactly 1,000,000 (nothing lost)
 /// ```
 pub fn calculate_prize_splits(vault_balance: u64, winner_splits: &[u16; 3]) -> PrizeSplit {
     // Calculate base amounts using basis points
@@ -53,7 +69,7 @@ pub fn calculate_prize_splits(vault_balance: u64, winner_splits: &[u16; 3]) -> P
     let second_amount = (vault_balance * winner_splits[1] as u64) / BASIS_POINTS_TOTAL as u64;
     let third_amount = (vault_balance * winner_splits[2] as u64) / BASIS_POINTS_TOTAL as u64;
 
-    // Calculate remainder and add to first place (prevents lamport loss)
+    // Calculate remainder and add to first place (prevents token loss)
     let total_distributed = first_amount + second_amount + third_amount;
     let remainder = vault_balance.saturating_sub(total_distributed);
 
@@ -64,9 +80,151 @@ pub fn calculate_prize_splits(vault_balance: u64, winner_splits: &[u16; 3]) -> P
     }
 }
 
+/// Integer-division remainder of a prize split
+///
+/// # Returns
+/// `vault_balance` minus the three rounded-down winner amounts
+pub fn prize_remainder(vault_balance: u64, winner_splits: &[u16; 3]) -> u64 {
+    let distributed: u64 = winner_splits
+        .iter()
+        .map(|&split| (vault_balance * split as u64) / BASIS_POINTS_TOTAL as u64)
+        .sum();
+
+    vault_balance.saturating_sub(distributed)
+}
+
+/// Route a split's remainder according to the configured policy
+///
+/// # Arguments
+/// * `splits` - Splits from `calculate_prize_splits` (remainder in 1st place)
+/// * `vault_balance` - Vault balance the splits were calculated from
+/// * `winner_splits` - Winner splits the amounts were calculated with
+/// * `policy` - Where the remainder goes
+///
+/// # Returns
+/// The winners' splits and the amount held back for the lucky draw or
+/// platform (0 under `ToFirstPlace`). Together they add up to `vault_balance`.
+pub fn apply_remainder_policy(
+    splits: PrizeSplit,
+    vault_balance: u64,
+    winner_splits: &[u16; 3],
+    policy: RemainderPolicy,
+) -> (PrizeSplit, u64) {
+    match policy {
+        RemainderPolicy::ToFirstPlace => (splits, 0),
+        RemainderPolicy::ToLuckyDraw | RemainderPolicy::ToPlatform => {
+            let remainder = prize_remainder(vault_balance, winner_splits);
+            (
+                PrizeSplit {
+                    first_place: splits.first_place - remainder,
+                    ..splits
+                },
+                remainder,
+            )
+        }
+    }
+}
+
+/// Share the prizes of tied ranks equally among the tied winners
+///
+/// # Arguments
+/// * `amounts` - Prize per winner in leaderboard order
+/// * `ranks` - Rank per winner from `compute_standings` (tied winners share one)
+///
+/// # Returns
+/// Prize per winner. Each group of tied winners splits the sum of the
+/// prizes of the positions it occupies; the division remainder goes to the
+/// group's first winner so the total is unchanged.
+pub fn split_tied_prizes(amounts: &[u64], ranks: &[u8]) -> Vec<u64> {
+    let mut split = amounts.to_vec();
+    let mut start = 0;
+
+    while start < split.len() {
+        let rank = ranks.get(start);
+        let end = (start + 1..split.len())
+            .find(|&i| ranks.get(i) != rank)
+            .unwrap_or(split.len());
+
+        let tied = (end - start) as u64;
+        if tied > 1 {
+            let pool: u64 = amounts[start..end].iter().sum();
+            for amount in split[start..end].iter_mut() {
+                *amount = pool / tied;
+            }
+            split[start] += pool % tied;
+        }
+
+        start = end;
+    }
+
+    split
+}
+
+/// Pick the winner splits that apply to a period
+///
+/// Leaderboards snapshot `winner_splits` at initialization so that changing
+/// the config mid-period cannot alter payouts players entered under.
+/// Leaderboards created before the snapshot existed have an empty snapshot
+/// and fall back to the live config.
+///
+/// # Arguments
+/// * `snapshot` - Splits stored on the leaderboard at initialization
+/// * `live` - Current splits from GlobalConfig
+///
+/// # Returns
+/// The 3 winner splits to use, or `InvalidWinnerSplits` if malformed
+pub fn resolve_winner_splits(snapshot: &[u16], live: &[u16]) -> Result<[u16; 3]> {
+    let splits = if snapshot.is_empty() { live } else { snapshot };
+
+    require!(splits.len() == 3, VobleError::InvalidWinnerSplits);
+
+    Ok([splits[0], splits[1], splits[2]])
+}
+
+/// Number of winners a period pays, applying the participation threshold
+///
+/// # Arguments
+/// * `winners_count` - Number of winners on the finalized leaderboard
+/// * `total_players` - Players who took part in the period
+/// * `min_participants` - Configured threshold for the period type (0 = none)
+///
+/// # Returns
+/// `winners_count`, or 0 when the period had fewer than `min_participants`
+/// players - its prizes then roll forward like an empty period's
+pub fn eligible_winners_count(winners_count: usize, total_players: u32, min_participants: u32) -> usize {
+    if total_players < min_participants {
+        0
+    } else {
+        winners_count
+    }
+}
+
+/// Calculate the payout of a period, or `None` if nobody played
+///
+/// # Arguments
+/// * `vault_balance` - Current prize vault balance
+/// * `winner_splits` - Winner splits for the period [1st, 2nd, 3rd]
+/// * `winners_count` - Number of winners on the finalized leaderboard
+///
+/// # Returns
+/// `Some(PrizeSplit)` when there is at least one winner. For an empty period
+/// `None` is returned: nothing is allocated and the whole vault balance rolls
+/// forward to the next period of the same type.
+pub fn calculate_period_payout(
+    vault_balance: u64,
+    winner_splits: &[u16; 3],
+    winners_count: usize,
+) -> Option<PrizeSplit> {
+    if winners_count == 0 {
+        return None;
+    }
+
+    Some(calculate_prize_splits(vault_balance, winner_splits))
+}
+
 /// Validate that prize splits add up exactly to vault balance
 ///
-/// This is a critical validation to ensure no lamports are lost or created
+/// This is a critical validation to ensure no tokens are lost or created
 /// during prize distribution.
 ///
 /// # Arguments
@@ -89,7 +247,7 @@ pub fn validate_prize_splits(vault_balance: u64, splits: &PrizeSplit) -> Result<
 /// according to the configured percentages.
 ///
 /// # Arguments
-/// * `ticket_price` - Total ticket price in lamports
+/// * `ticket_price` - Total ticket price in the mint's base units
 /// * `daily_split` - Daily pool percentage in basis points
 /// * `weekly_split` - Weekly pool percentage in basis points
 /// * `monthly_split` - Monthly pool percentage in basis points
@@ -132,20 +290,86 @@ pub fn validate_ticket_distribution(
     Ok(())
 }
 
+/// Split a ticket price across the five vaults
+///
+/// # Arguments
+/// * `ticket_price` - Total ticket price in the mint's base units
+/// * `splits` - Daily, weekly, monthly, platform and lucky draw splits in basis points
+///
+/// # Returns
+/// Amount per vault, in the same order as `splits` (rounded down)
+pub fn ticket_share_amounts(ticket_price: u64, splits: [u16; 5]) -> [u64; 5] {
+    splits.map(|split| (ticket_price * split as u64) / BASIS_POINTS_TOTAL as u64)
+}
+
+/// Check that the payer can cover the whole ticket before any transfer
+///
+/// # Arguments
+/// * `payer_balance` - Balance of the payer's token account
+/// * `ticket_price` - Total ticket price
+///
+/// # Returns
+/// `Ok(())` if the balance covers the ticket, `InsufficientTicketFunds` otherwise
+pub fn validate_payer_balance(payer_balance: u64, ticket_price: u64) -> Result<()> {
+    require!(payer_balance >= ticket_price, VobleError::InsufficientTicketFunds);
+
+    Ok(())
+}
+
+/// Pay a ticket into the five vaults with `transfer_checked`
+///
+/// # Arguments
+/// * `accounts` - Payer, vaults, mint and token program
+/// * `amounts` - Amount per vault, in the same order as `accounts.vaults`
+///
+/// # Returns
+/// Total amount actually transferred
+///
+/// # Notes
+/// - Zero-amount buckets are skipped (no CPI) to save compute
+/// - Amounts are not checked against the ticket price here; callers
+///   validate the split before paying
+pub fn execute_ticket_payment(
+    accounts: &TicketPaymentAccounts,
+    amounts: [u64; 5],
+) -> Result<u64> {
+    let decimals = accounts.mint.decimals;
+    let mut transferred = 0u64;
+
+    for index in payable_buckets(&amounts) {
+        transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.payer_token_account.to_account_info(),
+                    to: accounts.vaults[index].to_account_info(),
+                    authority: accounts.payer.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                },
+            ),
+            amounts[index],
+            decimals,
+        )?;
+        transferred += amounts[index];
+    }
+
+    Ok(transferred)
+}
+
+/// Indexes of the buckets that need a transfer (non-zero amounts)
+pub fn payable_buckets(amounts: &[u64; 5]) -> Vec<usize> {
+    (0..amounts.len()).filter(|&index| amounts[index] > 0).collect()
+}
+
 /// Get the appropriate vault seed based on period type
 ///
 /// # Arguments
 /// * `period_type` - The period type ("daily", "weekly", or "monthly")
 ///
 /// # Returns
-/// The corresponding vault seed bytes
-pub fn get_vault_seed_for_period(period_type: &str) -> &'static [u8] {
-    match period_type {
-        "daily" => SEED_DAILY_PRIZE_VAULT,
-        "weekly" => SEED_WEEKLY_PRIZE_VAULT,
-        "monthly" => SEED_MONTHLY_PRIZE_VAULT,
-        _ => SEED_DAILY_PRIZE_VAULT, // Default fallback
-    }
+/// The corresponding vault seed bytes, or `InvalidPeriodType` for any other string
+pub fn get_vault_seed_for_period(period_type: &str) -> Result<&'static [u8]> {
+    Ok(PeriodType::try_from(period_type)?.vault_seed())
 }
 
 /// Get the appropriate period seed based on period type
@@ -154,32 +378,29 @@ pub fn get_vault_seed_for_period(period_type: &str) -> &'static [u8] {
 /// * `period_type` - The period type ("daily", "weekly", or "monthly")
 ///
 /// # Returns
-/// The corresponding period seed bytes
-pub fn get_period_seed_for_type(period_type: &str) -> &'static [u8] {
-    match period_type {
-        "daily" => SEED_DAILY_PERIOD,
-        "weekly" => SEED_WEEKLY_PERIOD,
-        "monthly" => SEED_MONTHLY_PERIOD,
-        _ => SEED_DAILY_PERIOD, // Default fallback
-    }
+/// The corresponding period seed bytes, or `InvalidPeriodType` for any other string
+pub fn get_period_seed_for_type(period_type: &str) -> Result<&'static [u8]> {
+    Ok(PeriodType::try_from(period_type)?.period_state_seed())
 }
 
-/// Calculate the minimum vault balance needed to pay out prizes
+/// Check a token prize vault holds enough to pay out prizes
 ///
 /// # Arguments
-/// * `vault_account` - The vault account info
-/// * `prize_amount` - Amount to pay out
+/// * `vault_account` - The prize vault token account
+/// * `prize_amount` - Amount to pay out, in base units
 ///
 /// # Returns
 /// `Ok(())` if vault has sufficient balance, `Err` otherwise
-pub fn validate_vault_balance(vault_account: &AccountInfo, prize_amount: u64) -> Result<()> {
-    let vault_balance = vault_account.lamports();
-    let rent = Rent::get()?;
-    let min_balance = rent.minimum_balance(vault_account.data_len());
-
-    // Ensure vault has enough for prize + rent
+///
+/// # Notes
+/// Token balances need no reserve: rent is held in the account's lamports,
+/// which a token transfer never touches
+pub fn validate_vault_balance(
+    vault_account: &InterfaceAccount<TokenAccount>,
+    prize_amount: u64,
+) -> Result<()> {
     require!(
-        vault_balance >= prize_amount + min_balance,
+        vault_account.amount >= prize_amount,
         VobleError::InsufficientVaultBalance
     );
 
@@ -190,6 +411,13 @@ pub fn validate_vault_balance(vault_account: &AccountInfo, prize_amount: u64) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_payable_buckets_skip_zero_amounts() {
+        assert_eq!(payable_buckets(&[400, 300, 200, 100, 0]), vec![0, 1, 2, 3]);
+        assert_eq!(payable_buckets(&[0, 0, 1_000, 0, 0]), vec![2]);
+        assert!(payable_buckets(&[0; 5]).is_empty());
+    }
+
     #[test]
     fn test_calculate_prize_splits() {
         let vault_balance = 1_000_000;
@@ -219,6 +447,148 @@ mod tests {
         assert_eq!(total, vault_balance);
     }
 
+    #[test]
+    fn test_remainder_policies_conserve_vault_balance() {
+        let winner_splits = [5000, 3000, 2000];
+        let policies = [
+            RemainderPolicy::ToFirstPlace,
+            RemainderPolicy::ToLuckyDraw,
+            RemainderPolicy::ToPlatform,
+        ];
+
+        for vault_balance in [0, 1, 7, 999_999, 1_000_000, 1_234_567_891] {
+            for policy in policies {
+                let splits = calculate_prize_splits(vault_balance, &winner_splits);
+                let (splits, held_back) =
+                    apply_remainder_policy(splits, vault_balance, &winner_splits, policy);
+
+                let total = splits.first_place + splits.second_place + splits.third_place;
+                assert_eq!(total + held_back, vault_balance);
+                assert!(validate_prize_splits(vault_balance - held_back, &splits).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_remainder_routed_away_from_first_place() {
+        let winner_splits = [5000, 3000, 2000];
+
+        // 999_999 splits into 499_999 / 299_999 / 199_999 with 2 left over
+        assert_eq!(prize_remainder(999_999, &winner_splits), 2);
+
+        let splits = calculate_prize_splits(999_999, &winner_splits);
+        let (first_place, held_back) =
+            apply_remainder_policy(splits, 999_999, &winner_splits, RemainderPolicy::ToFirstPlace);
+        assert_eq!((first_place.first_place, held_back), (500_001, 0));
+
+        let splits = calculate_prize_splits(999_999, &winner_splits);
+        let (lucky_draw, held_back) =
+            apply_remainder_policy(splits, 999_999, &winner_splits, RemainderPolicy::ToLuckyDraw);
+        assert_eq!((lucky_draw.first_place, held_back), (499_999, 2));
+        assert_eq!(lucky_draw.second_place, 299_999);
+        assert_eq!(lucky_draw.third_place, 199_999);
+    }
+
+    #[test]
+    fn test_two_way_tie_for_first_splits_first_and_second() {
+        let splits = calculate_prize_splits(1_000_000, &[5000, 3000, 2000]);
+        let amounts = [splits.first_place, splits.second_place, splits.third_place];
+
+        let split = split_tied_prizes(&amounts, &[1, 1, 3]);
+
+        assert_eq!(split, vec![400_000, 400_000, 200_000]);
+        assert_eq!(split.iter().sum::<u64>(), 1_000_000);
+    }
+
+    #[test]
+    fn test_tied_split_conserves_odd_pools() {
+        // Three-way tie over 500_001 + 300_000 + 199_999
+        let split = split_tied_prizes(&[500_001, 300_000, 199_999], &[1, 1, 1]);
+        assert_eq!(split, vec![333_334, 333_333, 333_333]);
+
+        // Tie for second only
+        let split = split_tied_prizes(&[500_000, 300_001, 200_000], &[1, 2, 2]);
+        assert_eq!(split, vec![500_000, 250_001, 250_000]);
+
+        // No ties - unchanged
+        assert_eq!(
+            split_tied_prizes(&[500_000, 300_000, 200_000], &[1, 2, 3]),
+            vec![500_000, 300_000, 200_000]
+        );
+    }
+
+    #[test]
+    fn test_below_participation_threshold_rolls_over() {
+        let winner_splits = [5000, 3000, 2000];
+
+        // 2 players on a day that needs 5 - nobody is paid
+        let winners = eligible_winners_count(2, 2, 5);
+        assert_eq!(winners, 0);
+        assert!(calculate_period_payout(1_200_000, &winner_splits, winners).is_none());
+    }
+
+    #[test]
+    fn test_at_participation_threshold_pays() {
+        let winners = eligible_winners_count(3, 5, 5);
+        assert_eq!(winners, 3);
+
+        let splits = calculate_period_payout(3_000_000, &[5000, 3000, 2000], winners).unwrap();
+        assert!(validate_prize_splits(3_000_000, &splits).is_ok());
+    }
+
+    #[test]
+    fn test_above_participation_threshold_pays() {
+        assert_eq!(eligible_winners_count(3, 40, 5), 3);
+        assert_eq!(eligible_winners_count(1, 1, 0), 1); // No threshold configured
+    }
+
+    #[test]
+    fn test_empty_period_rolls_over() {
+        let winner_splits = [5000, 3000, 2000];
+
+        // Day 1: nobody played - nothing allocated, vault untouched
+        let day_one_balance = 400_000;
+        assert!(calculate_period_payout(day_one_balance, &winner_splits, 0).is_none());
+
+        // Day 2: day 1's balance is still in the vault alongside day 2's tickets
+        let day_two_balance = day_one_balance + 600_000;
+        let splits = calculate_period_payout(day_two_balance, &winner_splits, 2)
+            .expect("period with winners pays out");
+
+        assert!(validate_prize_splits(day_two_balance, &splits).is_ok());
+        assert_eq!(splits.second_place, 300_000);
+    }
+
+    #[test]
+    fn test_empty_period_with_empty_vault() {
+        // Nobody played and no tickets sold - still finalizable
+        assert!(calculate_period_payout(0, &[5000, 3000, 2000], 0).is_none());
+    }
+
+    #[test]
+    fn test_resolve_winner_splits_uses_snapshot() {
+        // Config changed to 70/20/10 after the leaderboard snapshotted 50/30/20
+        let snapshot = [5000, 3000, 2000];
+        let live = [7000, 2000, 1000];
+
+        let splits = resolve_winner_splits(&snapshot, &live).unwrap();
+        assert_eq!(splits, [5000, 3000, 2000]);
+
+        let prizes = calculate_prize_splits(1_000_000, &splits);
+        assert_eq!(prizes.first_place, 500_000);
+        assert_eq!(prizes.second_place, 300_000);
+        assert_eq!(prizes.third_place, 200_000);
+    }
+
+    #[test]
+    fn test_resolve_winner_splits_legacy_fallback() {
+        // Leaderboards created before snapshots fall back to the live config
+        let splits = resolve_winner_splits(&[], &[7000, 2000, 1000]).unwrap();
+        assert_eq!(splits, [7000, 2000, 1000]);
+
+        assert!(resolve_winner_splits(&[5000, 5000], &[7000, 2000, 1000]).is_err());
+    }
+
     #[test]
     fn test_validate_prize_splits() {
         let vault_balance = 1_000_000;
@@ -251,15 +621,49 @@ mod tests {
         assert!(validate_ticket_distribution(ticket_price, amounts).is_ok());
     }
 
+    #[test]
+    fn test_validate_payer_balance() {
+        let ticket_price = 1_000_000;
+
+        assert!(validate_payer_balance(ticket_price, ticket_price).is_ok());
+        assert!(validate_payer_balance(ticket_price + 1, ticket_price).is_ok());
+        assert_eq!(
+            validate_payer_balance(ticket_price - 1, ticket_price).unwrap_err(),
+            VobleError::InsufficientTicketFunds.into()
+        );
+        assert_eq!(
+            validate_payer_balance(0, ticket_price).unwrap_err(),
+            VobleError::InsufficientTicketFunds.into()
+        );
+    }
+
     #[test]
     fn test_get_vault_seed_for_period() {
-        assert_eq!(get_vault_seed_for_period("daily"), SEED_DAILY_PRIZE_VAULT);
-        assert_eq!(get_vault_seed_for_period("weekly"), SEED_WEEKLY_PRIZE_VAULT);
+        assert_eq!(get_vault_seed_for_period("daily").unwrap(), SEED_DAILY_PRIZE_VAULT);
+        assert_eq!(get_vault_seed_for_period("weekly").unwrap(), SEED_WEEKLY_PRIZE_VAULT);
         assert_eq!(
-            get_vault_seed_for_period("monthly"),
+            get_vault_seed_for_period("monthly").unwrap(),
             SEED_MONTHLY_PRIZE_VAULT
         );
-        assert_eq!(get_vault_seed_for_period("invalid"), SEED_DAILY_PRIZE_VAULT);
-        // Default
+    }
+
+    #[test]
+    fn test_unknown_period_types_error() {
+        // A typo must never route funds to the daily vault
+        for period_type in ["invalid", "Monthly", "monthy", "DAILY", ""] {
+            assert_eq!(
+                get_vault_seed_for_period(period_type).unwrap_err(),
+                VobleError::InvalidPeriodType.into()
+            );
+            assert_eq!(
+                get_period_seed_for_type(period_type).unwrap_err(),
+                VobleError::InvalidPeriodType.into()
+            );
+        }
+
+        assert_eq!(get_period_seed_for_type("monthly").unwrap(), SEED_MONTHLY_PERIOD);
+        for kind in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            assert_eq!(PeriodType::try_from(kind.to_str()).unwrap(), kind);
+        }
     }
 }
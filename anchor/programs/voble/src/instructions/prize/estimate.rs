@@ -0,0 +1,259 @@
+use crate::instructions::leaderboard::get_player_rank;
+use crate::state::{GlobalConfig, PeriodLeaderboard, VaultKind};
+use crate::{constants::*, contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use super::distribution;
+
+/// What a player would win if the period ended now
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrizeEstimate {
+    pub rank: Option<u8>, // 1-based rank, None if not on the board
+    pub amount: u64,      // Estimated prize (0 outside the prize places)
+}
+
+/// Estimate the caller's prize for a running period
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard, prize vault, config and caller
+/// * `period_id` - Period of the leaderboard (e.g., "D123")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+///
+/// # Returns
+/// The estimate as return data; also emitted as `PrizeEstimated`
+///
+/// # Validation
+/// - `prize_vault` must be the prize vault for `period_type`
+///
+/// # Notes
+/// - Read-only; simulate the transaction to read the result
+/// - Uses the same splits and participation threshold as `finalize_period`,
+///   applied to the vault's current balance. Prizes of earlier periods that
+///   are still unclaimed sit in the same vault and are not tracked on-chain,
+///   so the estimate can run high while such claims are outstanding
+pub fn estimate_my_prize(
+    ctx: Context<EstimateMyPrize>,
+    period_id: String,
+    period_type: u8,
+) -> Result<PrizeEstimate> {
+    estimate_and_emit(
+        &ctx.accounts.leaderboard,
+        &ctx.accounts.prize_vault,
+        &ctx.accounts.global_config,
+        ctx.accounts.player.key(),
+        period_id,
+        period_type,
+    )
+}
+
+/// Project any player's prize for a running period
+///
+/// Same estimate as `estimate_my_prize`, for a player other than the
+/// signer (e.g. a client showing "you're in line for $X" before the
+/// player connects a wallet).
+///
+/// # Arguments
+/// * `ctx` - The context containing the leaderboard, prize vault and config
+/// * `period_id` - Period of the leaderboard (e.g., "D123")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+/// * `player` - Player to project the prize for
+///
+/// # Returns
+/// The estimate as return data; also emitted as `PrizeEstimated`. A player
+/// who isn't ranked, or is ranked outside the prize places, gets 0
+///
+/// # Validation
+/// - `prize_vault` must be the prize vault for `period_type`
+pub fn get_projected_prize(
+    ctx: Context<GetProjectedPrize>,
+    period_id: String,
+    period_type: u8,
+    player: Pubkey,
+) -> Result<PrizeEstimate> {
+    estimate_and_emit(
+        &ctx.accounts.leaderboard,
+        &ctx.accounts.prize_vault,
+        &ctx.accounts.global_config,
+        player,
+        period_id,
+        period_type,
+    )
+}
+
+/// Validate the prize vault, estimate `player`'s prize and emit it
+fn estimate_and_emit(
+    leaderboard: &PeriodLeaderboard,
+    prize_vault: &InterfaceAccount<TokenAccount>,
+    config: &GlobalConfig,
+    player: Pubkey,
+    period_id: String,
+    period_type: u8,
+) -> Result<PrizeEstimate> {
+    let kind = match period_type {
+        0 => VaultKind::Daily,
+        1 => VaultKind::Weekly,
+        2 => VaultKind::Monthly,
+        _ => return Err(VobleError::InvalidPeriodState.into()),
+    };
+
+    // ========== VALIDATION ==========
+    let (expected_vault, _) = Pubkey::find_program_address(&[kind.vault_seed()], &crate::ID);
+    require_keys_eq!(prize_vault.key(), expected_vault, VobleError::Unauthorized);
+
+    // ========== ESTIMATE ==========
+    let winner_splits =
+        distribution::resolve_winner_splits(&leaderboard.winner_splits, &config.winner_splits)?;
+    let estimate = estimate_prize(
+        leaderboard,
+        player,
+        prize_vault.amount,
+        &winner_splits,
+        config.min_participants_for_prizes[period_type as usize],
+    );
+
+    msg!("🔮 Prize estimate for {} in {}", player, period_id);
+    msg!("   Rank: {:?}", estimate.rank);
+    msg!("   Estimated prize: {} base units", estimate.amount);
+
+    emit!(PrizeEstimated {
+        player,
+        period_id,
+        period_type,
+        rank: estimate.rank,
+        amount: estimate.amount,
+    });
+
+    Ok(estimate)
+}
+
+/// Prize a player would receive if the leaderboard were finalized as it stands
+///
+/// # Arguments
+/// * `leaderboard` - Current (unfinalized) leaderboard
+/// * `player` - Player to estimate for
+/// * `vault_balance` - Prize vault balance the period would pay out
+/// * `winner_splits` - Winner splits for the period [1st, 2nd, 3rd]
+/// * `min_participants` - Participation threshold for the period type
+pub fn estimate_prize(
+    leaderboard: &PeriodLeaderboard,
+    player: Pubkey,
+    vault_balance: u64,
+    winner_splits: &[u16; 3],
+    min_participants: u32,
+) -> PrizeEstimate {
+    let rank = get_player_rank(leaderboard, player);
+
+    let winners_count = distribution::eligible_winners_count(
+        leaderboard.entries.len().min(TOP_WINNERS_COUNT),
+        leaderboard.participants_count(),
+        min_participants,
+    );
+    let payout = distribution::calculate_period_payout(vault_balance, winner_splits, winners_count);
+
+    let amount = match (rank, payout) {
+        (Some(rank), Some(splits)) if (rank as usize) <= winners_count => match rank {
+            1 => splits.first_place,
+            2 => splits.second_place,
+            _ => splits.third_place,
+        },
+        _ => 0,
+    };
+
+    PrizeEstimate { rank, amount }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{LeaderEntry, PeriodType};
+
+    const SPLITS: [u16; 3] = [5000, 3000, 2000];
+
+    fn board(players: &[Pubkey]) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("D10", PeriodType::Daily);
+        board.entries = players
+            .iter()
+            .enumerate()
+            .map(|(i, player)| LeaderEntry {
+                player: *player,
+                score: 1_000 - i as u32,
+                guesses_used: 3,
+                time_ms: 30_000,
+                timestamp: 0,
+                username: format!("player{}", i),
+                equipped_flair: 0,
+                games_played: 1,
+                counted_days: 0,
+            })
+            .collect();
+        board.total_players = players.len() as u32;
+        board.winner_splits = SPLITS.to_vec();
+        board.entrants = players.len() as u32;
+        board
+    }
+
+    #[test]
+    fn test_estimate_by_rank() {
+        let players: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let leaderboard = board(&players);
+
+        let amounts: Vec<u64> = players
+            .iter()
+            .map(|p| estimate_prize(&leaderboard, *p, 1_000_000, &SPLITS, 0).amount)
+            .collect();
+
+        assert_eq!(amounts, vec![500_000, 300_000, 200_000, 0, 0]);
+        assert_eq!(
+            estimate_prize(&leaderboard, players[3], 1_000_000, &SPLITS, 0).rank,
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_estimate_not_on_board() {
+        let leaderboard = board(&[Pubkey::new_unique()]);
+
+        assert_eq!(
+            estimate_prize(&leaderboard, Pubkey::new_unique(), 1_000_000, &SPLITS, 0),
+            PrizeEstimate { rank: None, amount: 0 }
+        );
+    }
+
+    #[test]
+    fn test_estimate_empty_board() {
+        let leaderboard = board(&[]);
+
+        assert_eq!(
+            estimate_prize(&leaderboard, Pubkey::new_unique(), 1_000_000, &SPLITS, 0),
+            PrizeEstimate { rank: None, amount: 0 }
+        );
+    }
+
+    #[test]
+    fn test_estimate_leaderboard_not_full() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let leaderboard = board(&[first, second]);
+
+        // Same amounts finalize_period would allocate to the two places
+        assert_eq!(
+            estimate_prize(&leaderboard, first, 999_999, &SPLITS, 0),
+            PrizeEstimate { rank: Some(1), amount: 500_001 }
+        );
+        assert_eq!(
+            estimate_prize(&leaderboard, second, 999_999, &SPLITS, 0),
+            PrizeEstimate { rank: Some(2), amount: 299_999 }
+        );
+    }
+
+    #[test]
+    fn test_estimate_below_participation_threshold() {
+        let first = Pubkey::new_unique();
+        let leaderboard = board(&[first, Pubkey::new_unique()]);
+
+        assert_eq!(
+            estimate_prize(&leaderboard, first, 1_000_000, &SPLITS, 5),
+            PrizeEstimate { rank: Some(1), amount: 0 }
+        );
+    }
+}
@@ -2,20 +2,38 @@
 // PRIZE INSTRUCTIONS MODULE
 // ================================
 // Business logic for prize distribution and winner payouts
+//
+// Canonical end-of-period sequence (single path, no other finalize exists):
+// 1. leaderboard::finalize_leaderboard  - locks the board, determines winners
+// 2. prize::finalize_{daily,weekly,monthly} - consumes the locked board,
+//    creates PeriodState and computes prize amounts (fails with
+//    LeaderboardNotFinalized if step 1 has not run)
+// 3. prize::create_*_winner_entitlement - one per winner
+// 4. prize::claim_{daily,weekly,monthly} - winner pulls funds
+//    (or prize::claim_all for several entitlements in one transaction)
 
 pub mod claim_prize;
+pub mod close_entitlement;
 pub mod create_entitlement;
 pub mod distribution;
+pub mod estimate;
 pub mod finalize_period;
+pub mod preview_finalize;
+pub mod refund;
 
 // Re-export all public functions for easy access
 pub use claim_prize::*;
+pub use close_entitlement::*;
 pub use create_entitlement::*;
+pub use estimate::*;
 pub use finalize_period::*;
+pub use preview_finalize::*;
+pub use refund::*;
 
 // Re-export helper functions that might be needed externally
 pub use distribution::{
-    calculate_prize_splits, calculate_ticket_distribution, get_period_seed_for_type,
-    get_vault_seed_for_period, validate_prize_splits, validate_ticket_distribution,
-    validate_vault_balance, PrizeSplit,
+    apply_remainder_policy, calculate_period_payout, calculate_prize_splits,
+    calculate_ticket_distribution, get_period_seed_for_type, get_vault_seed_for_period,
+    prize_remainder, resolve_winner_splits, split_tied_prizes,
+    validate_prize_splits, validate_ticket_distribution, validate_vault_balance, PrizeSplit,
 };
@@ -0,0 +1,227 @@
+use crate::instructions::admin::prize_balance;
+use crate::instructions::leaderboard::{load_profile_usernames, refresh_usernames};
+use crate::state::{PeriodLeaderboard, PeriodState, PeriodType, VaultKind};
+use crate::utils::pda::derive_period_state_pda;
+use crate::utils::validate_period_id_for;
+use crate::{contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+use super::{plan_finalization, require_not_finalized, FinalizationPlan};
+
+/// Prize allocation `finalize_{daily,weekly,monthly}` would record right now
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FinalizePreview {
+    pub vault_balance: u64,
+    pub allocated_balance: u64,
+    pub reserved: u64, // Remainder held back in the vault by the remainder policy
+    pub total_participants: u32,
+    pub winners: Vec<Pubkey>,
+    pub winner_ranks: Vec<u8>,
+    pub winner_amounts: Vec<u64>,
+}
+
+impl From<&FinalizationPlan> for FinalizePreview {
+    fn from(plan: &FinalizationPlan) -> Self {
+        Self {
+            vault_balance: plan.vault_balance,
+            allocated_balance: plan.allocated_balance,
+            reserved: plan.remainder,
+            total_participants: plan.total_players,
+            winners: plan.winner_keys(),
+            winner_ranks: plan.winner_ranks.clone(),
+            winner_amounts: plan.winner_amounts.clone(),
+        }
+    }
+}
+
+/// Dry-run a period's finalization without writing any account
+///
+/// # Arguments
+/// * `ctx` - The context containing the config, leaderboard, prize vault, its
+///   ledger and period state address
+/// * `period_id` - Period to preview (e.g., "D123")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+///
+/// # Remaining Accounts
+/// Optional UserProfile accounts, used exactly as in finalize to refresh
+/// winner usernames (in memory only).
+///
+/// # Returns
+/// The preview as return data; also emitted as `PeriodFinalizePreview`
+///
+/// # Validation
+/// - Signer must be the config authority
+/// - Period ID prefix must match `period_type` (`PeriodTypeMismatch`)
+/// - `prize_vault` and `period_state` must be the accounts finalize would use
+/// - Period must not be finalized yet
+/// - Everything `plan_finalization` checks (pause, locked leaderboard, vault balance)
+///
+/// # Notes
+/// - Read-only; simulate the transaction to read the result
+/// - The commit-reveal word check of `finalize_daily` is not repeated
+pub fn preview_finalize(
+    ctx: Context<PreviewFinalize>,
+    period_id: String,
+    period_type: u8,
+) -> Result<FinalizePreview> {
+    let (kind, period_kind) = match period_type {
+        0 => (VaultKind::Daily, PeriodType::Daily),
+        1 => (VaultKind::Weekly, PeriodType::Weekly),
+        2 => (VaultKind::Monthly, PeriodType::Monthly),
+        _ => return Err(VobleError::InvalidPeriodState.into()),
+    };
+    validate_period_id_for(&period_id, period_kind)?;
+
+    // ========== VALIDATION ==========
+    let (expected_vault, _) = Pubkey::find_program_address(&[kind.vault_seed()], &crate::ID);
+    require_keys_eq!(
+        ctx.accounts.prize_vault.key(),
+        expected_vault,
+        VobleError::Unauthorized
+    );
+
+    let (expected_state, _) = derive_period_state_pda(&period_id, period_kind, &crate::ID);
+    require_keys_eq!(
+        ctx.accounts.period_state.key(),
+        expected_state,
+        VobleError::InvalidPeriodState
+    );
+    if ctx.accounts.period_state.owner == &crate::ID {
+        let data = ctx.accounts.period_state.try_borrow_data()?;
+        require_not_finalized(&PeriodState::try_deserialize(&mut &data[..])?)?;
+    }
+
+    // ========== PREVIEW ==========
+    let config = &ctx.accounts.global_config;
+    let mut leaderboard = PeriodLeaderboard::clone(&ctx.accounts.leaderboard);
+    let profiles = load_profile_usernames(ctx.remaining_accounts)?;
+    refresh_usernames(&mut leaderboard.entries, &profiles);
+
+    let plan = plan_finalization(
+        config,
+        &leaderboard,
+        prize_balance(&ctx.accounts.vault_ledger, ctx.accounts.prize_vault.amount),
+        config.min_participants_for(period_kind),
+    )?;
+    let preview = FinalizePreview::from(&plan);
+
+    msg!("🔍 Finalize preview for {} ({:?})", period_id, period_kind);
+    msg!("   Allocated: {} base units", preview.allocated_balance);
+    msg!("   Reserved: {} base units", preview.reserved);
+
+    emit!(PeriodFinalizePreview {
+        period_type: period_kind.to_string(),
+        period_id,
+        vault_balance: preview.vault_balance,
+        allocated_balance: preview.allocated_balance,
+        reserved: preview.reserved,
+        total_participants: preview.total_participants,
+        winners: preview.winners.clone(),
+        winner_ranks: preview.winner_ranks.clone(),
+        winner_amounts: preview.winner_amounts.clone(),
+    });
+
+    Ok(preview)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::prize::record_finalization;
+    use crate::state::{GlobalConfig, LeaderEntry, RemainderPolicy};
+
+    const VAULT_BALANCE: u64 = 1_000_003;
+
+    fn config(remainder_policy: RemainderPolicy, split_tied_prizes: bool) -> GlobalConfig {
+        // Every field zeroed, then the ones finalization reads
+        let data = vec![0u8; 8 + GlobalConfig::INIT_SPACE];
+        let mut config = GlobalConfig::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        config.winner_splits = vec![5_000, 3_000, 2_000];
+        config.remainder_policy = remainder_policy;
+        config.split_tied_prizes = split_tied_prizes;
+        config
+    }
+
+    fn leaderboard(scores: &[u32]) -> PeriodLeaderboard {
+        let mut board = PeriodLeaderboard::default_for_test("D42", PeriodType::Daily);
+        board.entries = scores
+            .iter()
+            .map(|&score| LeaderEntry {
+                player: Pubkey::new_unique(),
+                score,
+                guesses_used: 3,
+                time_ms: 30_000,
+                timestamp: 1,
+                username: "Test".to_string(),
+                equipped_flair: 0,
+                games_played: 1,
+                counted_days: 0,
+            })
+            .collect();
+        board.total_players = scores.len() as u32;
+        board.finalized = true;
+        board.finalized_at = Some(1);
+        board
+    }
+
+    fn empty_period_state() -> PeriodState {
+        let data = [0u8; 8 + PeriodState::INIT_SPACE];
+        PeriodState::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_preview_matches_finalization() {
+        let board = leaderboard(&[900, 900, 700, 500]);
+
+        for (policy, split_tied_prizes) in [
+            (RemainderPolicy::ToFirstPlace, false),
+            (RemainderPolicy::ToFirstPlace, true),
+            (RemainderPolicy::ToLuckyDraw, false),
+        ] {
+            let config = config(policy, split_tied_prizes);
+            let preview =
+                FinalizePreview::from(&plan_finalization(&config, &board, VAULT_BALANCE, 0).unwrap());
+
+            // The real finalize, run against the same accounts afterwards
+            let plan = plan_finalization(&config, &board, VAULT_BALANCE, 0).unwrap();
+            let mut state = empty_period_state();
+            record_finalization(&mut state, &plan, "D42", PeriodType::Daily, 255);
+
+            assert_eq!(preview.winners, state.winners);
+            assert_eq!(preview.allocated_balance, state.vault_balance_at_finalization);
+            assert_eq!(preview.reserved, state.remainder);
+            assert_eq!(preview.total_participants, state.total_participants);
+            assert_eq!(preview.winner_amounts, plan.winner_amounts);
+            assert_eq!(preview.winner_ranks, plan.winner_ranks);
+            assert_eq!(
+                preview.winner_amounts.iter().sum::<u64>(),
+                preview.allocated_balance
+            );
+            assert_eq!(preview.allocated_balance + preview.reserved, VAULT_BALANCE);
+        }
+    }
+
+    #[test]
+    fn test_preview_of_rolled_over_period() {
+        let config = config(RemainderPolicy::ToFirstPlace, false);
+        let plan = plan_finalization(&config, &leaderboard(&[900, 700]), VAULT_BALANCE, 5).unwrap();
+
+        assert!(plan.rolled_over());
+        let preview = FinalizePreview::from(&plan);
+        assert!(preview.winners.is_empty());
+        assert_eq!(preview.allocated_balance, 0);
+        assert_eq!(preview.vault_balance, VAULT_BALANCE);
+    }
+
+    #[test]
+    fn test_preview_requires_locked_leaderboard() {
+        let config = config(RemainderPolicy::ToFirstPlace, false);
+        let mut board = leaderboard(&[900]);
+        board.finalized = false;
+
+        assert_eq!(
+            plan_finalization(&config, &board, VAULT_BALANCE, 0).unwrap_err(),
+            VobleError::LeaderboardNotFinalized.into()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::instructions::admin::{record_outflow, release_refund_liability};
+use crate::instructions::game::score_deadline::settlement_period_ids;
+use crate::state::{PeriodType, TicketReceipt};
+use crate::{contexts::*, errors::VobleError, events::*};
+use anchor_lang::prelude::*;
+
+/// Refund a ticket's share of a canceled period's prize pool
+///
+/// # Arguments
+/// * `ctx` - The context containing the canceled leaderboard, the ticket
+///   receipt, the period's prize vault and ledger, and the player
+/// * `period_id` - Canceled period (e.g., "W45")
+/// * `period_type` - 0 = daily, 1 = weekly, 2 = monthly
+/// * `ticket_period_id` - Daily period the tickets were bought in (the
+///   receipt's period; equal to `period_id` for a daily cancellation)
+///
+/// # Validation
+/// - Leaderboard must be canceled
+/// - The receipt's day must fall in the canceled period
+/// - That share must not have been refunded already and must be non-zero
+/// - `prize_vault` must be the prize vault for `period_type`, cover the
+///   refund, and `vault_ledger` must track it
+///
+/// # Notes
+/// - Each daily receipt is refunded once per canceled period type, so a
+///   canceled weekly period takes one claim per day the player bought in
+/// - Tickets bought before receipts existed have no receipt and no refund
+/// - The refund is released from the vault ledger's `refund_liability`
+pub fn claim_refund(
+    ctx: Context<ClaimRefund>,
+    period_id: String,
+    _period_type: u8,
+    ticket_period_id: String,
+) -> Result<()> {
+    let config = &ctx.accounts.global_config;
+    let leaderboard = &ctx.accounts.leaderboard;
+    let period_type = leaderboard.period_type;
+
+    msg!("↩️  Claiming refund for canceled {} {}", period_type.to_str(), period_id);
+    msg!("   Player: {}", ctx.accounts.player.key());
+    msg!("   Tickets bought in: {}", ticket_period_id);
+
+    // ========== VALIDATION ==========
+    require!(leaderboard.canceled, VobleError::PeriodNotCanceled);
+
+    let (expected_vault, _) =
+        Pubkey::find_program_address(&[period_type.vault_seed()], &crate::ID);
+    require_keys_eq!(
+        ctx.accounts.prize_vault.key(),
+        expected_vault,
+        VobleError::Unauthorized
+    );
+    require_keys_eq!(
+        ctx.accounts.vault_ledger.vault,
+        expected_vault,
+        VobleError::Unauthorized
+    );
+
+    let amount = refundable_amount(
+        &ctx.accounts.ticket_receipt,
+        &leaderboard.period_id,
+        period_type,
+        config.period_epoch(),
+    )?;
+    require!(
+        ctx.accounts.prize_vault.amount >= amount,
+        VobleError::InsufficientVaultBalance
+    );
+
+    // ========== TRANSFER REFUND ==========
+    let bump = config.prize_vault_bump(period_type);
+    let vault_seeds = &[period_type.vault_seed(), &[bump]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    // Decimals stored at vault init; configs created before that fall back to the mint
+    let decimals = if config.usdc_decimals == 0 {
+        ctx.accounts.usdc_mint.decimals
+    } else {
+        config.usdc_decimals
+    };
+
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.prize_vault.to_account_info(),
+                mint: ctx.accounts.usdc_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+
+    record_outflow(
+        &mut ctx.accounts.vault_ledger,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+    release_refund_liability(&mut ctx.accounts.vault_ledger, amount);
+    ctx.accounts.ticket_receipt.refunded[period_type as usize] = true;
+
+    msg!("✅ Refunded {} base units", amount);
+
+    emit!(RefundClaimed {
+        player: ctx.accounts.player.key(),
+        period_id,
+        period_type,
+        ticket_period_id,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Share of a receipt's tickets refundable for a canceled period
+///
+/// # Arguments
+/// * `receipt` - The player's receipt for one daily period
+/// * `canceled_period_id` - Canceled period of `period_type`
+/// * `period_type` - Pool being refunded
+/// * `epoch_start` - Timestamp of period 0
+///
+/// # Returns
+/// The receipt's contribution to `period_type`'s pool
+///
+/// # Validation
+/// - The receipt's day must fall in `canceled_period_id` (`InvalidPeriodState`)
+/// - The share must not be refunded yet (`AlreadyRefunded`)
+/// - The share must be non-zero (`NothingToRefund`)
+pub fn refundable_amount(
+    receipt: &TicketReceipt,
+    canceled_period_id: &str,
+    period_type: PeriodType,
+    epoch_start: i64,
+) -> Result<u64> {
+    let periods = settlement_period_ids(&receipt.period_id, epoch_start)
+        .ok_or(VobleError::InvalidPeriodState)?;
+    let index = period_type as usize;
+
+    require!(
+        periods[index] == canceled_period_id,
+        VobleError::InvalidPeriodState
+    );
+    require!(!receipt.refunded[index], VobleError::AlreadyRefunded);
+    require!(receipt.contributions[index] > 0, VobleError::NothingToRefund);
+
+    Ok(receipt.contributions[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::game::record_ticket_receipt;
+    use crate::constants::PERIOD_EPOCH_START;
+
+    const EPOCH: i64 = PERIOD_EPOCH_START;
+
+    fn receipt() -> TicketReceipt {
+        TicketReceipt {
+            player: Pubkey::default(),
+            period_id: String::new(),
+            contributions: [0; 3],
+            refunded: [false; 3],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_refund_of_canceled_day() {
+        let player = Pubkey::new_unique();
+        let mut receipt = receipt();
+
+        // Two tickets in D9 (W1, M0)
+        record_ticket_receipt(&mut receipt, player, "D9", [400, 300, 200], 254);
+        record_ticket_receipt(&mut receipt, player, "D9", [400, 300, 200], 254);
+        assert_eq!(receipt.contributions, [800, 600, 400]);
+        assert_eq!(receipt.player, player);
+
+        assert_eq!(
+            refundable_amount(&receipt, "D9", PeriodType::Daily, EPOCH).unwrap(),
+            800
+        );
+        receipt.refunded[PeriodType::Daily as usize] = true;
+        assert_eq!(
+            refundable_amount(&receipt, "D9", PeriodType::Daily, EPOCH).unwrap_err(),
+            VobleError::AlreadyRefunded.into()
+        );
+
+        // The weekly share is separate and still refundable if W1 is canceled
+        assert_eq!(
+            refundable_amount(&receipt, "W1", PeriodType::Weekly, EPOCH).unwrap(),
+            600
+        );
+    }
+
+    #[test]
+    fn test_refund_requires_ticket_in_canceled_period() {
+        let mut receipt = receipt();
+        record_ticket_receipt(&mut receipt, Pubkey::new_unique(), "D9", [400, 300, 200], 254);
+
+        for (period_id, period_type) in [
+            ("D10", PeriodType::Daily),
+            ("W2", PeriodType::Weekly),
+            ("M1", PeriodType::Monthly),
+        ] {
+            assert_eq!(
+                refundable_amount(&receipt, period_id, period_type, EPOCH).unwrap_err(),
+                VobleError::InvalidPeriodState.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_nothing_to_refund_for_empty_share() {
+        let mut receipt = receipt();
+        // Prize schedule that sent nothing to the monthly pool
+        record_ticket_receipt(&mut receipt, Pubkey::new_unique(), "D9", [500, 400, 0], 254);
+
+        assert_eq!(
+            refundable_amount(&receipt, "M0", PeriodType::Monthly, EPOCH).unwrap_err(),
+            VobleError::NothingToRefund.into()
+        );
+    }
+}
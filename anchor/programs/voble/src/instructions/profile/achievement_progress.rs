@@ -0,0 +1,39 @@
+use crate::instructions::game::locked_achievement_progress;
+use crate::{contexts::*, events::*};
+use anchor_lang::prelude::*;
+
+/// Report how far a player is from each achievement they haven't unlocked
+///
+/// Emits one `AchievementProgress` event per locked achievement so clients
+/// can draw progress bars ("2 more wins for a 3-Game Streak") without
+/// duplicating the thresholds in `check_and_unlock_achievements`.
+///
+/// # Arguments
+/// * `ctx` - The context containing the player's profile
+/// * `player` - The player to report on (anyone may ask)
+///
+/// # Notes
+/// - Read-only; simulate the transaction to read the events
+/// - Streak achievements count consecutive wins from the current streak
+pub fn get_achievement_progress(ctx: Context<GetAchievementProgress>, player: Pubkey) -> Result<()> {
+    let profile = &ctx.accounts.user_profile;
+    let progress = locked_achievement_progress(profile);
+
+    msg!("🏆 Achievement progress for {}", player);
+    msg!("   Locked: {}", progress.len());
+
+    for (achievement_id, current, target) in progress {
+        let remaining = target.saturating_sub(current);
+        msg!("   #{}: {}/{} ({} to go)", achievement_id, current, target, remaining);
+
+        emit!(AchievementProgress {
+            player,
+            achievement_id,
+            current,
+            target,
+            remaining,
+        });
+    }
+
+    Ok(())
+}
@@ -36,6 +36,8 @@ pub fn initialize_user_profile(
     username: String,
 ) -> Result<()> {
     // ========== VALIDATION ==========
+    // ASCII only: the byte length checked here is then also the visible length
+    require!(username.is_ascii(), VobleError::UsernameNotAscii);
     require!(
         username.len() <= MAX_USERNAME_LENGTH,
         VobleError::SessionIdTooLong
@@ -73,6 +75,27 @@ pub fn initialize_user_profile(
     profile.created_at = now;
     profile.last_played = now;
 
+    // Initialize game epoch tracking
+    profile.game_epoch = 0;
+    profile.last_settled_epoch = 0;
+    profile.score_multiplier_bps = 0;
+    profile.last_ticket_at = 0;
+    profile.session_signer = Pubkey::default();
+    profile.points_balance = 0;
+    profile.owned_flairs = 0;
+    profile.equipped_flair = FLAIR_NONE;
+    profile.plays_this_period = 0;
+    profile.last_achievement_sync = 0;
+    profile.word_source = crate::state::PeriodType::Daily;
+    profile.word_difficulty = DEFAULT_WORD_DIFFICULTY;
+    profile.hole_in_one_bonus = 0;
+    profile.ticket_pool_splits = [0; 3];
+    profile.last_milestone_emitted = 0;
+    profile.entrants_at_purchase = 0;
+    profile.prize_pool_at_purchase = 0;
+    profile.delegated_at = 0;
+    profile.started_epoch = 0;
+
     // ========== EMIT EVENT ==========
     emit!(UserProfileCreated {
         player: profile.player,
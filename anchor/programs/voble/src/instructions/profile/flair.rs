@@ -0,0 +1,184 @@
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Buy a cosmetic flair with accumulated score points
+///
+/// # Arguments
+/// * `ctx` - The context containing the player's profile and the flair catalog
+/// * `flair_id` - Catalog id of the flair to buy (1-64)
+///
+/// # Validation
+/// - Flair must be listed in the catalog
+/// - Player must not already own it
+/// - Supply cap (if any) must not be exhausted
+/// - Player must have enough points
+///
+/// # Notes
+/// - Points are credited 1:1 with settled score in `update_player_stats`
+/// - Buying does not equip the flair; call `equip_flair` afterwards
+pub fn purchase_flair(ctx: Context<PurchaseFlair>, flair_id: u8) -> Result<()> {
+    // Reborrow the profile once so its fields can be borrowed separately
+    let profile: &mut UserProfile = &mut ctx.accounts.user_profile;
+    let catalog = &mut ctx.accounts.flair_catalog;
+
+    // ========== FIND FLAIR ==========
+    let entry = catalog
+        .flairs
+        .iter_mut()
+        .find(|flair| flair.id == flair_id)
+        .ok_or(VobleError::InvalidFlair)?;
+
+    // ========== PURCHASE ==========
+    buy_flair(entry, &mut profile.points_balance, &mut profile.owned_flairs)?;
+
+    msg!("🎨 Flair {} purchased by {}", flair_id, profile.player);
+    msg!("   Cost: {} points", entry.cost);
+    msg!("   Points left: {}", profile.points_balance);
+
+    emit!(FlairPurchased {
+        player: profile.player,
+        flair_id,
+        cost: entry.cost,
+        points_balance: profile.points_balance,
+        minted: entry.minted,
+    });
+
+    Ok(())
+}
+
+/// Show an owned flair next to the player's leaderboard entries
+///
+/// # Arguments
+/// * `ctx` - The context containing the player's profile
+/// * `flair_id` - Owned flair to equip, or FLAIR_NONE to unequip
+///
+/// # Notes
+/// - Leaderboard entries pick up the flair the next time they are written
+pub fn equip_flair(ctx: Context<EquipFlair>, flair_id: u8) -> Result<()> {
+    let profile = &mut ctx.accounts.user_profile;
+
+    // ========== VALIDATION ==========
+    if flair_id != FLAIR_NONE {
+        require!(
+            owns_flair(profile.owned_flairs, flair_id)?,
+            VobleError::FlairNotOwned
+        );
+    }
+
+    profile.equipped_flair = flair_id;
+
+    msg!("🎨 Flair {} equipped by {}", flair_id, profile.player);
+
+    emit!(FlairEquipped {
+        player: profile.player,
+        flair_id,
+    });
+
+    Ok(())
+}
+
+/// Bitmask bit for a flair id
+///
+/// # Returns
+/// `1 << (flair_id - 1)`, or `InvalidFlair` outside 1..=MAX_FLAIRS
+pub fn flair_bit(flair_id: u8) -> Result<u64> {
+    require!(
+        flair_id != FLAIR_NONE && flair_id as usize <= MAX_FLAIRS,
+        VobleError::InvalidFlair
+    );
+    Ok(1u64 << (flair_id - 1))
+}
+
+/// Whether the owned-flair bitmask contains a flair
+pub fn owns_flair(owned_flairs: u64, flair_id: u8) -> Result<bool> {
+    Ok(owned_flairs & flair_bit(flair_id)? != 0)
+}
+
+/// Charge a player for a flair and record ownership
+///
+/// Leaves every value untouched if any check fails.
+pub fn buy_flair(entry: &mut FlairEntry, points_balance: &mut u64, owned_flairs: &mut u64) -> Result<()> {
+    let bit = flair_bit(entry.id)?;
+
+    require!(*owned_flairs & bit == 0, VobleError::FlairAlreadyOwned);
+    require!(
+        entry.supply_cap == 0 || entry.minted < entry.supply_cap,
+        VobleError::FlairSoldOut
+    );
+    require!(*points_balance >= entry.cost, VobleError::InsufficientPoints);
+
+    *points_balance -= entry.cost;
+    *owned_flairs |= bit;
+    entry.minted = entry.minted.saturating_add(1);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flair(id: u8, cost: u64, supply_cap: u32) -> FlairEntry {
+        FlairEntry {
+            id,
+            cost,
+            supply_cap,
+            minted: 0,
+        }
+    }
+
+    #[test]
+    fn test_buy_flair_deducts_points_and_sets_bit() {
+        let mut entry = flair(3, 500, 0);
+        let (mut points, mut owned) = (800, 0);
+
+        buy_flair(&mut entry, &mut points, &mut owned).unwrap();
+
+        assert_eq!(points, 300);
+        assert_eq!(owned, 0b100);
+        assert_eq!(entry.minted, 1);
+        assert!(owns_flair(owned, 3).unwrap());
+    }
+
+    #[test]
+    fn test_buy_flair_supply_cap_exhausted() {
+        let mut entry = flair(1, 100, 1);
+        let (mut first_points, mut first_owned) = (100, 0);
+        let (mut second_points, mut second_owned) = (1_000, 0);
+
+        buy_flair(&mut entry, &mut first_points, &mut first_owned).unwrap();
+        let sold_out = buy_flair(&mut entry, &mut second_points, &mut second_owned);
+
+        assert_eq!(sold_out.unwrap_err(), VobleError::FlairSoldOut.into());
+        assert_eq!(entry.minted, 1);
+        assert_eq!(second_points, 1_000);
+        assert_eq!(second_owned, 0);
+    }
+
+    #[test]
+    fn test_buy_flair_rejects_duplicates_and_short_balance() {
+        let mut entry = flair(2, 100, 0);
+        let (mut points, mut owned) = (99, 0);
+
+        assert_eq!(
+            buy_flair(&mut entry, &mut points, &mut owned).unwrap_err(),
+            VobleError::InsufficientPoints.into()
+        );
+
+        points = 500;
+        buy_flair(&mut entry, &mut points, &mut owned).unwrap();
+        assert_eq!(
+            buy_flair(&mut entry, &mut points, &mut owned).unwrap_err(),
+            VobleError::FlairAlreadyOwned.into()
+        );
+        assert_eq!(points, 400);
+    }
+
+    #[test]
+    fn test_flair_bit_bounds() {
+        assert!(flair_bit(FLAIR_NONE).is_err());
+        assert_eq!(flair_bit(1).unwrap(), 1);
+        assert_eq!(flair_bit(64).unwrap(), 1 << 63);
+        assert!(flair_bit(65).is_err());
+    }
+}
@@ -1,3 +1,11 @@
+pub mod achievement_progress;
 pub mod create_profile;
+pub mod flair;
+pub mod public_profile;
+pub mod sync_achievements;
 
+pub use achievement_progress::*;
 pub use create_profile::*;
+pub use flair::*;
+pub use public_profile::*;
+pub use sync_achievements::*;
@@ -0,0 +1,66 @@
+use crate::utils::validation::{validate_avatar_uri, validate_bio, validate_country_code};
+use crate::{contexts::*, events::*};
+use anchor_lang::prelude::*;
+
+/// Create or update the caller's public profile
+///
+/// # Arguments
+/// * `ctx` - The context containing the caller's public profile
+/// * `bio` - Free text shown on the profile page (max 160 bytes, may be empty)
+/// * `country` - ISO 3166-1 alpha-2 code, uppercase (`[0, 0]` to hide it)
+/// * `avatar_uri` - https:// or ipfs:// link to an avatar (empty for none)
+///
+/// # Validation
+/// - Bio: length, control characters (`sanitize_string`) and profanity
+/// - Country: two uppercase letters or `[0, 0]`
+/// - Avatar URI: length, scheme, no control characters or whitespace
+///
+/// # Notes
+/// - Opt-in and separate from UserProfile; the caller pays its rent
+/// - Leaderboard and stat events are unchanged, indexers join by player
+pub fn set_public_profile(
+    ctx: Context<SetPublicProfile>,
+    bio: String,
+    country: [u8; 2],
+    avatar_uri: String,
+) -> Result<()> {
+    // ========== VALIDATION ==========
+    validate_bio(&bio)?;
+    validate_country_code(&country)?;
+    validate_avatar_uri(&avatar_uri)?;
+
+    // ========== WRITE PROFILE ==========
+    let public_profile = &mut ctx.accounts.public_profile;
+    public_profile.player = ctx.accounts.player.key();
+    public_profile.bio = bio;
+    public_profile.country = country;
+    public_profile.avatar_uri = avatar_uri;
+    public_profile.updated_at = Clock::get()?.unix_timestamp;
+    public_profile.bump = ctx.bumps.public_profile;
+
+    msg!("🪪 Public profile updated for {}", public_profile.player);
+
+    emit!(PublicProfileUpdated {
+        player: public_profile.player,
+        bio: public_profile.bio.clone(),
+        country: public_profile.country,
+        avatar_uri: public_profile.avatar_uri.clone(),
+        updated_at: public_profile.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Delete the caller's public profile and refund its rent
+///
+/// # Arguments
+/// * `ctx` - The context containing the caller's public profile
+pub fn close_public_profile(ctx: Context<ClosePublicProfile>) -> Result<()> {
+    msg!("🪪 Public profile closed for {}", ctx.accounts.player.key());
+
+    emit!(PublicProfileClosed {
+        player: ctx.accounts.player.key(),
+    });
+
+    Ok(())
+}
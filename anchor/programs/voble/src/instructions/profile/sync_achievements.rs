@@ -0,0 +1,128 @@
+use crate::instructions::game::check_and_unlock_achievements;
+use crate::{constants::*, contexts::*, errors::VobleError, events::*, state::*};
+use anchor_lang::prelude::*;
+
+/// Unlock achievements the caller's current stats already qualify for
+///
+/// Achievements are normally checked when a game settles. Profiles that
+/// qualified before an achievement existed (or whose settlement skipped the
+/// check) can catch up here without playing another game.
+///
+/// # Arguments
+/// * `ctx` - The context containing the caller's own profile
+///
+/// # Validation
+/// - Profile must belong to the signer (PDA seeds)
+/// - At most one sync per ACHIEVEMENT_SYNC_INTERVAL
+///
+/// # Notes
+/// - Grows profiles created before `last_achievement_sync` existed to the current size
+/// - Emits `AchievementsSynced` with the ids unlocked by this call (possibly empty)
+pub fn sync_achievements(ctx: Context<SyncAchievements>) -> Result<()> {
+    let profile = &mut ctx.accounts.user_profile;
+    let now = Clock::get()?.unix_timestamp;
+
+    let unlocked = sync_profile_achievements(profile, now)?;
+
+    msg!("🏆 Achievements synced for {}", profile.player);
+    msg!("   Newly unlocked: {:?}", unlocked);
+
+    emit!(AchievementsSynced {
+        player: profile.player,
+        unlocked,
+        synced_at: now,
+    });
+
+    Ok(())
+}
+
+/// Run the achievement check on a profile and record the sync time
+///
+/// # Returns
+/// Ids of the achievements unlocked by this call
+pub fn sync_profile_achievements(profile: &mut UserProfile, now: i64) -> Result<Vec<u8>> {
+    require!(
+        profile.last_achievement_sync == 0
+            || now >= profile.last_achievement_sync.saturating_add(ACHIEVEMENT_SYNC_INTERVAL),
+        VobleError::AchievementSyncTooSoon
+    );
+
+    let previously_unlocked: Vec<u8> = profile
+        .achievements
+        .iter()
+        .filter(|a| a.unlocked_at.is_some())
+        .map(|a| a.id)
+        .collect();
+
+    check_and_unlock_achievements(profile, now)?;
+    profile.last_achievement_sync = now;
+
+    Ok(profile
+        .achievements
+        .iter()
+        .filter(|a| a.unlocked_at.is_some() && !previously_unlocked.contains(&a.id))
+        .map(|a| a.id)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_ONE: i64 = 1_700_000_000;
+
+    fn create_test_profile() -> UserProfile {
+        let mut profile = UserProfile::default_for_test(Pubkey::new_unique());
+        profile.username = "TestPlayer".to_string();
+        profile
+    }
+
+    #[test]
+    fn test_sync_unlocks_qualifying_achievements() {
+        let mut profile = create_test_profile();
+        profile.total_games_played = 3;
+        profile.games_won = 3;
+        profile.current_streak = 3;
+
+        let unlocked = sync_profile_achievements(&mut profile, DAY_ONE).unwrap();
+
+        assert_eq!(
+            unlocked,
+            vec![ACHIEVEMENT_FIRST_GAME, ACHIEVEMENT_FIRST_WIN, ACHIEVEMENT_STREAK_3]
+        );
+        assert_eq!(profile.last_achievement_sync, DAY_ONE);
+    }
+
+    #[test]
+    fn test_sync_is_idempotent() {
+        let mut profile = create_test_profile();
+        profile.total_games_played = 1;
+        profile.games_won = 1;
+
+        sync_profile_achievements(&mut profile, DAY_ONE).unwrap();
+        let achievements = profile.achievements.clone();
+
+        let unlocked =
+            sync_profile_achievements(&mut profile, DAY_ONE + ACHIEVEMENT_SYNC_INTERVAL).unwrap();
+
+        assert!(unlocked.is_empty());
+        assert_eq!(profile.achievements.len(), achievements.len());
+        for (before, after) in achievements.iter().zip(profile.achievements.iter()) {
+            assert_eq!(before.id, after.id);
+            assert_eq!(before.unlocked_at, after.unlocked_at);
+        }
+    }
+
+    #[test]
+    fn test_sync_limited_to_once_per_day() {
+        let mut profile = create_test_profile();
+        sync_profile_achievements(&mut profile, DAY_ONE).unwrap();
+
+        profile.total_games_played = 1;
+        let result = sync_profile_achievements(&mut profile, DAY_ONE + ACHIEVEMENT_SYNC_INTERVAL - 1);
+
+        assert_eq!(result.unwrap_err(), VobleError::AchievementSyncTooSoon.into());
+        assert!(profile.achievements.is_empty());
+        assert_eq!(profile.last_achievement_sync, DAY_ONE);
+    }
+}
@@ -17,6 +17,7 @@ pub use state::*;
 
 // Import instruction modules
 use instructions::admin;
+use instructions::duel;
 use instructions::game;
 use instructions::leaderboard;
 use instructions::prize;
@@ -34,40 +35,194 @@ pub mod voble {
     // Admin instructions
     pub fn initialize_global_config(
         ctx: Context<InitializeGlobalConfig>,
-        ticket_price: u64,
+        init: admin::ConfigInit,
+    ) -> Result<()> {
+        admin::initialize_global_config(ctx, init)
+    }
+
+    /// Extend a config created before fields were appended to the current layout
+    pub fn grow_global_config(ctx: Context<GrowGlobalConfig>) -> Result<()> {
+        admin::grow_global_config(ctx)
+    }
+
+    /// Move a v1 deployment's config to the v2 PDA and close the v1 account
+    pub fn migrate_config_v1_to_v2(
+        ctx: Context<MigrateConfigV1ToV2>,
+        period_epoch_start: Option<i64>,
+    ) -> Result<()> {
+        admin::migrate_config_v1_to_v2(ctx, period_epoch_start)
+    }
+
+    pub fn set_config(ctx: Context<SetConfig>, update: admin::ConfigUpdate) -> Result<()> {
+        admin::set_config(ctx, update)
+    }
+
+    /// Record the deployed PROGRAM_VERSION in the config after an upgrade
+    pub fn record_upgrade(ctx: Context<SetConfig>) -> Result<()> {
+        admin::record_upgrade(ctx)
+    }
+
+    /// Emit whether the signer is the config authority (for client-side admin gating)
+    pub fn is_authority(ctx: Context<IsAuthority>) -> Result<()> {
+        admin::is_authority(ctx)
+    }
+
+    /// Set the players a period type needs before it pays prizes (0 = none)
+    pub fn set_prize_threshold(
+        ctx: Context<SetConfig>,
+        period_type: u8,
+        min_participants: u32,
+    ) -> Result<()> {
+        admin::set_prize_threshold(ctx, period_type, min_participants)
+    }
+
+    /// Set the slot cool-down of initialize/reset/delegate_session (0 = default)
+    pub fn set_session_action_gap(
+        ctx: Context<SetConfig>,
+        action: u8,
+        min_slot_gap: u64,
+    ) -> Result<()> {
+        admin::set_session_action_gap(ctx, action, min_slot_gap)
+    }
+
+    /// Schedule a happy hour score multiplier window within a period
+    pub fn set_happy_hour(
+        ctx: Context<SetHappyHour>,
+        period_id: String,
+        start: i64,
+        end: i64,
+        multiplier_bps: u16,
+    ) -> Result<()> {
+        admin::set_happy_hour(ctx, period_id, start, end, multiplier_bps)
+    }
+
+    /// Commit to a daily period's word as hash(word_index || salt) before it starts
+    pub fn commit_daily_word(
+        ctx: Context<CommitDailyWord>,
+        period_id: String,
+        commitment: [u8; 32],
+        reveal_deadline: i64,
+    ) -> Result<()> {
+        admin::commit_daily_word(ctx, period_id, commitment, reveal_deadline)
+    }
+
+    /// Reveal and verify a committed daily word after its period ends
+    pub fn reveal_daily_word(
+        ctx: Context<RevealDailyWord>,
+        period_id: String,
+        word_index: u32,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        admin::reveal_daily_word(ctx, period_id, word_index, salt)
+    }
+
+    /// Cancel a daily period whose committed word missed its reveal deadline
+    pub fn cancel_unrevealed_period(
+        ctx: Context<CancelUnrevealedPeriod>,
+        period_id: String,
+    ) -> Result<()> {
+        admin::cancel_unrevealed_period(ctx, period_id)
+    }
+
+    /// List a cosmetic flair or update its price and supply cap
+    pub fn set_flair(ctx: Context<SetFlair>, flair_id: u8, cost: u64, supply_cap: u32) -> Result<()> {
+        admin::set_flair(ctx, flair_id, cost, supply_cap)
+    }
+
+    /// Tag a word with its difficulty (1-5) for difficulty-adjusted scoring
+    pub fn set_word_difficulty(
+        ctx: Context<SetWordDifficulty>,
+        word_index: u32,
+        difficulty: u8,
+    ) -> Result<()> {
+        admin::set_word_difficulty(ctx, word_index, difficulty)
+    }
+
+    /// Pick a shared daily word that doesn't repeat the recent ones
+    pub fn assign_shared_word(ctx: Context<AssignSharedWord>, period_id: String) -> Result<()> {
+        admin::assign_shared_word(ctx, period_id)
+    }
+
+    /// Pause or resume a single period without the global kill switch
+    pub fn set_period_paused(
+        ctx: Context<SetPeriodPaused>,
+        period_id: String,
+        period_type: u8,
+        paused: bool,
+    ) -> Result<()> {
+        admin::set_period_paused(ctx, period_id, period_type, paused)
+    }
+
+    /// Void a compromised period: no finalization, tickets refundable via claim_refund
+    pub fn cancel_period(
+        ctx: Context<CancelPeriod>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<()> {
+        admin::cancel_period(ctx, period_id, period_type)
+    }
+
+    /// Switch a feature flag on or off for staged rollouts
+    pub fn set_feature(ctx: Context<SetFeature>, flag: u8, enabled: bool) -> Result<()> {
+        admin::set_feature(ctx, flag, enabled)
+    }
+
+    /// Override a future period's daily/weekly/monthly ticket splits
+    pub fn create_prize_schedule_override(
+        ctx: Context<CreatePrizeScheduleOverride>,
+        period_id: String,
         prize_split_daily: u16,
         prize_split_weekly: u16,
         prize_split_monthly: u16,
-        platform_revenue_split: u16,
-        lucky_draw_split: u16,
-        winner_splits: Vec<u16>,
-        usdc_mint: Pubkey,
     ) -> Result<()> {
-        admin::initialize_global_config(
+        admin::create_prize_schedule_override(
             ctx,
-            ticket_price,
+            period_id,
             prize_split_daily,
             prize_split_weekly,
             prize_split_monthly,
-            platform_revenue_split,
-            lucky_draw_split,
-            winner_splits,
-            usdc_mint,
         )
     }
 
-    pub fn set_config(
-        ctx: Context<SetConfig>,
-        ticket_price: Option<u64>,
-        paused: Option<bool>,
+    /// Remove a period's prize split override before it starts or after it ends
+    pub fn close_prize_schedule_override(
+        ctx: Context<ClosePrizeScheduleOverride>,
+        period_id: String,
     ) -> Result<()> {
-        admin::set_config(ctx, ticket_price, paused)
+        admin::close_prize_schedule_override(ctx, period_id)
+    }
+
+    /// Ban a wallet from buying tickets
+    pub fn block_player(ctx: Context<BlockPlayer>, player: Pubkey) -> Result<()> {
+        admin::block_player(ctx, player)
+    }
+
+    /// Lift a wallet's ban
+    pub fn unblock_player(ctx: Context<UnblockPlayer>, player: Pubkey) -> Result<()> {
+        admin::unblock_player(ctx, player)
     }
 
     pub fn initialize_vaults(ctx: Context<InitializeVaults>) -> Result<()> {
         admin::initialize_vaults(ctx)
     }
 
+    /// Cache canonical PDA bumps on configs created before bump caching
+    pub fn cache_config_bumps(ctx: Context<CacheConfigBumps>) -> Result<()> {
+        admin::cache_config_bumps(ctx)
+    }
+
+    /// Log the compute units of deriving config PDAs with and without cached bumps (developer builds with `debug-logs` only)
+    #[cfg(feature = "debug-logs")]
+    pub fn bench_config_bumps(ctx: Context<BenchConfigBumps>, cached: bool) -> Result<()> {
+        admin::bench_config_bumps(ctx, cached)
+    }
+
+    /// Store canonical bumps on leaderboards, period states and entitlements
+    /// created before bumps were kept on the account
+    pub fn backfill_account_bumps(ctx: Context<BackfillAccountBumps>) -> Result<()> {
+        admin::backfill_account_bumps(ctx)
+    }
+
     pub fn withdraw_platform_revenue(
         ctx: Context<WithdrawPlatformRevenue>,
         amount: Option<u64>,
@@ -75,6 +230,48 @@ pub mod voble {
         admin::withdraw_platform_revenue(ctx, amount)
     }
 
+    /// Announce a timelocked admin action (large price change or withdrawal)
+    pub fn queue_admin_action(
+        ctx: Context<QueueAdminAction>,
+        action_kind: u8,
+        payload_hash: [u8; 32],
+        execute_after: i64,
+    ) -> Result<()> {
+        admin::queue_admin_action(ctx, action_kind, payload_hash, execute_after)
+    }
+
+    /// Withdraw a queued admin action before it executes
+    pub fn cancel_admin_action(
+        ctx: Context<CancelAdminAction>,
+        payload_hash: [u8; 32],
+    ) -> Result<()> {
+        admin::cancel_admin_action(ctx, payload_hash)
+    }
+
+    /// Set the admin timelock delay and the withdrawal threshold it applies above
+    pub fn set_admin_timelock(
+        ctx: Context<SetConfig>,
+        delay_secs: Option<i64>,
+        withdraw_threshold: Option<u64>,
+    ) -> Result<()> {
+        admin::set_admin_timelock(ctx, delay_secs, withdraw_threshold)
+    }
+
+    /// Permissionless crank sweeping platform revenue to the treasury
+    pub fn sweep_platform_revenue(ctx: Context<SweepPlatformRevenue>) -> Result<()> {
+        admin::sweep_platform_revenue(ctx)
+    }
+
+    /// Start tracking cumulative inflows/outflows for one vault
+    pub fn initialize_vault_ledger(ctx: Context<InitializeVaultLedger>, kind: u8) -> Result<()> {
+        admin::initialize_vault_ledger(ctx, kind)
+    }
+
+    /// Emit a vault's ledger totals, live balance and discrepancy
+    pub fn get_vault_report(ctx: Context<GetVaultReport>) -> Result<()> {
+        admin::get_vault_report(ctx)
+    }
+
     // Core Wordle Game Instructions
     pub fn initialize_user_profile(
         ctx: Context<InitializeUserProfile>,
@@ -83,9 +280,48 @@ pub mod voble {
         profile::initialize_user_profile(ctx, username)
     }
 
+    /// Buy a cosmetic flair with accumulated score points
+    pub fn purchase_flair(ctx: Context<PurchaseFlair>, flair_id: u8) -> Result<()> {
+        profile::purchase_flair(ctx, flair_id)
+    }
+
+    /// Equip an owned flair (FLAIR_NONE to unequip)
+    pub fn equip_flair(ctx: Context<EquipFlair>, flair_id: u8) -> Result<()> {
+        profile::equip_flair(ctx, flair_id)
+    }
+
+    /// Unlock any achievements the caller's stats already qualify for (once per day)
+    pub fn sync_achievements(ctx: Context<SyncAchievements>) -> Result<()> {
+        profile::sync_achievements(ctx)
+    }
+
+    /// Report how far a player is from each achievement they haven't unlocked
+    pub fn get_achievement_progress(
+        ctx: Context<GetAchievementProgress>,
+        player: Pubkey,
+    ) -> Result<()> {
+        profile::get_achievement_progress(ctx, player)
+    }
+
+    /// Create or update the caller's opt-in public profile (bio, country, avatar)
+    pub fn set_public_profile(
+        ctx: Context<SetPublicProfile>,
+        bio: String,
+        country: [u8; 2],
+        avatar_uri: String,
+    ) -> Result<()> {
+        profile::set_public_profile(ctx, bio, country, avatar_uri)
+    }
+
+    /// Delete the caller's public profile and refund its rent
+    pub fn close_public_profile(ctx: Context<ClosePublicProfile>) -> Result<()> {
+        profile::close_public_profile(ctx)
+    }
+
     // Prize instructions
     // Note: finalize_period_with_leaderboard removed due to Anchor limitation with runtime match in seeds
     // Use finalize_daily, finalize_weekly, finalize_monthly instead
+    // These require finalize_leaderboard to have locked the board first
 
     pub fn finalize_daily(ctx: Context<FinalizeDaily>, period_id: String) -> Result<()> {
         prize::finalize_daily(ctx, period_id)
@@ -111,6 +347,74 @@ pub mod voble {
         prize::claim_monthly(ctx)
     }
 
+    pub fn claim_daily_with_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimDaily<'info>>,
+        destinations: Vec<PrizeDestination>,
+    ) -> Result<()> {
+        prize::claim_daily_with_split(ctx, destinations)
+    }
+
+    pub fn claim_weekly_with_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimWeekly<'info>>,
+        destinations: Vec<PrizeDestination>,
+    ) -> Result<()> {
+        prize::claim_weekly_with_split(ctx, destinations)
+    }
+
+    pub fn claim_monthly_with_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimMonthly<'info>>,
+        destinations: Vec<PrizeDestination>,
+    ) -> Result<()> {
+        prize::claim_monthly_with_split(ctx, destinations)
+    }
+
+    pub fn claim_all<'info>(ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>) -> Result<()> {
+        prize::claim_all(ctx)
+    }
+
+    /// Close a claimed entitlement, returning its rent to the authority
+    pub fn close_claimed_entitlement(ctx: Context<CloseClaimedEntitlement>) -> Result<()> {
+        prize::close_claimed_entitlement(ctx)
+    }
+
+    /// Estimate the caller's prize if the period ended now (return data + event)
+    pub fn estimate_my_prize(
+        ctx: Context<EstimateMyPrize>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<prize::PrizeEstimate> {
+        prize::estimate_my_prize(ctx, period_id, period_type)
+    }
+
+    /// Refund a ticket's share of a canceled period's prize pool
+    pub fn claim_refund(
+        ctx: Context<ClaimRefund>,
+        period_id: String,
+        period_type: u8,
+        ticket_period_id: String,
+    ) -> Result<()> {
+        prize::claim_refund(ctx, period_id, period_type, ticket_period_id)
+    }
+
+    /// Project any player's prize if the period ended now (return data + event)
+    pub fn get_projected_prize(
+        ctx: Context<GetProjectedPrize>,
+        period_id: String,
+        period_type: u8,
+        player: Pubkey,
+    ) -> Result<prize::PrizeEstimate> {
+        prize::get_projected_prize(ctx, period_id, period_type, player)
+    }
+
+    /// Dry-run a period's finalization (return data + event), writing nothing
+    pub fn preview_finalize(
+        ctx: Context<PreviewFinalize>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<prize::FinalizePreview> {
+        prize::preview_finalize(ctx, period_id, period_type)
+    }
+
     pub fn create_daily_winner_entitlement(
         ctx: Context<CreateDailyWinnerEntitlement>,
         period_id: String,
@@ -138,13 +442,72 @@ pub mod voble {
         prize::create_monthly_winner_entitlement(ctx, period_id, rank, amount)
     }
 
+    // Duel instructions
+    /// Challenge a player to an escrowed wager on today's daily period
+    pub fn create_duel(
+        ctx: Context<CreateDuel>,
+        opponent: Pubkey,
+        stake_amount: u64,
+        period_id: String,
+    ) -> Result<()> {
+        duel::create_duel(ctx, opponent, stake_amount, period_id)
+    }
+
+    /// Accept a duel by escrowing the same stake
+    pub fn accept_duel(ctx: Context<AcceptDuel>) -> Result<()> {
+        duel::accept_duel(ctx)
+    }
+
+    /// Withdraw an unaccepted duel
+    pub fn cancel_duel(ctx: Context<CancelDuel>) -> Result<()> {
+        duel::cancel_duel(ctx)
+    }
+
+    /// Pay out an accepted duel once its daily board is finalized (permissionless)
+    pub fn resolve_duel(ctx: Context<ResolveDuel>) -> Result<()> {
+        duel::resolve_duel(ctx)
+    }
+
+    /// Return the stakes of an expired or voided duel (permissionless)
+    pub fn refund_duel(ctx: Context<RefundDuel>) -> Result<()> {
+        duel::refund_duel(ctx)
+    }
+
     // Leaderboard functions
     pub fn initialize_period_leaderboard(
         ctx: Context<InitializePeriodLeaderboard>,
         period_id: String,
         period_type: u8,
+        aggregation: Option<u8>,
     ) -> Result<()> {
-        leaderboard::initialize_period_leaderboard(ctx, period_id, period_type)
+        leaderboard::initialize_period_leaderboard(ctx, period_id, period_type, aggregation)
+    }
+
+    /// Create the next `count` leaderboards of a period type, skipping existing ones
+    pub fn initialize_future_leaderboards<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeFutureLeaderboards<'info>>,
+        period_type: u8,
+        count: u8,
+    ) -> Result<()> {
+        leaderboard::initialize_future_leaderboards(ctx, period_type, count)
+    }
+
+    /// Allocate the next 10 KiB of a leaderboard whose capacity doesn't fit one init
+    pub fn grow_leaderboard(
+        ctx: Context<GrowLeaderboard>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<()> {
+        leaderboard::grow_leaderboard(ctx, period_id, period_type)
+    }
+
+    /// Rewrite a leaderboard created before entries carried flair and game counts
+    pub fn migrate_leaderboard(
+        ctx: Context<MigrateLeaderboard>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<()> {
+        leaderboard::migrate_leaderboard(ctx, period_id, period_type)
     }
 
     pub fn finalize_leaderboard(
@@ -155,6 +518,65 @@ pub mod voble {
         leaderboard::finalize_leaderboard(ctx, period_id, period_type)
     }
 
+    /// Refresh the caller's own leaderboard entry with their current username
+    pub fn update_leaderboard_username(
+        ctx: Context<UpdateLeaderboardUsername>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<()> {
+        leaderboard::update_leaderboard_username(ctx, period_id, period_type)
+    }
+
+    /// Emit the player's rank on the daily, weekly and monthly leaderboards
+    pub fn get_player_ranks(
+        ctx: Context<GetPlayerRanks>,
+        player: Pubkey,
+        daily_period_id: String,
+        weekly_period_id: String,
+        monthly_period_id: String,
+    ) -> Result<()> {
+        leaderboard::get_player_ranks(
+            ctx,
+            player,
+            daily_period_id,
+            weekly_period_id,
+            monthly_period_id,
+        )
+    }
+
+    /// Emit a chunk of a finalized leaderboard's full standings for indexers
+    pub fn emit_final_standings(
+        ctx: Context<EmitFinalStandings>,
+        period_id: String,
+        period_type: u8,
+        start_index: u16,
+        count: u16,
+    ) -> Result<()> {
+        leaderboard::emit_final_standings(ctx, period_id, period_type, start_index, count)
+    }
+
+    /// Fold a finalized daily leaderboard into its weekly and monthly boards
+    pub fn roll_daily_into_weekly(
+        ctx: Context<RollDailyIntoWeekly>,
+        daily_period_id: String,
+    ) -> Result<()> {
+        leaderboard::roll_daily_into_weekly(ctx, daily_period_id)
+    }
+
+    /// Emit a checksum of a leaderboard's ordered standings for indexers
+    pub fn get_leaderboard_checksum(
+        ctx: Context<GetLeaderboardChecksum>,
+        period_id: String,
+        period_type: u8,
+    ) -> Result<()> {
+        leaderboard::get_leaderboard_checksum(ctx, period_id, period_type)
+    }
+
+    /// Emit the current period IDs and boundaries for this deployment's epoch
+    pub fn get_period_timing(ctx: Context<GetPeriodTiming>) -> Result<()> {
+        leaderboard::get_period_timing(ctx)
+    }
+
     // Voble game functions
 
     /// Initialize session account (one-time setup)
@@ -171,8 +593,8 @@ pub mod voble {
     }
 
     /// Delegate session to Ephemeral Rollup
-    pub fn delegate_session(ctx: Context<DelegateSession>) -> Result<()> {
-        game::delegate_session(ctx)
+    pub fn delegate_session(ctx: Context<DelegateSession>, commit_frequency_ms: Option<u32>) -> Result<()> {
+        game::delegate_session(ctx, commit_frequency_ms)
     }
 
     pub fn record_keystroke(ctx: Context<RecordKeystroke>, key: String) -> Result<()> {
@@ -184,19 +606,95 @@ pub mod voble {
         game::reset_session(ctx, period_id)
     }
 
-    pub fn submit_guess(ctx: Context<SubmitGuess>, period_id: String, guess: String) -> Result<()> {
+    /// Overwrite the typing buffer with a whole word, once per game (mulligan)
+    pub fn retype_current_guess(ctx: Context<RetypeCurrentGuess>, word: String) -> Result<()> {
+        game::retype_current_guess(ctx, word)
+    }
+
+    /// Submit a guess (or the typing buffer if none is passed); its letter
+    /// results come back as return data (`GuessOutcome`)
+    pub fn submit_guess(
+        ctx: Context<SubmitGuess>,
+        period_id: String,
+        guess: Option<String>,
+    ) -> Result<game::GuessOutcome> {
         game::submit_guess(ctx, period_id, guess)
     }
 
+    /// Rewrite a session created before guesses were stored as letters only
+    pub fn migrate_session(ctx: Context<MigrateSession>) -> Result<()> {
+        game::migrate_session(ctx)
+    }
+
+    /// Emit the colour grid of a finished game without revealing letters
+    pub fn get_shareable_board(ctx: Context<GetShareableBoard>) -> Result<()> {
+        game::get_shareable_board(ctx)
+    }
+
+    /// Log every field of a session (developer builds with `debug-logs` only)
+    #[cfg(feature = "debug-logs")]
+    pub fn dump_session_state(ctx: Context<DumpSessionState>) -> Result<()> {
+        game::dump_session_state(ctx)
+    }
+
+    /// Log the compute units of one leaderboard entry write (developer builds with `debug-logs` only)
+    #[cfg(feature = "debug-logs")]
+    pub fn bench_leaderboard_write(
+        ctx: Context<BenchLeaderboardWrite>,
+        aggregation: AggregationStrategy,
+        legacy: bool,
+        seed: u64,
+    ) -> Result<()> {
+        game::bench_leaderboard_write(ctx, aggregation, legacy, seed)
+    }
+
+    /// Emit the shared word of a finalized daily period for verification
+    pub fn reveal_period_word(ctx: Context<RevealPeriodWord>, period_id: String) -> Result<()> {
+        game::reveal_period_word(ctx, period_id)
+    }
+
     pub fn update_player_stats(ctx: Context<UpdatePlayerStats>) -> Result<()> {
         game::update_player_stats(ctx)
     }
 
+    pub fn update_leaderboard_stats(ctx: Context<UpdateLeaderboardStats>) -> Result<()> {
+        game::update_leaderboard_stats(ctx)
+    }
+
+    pub fn update_profile_stats(ctx: Context<UpdateProfileStats>) -> Result<()> {
+        game::update_profile_stats(ctx)
+    }
+
     /// Undelegate session from Ephemeral Rollup  
     pub fn undelegate_session(ctx: Context<UndelegateSession>) -> Result<()> {
         game::undelegate_session(ctx)
     }
 
+    /// Magic Action handler freeing a player's active session slot after undelegation
+    pub fn release_session_slot(ctx: Context<ReleaseSessionSlot>) -> Result<()> {
+        game::release_session_slot(ctx)
+    }
+
+    /// Authority-only recovery for a session stuck delegated to the ER
+    pub fn force_undelegate_session(ctx: Context<ForceUndelegateSession>) -> Result<()> {
+        admin::force_undelegate_session(ctx)
+    }
+
+    /// Permissionless recovery for a session left delegated past the staleness window
+    pub fn force_undelegate_stale_session(
+        ctx: Context<ForceUndelegateStaleSession>,
+        daily_period_id: String,
+        weekly_period_id: String,
+        monthly_period_id: String,
+    ) -> Result<()> {
+        game::force_undelegate_stale_session(ctx, daily_period_id, weekly_period_id, monthly_period_id)
+    }
+
+    /// Base-layer recovery for a session stuck on an unreachable ER
+    pub fn recover_stale_session(ctx: Context<RecoverStaleSession>) -> Result<()> {
+        game::recover_stale_session(ctx)
+    }
+
     pub fn commit_and_update_stats(
         ctx: Context<CommitAndUpdateStats>,
         daily_period_id: String,
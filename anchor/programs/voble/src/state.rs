@@ -2,6 +2,16 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    DEFAULT_ADMIN_TIMELOCK, DEFAULT_CURRENCY_DECIMALS, DEFAULT_CURRENCY_SYMBOL, DEFAULT_TIMELOCK_WITHDRAW_THRESHOLD, DEFAULT_HANDLER_COMPUTE_UNITS, DEFAULT_LEADERBOARD_CAPACITY, DEFAULT_MAX_ACTIVE_SESSIONS, DEFAULT_SCORE_GRACE_WINDOW,
+    DEFAULT_SESSION_STALE_AFTER, DEFAULT_SHARED_WORD_WINDOW, DEFAULT_WORD_DIFFICULTY,
+    FEATURE_FLAG_COUNT, MAX_SHARED_WORD_WINDOW, PERIOD_EPOCH_START, TOTAL_WORD_COUNT, WORD_LENGTH,
+};
+use crate::errors::VobleError;
+use crate::utils::math::format_token_amount;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use crate::utils::words::{evaluate_guess, get_word_by_index};
+
 // ============================================================================
 // GLOBAL CONFIGURATION
 // ============================================================================
@@ -11,7 +21,7 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct GlobalConfig {
     pub authority: Pubkey,
-    pub ticket_price: u64,
+    pub ticket_price: u64, // In the payment mint's base units (1_000_000 = 1 USDC)
     pub prize_split_daily: u16,
     pub prize_split_weekly: u16,
     pub prize_split_monthly: u16,
@@ -21,6 +31,392 @@ pub struct GlobalConfig {
     pub winner_splits: Vec<u16>,
     pub paused: bool,
     pub usdc_mint: Pubkey,
+
+    // Platform revenue auto-sweep
+    pub treasury: Pubkey,            // Treasury token account receiving swept revenue
+    pub sweep_interval_seconds: i64, // Minimum seconds between two sweeps
+    pub platform_float: u64,         // Amount always left behind in the platform vault
+    pub sweep_bounty: u64,           // Paid to the cranker out of each sweep
+
+    // Ticket purchase throttling
+    pub ticket_cooldown_secs: i64, // Minimum seconds between two purchases by one player (0 = off)
+
+    pub usdc_decimals: u8, // Validated mint decimals, stored at vault init (0 = not yet stored)
+
+    pub bumps: ConfigBumps, // Canonical PDA bumps, cached so constraints skip find_program_address
+
+    pub word_strategy: WordStrategy, // How the daily word is chosen and proven
+
+    pub max_participants_per_period: u32, // Soft cap on new entrants per period (u32::MAX = uncapped, 0 = legacy)
+
+    pub min_participants_for_prizes: [u32; 3], // Players needed before a period pays out [daily, weekly, monthly] (0 = none)
+
+    pub leaderboard_mode: LeaderboardMode, // How new weekly/monthly leaderboards are fed
+
+    pub remainder_policy: RemainderPolicy, // Where prize-split rounding dust goes at finalization
+
+    pub paused_periods: u32, // Leaderboards currently paused by set_period_paused
+
+    pub split_tied_prizes: bool, // Winners tied for a paid rank share the prizes of their positions
+
+    pub session_stale_after_secs: i64, // Session age after which anyone may undelegate it (0 = default)
+
+    pub max_active_sessions: u32, // Cap on SessionCounter.active_sessions (0 = default)
+
+    pub handler_compute_units: u32, // Compute budget per settlement call handler (0 = default)
+    pub handler_escrow_index: u8,   // Magic Actions escrow that pays for the handlers
+    pub split_stats_handlers: bool, // Settle leaderboards and profile in separate handlers
+
+    pub period_epoch_start: i64, // Timestamp of period 0 for this deployment (0 = PERIOD_EPOCH_START)
+
+    pub hole_in_one_bonus: u32, // Flat score bonus for a first-guess solve (0 = off)
+
+    pub grace_window_seconds: i64, // Time after a period ends that its games still count (0 = default)
+
+    pub notify_lamports: u64, // SOL sent by the authority to each winner on entitlement creation (0 = off)
+
+    pub program_version: u16, // PROGRAM_VERSION recorded at init or by record_upgrade (0 = before versioning)
+
+    pub streak_decay_periods: u32, // Skipped periods a win streak survives (0 = unlimited)
+
+    pub session_action_gaps: [u64; 3], // Min slots between initialize/reset/delegate_session calls (0 = default)
+
+    pub allow_mulligan: bool,    // Players may overwrite their typing buffer once per game (retype_current_guess)
+    pub shared_word_window: u8,  // Recent shared daily words a new one may not repeat (0 = default)
+    pub leaderboard_capacity: u16, // Entries new leaderboards keep (0 = default)
+    pub admin_timelock_secs: i64,  // Delay before a queued admin action may execute (0 = default)
+    pub timelock_withdraw_threshold: u64, // Platform withdrawals above this need a queued action (0 = default)
+    #[max_len(8)]
+    pub currency_symbol: String, // Ticker clients show for amounts, e.g. "USDC" (empty = default)
+    pub currency_decimals: u8,   // Decimals clients format amounts with (0 = default)
+    pub char_class: CharClass,   // Characters words and guesses are made of (legacy 0 = alphabetic)
+    pub ticket_word_source: PeriodType, // Word list tickets draw the session word from (legacy 0 = daily)
+    pub reference_ticket_price: u64, // Price the untimelocked ±10% band is measured from (0 = current price)
+    pub withdraw_window_start: i64,  // Start of the current untimelocked withdrawal window
+    pub withdrawn_in_window: u64,    // Untimelocked platform withdrawals since withdraw_window_start
+}
+
+/// Source of the word each game is played against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WordStrategy {
+    Deterministic = 0, // Hash-derived selection on the ER (select_word_for_session)
+    CommitReveal = 1,  // Every session plays the period's shared word; admin commits hash(word_index || salt) before the period, reveals after
+}
+
+// Implement Space manually for WordStrategy
+impl anchor_lang::Space for WordStrategy {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+/// How weekly and monthly leaderboards receive scores
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LeaderboardMode {
+    LivePerGame = 0, // update_player_stats writes every board on each settled game
+    DailyRollup = 1, // Only the daily board is written live; roll_daily_into_weekly folds finals in
+}
+
+impl anchor_lang::Space for LeaderboardMode {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+/// Admin change that must be queued before it executes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AdminActionKind {
+    TicketPrice = 0,       // set_config ticket price change beyond the free band
+    WithdrawRevenue = 1,   // withdraw_platform_revenue above the threshold
+    TimelockSettings = 2,  // set_admin_timelock loosening the delay or threshold
+    PrizeSchedule = 3,     // create_prize_schedule_override changing a period's pool splits
+    SweepSettings = 4,     // set_config changing the sweep treasury, platform float or bounty
+}
+
+impl anchor_lang::Space for AdminActionKind {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+/// Destination of the integer-division remainder of a period's prize split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RemainderPolicy {
+    ToFirstPlace = 0, // Added to the 1st place prize
+    ToLuckyDraw = 1,  // Held back for the lucky draw pool
+    ToPlatform = 2,   // Held back as platform revenue
+}
+
+impl anchor_lang::Space for RemainderPolicy {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+/// Characters the word lists and guesses are made of
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CharClass {
+    Alphabetic = 0,   // A-Z, guesses case insensitive
+    Numeric = 1,      // 0-9, e.g. 6-digit number puzzles
+    Alphanumeric = 2, // A-Z and 0-9
+}
+
+impl anchor_lang::Space for CharClass {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+impl CharClass {
+    /// Parse the `set_config` argument (0 = alphabetic, 1 = numeric, 2 = alphanumeric)
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Alphabetic),
+            1 => Some(Self::Numeric),
+            2 => Some(Self::Alphanumeric),
+            _ => None,
+        }
+    }
+
+    /// Whether a character belongs to the class (either case for letters)
+    pub fn allows(self, ch: char) -> bool {
+        match self {
+            Self::Alphabetic => ch.is_ascii_alphabetic(),
+            Self::Numeric => ch.is_ascii_digit(),
+            Self::Alphanumeric => ch.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+/// Canonical bumps of the global config and vault PDAs (0 = not cached yet)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq)]
+pub struct ConfigBumps {
+    pub global_config: u8,
+    pub daily_prize_vault: u8,
+    pub weekly_prize_vault: u8,
+    pub monthly_prize_vault: u8,
+    pub platform_vault: u8,
+    pub lucky_draw_vault: u8,
+}
+
+impl GlobalConfig {
+    /// Cached bump of the prize vault for a period type
+    pub fn prize_vault_bump(&self, period_type: PeriodType) -> u8 {
+        match period_type {
+            PeriodType::Daily => self.bumps.daily_prize_vault,
+            PeriodType::Weekly => self.bumps.weekly_prize_vault,
+            PeriodType::Monthly => self.bumps.monthly_prize_vault,
+        }
+    }
+
+    /// Age at which a delegated session counts as stuck (legacy configs read as the default)
+    pub fn session_stale_after(&self) -> i64 {
+        if self.session_stale_after_secs == 0 {
+            DEFAULT_SESSION_STALE_AFTER
+        } else {
+            self.session_stale_after_secs
+        }
+    }
+
+    /// Cap on sessions delegated at once (legacy configs read as the default)
+    pub fn active_session_cap(&self) -> u32 {
+        if self.max_active_sessions == 0 {
+            DEFAULT_MAX_ACTIVE_SESSIONS
+        } else {
+            self.max_active_sessions
+        }
+    }
+
+    /// Compute budget per settlement call handler (legacy configs read as the default)
+    pub fn handler_compute_budget(&self) -> u32 {
+        if self.handler_compute_units == 0 {
+            DEFAULT_HANDLER_COMPUTE_UNITS
+        } else {
+            self.handler_compute_units
+        }
+    }
+
+    /// Ticket price as a decimal amount of the payment mint, for display
+    ///
+    /// `ticket_price` is stored in base units; pass the mint's decimals
+    /// (e.g. 6 for USDC, so 1_500_000 displays as "1.5").
+    pub fn ticket_price_ui(&self, decimals: u8) -> String {
+        format_token_amount(self.ticket_price, decimals)
+    }
+
+    /// Recent shared daily words a new one may not repeat (legacy configs read as the default)
+    pub fn shared_word_window(&self) -> usize {
+        if self.shared_word_window == 0 {
+            DEFAULT_SHARED_WORD_WINDOW as usize
+        } else {
+            self.shared_word_window as usize
+        }
+    }
+
+    /// Delay before a queued admin action may execute (legacy configs read as the default)
+    pub fn admin_timelock(&self) -> i64 {
+        if self.admin_timelock_secs == 0 {
+            DEFAULT_ADMIN_TIMELOCK
+        } else {
+            self.admin_timelock_secs
+        }
+    }
+
+    /// Price the untimelocked price band is measured from (legacy configs read as the current price)
+    pub fn ticket_price_reference(&self) -> u64 {
+        if self.reference_ticket_price == 0 {
+            self.ticket_price
+        } else {
+            self.reference_ticket_price
+        }
+    }
+
+    /// Platform withdrawal above which the timelock applies (legacy configs read as the default)
+    pub fn timelock_withdraw_threshold(&self) -> u64 {
+        if self.timelock_withdraw_threshold == 0 {
+            DEFAULT_TIMELOCK_WITHDRAW_THRESHOLD
+        } else {
+            self.timelock_withdraw_threshold
+        }
+    }
+
+    /// Ticker clients show for amounts (legacy configs read as the default)
+    pub fn currency_symbol(&self) -> &str {
+        if self.currency_symbol.is_empty() {
+            DEFAULT_CURRENCY_SYMBOL
+        } else {
+            &self.currency_symbol
+        }
+    }
+
+    /// Decimals clients format amounts with (legacy configs read as the default)
+    pub fn currency_decimals(&self) -> u8 {
+        if self.currency_decimals == 0 {
+            DEFAULT_CURRENCY_DECIMALS
+        } else {
+            self.currency_decimals
+        }
+    }
+
+    /// Entries new leaderboards keep (legacy configs read as the default)
+    pub fn leaderboard_capacity(&self) -> u16 {
+        if self.leaderboard_capacity == 0 {
+            DEFAULT_LEADERBOARD_CAPACITY
+        } else {
+            self.leaderboard_capacity
+        }
+    }
+
+    /// Time after a period ends that its games still count (legacy configs read as the default)
+    pub fn score_grace_window(&self) -> i64 {
+        if self.grace_window_seconds == 0 {
+            DEFAULT_SCORE_GRACE_WINDOW
+        } else {
+            self.grace_window_seconds
+        }
+    }
+
+    /// Timestamp of period 0 (legacy configs read as PERIOD_EPOCH_START)
+    pub fn period_epoch(&self) -> i64 {
+        if self.period_epoch_start == 0 {
+            PERIOD_EPOCH_START
+        } else {
+            self.period_epoch_start
+        }
+    }
+
+    /// Players a period of this type needs before it pays prizes (0 = no threshold)
+    pub fn min_participants_for(&self, period_type: PeriodType) -> u32 {
+        self.min_participants_for_prizes[period_type as usize]
+    }
+
+    /// Whether every bump has been cached (false for configs created before caching)
+    pub fn bumps_cached(&self) -> bool {
+        let b = &self.bumps;
+        [
+            b.global_config,
+            b.daily_prize_vault,
+            b.weekly_prize_vault,
+            b.monthly_prize_vault,
+            b.platform_vault,
+            b.lucky_draw_vault,
+        ]
+        .iter()
+        .all(|&bump| bump != 0)
+    }
+}
+
+/// Bookkeeping for the platform vault (sweep schedule)
+#[account]
+#[derive(InitSpace)]
+pub struct VaultState {
+    pub last_sweep: i64,
+    pub total_swept: u64,
+    pub bump: u8,
+}
+
+/// Players holding one of the ER's active session slots
+///
+/// Kept out of GlobalConfig so taking a slot doesn't write-lock the config
+/// that nearly every instruction reads.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionCounter {
+    pub active_sessions: u32,
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    /// Account size of the current layout
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// Account size after one `grow_global_config` step from `current_len` bytes
+    ///
+    /// Configs created before fields were appended are shorter than `SPACE`;
+    /// they grow at most 10 KiB per instruction and never shrink.
+    pub fn next_space(current_len: usize) -> usize {
+        Self::SPACE
+            .min(current_len + MAX_PERMITTED_DATA_INCREASE)
+            .max(current_len)
+    }
+}
+
+/// Token vaults tracked by a VaultLedger
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum VaultKind {
+    Daily = 0,
+    Weekly = 1,
+    Monthly = 2,
+    Platform = 3,
+    LuckyDraw = 4,
+}
+
+impl anchor_lang::Space for VaultKind {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+/// Cumulative inflow/outflow counters for one vault (accounting reconciliation)
+///
+/// `opening_balance + total_in - total_out` should always equal the vault's
+/// token balance; `get_vault_report` emits the difference.
+#[account]
+#[derive(InitSpace)]
+pub struct VaultLedger {
+    pub kind: VaultKind,
+    pub vault: Pubkey,
+    pub opening_balance: u64, // Vault balance when the ledger was created
+    pub total_in: u128,       // Ticket payments
+    pub total_out: u128,      // Claims, withdrawals and sweeps
+    pub last_updated: i64,
+    pub bump: u8,
+    pub refund_liability: u64,     // Canceled periods' pools not yet refunded
+    pub current_pool: PoolPeriod,  // Latest period paid into this prize vault
+    pub previous_pool: PoolPeriod, // The period before `current_pool`
+}
+
+/// Ticket inflow of one period's pool, tracked by its prize vault's ledger
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace, Debug, PartialEq, Eq)]
+pub struct PoolPeriod {
+    #[max_len(20)]
+    pub period_id: String, // Empty until the vault sees a ticket
+    pub inflow: u64,
+    pub canceled: bool, // Inflow is owed back to players as refunds
 }
 
 // ============================================================================
@@ -61,6 +457,67 @@ pub struct UserProfile {
     // Timestamps
     pub created_at: i64,
     pub last_played: i64,
+
+    // Game epoch tracking (incremented on every ticket purchase)
+    pub game_epoch: u64,         // Epoch of the most recently purchased game
+    pub last_settled_epoch: u64, // Epoch of the last game applied to stats
+
+    // Happy hour multiplier active when the latest ticket was bought (bps, 0 = none)
+    pub score_multiplier_bps: u16,
+
+    pub last_ticket_at: i64, // Timestamp of the latest ticket purchase (cooldown check)
+
+    // Ephemeral key allowed to sign gameplay on the ER (default = wallet only)
+    pub session_signer: Pubkey,
+
+    // Cosmetic flair (points are credited 1:1 with settled score)
+    pub points_balance: u64, // Spendable score points
+    pub owned_flairs: u64,   // Bit (id - 1) set for every owned flair
+    pub equipped_flair: u8,  // Flair shown on leaderboards (FLAIR_NONE = none)
+
+    pub plays_this_period: u32, // Tickets bought in last_paid_period
+
+    pub last_achievement_sync: i64, // Timestamp of the latest sync_achievements call
+
+    pub word_source: PeriodType, // Word list for the latest ticket (copied into the session word on reset)
+
+    pub word_difficulty: u8, // Difficulty of the latest ticket's word (0 = bought before tagging)
+
+    pub session_nonce: u32,   // Generation of the player's live session PDA (see SessionAccount::nonce_seed)
+    pub forfeited_epoch: u64, // Game epoch lost to recover_stale_session; its ticket can't start a game (0 = none)
+    pub holds_session_slot: bool, // Counted in SessionCounter.active_sessions until released
+    pub hole_in_one_bonus: u32, // GlobalConfig.hole_in_one_bonus at the latest ticket purchase
+    pub ticket_pool_splits: [u16; 3], // Daily/weekly/monthly bps the latest ticket was split with (override or config)
+    pub last_milestone_emitted: u32,  // Highest STREAK_MILESTONES entry reached in the current streak (0 = none)
+    pub entrants_at_purchase: u32,    // Daily board entrants right after the latest ticket (copied into VobleGameStarted)
+    pub prize_pool_at_purchase: u64,  // Daily prize vault balance right after the latest ticket
+    pub delegated_at: i64,   // Base-layer time the live session was delegated (0 = on the base layer)
+    pub started_epoch: u64,  // Latest game epoch whose ticket reached the ER, so a game may have started (0 = none)
+}
+
+impl UserProfile {
+    /// Reset the per-period play tracking when a new period starts
+    ///
+    /// `has_played_this_period` and `plays_this_period` describe the period of
+    /// the latest ticket (`last_paid_period`). Call this before acting on them
+    /// so they never carry over from an earlier period.
+    pub fn ensure_period(&mut self, current_period: &str) {
+        if self.last_paid_period != current_period {
+            self.has_played_this_period = false;
+            self.plays_this_period = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+impl UserProfile {
+    /// Zeroed profile of `player` for unit tests
+    pub fn default_for_test(player: Pubkey) -> Self {
+        let data = vec![0u8; 8 + Self::INIT_SPACE];
+        let mut profile = Self::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        profile.player = player;
+        profile
+    }
 }
 
 /// Separate SessionAccount for active game (Priority 1 & 3: Separate account + Fixed arrays)
@@ -87,14 +544,119 @@ pub struct SessionAccount {
     pub keystrokes: Vec<KeystrokeData>,
     #[max_len(6)]
     pub current_input: String,  // Current typing buffer
+
+    // Appended fields - legacy accounts read these as zero (version 0 = Idle)
+    pub version: u8,            // Account layout version (SESSION_ACCOUNT_VERSION)
+    pub game_epoch: u64,        // Copied from UserProfile.game_epoch on reset
+    pub state: SessionState,    // Lifecycle state of the current game
+    pub score_multiplier_bps: u16, // Copied from UserProfile.score_multiplier_bps on reset
+    pub commit_frequency_ms: u32,  // ER commit frequency chosen at delegation (diagnostics)
+    pub bump: u8,                  // Canonical PDA bump (backfilled by migrate_session)
+    pub word_commitment: [u8; 32], // Daily word commitment in force at reset (zero = none)
+    pub word_difficulty: u8,       // Copied from UserProfile.word_difficulty on reset (1-5)
+    pub input_guess_index: u8,     // Guess the typing buffer belongs to (Enter or a submitted guess advances it)
+    pub rejected_keystrokes: u16,  // Keystrokes dropped by the per-guess/per-session limits
+    pub session_nonce: u32,        // UserProfile.session_nonce this session was created under (0 = original PDA)
+    pub delegated: bool,           // Set by delegate_session, cleared on the ER before undelegation
+    pub hole_in_one_bonus: u32,    // Copied from UserProfile.hole_in_one_bonus on reset
+    pub deadline_rule: ScoreDeadlineRule, // How the settlement commit met the period deadline
+    pub program_version: u16,      // PROGRAM_VERSION that reset the current game (0 = before versioning)
+    pub transcript_hash: [u8; 32], // sha256 of the guess transcript, set on completion (zero = not recorded)
+    pub last_action_slots: [u64; 3], // Slot of the latest initialize/reset/delegate_session (see utils::ratelimit)
+    pub mulligan_used: bool,       // retype_current_guess was used this game
 }
 
-/// Guess data with result (used in fixed array)
+impl SessionAccount {
+    /// Third session PDA seed for a session generation
+    ///
+    /// Generation 0 uses an empty seed, so it derives the same address as the
+    /// original `[SEED_SESSION, player]` PDA. `recover_stale_session` bumps the
+    /// generation when a session is stuck on an unreachable ER.
+    pub fn nonce_seed(session_nonce: u32) -> Vec<u8> {
+        if session_nonce == 0 {
+            Vec::new()
+        } else {
+            session_nonce.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Lifecycle state, treating pre-versioned accounts as Idle
+    pub fn current_state(&self) -> SessionState {
+        if self.version == 0 {
+            SessionState::Idle
+        } else {
+            self.state
+        }
+    }
+}
+
+#[cfg(test)]
+impl SessionAccount {
+    /// Zeroed session of `player` for unit tests (a legacy, Idle account)
+    ///
+    /// Tests set only the fields they exercise, so appending a field doesn't
+    /// mean editing every fixture.
+    pub fn default_for_test(player: Pubkey) -> Self {
+        let data = vec![0u8; 8 + Self::INIT_SPACE];
+        let mut session = Self::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        session.player = player;
+        session
+    }
+}
+
+/// Lifecycle of a game inside a SessionAccount
+///
+/// Idle → InProgress → CompletedUnsettled → Settled → InProgress → ...
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum SessionState {
+    Idle,               // No game started yet (or legacy account)
+    InProgress,         // Game running on the ER
+    CompletedUnsettled, // Game over, stats not yet pushed to the base layer
+    Settled,            // Settlement commit issued, stats applied by the handler
+    Abandoned,          // Force-closed by the authority, never settled
+}
+
+/// How a settled game's commit time compares to its period's end
+///
+/// A game always counts for the period its ticket was bought in; this only
+/// decides whether it still reaches that period's leaderboards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum ScoreDeadlineRule {
+    OnTime,      // Committed before the period ended (legacy sessions read as this)
+    GraceWindow, // Committed after the period ended, within grace_window_seconds
+    Late,        // Committed after the grace window; profile only, LateScore emitted
+}
+
+/// Guessed word (used in fixed array)
+///
+/// Only the letters are stored; the colour result is derived on read from
+/// the session's `word_index` via `GuessData::results`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct GuessData {
-    #[max_len(6)]
-    pub guess: String, // The guessed word
-    pub result: [LetterResult; 6], // Result for each letter position
+    pub guess: [u8; WORD_LENGTH], // The guessed word (uppercase ASCII)
+}
+
+impl GuessData {
+    /// Pack an uppercase guess into its stored form
+    pub fn new(guess: &str) -> Result<Self> {
+        require!(guess.is_ascii(), VobleError::InvalidGuess);
+        let guess: [u8; WORD_LENGTH] = guess
+            .as_bytes()
+            .try_into()
+            .map_err(|_| error!(VobleError::InvalidGuessLength))?;
+        Ok(Self { guess })
+    }
+
+    /// The guessed word as a string
+    pub fn word(&self) -> &str {
+        std::str::from_utf8(&self.guess).unwrap_or_default()
+    }
+
+    /// Recompute the colour result against the session's target word
+    pub fn results(&self, word_index: u32) -> Result<[LetterResult; WORD_LENGTH]> {
+        let target_word = get_word_by_index(word_index)?;
+        Ok(evaluate_guess(self.word(), target_word))
+    }
 }
 
 /// Result for a single guess
@@ -135,6 +697,25 @@ pub struct PeriodIds {
     pub month_id: String,
 }
 
+/// Happy hour score multiplier windows for a single period
+#[account]
+#[derive(InitSpace)]
+pub struct ScoreMultiplierWindow {
+    #[max_len(20)]
+    pub period_id: String,
+    #[max_len(4)]
+    pub windows: Vec<MultiplierWindow>, // Non-overlapping, at most MAX_MULTIPLIER_WINDOWS
+    pub bump: u8,
+}
+
+/// A single happy hour: tickets bought in [start, end) get the multiplier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct MultiplierWindow {
+    pub start: i64,
+    pub end: i64,
+    pub multiplier_bps: u16, // 10000 = 1x, capped at MAX_SCORE_MULTIPLIER_BPS
+}
+
 // ============================================================================
 // PRIZE & WINNER MANAGEMENT
 // ============================================================================
@@ -151,6 +732,14 @@ pub struct WinnerEntitlement {
     pub rank: u8,
     pub amount: u64,
     pub claimed: bool,
+    pub bump: u8, // Canonical PDA bump (backfilled by backfill_account_bumps)
+}
+
+/// One wallet receiving part of a prize claimed with a `claim_*_with_split`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrizeDestination {
+    pub owner: Pubkey, // Paid to this wallet's associated token account
+    pub bps: u16,      // Share of the prize in basis points
 }
 
 /// Period state tracking finalization and winners
@@ -166,6 +755,50 @@ pub struct PeriodState {
     pub vault_balance_at_finalization: u64,
     #[max_len(3)]
     pub winners: Vec<Pubkey>,
+    pub bump: u8, // Canonical PDA bump (backfilled by backfill_account_bumps)
+    pub revealed_word_index: Option<u32>, // Verified daily word (commit-reveal periods only)
+    pub remainder: u64,                   // Split remainder held back in the vault (0 under ToFirstPlace)
+    pub remainder_policy: RemainderPolicy, // Policy the remainder was routed under
+    pub program_version: u16,              // PROGRAM_VERSION that finalized the period (0 = before versioning)
+    pub claimed_count: u8,                 // Claimed entitlements closed by close_claimed_entitlement
+    pub closed_winners: u8,                // Bit i set once winners[i]'s claimed entitlement was closed
+}
+
+/// Admin commitment to a period's word, revealed once the period is over
+#[account]
+#[derive(InitSpace)]
+pub struct DailyWordCommitment {
+    #[max_len(20)]
+    pub period_id: String,
+    pub commitment: [u8; 32], // sha256(word_index_le || salt)
+    pub committed_at: i64,
+    pub reveal_deadline: i64, // Reveal must land by this timestamp
+    pub revealed: bool,
+    pub word_index: u32, // Valid once revealed
+    pub salt: [u8; 32],  // Valid once revealed
+    pub bump: u8,
+}
+
+/// Prize pool splits replacing the config's for one ticket period
+#[account]
+#[derive(InitSpace)]
+pub struct PrizeScheduleOverride {
+    #[max_len(20)]
+    pub period_id: String,
+    pub prize_split_daily: u16,
+    pub prize_split_weekly: u16,
+    pub prize_split_monthly: u16,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Blocklist entry; while it exists the player can't buy tickets
+#[account]
+#[derive(InitSpace)]
+pub struct BlockedPlayer {
+    pub player: Pubkey,
+    pub blocked_at: i64,
+    pub bump: u8,
 }
 
 // ============================================================================
@@ -201,16 +834,74 @@ impl anchor_lang::Space for PeriodType {
 }
 
 impl PeriodType {
-    pub fn to_string(&self) -> String {
+    /// Parse a u8 argument (0 = daily, 1 = weekly, 2 = monthly)
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(PeriodType::Daily),
+            1 => Some(PeriodType::Weekly),
+            2 => Some(PeriodType::Monthly),
+            _ => None,
+        }
+    }
+
+    /// Canonical string stored on accounts and used in PDA seeds
+    pub fn to_str(&self) -> &'static str {
         match self {
-            PeriodType::Daily => "daily".to_string(),
-            PeriodType::Weekly => "weekly".to_string(),
-            PeriodType::Monthly => "monthly".to_string(),
+            PeriodType::Daily => "daily",
+            PeriodType::Weekly => "weekly",
+            PeriodType::Monthly => "monthly",
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        self.to_str().to_string()
+    }
+}
+
+impl TryFrom<&str> for PeriodType {
+    type Error = anchor_lang::error::Error;
+
+    /// Parse a canonical period type string; anything else (including
+    /// "Daily") is `InvalidPeriodType` rather than a fallback
+    fn try_from(period_type: &str) -> Result<Self> {
+        match period_type {
+            "daily" => Ok(PeriodType::Daily),
+            "weekly" => Ok(PeriodType::Weekly),
+            "monthly" => Ok(PeriodType::Monthly),
+            _ => err!(VobleError::InvalidPeriodType),
+        }
+    }
+}
+
+/// How repeat games by the same player combine into one leaderboard entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AggregationStrategy {
+    Best = 0,    // Keep the single highest-scoring game
+    Sum = 1,     // Add every game's score
+    Average = 2, // Mean score across the player's games
+}
+
+impl anchor_lang::Space for AggregationStrategy {
+    const INIT_SPACE: usize = 1; // u8 repr
+}
+
+impl AggregationStrategy {
+    /// Strategy used when the authority doesn't pick one
+    /// (best game for daily boards, summed score for weekly/monthly)
+    pub fn default_for(period_type: PeriodType) -> Self {
+        match period_type {
+            PeriodType::Daily => AggregationStrategy::Best,
+            PeriodType::Weekly | PeriodType::Monthly => AggregationStrategy::Sum,
         }
     }
 }
 
 /// Single leaderboard entry
+///
+/// Entries sit back to back in `PeriodLeaderboard::entries`, so a field added
+/// here moves every entry after the first. Boards of the original layout are
+/// rewritten by `migrate_leaderboard`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct LeaderEntry {
     pub player: Pubkey,
@@ -220,6 +911,9 @@ pub struct LeaderEntry {
     pub timestamp: i64,
     #[max_len(32)]
     pub username: String,
+    pub equipped_flair: u8, // Player's flair when the entry was last written
+    pub games_played: u16,  // Games folded into this entry (Average strategy divisor)
+    pub counted_days: u64,  // daily_period_mask bits of the days folded in (0 = unknown)
 }
 
 /// Period leaderboard tracking top players
@@ -229,13 +923,287 @@ pub struct PeriodLeaderboard {
     #[max_len(20)]
     pub period_id: String,
     pub period_type: PeriodType,
-    #[max_len(100)] // Using MAX_LEADERBOARD_SIZE constant
+    #[max_len(0)] // Sized at runtime by PeriodLeaderboard::space
     pub entries: Vec<LeaderEntry>,
     pub total_players: u32,
     pub prize_pool: u64,
     pub finalized: bool,
     pub created_at: i64,
     pub finalized_at: Option<i64>,
+    #[max_len(3)]
+    pub winner_splits: Vec<u16>, // Snapshot of GlobalConfig.winner_splits at initialization
+    pub bump: u8,                // Canonical PDA bump (backfilled by backfill_account_bumps)
+    pub aggregation: AggregationStrategy, // How repeat games combine into one entry
+    pub emitted_up_to: u16,               // Entries exported by emit_final_standings so far
+    pub entrants: u32,                    // Distinct players who bought a ticket (PlayerParticipation markers)
+    pub mode: LeaderboardMode,            // Snapshot of GlobalConfig.leaderboard_mode (daily boards are always live)
+    pub rolled_up: bool,                  // Daily boards: final standings folded into weekly/monthly
+    pub paused: bool,                     // Frozen by set_period_paused (daily boards stop new games)
+    pub capacity: u16,                    // Snapshot of GlobalConfig.leaderboard_capacity (0 = legacy, default)
+    pub canceled: bool,                   // Voided by cancel_period: never finalized, tickets refundable
+    pub participants: u32,                // Distinct players who settled a game here, solved or not
+    pub rolled_up_days: u8,               // Rollup-mode boards: daily boards folded in so far
+}
+
+impl PeriodLeaderboard {
+    /// Entries the board keeps (legacy boards read as the default)
+    pub fn capacity(&self) -> usize {
+        if self.capacity == 0 {
+            DEFAULT_LEADERBOARD_CAPACITY as usize
+        } else {
+            self.capacity as usize
+        }
+    }
+
+    /// Entries that fit in an account of `data_len` bytes, up to `capacity()`
+    ///
+    /// Boards above 10 KiB are allocated over several `grow_leaderboard`
+    /// calls; until then they can only hold what the current allocation fits.
+    pub fn allocated_capacity(&self, data_len: usize) -> usize {
+        let fits = data_len.saturating_sub(Self::space(0)) / LeaderEntry::INIT_SPACE;
+        self.capacity().min(fits)
+    }
+
+    /// Account size of a board holding `capacity` entries
+    pub fn space(capacity: usize) -> usize {
+        8 + Self::INIT_SPACE + capacity * LeaderEntry::INIT_SPACE
+    }
+
+    /// Account size after one allocation step from `current_len` bytes
+    ///
+    /// A single instruction can add at most 10 KiB to an account, so boards
+    /// larger than that are allocated by `init` and then `grow_leaderboard`
+    /// until they reach `space(capacity)`.
+    pub fn next_space(current_len: usize, capacity: usize) -> usize {
+        Self::space(capacity).min(current_len + MAX_PERMITTED_DATA_INCREASE)
+    }
+
+    /// `init_if_needed` space for a board that may already exist
+    ///
+    /// New boards get their first allocation step; existing ones are taken
+    /// at their current size, which `grow_leaderboard` may have extended.
+    pub fn init_if_needed_space(info: &AccountInfo, capacity: usize) -> usize {
+        if info.owner == &anchor_lang::system_program::ID {
+            Self::next_space(0, capacity)
+        } else {
+            info.data_len()
+        }
+    }
+
+    /// Distinct players in the period, for participation thresholds
+    ///
+    /// `total_players` counts leaderboard pushes, so a player truncated off
+    /// the board and re-added is counted twice, and unsolved games never
+    /// push. Boards whose tickets were counted at purchase use `entrants`
+    /// instead; boards that never saw a purchase (weekly, monthly) use the
+    /// settled `participants`, with the pushes as a floor for boards that
+    /// started before participants were counted.
+    pub fn participants_count(&self) -> u32 {
+        if self.entrants > 0 {
+            self.entrants
+        } else {
+            self.participants.max(self.total_players)
+        }
+    }
+}
+
+#[cfg(any(test, feature = "debug-logs"))]
+impl PeriodLeaderboard {
+    /// Empty live board for unit tests and benchmarks (Best aggregation, 50/30/20 splits)
+    ///
+    /// Tests set only the fields they exercise, so appending a field doesn't
+    /// mean editing every fixture.
+    pub fn default_for_test(period_id: &str, period_type: PeriodType) -> Self {
+        let data = vec![0u8; 8 + Self::space(0)];
+        let mut board = Self::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        board.period_id = period_id.to_string();
+        board.period_type = period_type;
+        board.winner_splits = vec![5000, 3000, 2000];
+        board
+    }
+}
+
+/// Marks that a player bought a ticket in a period (one per player per period)
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerParticipation {
+    pub player: Pubkey,
+    #[max_len(20)]
+    pub period_id: String,
+    pub first_ticket_at: i64, // 0 until the first ticket is recorded
+    pub tickets: u32,         // Tickets bought by the player in the period
+    pub bump: u8,
+}
+
+/// Timelocked admin action announced by `queue_admin_action`
+///
+/// Stored at `[SEED_PENDING_ADMIN_ACTION, payload_hash]` and closed when
+/// the action executes or is canceled.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAdminAction {
+    pub authority: Pubkey,
+    pub kind: AdminActionKind,
+    pub payload_hash: [u8; 32], // Hash of the guarded arguments (see admin::timelock)
+    pub queued_at: i64,
+    pub execute_after: i64,
+    pub bump: u8,
+}
+
+/// A player's ticket payments into the prize pools during one daily period
+///
+/// Lets `claim_refund` return a canceled period's share of each ticket.
+/// Tickets bought before receipts existed have none and can't be refunded.
+#[account]
+#[derive(InitSpace)]
+pub struct TicketReceipt {
+    pub player: Pubkey,
+    #[max_len(20)]
+    pub period_id: String,        // Daily period the tickets were bought in
+    pub contributions: [u64; 3],  // Daily/weekly/monthly pool shares paid, summed over the tickets
+    pub refunded: [bool; 3],      // Share already returned after its period was canceled
+    pub bump: u8,
+}
+
+/// Head-to-head wager between two players on one daily period
+///
+/// Both stakes sit in the duel's escrow token account until `resolve_duel`
+/// pays out on the finalized daily board, or `cancel_duel` / `refund_duel`
+/// returns them. The duel and its escrow are closed when it settles.
+#[account]
+#[derive(InitSpace)]
+pub struct Duel {
+    pub challenger: Pubkey,
+    pub opponent: Pubkey,
+    #[max_len(20)]
+    pub period_id: String,     // Daily period both players compete in
+    pub stake: u64,            // Each player's stake in the mint's base units
+    pub status: DuelStatus,
+    pub created_at: i64,
+    pub accept_deadline: i64,  // End of the period; an unaccepted duel is refundable from here
+    pub bump: u8,
+    pub escrow_bump: u8,       // Bump of the escrow token account PDA
+}
+
+/// Lifecycle of a duel; settled duels are closed rather than marked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum DuelStatus {
+    Open,     // Challenger staked, waiting for the opponent
+    Accepted, // Both staked, waiting for the daily board to finalize
+}
+
+/// Player-written profile details shown on shareable profile pages
+///
+/// Kept out of UserProfile so game-critical accounts stay small; indexers
+/// join it with leaderboard and stat events by `player`.
+#[account]
+#[derive(InitSpace)]
+pub struct PublicProfile {
+    pub player: Pubkey,
+    #[max_len(160)] // MAX_BIO_LENGTH
+    pub bio: String,
+    pub country: [u8; 2], // ISO 3166-1 alpha-2 code, uppercase ([0, 0] = not shown)
+    #[max_len(128)] // MAX_AVATAR_URI_LENGTH
+    pub avatar_uri: String, // https:// or ipfs:// (empty = no avatar)
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Admin-maintained catalog of cosmetic flairs bought with score points
+#[account]
+#[derive(InitSpace)]
+pub struct FlairCatalog {
+    #[max_len(64)] // MAX_FLAIRS
+    pub flairs: Vec<FlairEntry>,
+    pub bump: u8,
+}
+
+/// Daily word indexes recently assigned to shared-word periods
+///
+/// A ring buffer: `next` is overwritten by the following assignment once
+/// all MAX_SHARED_WORD_WINDOW slots are filled.
+#[account]
+#[derive(InitSpace)]
+pub struct UsedWords {
+    pub recent: [u32; MAX_SHARED_WORD_WINDOW],
+    pub len: u8,          // Filled slots in `recent`
+    pub next: u8,         // Slot the next assignment is written to
+    pub last_period: u64, // Number of the latest assigned daily period (valid once len > 0)
+    pub bump: u8,
+}
+
+impl UsedWords {
+    /// The last `window` assigned indexes, most recent first
+    pub fn recent_window(&self, window: usize) -> Vec<u32> {
+        let capacity = self.recent.len();
+        (1..=window.min(self.len as usize))
+            .map(|back| self.recent[(self.next as usize + capacity - back) % capacity])
+            .collect()
+    }
+
+    /// Record an assigned index, overwriting the oldest once full
+    pub fn record(&mut self, period_number: u64, word_index: u32) {
+        let capacity = self.recent.len();
+        self.recent[self.next as usize] = word_index;
+        self.next = ((self.next as usize + 1) % capacity) as u8;
+        self.len = (self.len as usize + 1).min(capacity) as u8;
+        self.last_period = period_number;
+    }
+}
+
+/// Shared word every session of a daily period plays (commit-reveal periods)
+#[account]
+#[derive(InitSpace)]
+pub struct SharedWord {
+    #[max_len(20)]
+    pub period_id: String,
+    pub word_index: u32, // Index into VOBLE_WORDS
+    pub assigned_at: i64,
+    pub bump: u8,
+    pub word_difficulty: u8, // WordBank difficulty of the word when assigned (0 = default)
+}
+
+/// Admin-set difficulty of every word, indexed by global word index
+#[account]
+#[derive(InitSpace)]
+pub struct WordBank {
+    pub difficulties: [u8; TOTAL_WORD_COUNT], // 1-5, DEFAULT_WORD_DIFFICULTY until tagged
+    pub bump: u8,
+}
+
+impl WordBank {
+    /// Difficulty of a word, or the default for indexes outside the bank
+    pub fn difficulty_of(&self, word_index: u32) -> u8 {
+        self.difficulties
+            .get(word_index as usize)
+            .copied()
+            .unwrap_or(DEFAULT_WORD_DIFFICULTY)
+    }
+}
+
+/// Features switched on per deployment, so devnet can run ahead of mainnet
+#[account]
+#[derive(InitSpace)]
+pub struct FeatureFlags {
+    pub enabled: u64,                            // Bit n set = feature n on
+    pub activated_at: [i64; FEATURE_FLAG_COUNT], // Last time each feature was switched on (0 = never)
+    pub bump: u8,
+}
+
+impl FeatureFlags {
+    /// Whether a feature is switched on (unknown flags are off)
+    pub fn is_enabled(&self, flag: u8) -> bool {
+        (flag as usize) < FEATURE_FLAG_COUNT && self.enabled & (1u64 << flag) != 0
+    }
+}
+
+/// Single purchasable flair
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace, PartialEq, Eq)]
+pub struct FlairEntry {
+    pub id: u8,          // 1..=MAX_FLAIRS
+    pub cost: u64,       // Price in score points
+    pub supply_cap: u32, // Maximum copies (0 = unlimited)
+    pub minted: u32,     // Copies sold so far
 }
 
 /// Individual keystroke data for anti-cheat and analytics
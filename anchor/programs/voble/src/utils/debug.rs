@@ -0,0 +1,123 @@
+//! Compute and size instrumentation for the `debug-logs` feature
+//!
+//! Build with `anchor build -- --features debug-logs` to log the compute
+//! units spent between checkpoints of the heavy handlers, plus entry counts,
+//! vector capacities and serialized sizes. Without the feature every helper
+//! here is a no-op the compiler removes, so release builds neither log nor
+//! spend compute on it.
+//!
+//! The benchmark instructions (e.g. `bench_leaderboard_write`) report through
+//! the same meter; `voble-client`'s `compute_units` test runs them.
+
+use anchor_lang::prelude::*;
+
+use crate::state::{PeriodLeaderboard, SessionAccount};
+
+/// Whether the program was built with `debug-logs`
+pub const ENABLED: bool = cfg!(feature = "debug-logs");
+
+/// `msg!` that only runs with the `debug-logs` feature
+///
+/// The arguments are still type-checked without the feature, so both builds
+/// keep compiling.
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        if $crate::utils::debug::ENABLED {
+            anchor_lang::prelude::msg!($($arg)*);
+        }
+    };
+}
+pub(crate) use debug_log;
+
+/// Compute units left in the transaction (0 without `debug-logs`)
+pub fn remaining_compute_units() -> u64 {
+    #[cfg(feature = "debug-logs")]
+    {
+        solana_program::compute_units::sol_remaining_compute_units()
+    }
+    #[cfg(not(feature = "debug-logs"))]
+    {
+        0
+    }
+}
+
+/// Logs the compute units a handler spends between named checkpoints
+pub struct ComputeMeter {
+    label: &'static str,
+    last: u64,
+}
+
+impl ComputeMeter {
+    /// Start measuring `label` from the units left now
+    pub fn start(label: &'static str) -> Self {
+        let last = remaining_compute_units();
+        debug_log!("⏱️  [{}] start: {} CU remaining", label, last);
+        Self { label, last }
+    }
+
+    /// Log the units used since the previous checkpoint
+    pub fn checkpoint(&mut self, point: &str) {
+        if ENABLED {
+            let now = remaining_compute_units();
+            msg!(
+                "⏱️  [{}] {}: {} CU used, {} remaining",
+                self.label,
+                point,
+                self.last.saturating_sub(now),
+                now
+            );
+            self.last = now;
+        }
+    }
+}
+
+/// Borsh size of a value in bytes (account data minus the discriminator)
+pub fn serialized_len<T: AnchorSerialize>(value: &T) -> usize {
+    let mut buffer = Vec::new();
+    value.serialize(&mut buffer).map_or(0, |_| buffer.len())
+}
+
+/// Log a leaderboard's entry count, capacities and serialized size
+pub fn log_leaderboard(leaderboard: &PeriodLeaderboard) {
+    debug_log!(
+        "   🔎 {} {}: {}/{} entries, vec capacity {}, {} bytes serialized",
+        leaderboard.period_type.to_str(),
+        leaderboard.period_id,
+        leaderboard.entries.len(),
+        leaderboard.capacity(),
+        leaderboard.entries.capacity(),
+        8 + serialized_len(leaderboard)
+    );
+}
+
+/// Log a session's keystroke buffer and serialized size
+pub fn log_session(session: &SessionAccount) {
+    debug_log!(
+        "   🔎 session {}: {} keystrokes (vec capacity {}), {} bytes serialized",
+        session.player,
+        session.keystrokes.len(),
+        session.keystrokes.capacity(),
+        8 + serialized_len(session)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meter_runs_in_either_build() {
+        assert_eq!(ENABLED, cfg!(feature = "debug-logs"));
+
+        let mut meter = ComputeMeter::start("test");
+        meter.checkpoint("nothing");
+        debug_log!("unused without the feature: {}", serialized_len(&0u8));
+    }
+
+    #[test]
+    fn test_serialized_len_counts_borsh_bytes() {
+        assert_eq!(serialized_len(&"D12".to_string()), 4 + 3);
+        assert_eq!(serialized_len(&vec![0u64; 3]), 4 + 3 * 8);
+        assert_eq!(serialized_len(&Some(7u32)), 1 + 4);
+    }
+}
@@ -283,6 +283,34 @@ pub fn sol_to_lamports(sol: u64) -> u64 {
     sol.saturating_mul(1_000_000_000)
 }
 
+/// Format a token amount in base units as a decimal UI amount (for display/logging)
+///
+/// # Arguments
+/// * `amount` - Amount in the mint's base units
+/// * `decimals` - Decimals of the mint
+///
+/// # Returns
+/// The amount with trailing fractional zeros removed
+///
+/// # Example
+/// ```
+/// assert_eq!(format_token_amount(1_500_000, 6), "1.5"); // 1.5 USDC
+/// assert_eq!(format_token_amount(250_000, 6), "0.25");
+/// assert_eq!(format_token_amount(2_000_000, 6), "2");
+/// ```
+pub fn format_token_amount(amount: u64, decimals: u8) -> String {
+    let scale = 10u128.pow(decimals as u32);
+    let whole = amount as u128 / scale;
+    let fraction = amount as u128 % scale;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
 // ================================
 // ROUNDING UTILITIES
 // ================================
@@ -329,6 +357,19 @@ mod tests {
         assert_eq!(calculate_bps(1_000_000, 0), 0); // 0%
     }
 
+    #[test]
+    fn test_format_token_amount() {
+        assert_eq!(format_token_amount(1_000_000, 6), "1"); // MIN_TICKET_PRICE in USDC
+        assert_eq!(format_token_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_token_amount(1_234_567, 6), "1.234567");
+        assert_eq!(format_token_amount(10_000, 6), "0.01");
+        assert_eq!(format_token_amount(1, 6), "0.000001");
+        assert_eq!(format_token_amount(0, 6), "0");
+        assert_eq!(format_token_amount(1_000_000, 9), "0.001"); // Same base units, 9 decimals
+        assert_eq!(format_token_amount(42, 0), "42");
+        assert_eq!(format_token_amount(u64::MAX, 9), "18446744073.709551615");
+    }
+
     #[test]
     fn test_is_valid_bps() {
         assert!(is_valid_bps(0));
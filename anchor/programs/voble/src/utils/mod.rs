@@ -24,7 +24,20 @@
 //! - Basis points calculations (avoiding floating-point arithmetic)
 //! - Safe arithmetic operations (overflow/underflow checks)
 //! - Prize distribution calculations
-//! - SOL/lamports conversions
+//! - SOL/lamports conversions and token amount display
+//!
+//! ## `debug`
+//! Instrumentation compiled in only with the `debug-logs` feature:
+//! - Compute units spent between handler checkpoints
+//! - Entry counts, vector capacities and serialized sizes
+//!
+//! ## `ratelimit`
+//! Slot-based cool-downs for session lifecycle instructions:
+//! - Per-action last slot kept on the session
+//! - Per-action minimum gaps configured in GlobalConfig
+//!
+//! ## `test_accounts` (tests only)
+//! In-memory `AccountInfo`s for running a context's account constraints
 //!
 //! ## `validation`
 //! Input validation functions for security and data integrity. Validates:
@@ -45,7 +58,8 @@
 //! // Calculate current period
 //! let period_id = period::get_current_period_id(
 //!     period::PeriodType::Daily,
-//!     Clock::get()?.unix_timestamp
+//!     Clock::get()?.unix_timestamp,
+//!     config.period_epoch(),
 //! );
 //!
 //! // Calculate prize amount
@@ -55,10 +69,15 @@
 //! validation::validate_username(&username)?;
 //! ```
 
+pub mod debug;
 pub mod math;
 pub mod pda;
 pub mod period;
+pub mod ratelimit;
+#[cfg(test)]
+pub mod test_accounts;
 pub mod validation;
+pub mod words;
 
 // Re-export commonly used items for convenience
 pub use math::{calculate_bps, validate_bps_sum_equals_100, BASIS_POINTS_TOTAL};
@@ -66,5 +85,6 @@ pub use period::{
     get_current_period_id, validate_period_id as validate_period_id_format, PeriodType,
 };
 pub use validation::{
-    validate_guess, validate_period_id, validate_rank, validate_username, validate_winner_splits,
+    period_id_seed, validate_guess, validate_period_id, validate_period_id_for, validate_rank,
+    validate_username, validate_winner_splits,
 };
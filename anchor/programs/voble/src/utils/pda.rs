@@ -16,6 +16,7 @@
 //! - Period: Leaderboards, period states, entitlements
 
 use crate::constants::*;
+use crate::state::{ConfigBumps, PeriodType, SessionAccount, VaultKind};
 use anchor_lang::prelude::*;
 
 // ================================
@@ -69,11 +70,56 @@ pub fn derive_lucky_draw_vault_pda(program_id: &Pubkey) -> (Pubkey, u8) {
 /// # Returns
 /// `Some((Pubkey, u8))` if valid period type, `None` otherwise
 pub fn derive_vault_pda_for_period(period_type: &str, program_id: &Pubkey) -> Option<(Pubkey, u8)> {
-    match period_type {
-        "daily" => Some(derive_daily_vault_pda(program_id)),
-        "weekly" => Some(derive_weekly_vault_pda(program_id)),
-        "monthly" => Some(derive_monthly_vault_pda(program_id)),
-        _ => None,
+    let period_type = PeriodType::try_from(period_type).ok()?;
+    Some(Pubkey::find_program_address(&[period_type.vault_seed()], program_id))
+}
+
+impl VaultKind {
+    /// Vault ledger seed component for this vault
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            VaultKind::Daily => &[0],
+            VaultKind::Weekly => &[1],
+            VaultKind::Monthly => &[2],
+            VaultKind::Platform => &[3],
+            VaultKind::LuckyDraw => &[4],
+        }
+    }
+
+    /// Token vault seed for this vault
+    pub fn vault_seed(&self) -> &'static [u8] {
+        match self {
+            VaultKind::Daily => SEED_DAILY_PRIZE_VAULT,
+            VaultKind::Weekly => SEED_WEEKLY_PRIZE_VAULT,
+            VaultKind::Monthly => SEED_MONTHLY_PRIZE_VAULT,
+            VaultKind::Platform => SEED_PLATFORM_VAULT,
+            VaultKind::LuckyDraw => SEED_LUCKY_DRAW_VAULT,
+        }
+    }
+}
+
+/// Derive the ledger PDA tracking one vault's inflows and outflows
+pub fn derive_vault_ledger_pda(kind: VaultKind, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_VAULT_LEDGER, kind.seed()], program_id)
+}
+
+/// Derive the singleton counter of sessions holding an active slot
+pub fn derive_session_counter_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_SESSION_COUNTER], program_id)
+}
+
+/// Derive the canonical bumps of the global config and all vaults
+///
+/// Used once to populate `GlobalConfig.bumps`; afterwards contexts read the
+/// cached values instead of calling `find_program_address` again.
+pub fn derive_config_bumps(program_id: &Pubkey) -> ConfigBumps {
+    ConfigBumps {
+        global_config: derive_global_config_pda(program_id).1,
+        daily_prize_vault: derive_daily_vault_pda(program_id).1,
+        weekly_prize_vault: derive_weekly_vault_pda(program_id).1,
+        monthly_prize_vault: derive_monthly_vault_pda(program_id).1,
+        platform_vault: derive_platform_vault_pda(program_id).1,
+        lucky_draw_vault: derive_lucky_draw_vault_pda(program_id).1,
     }
 }
 
@@ -97,6 +143,179 @@ pub fn derive_vault_pda_for_period(period_type: &str, program_id: &Pubkey) -> Op
 pub fn derive_user_profile_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEED_USER_PROFILE, user.as_ref()], program_id)
 }
+
+/// Derive a player's game session PDA
+///
+/// # Arguments
+/// * `player` - The player's wallet public key
+/// * `program_id` - The program ID
+pub fn derive_session_pda(player: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_SESSION, player.as_ref()], program_id)
+}
+
+/// Derive a player's session PDA for a session generation
+///
+/// # Arguments
+/// * `player` - The player's wallet public key
+/// * `session_nonce` - `UserProfile.session_nonce` (0 = the `derive_session_pda` address)
+/// * `program_id` - The program ID
+pub fn derive_session_pda_with_nonce(
+    player: &Pubkey,
+    session_nonce: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_SESSION,
+            player.as_ref(),
+            &SessionAccount::nonce_seed(session_nonce),
+        ],
+        program_id,
+    )
+}
+
+/// Derive a player's blocklist entry PDA (exists only while blocked)
+pub fn derive_blocked_player_pda(player: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_BLOCKED_PLAYER, player.as_ref()], program_id)
+}
+
+// ================================
+// PERIOD ACCOUNT PDAs
+// ================================
+
+impl PeriodType {
+    /// Single-byte discriminator used in leaderboard seeds
+    pub fn seed_byte(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Leaderboard seed component for this period type
+    ///
+    /// Use this in `seeds = [...]` constraints instead of raw `&[0]` / `&[1]` /
+    /// `&[2]` literals.
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            PeriodType::Daily => &[0],
+            PeriodType::Weekly => &[1],
+            PeriodType::Monthly => &[2],
+        }
+    }
+
+    /// Period state seed prefix for this period type
+    pub fn period_state_seed(&self) -> &'static [u8] {
+        match self {
+            PeriodType::Daily => SEED_DAILY_PERIOD,
+            PeriodType::Weekly => SEED_WEEKLY_PERIOD,
+            PeriodType::Monthly => SEED_MONTHLY_PERIOD,
+        }
+    }
+
+    /// Prize vault tracked for this period type
+    pub fn vault_kind(&self) -> VaultKind {
+        match self {
+            PeriodType::Daily => VaultKind::Daily,
+            PeriodType::Weekly => VaultKind::Weekly,
+            PeriodType::Monthly => VaultKind::Monthly,
+        }
+    }
+
+    /// Token vault seed of this period type's prize vault
+    pub fn vault_seed(&self) -> &'static [u8] {
+        self.vault_kind().vault_seed()
+    }
+}
+
+/// Derive a period leaderboard PDA
+///
+/// # Arguments
+/// * `period_id` - The period ID (e.g., "D123")
+/// * `period_type` - Daily, weekly or monthly
+/// * `program_id` - The program ID
+pub fn derive_leaderboard_pda(
+    period_id: &str,
+    period_type: PeriodType,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_LEADERBOARD, period_id.as_bytes(), period_type.seed()],
+        program_id,
+    )
+}
+
+/// Derive a period state PDA
+///
+/// # Arguments
+/// * `period_id` - The period ID (e.g., "D123")
+/// * `period_type` - Daily, weekly or monthly
+/// * `program_id` - The program ID
+pub fn derive_period_state_pda(
+    period_id: &str,
+    period_type: PeriodType,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[period_type.period_state_seed(), period_id.as_bytes()],
+        program_id,
+    )
+}
+
+/// Derive a player's participation marker PDA for a period
+///
+/// # Arguments
+/// * `period_id` - The daily period ID the ticket was bought in
+/// * `player` - The player's wallet public key
+/// * `program_id` - The program ID
+pub fn derive_player_participation_pda(
+    period_id: &str,
+    player: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PLAYER_PARTICIPATION, period_id.as_bytes(), player.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive a player's ticket receipt PDA for a period
+///
+/// # Arguments
+/// * `period_id` - The daily period ID the ticket was bought in
+/// * `player` - The player's wallet public key
+/// * `program_id` - The program ID
+pub fn derive_ticket_receipt_pda(
+    period_id: &str,
+    player: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_TICKET_RECEIPT, period_id.as_bytes(), player.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive a winner entitlement PDA
+///
+/// # Arguments
+/// * `player` - The winner's wallet public key
+/// * `period_type` - "daily", "weekly", or "monthly"
+/// * `period_id` - The period ID (e.g., "D123")
+/// * `program_id` - The program ID
+pub fn derive_winner_entitlement_pda(
+    player: &Pubkey,
+    period_type: &str,
+    period_id: &str,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_WINNER_ENTITLEMENT,
+            player.as_ref(),
+            period_type.as_bytes(),
+            period_id.as_bytes(),
+        ],
+        program_id,
+    )
+}
 // ================================
 // HELPER FUNCTIONS
 // ================================
@@ -106,22 +325,12 @@ pub fn derive_user_profile_pda(user: &Pubkey, program_id: &Pubkey) -> (Pubkey, u
 /// # Returns
 /// `Some(&[u8])` if valid period type, `None` otherwise
 pub fn get_period_seed_prefix(period_type: &str) -> Option<&'static [u8]> {
-    match period_type {
-        "daily" => Some(SEED_DAILY_PERIOD),
-        "weekly" => Some(SEED_WEEKLY_PERIOD),
-        "monthly" => Some(SEED_MONTHLY_PERIOD),
-        _ => None,
-    }
+    PeriodType::try_from(period_type).ok().map(|kind| kind.period_state_seed())
 }
 
 /// Get the vault seed based on period type
 pub fn get_vault_seed(period_type: &str) -> Option<&'static [u8]> {
-    match period_type {
-        "daily" => Some(SEED_DAILY_PRIZE_VAULT),
-        "weekly" => Some(SEED_WEEKLY_PRIZE_VAULT),
-        "monthly" => Some(SEED_MONTHLY_PRIZE_VAULT),
-        _ => None,
-    }
+    PeriodType::try_from(period_type).ok().map(|kind| kind.vault_seed())
 }
 
 #[cfg(test)]
@@ -166,6 +375,80 @@ mod tests {
         assert_eq!(bump1, bump2);
     }
 
+    #[test]
+    fn test_derive_config_bumps_match_helpers() {
+        let program_id = Pubkey::new_unique();
+        let bumps = derive_config_bumps(&program_id);
+
+        assert_eq!(bumps.global_config, derive_global_config_pda(&program_id).1);
+        assert_eq!(bumps.daily_prize_vault, derive_daily_vault_pda(&program_id).1);
+        assert_eq!(bumps.weekly_prize_vault, derive_weekly_vault_pda(&program_id).1);
+        assert_eq!(bumps.monthly_prize_vault, derive_monthly_vault_pda(&program_id).1);
+        assert_eq!(bumps.platform_vault, derive_platform_vault_pda(&program_id).1);
+        assert_eq!(bumps.lucky_draw_vault, derive_lucky_draw_vault_pda(&program_id).1);
+
+        // A cached bump must recreate the same address without searching
+        let (vault, bump) = derive_daily_vault_pda(&program_id);
+        let recreated =
+            Pubkey::create_program_address(&[SEED_DAILY_PRIZE_VAULT, &[bump]], &program_id)
+                .unwrap();
+        assert_eq!(recreated, vault);
+    }
+
+    #[test]
+    fn test_period_type_seed_bytes() {
+        assert_eq!(PeriodType::Daily.seed_byte(), 0);
+        assert_eq!(PeriodType::Weekly.seed_byte(), 1);
+        assert_eq!(PeriodType::Monthly.seed_byte(), 2);
+
+        for period_type in [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly] {
+            assert_eq!(period_type.seed(), &[period_type.seed_byte()]);
+        }
+    }
+
+    #[test]
+    fn test_derive_pdas_match_raw_seeds() {
+        let program_id = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+
+        // Leaderboards
+        for (period_type, byte) in [
+            (PeriodType::Daily, 0u8),
+            (PeriodType::Weekly, 1u8),
+            (PeriodType::Monthly, 2u8),
+        ] {
+            let raw = Pubkey::find_program_address(
+                &[SEED_LEADERBOARD, b"D123", &[byte]],
+                &program_id,
+            );
+            assert_eq!(derive_leaderboard_pda("D123", period_type, &program_id), raw);
+        }
+
+        // Period states
+        for (period_type, seed) in [
+            (PeriodType::Daily, SEED_DAILY_PERIOD),
+            (PeriodType::Weekly, SEED_WEEKLY_PERIOD),
+            (PeriodType::Monthly, SEED_MONTHLY_PERIOD),
+        ] {
+            let raw = Pubkey::find_program_address(&[seed, b"D123"], &program_id);
+            assert_eq!(derive_period_state_pda("D123", period_type, &program_id), raw);
+        }
+
+        // Session
+        let raw = Pubkey::find_program_address(&[SEED_SESSION, player.as_ref()], &program_id);
+        assert_eq!(derive_session_pda(&player, &program_id), raw);
+
+        // Winner entitlement
+        let raw = Pubkey::find_program_address(
+            &[SEED_WINNER_ENTITLEMENT, player.as_ref(), b"daily", b"D123"],
+            &program_id,
+        );
+        assert_eq!(
+            derive_winner_entitlement_pda(&player, "daily", "D123", &program_id),
+            raw
+        );
+    }
+
     #[test]
     fn test_period_seeds_helper() {
         assert_eq!(get_period_seed_prefix("daily"), Some(SEED_DAILY_PERIOD));
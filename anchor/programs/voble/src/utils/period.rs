@@ -58,24 +58,34 @@ impl PeriodType {
 // - PERIOD_DAILY_DURATION (7 minutes for testing)
 // - PERIOD_WEEKLY_DURATION (12 minutes for testing)
 // - PERIOD_MONTHLY_DURATION (15 minutes for testing)
-// - PERIOD_EPOCH_START (November 14, 2023)
+// The epoch start is per deployment (GlobalConfig::period_epoch), so every
+// timestamp conversion takes it as `epoch_start`.
 
 /// Calculate the current period ID based on current timestamp
 ///
 /// # Arguments
 /// * `period_type` - The type of period (daily, weekly, monthly)
 /// * `current_timestamp` - Current Unix timestamp
+/// * `epoch_start` - Timestamp of period 0 (`GlobalConfig::period_epoch`)
 ///
 /// # Returns
 /// Period ID string (e.g., "D123", "W45", "M12")
 ///
 /// # Example
 /// ```
-/// let period_id = get_current_period_id(PeriodType::Daily, Clock::get()?.unix_timestamp);
+/// let period_id = get_current_period_id(
+///     PeriodType::Daily,
+///     Clock::get()?.unix_timestamp,
+///     config.period_epoch(),
+/// );
 /// // Returns something like "D123"
 /// ```
-pub fn get_current_period_id(period_type: PeriodType, current_timestamp: i64) -> String {
-    let period_number = calculate_period_number(period_type, current_timestamp);
+pub fn get_current_period_id(
+    period_type: PeriodType,
+    current_timestamp: i64,
+    epoch_start: i64,
+) -> String {
+    let period_number = calculate_period_number(period_type, current_timestamp, epoch_start);
     format!("{}{}", period_type.prefix(), period_number)
 }
 
@@ -84,11 +94,12 @@ pub fn get_current_period_id(period_type: PeriodType, current_timestamp: i64) ->
 /// # Arguments
 /// * `period_type` - The type of period
 /// * `timestamp` - Unix timestamp
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// The period number (e.g., 123 for day 123 since epoch)
-pub fn calculate_period_number(period_type: PeriodType, timestamp: i64) -> i64 {
-    let elapsed_seconds = timestamp.saturating_sub(PERIOD_EPOCH_START);
+pub fn calculate_period_number(period_type: PeriodType, timestamp: i64, epoch_start: i64) -> i64 {
+    let elapsed_seconds = timestamp.saturating_sub(epoch_start);
 
     match period_type {
         PeriodType::Daily => elapsed_seconds / PERIOD_DAILY_DURATION,
@@ -128,10 +139,27 @@ pub fn validate_period_id(period_id: &str) -> bool {
     // Check if prefix is valid
     let valid_prefix = matches!(prefix, 'D' | 'W' | 'M');
 
-    // Check if number part is a valid positive integer
-    let valid_number = number_part.parse::<u64>().is_ok();
+    valid_prefix && is_canonical_period_number(number_part)
+}
 
-    valid_prefix && valid_number
+/// Whether the number part of a period ID is in canonical decimal form
+///
+/// `u64::from_str` also accepts "+5" and "05", which name the same period
+/// as "5" but give different PDA seeds. Only the form `get_current_period_id`
+/// produces is accepted: digits only, no leading zeros.
+///
+/// # Example
+/// ```
+/// assert!(is_canonical_period_number("0"));
+/// assert!(is_canonical_period_number("123"));
+/// assert!(!is_canonical_period_number("05"));
+/// assert!(!is_canonical_period_number("+5"));
+/// ```
+pub fn is_canonical_period_number(number_part: &str) -> bool {
+    !number_part.is_empty()
+        && number_part.bytes().all(|b| b.is_ascii_digit())
+        && !(number_part.len() > 1 && number_part.starts_with('0'))
+        && number_part.parse::<u64>().is_ok()
 }
 
 /// Parse period ID and extract type and number
@@ -166,24 +194,41 @@ pub fn parse_period_id(period_id: &str) -> Option<(PeriodType, u64)> {
     Some((period_type, number))
 }
 
+/// Bit identifying a daily period within any 64-day window
+///
+/// Weekly and monthly boards record which days they already counted for a
+/// player as a mask of these bits. Days 64 apart share a bit, which never
+/// matters since no board spans more than a month of days.
+///
+/// # Returns
+/// `1 << (day % 64)`, or 0 if `period_id` is not a daily period ID
+pub fn daily_period_mask(period_id: &str) -> u64 {
+    match parse_period_id(period_id) {
+        Some((PeriodType::Daily, day)) => 1 << (day % 64),
+        _ => 0,
+    }
+}
+
 /// Check if a period has ended
 ///
 /// # Arguments
 /// * `period_id` - The period ID to check
 /// * `current_timestamp` - Current Unix timestamp
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// `true` if the period has ended, `false` if still active
 ///
 /// # Example
 /// ```
-/// if has_period_ended("D122", Clock::get()?.unix_timestamp) {
+/// if has_period_ended("D122", Clock::get()?.unix_timestamp, config.period_epoch()) {
 ///     msg!("Period D122 has ended");
 /// }
 /// ```
-pub fn has_period_ended(period_id: &str, current_timestamp: i64) -> bool {
+pub fn has_period_ended(period_id: &str, current_timestamp: i64, epoch_start: i64) -> bool {
     if let Some((period_type, period_number)) = parse_period_id(period_id) {
-        let current_period_number = calculate_period_number(period_type, current_timestamp);
+        let current_period_number =
+            calculate_period_number(period_type, current_timestamp, epoch_start);
         current_period_number > period_number as i64
     } else {
         false
@@ -194,10 +239,11 @@ pub fn has_period_ended(period_id: &str, current_timestamp: i64) -> bool {
 ///
 /// # Arguments
 /// * `period_id` - The period ID
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// Unix timestamp when the period started
-pub fn get_period_start_timestamp(period_id: &str) -> Option<i64> {
+pub fn get_period_start_timestamp(period_id: &str, epoch_start: i64) -> Option<i64> {
     let (period_type, period_number) = parse_period_id(period_id)?;
 
     let seconds_offset = match period_type {
@@ -206,17 +252,18 @@ pub fn get_period_start_timestamp(period_id: &str) -> Option<i64> {
         PeriodType::Monthly => period_number as i64 * PERIOD_MONTHLY_DURATION,
     };
 
-    Some(PERIOD_EPOCH_START + seconds_offset)
+    Some(epoch_start + seconds_offset)
 }
 
 /// Get the end timestamp for a specific period
 ///
 /// # Arguments
 /// * `period_id` - The period ID
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// Unix timestamp when the period ends
-pub fn get_period_end_timestamp(period_id: &str) -> Option<i64> {
+pub fn get_period_end_timestamp(period_id: &str, epoch_start: i64) -> Option<i64> {
     let (period_type, _period_number) = parse_period_id(period_id)?;
 
     let duration = match period_type {
@@ -225,7 +272,7 @@ pub fn get_period_end_timestamp(period_id: &str) -> Option<i64> {
         PeriodType::Monthly => PERIOD_MONTHLY_DURATION,
     };
 
-    let start = get_period_start_timestamp(period_id)?;
+    let start = get_period_start_timestamp(period_id, epoch_start)?;
     Some(start + duration)
 }
 
@@ -234,13 +281,14 @@ pub fn get_period_end_timestamp(period_id: &str) -> Option<i64> {
 /// # Arguments
 /// * `period_id` - The period ID
 /// * `timestamp` - Unix timestamp to check
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// `true` if timestamp is within the period, `false` otherwise
-pub fn is_timestamp_in_period(period_id: &str, timestamp: i64) -> bool {
+pub fn is_timestamp_in_period(period_id: &str, timestamp: i64, epoch_start: i64) -> bool {
     if let (Some(start), Some(end)) = (
-        get_period_start_timestamp(period_id),
-        get_period_end_timestamp(period_id),
+        get_period_start_timestamp(period_id, epoch_start),
+        get_period_end_timestamp(period_id, epoch_start),
     ) {
         timestamp >= start && timestamp < end
     } else {
@@ -282,12 +330,17 @@ pub fn get_next_period_id(period_id: &str) -> Option<String> {
 /// # Arguments
 /// * `period_type` - The type of period
 /// * `current_timestamp` - Current Unix timestamp
+/// * `epoch_start` - Timestamp of period 0
 ///
 /// # Returns
 /// Seconds remaining in the current period
-pub fn get_time_remaining_in_period(period_type: PeriodType, current_timestamp: i64) -> i64 {
-    let current_period_id = get_current_period_id(period_type, current_timestamp);
-    let period_end = get_period_end_timestamp(&current_period_id).unwrap_or(0);
+pub fn get_time_remaining_in_period(
+    period_type: PeriodType,
+    current_timestamp: i64,
+    epoch_start: i64,
+) -> i64 {
+    let current_period_id = get_current_period_id(period_type, current_timestamp, epoch_start);
+    let period_end = get_period_end_timestamp(&current_period_id, epoch_start).unwrap_or(0);
     (period_end - current_timestamp).max(0)
 }
 
@@ -307,6 +360,9 @@ mod tests {
         assert!(!validate_period_id("X123"));
         assert!(!validate_period_id("123"));
         assert!(!validate_period_id("Dabc"));
+        assert!(!validate_period_id("D05"));
+        assert!(!validate_period_id("D+5"));
+        assert!(!validate_period_id("D00"));
     }
 
     #[test]
@@ -322,6 +378,17 @@ mod tests {
         assert!(parse_period_id("X999").is_none());
     }
 
+    #[test]
+    fn test_daily_period_mask() {
+        assert_eq!(daily_period_mask("D0"), 1);
+        assert_eq!(daily_period_mask("D3"), 0b1000);
+        assert_eq!(daily_period_mask("D65"), 0b10);
+        assert_ne!(daily_period_mask("D9"), daily_period_mask("D10"));
+
+        assert_eq!(daily_period_mask("W3"), 0);
+        assert_eq!(daily_period_mask("legacy"), 0);
+    }
+
     #[test]
     fn test_period_type_conversions() {
         assert_eq!(PeriodType::from_str("daily"), Some(PeriodType::Daily));
@@ -344,7 +411,7 @@ mod tests {
     fn test_calculate_period_number() {
         // Test with epoch time (should be period 0)
         assert_eq!(
-            calculate_period_number(PeriodType::Daily, PERIOD_EPOCH_START),
+            calculate_period_number(PeriodType::Daily, PERIOD_EPOCH_START, PERIOD_EPOCH_START),
             0
         );
 
@@ -352,7 +419,8 @@ mod tests {
         assert_eq!(
             calculate_period_number(
                 PeriodType::Daily,
-                PERIOD_EPOCH_START + PERIOD_DAILY_DURATION
+                PERIOD_EPOCH_START + PERIOD_DAILY_DURATION,
+                PERIOD_EPOCH_START
             ),
             1
         );
@@ -361,9 +429,27 @@ mod tests {
         assert_eq!(
             calculate_period_number(
                 PeriodType::Weekly,
-                PERIOD_EPOCH_START + PERIOD_WEEKLY_DURATION
+                PERIOD_EPOCH_START + PERIOD_WEEKLY_DURATION,
+                PERIOD_EPOCH_START
             ),
             1
         );
     }
+
+    #[test]
+    fn test_environments_number_periods_from_their_own_epoch() {
+        let devnet_epoch = PERIOD_EPOCH_START;
+        let mainnet_epoch = PERIOD_EPOCH_START + 40 * PERIOD_DAILY_DURATION;
+        let now = mainnet_epoch + 3 * PERIOD_DAILY_DURATION + 1;
+
+        assert_eq!(get_current_period_id(PeriodType::Daily, now, devnet_epoch), "D43");
+        assert_eq!(get_current_period_id(PeriodType::Daily, now, mainnet_epoch), "D3");
+
+        assert_eq!(
+            get_period_start_timestamp("D3", mainnet_epoch),
+            Some(mainnet_epoch + 3 * PERIOD_DAILY_DURATION)
+        );
+        assert!(is_timestamp_in_period("D3", now, mainnet_epoch));
+        assert!(has_period_ended("D3", now, devnet_epoch));
+    }
 }
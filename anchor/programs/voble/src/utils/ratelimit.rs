@@ -0,0 +1,133 @@
+//! Rate Limiting Utilities
+//!
+//! Slot-based cool-downs for the session lifecycle instructions that aren't
+//! gameplay (`initialize_session`, `reset_session`, `delegate_session`), so a
+//! player can't spam them to churn their session or flood events.
+//!
+//! Each action keeps its own last slot on the session: base layer and ER
+//! slots come from different clocks and can't be compared with each other.
+
+use crate::constants::DEFAULT_SESSION_ACTION_SLOT_GAP;
+use crate::errors::VobleError;
+use crate::state::{GlobalConfig, SessionAccount};
+use anchor_lang::prelude::*;
+
+/// Rate-limited session instruction (index into the slot arrays)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SessionAction {
+    Initialize = 0,
+    Reset = 1,
+    Delegate = 2,
+}
+
+impl SessionAction {
+    /// Parse an action from its instruction argument
+    pub fn from_u8(action: u8) -> Option<Self> {
+        match action {
+            0 => Some(Self::Initialize),
+            1 => Some(Self::Reset),
+            2 => Some(Self::Delegate),
+            _ => None,
+        }
+    }
+}
+
+/// Minimum slots between two calls of `action` (legacy 0 = default)
+pub fn session_action_gap(config: &GlobalConfig, action: SessionAction) -> u64 {
+    match config.session_action_gaps[action as usize] {
+        0 => DEFAULT_SESSION_ACTION_SLOT_GAP,
+        gap => gap,
+    }
+}
+
+/// Check that enough slots have passed since an action last ran
+///
+/// # Arguments
+/// * `last_slot` - Slot the action last ran in (0 = never)
+/// * `current_slot` - Current slot
+/// * `min_gap` - Required slot gap
+///
+/// # Validation
+/// - `current_slot - last_slot` must be at least `min_gap` (`RateLimited`);
+///   a slot behind `last_slot` (a different validator's clock) passes
+pub fn check_rate_limit(last_slot: u64, current_slot: u64, min_gap: u64) -> Result<()> {
+    if last_slot == 0 || current_slot < last_slot {
+        return Ok(());
+    }
+
+    require!(current_slot - last_slot >= min_gap, VobleError::RateLimited);
+    Ok(())
+}
+
+/// Rate-limit a session action and record the slot it ran in
+pub fn record_session_action(
+    session: &mut SessionAccount,
+    action: SessionAction,
+    current_slot: u64,
+    min_gap: u64,
+) -> Result<()> {
+    let last_slot = &mut session.last_action_slots[action as usize];
+    check_rate_limit(*last_slot, current_slot, min_gap)?;
+    *last_slot = current_slot;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAP: u64 = DEFAULT_SESSION_ACTION_SLOT_GAP;
+
+    #[test]
+    fn test_first_action_allowed() {
+        assert!(check_rate_limit(0, 1, GAP).is_ok());
+    }
+
+    #[test]
+    fn test_repeat_within_gap_rejected() {
+        let last = 1_000;
+
+        for slot in last..last + GAP {
+            assert_eq!(
+                check_rate_limit(last, slot, GAP).unwrap_err(),
+                VobleError::RateLimited.into()
+            );
+        }
+        assert!(check_rate_limit(last, last + GAP, GAP).is_ok());
+    }
+
+    #[test]
+    fn test_slot_from_another_clock_allowed() {
+        // Slot recorded on the ER, checked against a lower base layer slot
+        assert!(check_rate_limit(5_000_000, 300_000, GAP).is_ok());
+    }
+
+    #[test]
+    fn test_zero_gap_disables_limit() {
+        assert!(check_rate_limit(1_000, 1_000, 0).is_ok());
+    }
+
+    #[test]
+    fn test_actions_tracked_separately() {
+        let mut session = SessionAccount::default_for_test(Pubkey::default());
+
+        record_session_action(&mut session, SessionAction::Delegate, 1_000, GAP).unwrap();
+        record_session_action(&mut session, SessionAction::Reset, 1_001, GAP).unwrap();
+        assert_eq!(
+            record_session_action(&mut session, SessionAction::Delegate, 1_001, GAP).unwrap_err(),
+            VobleError::RateLimited.into()
+        );
+
+        // A rejected attempt doesn't push the window forward
+        assert_eq!(session.last_action_slots, [0, 1_001, 1_000]);
+        record_session_action(&mut session, SessionAction::Delegate, 1_000 + GAP, GAP).unwrap();
+        assert_eq!(session.last_action_slots[SessionAction::Delegate as usize], 1_000 + GAP);
+    }
+
+    #[test]
+    fn test_action_from_u8() {
+        assert_eq!(SessionAction::from_u8(1), Some(SessionAction::Reset));
+        assert_eq!(SessionAction::from_u8(3), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! In-memory Accounts for Context Tests
+//!
+//! Builds the `AccountInfo`s an instruction receives, so tests can run a
+//! context's `try_accounts` (its account constraints) off-chain. Execution
+//! stops at the first CPI, which only works on-chain, so this covers the
+//! rejections that happen before one.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::{
+    self,
+    solana_program::{program_option::COption, program_pack::Pack},
+    state::{Account, AccountState, Mint},
+};
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+/// Owned state behind one `AccountInfo`
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    /// Program-owned account holding `value`
+    pub fn program_account<T: AccountSerialize>(value: &T) -> Self {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        Self {
+            data,
+            ..Self::wallet(Pubkey::new_unique())
+        }
+        .owned_by(crate::ID)
+    }
+
+    /// Account holding raw `data` owned by `owner` (e.g. a packed token account)
+    pub fn raw(owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            data,
+            ..Self::wallet(Pubkey::new_unique())
+        }
+        .owned_by(owner)
+    }
+
+    /// SPL Token account of `mint` belonging to `owner`
+    pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+        let account = Account {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..Account::default()
+        };
+        let mut data = vec![0u8; Account::LEN];
+        Account::pack(account, &mut data).unwrap();
+        Self::raw(spl_token::ID, data)
+    }
+
+    /// SPL Token mint with `decimals`
+    pub fn mint(decimals: u8) -> Self {
+        let mint = Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0u8; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        Self::raw(spl_token::ID, data)
+    }
+
+    /// Empty system account
+    pub fn wallet(key: Pubkey) -> Self {
+        Self {
+            key,
+            owner: anchor_lang::system_program::ID,
+            lamports: 1_000_000_000,
+            data: Vec::new(),
+            is_signer: false,
+            executable: false,
+        }
+    }
+
+    /// Empty system account signing the transaction
+    pub fn signer(key: Pubkey) -> Self {
+        Self {
+            is_signer: true,
+            ..Self::wallet(key)
+        }
+    }
+
+    /// Executable program account
+    pub fn program(id: Pubkey) -> Self {
+        Self {
+            owner: Pubkey::default(),
+            executable: true,
+            ..Self::wallet(id)
+        }
+    }
+
+    pub fn owned_by(mut self, owner: Pubkey) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    pub fn at(mut self, key: Pubkey) -> Self {
+        self.key = key;
+        self
+    }
+}
+
+/// Writable `AccountInfo`s over the accounts, in order
+pub fn account_infos(accounts: &mut [TestAccount]) -> Vec<AccountInfo<'_>> {
+    stub_rent_sysvar();
+    accounts
+        .iter_mut()
+        .map(|account| {
+            AccountInfo::new(
+                &account.key,
+                account.is_signer,
+                true,
+                &mut account.lamports,
+                &mut account.data,
+                &account.owner,
+                account.executable,
+                0,
+            )
+        })
+        .collect()
+}
+
+/// Serialized instruction arguments (without the discriminator, as
+/// `try_accounts` receives them)
+pub fn ix_args<T: AnchorSerialize>(args: T) -> Vec<u8> {
+    let mut data = Vec::new();
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// Serve the default `Rent` to `Rent::get()`, which `init` constraints call
+fn stub_rent_sysvar() {
+    struct RentStub;
+
+    impl SyscallStubs for RentStub {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            // Safety: the sysvar getter passes a pointer to a `Rent`
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    set_syscall_stubs(Box::new(RentStub));
+}
@@ -5,9 +5,13 @@
 //!
 //! # Validation Categories
 //! - Username validation
+//! - Public profile validation
 //! - Period ID validation
 //! - Guess validation (for Wordle game)
+//! - Settled game validation (score and time consistency)
+//! - Payout token account validation
 //! - Amount validation
+//! - Currency symbol validation
 //! - String sanitization
 //!
 //! # Security Considerations
@@ -18,7 +22,11 @@
 
 use crate::constants::*;
 use crate::errors::VobleError;
+use crate::instructions::game::scoring;
+use crate::state::{CharClass, PeriodType};
+use crate::utils::period::is_canonical_period_number;
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 
 // ================================
 // USERNAME VALIDATION
@@ -27,7 +35,9 @@ use anchor_lang::prelude::*;
 /// Validate a username
 ///
 /// # Rules
-/// - Must be 3-20 characters
+/// - ASCII only, so the character count equals the byte count stored
+///   against `#[max_len(32)]` on the account
+/// - Must be MIN_USERNAME_LENGTH-MAX_USERNAME_LENGTH (3-32) characters
 /// - Can contain: letters, numbers, underscores, hyphens
 /// - Cannot start or end with underscore or hyphen
 /// - Cannot contain consecutive special characters
@@ -46,7 +56,10 @@ use anchor_lang::prelude::*;
 /// validate_username("alice__bob")?; // Error: consecutive underscores
 /// ```
 pub fn validate_username(username: &str) -> Result<()> {
-    // Check length
+    // Non-ASCII names would make `len()` (bytes) disagree with the visible length
+    require!(username.is_ascii(), VobleError::UsernameNotAscii);
+
+    // Check length (bytes == characters for ASCII)
     let len = username.len();
     require!(
         len >= MIN_USERNAME_LENGTH && len <= MAX_USERNAME_LENGTH,
@@ -59,13 +72,13 @@ pub fn validate_username(username: &str) -> Result<()> {
     // Check first and last characters
     let first_char = username.chars().next().unwrap();
     let last_char = username.chars().last().unwrap();
-    require!(first_char.is_alphanumeric(), VobleError::InvalidUsername);
-    require!(last_char.is_alphanumeric(), VobleError::InvalidUsername);
+    require!(first_char.is_ascii_alphanumeric(), VobleError::InvalidUsername);
+    require!(last_char.is_ascii_alphanumeric(), VobleError::InvalidUsername);
 
     // Check for valid characters and no consecutive special chars
     let mut prev_was_special = false;
     for ch in username.chars() {
-        let is_valid = ch.is_alphanumeric() || ch == '_' || ch == '-';
+        let is_valid = ch.is_ascii_alphanumeric() || ch == '_' || ch == '-';
         require!(is_valid, VobleError::InvalidUsername);
 
         let is_special = ch == '_' || ch == '-';
@@ -106,6 +119,113 @@ pub fn is_username_appropriate(username: &str) -> bool {
     true
 }
 
+// ================================
+// PUBLIC PROFILE VALIDATION
+// ================================
+
+/// Check free text for profanity
+///
+/// Unlike `is_username_appropriate`, words like "admin" are fine here;
+/// only abusive words are blocked.
+///
+/// # Arguments
+/// * `text` - The text to check
+///
+/// # Returns
+/// `true` if the text appears clean, `false` otherwise
+pub fn is_text_appropriate(text: &str) -> bool {
+    let lowercase = text.to_lowercase();
+
+    // Basic profanity filter (extend as needed)
+    let blocked_words = [
+        "fuck", "shit", "bitch", "cunt",
+        // Add more blocked words as needed
+    ];
+
+    !blocked_words.iter().any(|word| lowercase.contains(word))
+}
+
+/// Validate a public profile bio
+///
+/// # Rules
+/// - At most MAX_BIO_LENGTH bytes (the account's `#[max_len]`)
+/// - No control characters other than newlines and tabs (`sanitize_string`
+///   must leave it unchanged)
+/// - Must pass the profanity check
+///
+/// # Arguments
+/// * `bio` - The bio to validate (may be empty)
+///
+/// # Returns
+/// `Ok(())` if valid, `Err` otherwise
+pub fn validate_bio(bio: &str) -> Result<()> {
+    require!(bio.len() <= MAX_BIO_LENGTH, VobleError::InvalidBio);
+    require!(sanitize_string(bio) == bio, VobleError::InvalidBio);
+    require!(is_text_appropriate(bio), VobleError::InappropriateContent);
+
+    Ok(())
+}
+
+/// Validate a public profile avatar URI
+///
+/// # Rules
+/// - Empty (no avatar), or one of AVATAR_URI_SCHEMES followed by a
+///   non-empty location
+/// - At most MAX_AVATAR_URI_LENGTH bytes
+/// - No control characters or whitespace
+/// - Must pass the profanity check
+///
+/// # Arguments
+/// * `avatar_uri` - The URI to validate
+///
+/// # Returns
+/// `Ok(())` if valid, `Err` otherwise
+pub fn validate_avatar_uri(avatar_uri: &str) -> Result<()> {
+    if avatar_uri.is_empty() {
+        return Ok(());
+    }
+
+    require!(
+        avatar_uri.len() <= MAX_AVATAR_URI_LENGTH,
+        VobleError::InvalidAvatarUri
+    );
+    require!(
+        sanitize_string(avatar_uri) == avatar_uri
+            && !avatar_uri.chars().any(char::is_whitespace),
+        VobleError::InvalidAvatarUri
+    );
+    require!(
+        AVATAR_URI_SCHEMES.iter().any(|scheme| {
+            avatar_uri
+                .strip_prefix(scheme)
+                .is_some_and(|location| !location.is_empty())
+        }),
+        VobleError::InvalidAvatarUri
+    );
+    require!(is_text_appropriate(avatar_uri), VobleError::InappropriateContent);
+
+    Ok(())
+}
+
+/// Validate a public profile country code
+///
+/// # Rules
+/// - Two uppercase ASCII letters (ISO 3166-1 alpha-2), or `[0, 0]` to hide it
+///
+/// # Arguments
+/// * `country` - The country code bytes
+///
+/// # Returns
+/// `Ok(())` if valid, `Err` otherwise
+pub fn validate_country_code(country: &[u8; 2]) -> Result<()> {
+    require!(
+        *country == [0, 0] || country.iter().all(u8::is_ascii_uppercase),
+        VobleError::InvalidCountryCode
+    );
+
+    Ok(())
+}
+
 // ================================
 // PERIOD ID VALIDATION
 // ================================
@@ -114,7 +234,9 @@ pub fn is_username_appropriate(username: &str) -> bool {
 ///
 /// # Rules
 /// - Must start with D, W, or M (for daily, weekly, monthly)
-/// - Must be followed by a positive integer
+/// - Must be followed by a non-negative integer in canonical decimal form
+///   (no sign, no leading zeros), so each period has exactly one ID and
+///   one set of PDA seeds
 /// - Maximum length: MAX_PERIOD_ID_LENGTH
 ///
 /// # Arguments
@@ -128,6 +250,7 @@ pub fn is_username_appropriate(username: &str) -> bool {
 /// validate_period_id("D123")?; // OK
 /// validate_period_id("W45")?; // OK
 /// validate_period_id("X999")?; // Error: invalid prefix
+/// validate_period_id("D05")?; // Error: leading zero
 /// ```
 pub fn validate_period_id(period_id: &str) -> Result<()> {
     // Check if empty
@@ -152,13 +275,56 @@ pub fn validate_period_id(period_id: &str) -> Result<()> {
     // Check number part
     let number_part = &period_id[1..];
     require!(
-        number_part.parse::<u64>().is_ok(),
+        is_canonical_period_number(number_part),
         VobleError::InvalidPeriodState
     );
 
     Ok(())
 }
 
+/// Validate a period ID for an instruction that works on one period type
+///
+/// Account seeds combine the period ID with the period type, so without
+/// this a weekly instruction would accept "D123" and create weekly
+/// accounts keyed on a daily ID.
+///
+/// # Rules
+/// - Everything `validate_period_id` checks
+/// - Prefix must match `period_type` (D daily, W weekly, M monthly)
+///
+/// # Example
+/// ```
+/// validate_period_id_for("W45", PeriodType::Weekly)?; // OK
+/// validate_period_id_for("D123", PeriodType::Weekly)?; // Error: PeriodTypeMismatch
+/// ```
+pub fn validate_period_id_for(period_id: &str, period_type: PeriodType) -> Result<()> {
+    validate_period_id(period_id)?;
+
+    let expected_prefix = match period_type {
+        PeriodType::Daily => 'D',
+        PeriodType::Weekly => 'W',
+        PeriodType::Monthly => 'M',
+    };
+    require!(
+        period_id.starts_with(expected_prefix),
+        VobleError::PeriodTypeMismatch
+    );
+
+    Ok(())
+}
+
+/// Seed bytes of a period ID, validated for `period_type` first
+///
+/// Used in account `seeds`, so a malformed ID fails with the errors of
+/// `validate_period_id_for` before Anchor derives any address from it.
+/// A constraint elsewhere would be too late: `init` accounts are derived
+/// before every other constraint, and `find_program_address` panics on a
+/// seed over 32 bytes.
+pub fn period_id_seed(period_id: &str, period_type: PeriodType) -> Result<&[u8]> {
+    validate_period_id_for(period_id, period_type)?;
+    Ok(period_id.as_bytes())
+}
+
 // ================================
 // GUESS VALIDATION (WORDLE)
 // ================================
@@ -167,33 +333,63 @@ pub fn validate_period_id(period_id: &str) -> Result<()> {
 ///
 /// # Rules
 /// - Must be exactly WORD_LENGTH characters (typically 5)
-/// - Must contain only alphabetic characters
+/// - Must contain only ASCII characters of the configured class; accented
+///   or non-Latin letters fail with `NonAsciiGuess` since they can never
+///   match the word list
 /// - Case insensitive (will be converted to uppercase)
 ///
 /// # Arguments
 /// * `guess` - The guess to validate
+/// * `char_class` - The config's character class
 ///
 /// # Returns
 /// `Ok(())` if valid, `Err` otherwise
 ///
 /// # Example
 /// ```
-/// validate_guess("HELLO")?; // OK
-/// validate_guess("HEL")?; // Error: too short
-/// validate_guess("HELLO1")?; // Error: contains number
+/// validate_guess("GARDEN", CharClass::Alphabetic)?; // OK
+/// validate_guess("HEL", CharClass::Alphabetic)?; // Error: too short
+/// validate_guess("GARDE1", CharClass::Alphabetic)?; // Error: contains number
+/// validate_guess("GARDÉN", CharClass::Alphabetic)?; // Error: NonAsciiGuess
+/// validate_guess("104729", CharClass::Numeric)?; // OK
 /// ```
-pub fn validate_guess(guess: &str) -> Result<()> {
+pub fn validate_guess(guess: &str, char_class: CharClass) -> Result<()> {
+    // Non-ASCII first - multibyte letters would otherwise surface as a length error
+    require!(guess.is_ascii(), VobleError::NonAsciiGuess);
+
     // Check length
     require!(guess.len() == WORD_LENGTH, VobleError::InvalidGuessLength);
 
-    // Check if all characters are alphabetic
+    // Check every character is in the class
     for ch in guess.chars() {
-        require!(ch.is_alphabetic(), VobleError::InvalidGuess);
+        require!(char_class.allows(ch), VobleError::InvalidGuess);
     }
 
     Ok(())
 }
 
+/// Validate that a word list can be played under a character class
+///
+/// # Rules
+/// - Every word is WORD_LENGTH characters of the class
+/// - Letters are uppercase, since guesses are uppercased before evaluation
+///
+/// # Returns
+/// `WordListCharClassMismatch` if any word breaks a rule
+pub fn validate_word_list(words: &[&str], char_class: CharClass) -> Result<()> {
+    let playable = |word: &&str| {
+        word.len() == WORD_LENGTH
+            && word
+                .chars()
+                .all(|ch| char_class.allows(ch) && !ch.is_ascii_lowercase())
+    };
+    require!(
+        words.iter().all(playable),
+        VobleError::WordListCharClassMismatch
+    );
+    Ok(())
+}
+
 /// Normalize a guess (convert to uppercase)
 ///
 /// # Arguments
@@ -205,14 +401,134 @@ pub fn normalize_guess(guess: &str) -> String {
     guess.to_uppercase()
 }
 
+// ================================
+// SETTLED GAME VALIDATION
+// ================================
+
+/// Validate that a settled score is the one its game would earn
+///
+/// # Arguments
+/// * `is_solved` - Whether the player guessed the word
+/// * `guesses_used` - Number of guesses taken
+/// * `time_ms` - Time taken to complete in milliseconds
+/// * `score` - Score recorded on the session
+/// * `difficulty` - Word difficulty snapshotted on the session
+/// * `multiplier_bps` - Happy hour multiplier snapshotted on the session
+/// * `hole_in_one_bonus` - Hole in one bonus snapshotted on the session
+///
+/// # Returns
+/// `Ok(())` if `score` matches `scoring::score_game`, `Err` otherwise
+///
+/// # Example
+/// ```
+/// validate_score_consistency(true, 3, 30_000, 900, 0, 0, 0)?; // OK
+/// validate_score_consistency(true, 7, 30_000, 1_500, 0, 0, 0)?; // Error: ScoreMismatch
+/// ```
+pub fn validate_score_consistency(
+    is_solved: bool,
+    guesses_used: u8,
+    time_ms: u64,
+    score: u32,
+    difficulty: u8,
+    multiplier_bps: u16,
+    hole_in_one_bonus: u32,
+) -> Result<()> {
+    require!(
+        (1..=MAX_GUESSES).contains(&guesses_used),
+        VobleError::InvalidGuessCount
+    );
+
+    let expected = scoring::score_game(
+        is_solved,
+        guesses_used,
+        time_ms,
+        difficulty,
+        multiplier_bps,
+        hole_in_one_bonus,
+    );
+    require!(score == expected, VobleError::ScoreMismatch);
+
+    Ok(())
+}
+
+/// Validate that a settled game took long enough for its guesses
+///
+/// # Rules
+/// - At least MIN_MS_PER_GUESS per guess made
+///
+/// # Arguments
+/// * `guesses_used` - Number of guesses taken
+/// * `time_ms` - Time taken to complete in milliseconds
+///
+/// # Returns
+/// `Ok(())` if plausible, `Err` otherwise
+pub fn validate_time_plausibility(guesses_used: u8, time_ms: u64) -> Result<()> {
+    require!(
+        time_ms >= guesses_used as u64 * MIN_MS_PER_GUESS,
+        VobleError::ImplausibleTime
+    );
+
+    Ok(())
+}
+
+// ================================
+// PAYOUT TOKEN ACCOUNT VALIDATION
+// ================================
+
+/// Fields of a token account checked before paying out to it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayoutAccount {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub frozen: bool,
+}
+
+impl From<&TokenAccount> for PayoutAccount {
+    fn from(account: &TokenAccount) -> Self {
+        Self {
+            mint: account.mint,
+            owner: account.owner,
+            frozen: account.is_frozen(),
+        }
+    }
+}
+
+/// Validate a token account about to receive a payout
+///
+/// Catches the account shapes `transfer_checked` would otherwise reject with
+/// an opaque SPL error from inside the CPI.
+///
+/// # Arguments
+/// * `account` - The receiving token account
+/// * `expected_mint` - The config's USDC mint
+/// * `expected_owner` - Wallet the account must belong to (`None` = any wallet)
+///
+/// # Returns
+/// - `PayoutMintMismatch` for another mint's account
+/// - `PayoutAccountFrozen` for a frozen account
+/// - `PayoutOwnerMismatch` for someone else's account
+pub fn validate_payout_account(
+    account: &PayoutAccount,
+    expected_mint: &Pubkey,
+    expected_owner: Option<&Pubkey>,
+) -> Result<()> {
+    require_keys_eq!(account.mint, *expected_mint, VobleError::PayoutMintMismatch);
+    require!(!account.frozen, VobleError::PayoutAccountFrozen);
+    if let Some(owner) = expected_owner {
+        require_keys_eq!(account.owner, *owner, VobleError::PayoutOwnerMismatch);
+    }
+
+    Ok(())
+}
+
 // ================================
 // AMOUNT VALIDATION
 // ================================
 
-/// Validate a lamports amount is positive and non-zero
+/// Validate a token amount (base units) is positive and non-zero
 ///
 /// # Arguments
-/// * `amount` - Amount in lamports
+/// * `amount` - Amount in the mint's base units
 ///
 /// # Returns
 /// `Ok(())` if valid, `Err` otherwise
@@ -221,7 +537,7 @@ pub fn validate_amount_positive(amount: u64) -> Result<()> {
     Ok(())
 }
 
-/// Validate a lamports amount is within a range
+/// Validate a token amount (base units) is within a range
 ///
 /// # Arguments
 /// * `amount` - Amount to validate
@@ -338,6 +654,61 @@ pub fn clean_string(input: &str) -> String {
 // ACCOUNT VALIDATION
 // ================================
 
+// ================================
+// MINT VALIDATION
+// ================================
+
+/// Validate the decimals of the payment mint
+///
+/// # Arguments
+/// * `decimals` - Decimals of the mint used for tickets and prizes
+///
+/// # Returns
+/// `Ok(())` if between MIN_MINT_DECIMALS and MAX_MINT_DECIMALS, `Err` otherwise
+///
+/// # Notes
+/// A 0-decimal mint would make every basis-point split of the ticket price
+/// round to whole tokens, breaking prize math.
+pub fn validate_mint_decimals(decimals: u8) -> Result<()> {
+    require!(
+        (MIN_MINT_DECIMALS..=MAX_MINT_DECIMALS).contains(&decimals),
+        VobleError::InvalidMintDecimals
+    );
+    Ok(())
+}
+
+/// Validate that the config's display decimals match the payment mint
+///
+/// # Arguments
+/// * `currency_decimals` - Decimals clients format amounts with
+/// * `mint_decimals` - Decimals of the mint used for tickets and prizes
+///
+/// # Notes
+/// Amounts are always in the mint's base units, so display decimals that
+/// differ from the mint's would show every amount scaled by a power of ten.
+pub fn validate_currency_decimals(currency_decimals: u8, mint_decimals: u8) -> Result<()> {
+    require!(
+        currency_decimals == mint_decimals,
+        VobleError::CurrencyDecimalsMismatch
+    );
+    Ok(())
+}
+
+/// Validate the currency symbol clients show for amounts
+///
+/// # Rules
+/// - 1 to MAX_CURRENCY_SYMBOL_LENGTH bytes
+/// - Printable ASCII only, no spaces (e.g. "USDC", "PYUSD")
+pub fn validate_currency_symbol(symbol: &str) -> Result<()> {
+    require!(
+        !symbol.is_empty()
+            && symbol.len() <= MAX_CURRENCY_SYMBOL_LENGTH
+            && symbol.bytes().all(|b| b.is_ascii_graphic()),
+        VobleError::InvalidCurrencySymbol
+    );
+    Ok(())
+}
+
 /// Validate that a public key is not the default/system key
 ///
 /// # Arguments
@@ -367,6 +738,64 @@ pub fn validate_pubkey_match(expected: &Pubkey, actual: &Pubkey) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_consistent_scores_accepted() {
+        // 3 guesses in 30s: 600 base + 300 time bonus
+        assert!(validate_score_consistency(true, 3, 30_000, 900, 0, 0, 0).is_ok());
+        // Hard word, 2x happy hour: 30s is inside its 36s tier 1 window, 1100 * 1.2 * 2
+        assert!(validate_score_consistency(true, 3, 30_000, 2_640, 4, 20_000, 0).is_ok());
+        // Hole in one with a bonus: 1000 + 500 + 250
+        assert!(validate_score_consistency(true, 1, 10_000, 1_750, 0, 0, 250).is_ok());
+        // Unsolved games score nothing
+        assert!(validate_score_consistency(false, 7, 400_000, 0, 5, 30_000, 250).is_ok());
+
+        assert!(validate_time_plausibility(7, 7 * MIN_MS_PER_GUESS).is_ok());
+        assert!(validate_time_plausibility(1, 400_000).is_ok());
+    }
+
+    #[test]
+    fn test_impossible_scores_rejected() {
+        let impossible = [
+            // 1500 needs a first-guess solve; 7 guesses top out at 600
+            (true, 7, 10_000, 1_500, 0, 0, 0),
+            // Unsolved games can't score
+            (false, 3, 30_000, 900, 0, 0, 0),
+            // Slow solve claiming the speed bonus
+            (true, 3, 400_000, 1_100, 0, 0, 0),
+            // Multiplier not applied
+            (true, 3, 30_000, 900, 0, 20_000, 0),
+            // Hole in one bonus claimed on a later guess
+            (true, 2, 10_000, 1_550, 0, 0, 250),
+            // Easy word scored as neutral
+            (true, 3, 30_000, 900, 1, 0, 0),
+        ];
+        for (solved, guesses, time_ms, score, difficulty, multiplier, bonus) in impossible {
+            assert_eq!(
+                validate_score_consistency(solved, guesses, time_ms, score, difficulty, multiplier, bonus)
+                    .unwrap_err(),
+                VobleError::ScoreMismatch.into()
+            );
+        }
+
+        // Guess counts no game can end with
+        for guesses in [0, MAX_GUESSES + 1] {
+            assert_eq!(
+                validate_score_consistency(false, guesses, 30_000, 0, 0, 0, 0).unwrap_err(),
+                VobleError::InvalidGuessCount.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_impossible_times_rejected() {
+        for (guesses, time_ms) in [(1, 0), (3, 2_999), (7, 6_000)] {
+            assert_eq!(
+                validate_time_plausibility(guesses, time_ms).unwrap_err(),
+                VobleError::ImplausibleTime.into()
+            );
+        }
+    }
+
     #[test]
     fn test_validate_username() {
         // Valid usernames
@@ -377,7 +806,8 @@ mod tests {
 
         // Invalid usernames
         assert!(validate_username("ab").is_err()); // Too short
-        assert!(validate_username("a".repeat(21).as_str()).is_err()); // Too long
+        assert!(validate_username("a".repeat(32).as_str()).is_ok()); // At the limit
+        assert!(validate_username("a".repeat(33).as_str()).is_err()); // Too long
         assert!(validate_username("_alice").is_err()); // Starts with underscore
         assert!(validate_username("alice_").is_err()); // Ends with underscore
         assert!(validate_username("alice__bob").is_err()); // Consecutive underscores
@@ -385,6 +815,101 @@ mod tests {
         assert!(validate_username("alice@bob").is_err()); // Invalid character
     }
 
+    #[test]
+    fn test_validate_username_rejects_multibyte() {
+        // 11 characters but 22 bytes - would have passed a byte-length check
+        assert_eq!(
+            validate_username("ÅÅÅÅÅÅÅÅÅÅÅ").unwrap_err(),
+            VobleError::UsernameNotAscii.into()
+        );
+        // 20 characters, 60 bytes - over the account's max_len(32)
+        assert_eq!(
+            validate_username(&"名".repeat(20)).unwrap_err(),
+            VobleError::UsernameNotAscii.into()
+        );
+        assert_eq!(
+            validate_username("josé").unwrap_err(),
+            VobleError::UsernameNotAscii.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_bio() {
+        assert!(validate_bio("").is_ok());
+        assert!(validate_bio("Daily player.\nFavourite opener: CRANES").is_ok());
+        assert!(validate_bio(&"a".repeat(MAX_BIO_LENGTH)).is_ok());
+        assert!(validate_bio("Server admin by day").is_ok()); // Not a username
+
+        // Oversize, counted in bytes
+        assert_eq!(
+            validate_bio(&"a".repeat(MAX_BIO_LENGTH + 1)).unwrap_err(),
+            VobleError::InvalidBio.into()
+        );
+        assert_eq!(
+            validate_bio(&"é".repeat(MAX_BIO_LENGTH / 2 + 1)).unwrap_err(),
+            VobleError::InvalidBio.into()
+        );
+
+        // Control characters
+        assert_eq!(
+            validate_bio("hello\u{0}world").unwrap_err(),
+            VobleError::InvalidBio.into()
+        );
+        assert_eq!(
+            validate_bio("bell\u{7}").unwrap_err(),
+            VobleError::InvalidBio.into()
+        );
+        assert_eq!(
+            validate_bio("fresh\u{1b}[2J").unwrap_err(),
+            VobleError::InvalidBio.into()
+        );
+
+        assert_eq!(
+            validate_bio("what the FUCK").unwrap_err(),
+            VobleError::InappropriateContent.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_avatar_uri() {
+        assert!(validate_avatar_uri("").is_ok());
+        assert!(validate_avatar_uri("https://example.com/avatar.png").is_ok());
+        assert!(validate_avatar_uri("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oc").is_ok());
+
+        let oversize = format!("https://{}", "a".repeat(MAX_AVATAR_URI_LENGTH));
+        for uri in [
+            "http://example.com/avatar.png", // Not https
+            "javascript:alert(1)",
+            "data:image/png;base64,AAAA",
+            "https://",                      // No location
+            "HTTPS://example.com/a.png",     // Scheme is case sensitive
+            "https://example.com/a b.png",   // Whitespace
+            "https://example.com/\u{0}.png", // Control character
+            "ipfs://cid\n",
+            oversize.as_str(),
+        ] {
+            assert_eq!(
+                validate_avatar_uri(uri).unwrap_err(),
+                VobleError::InvalidAvatarUri.into(),
+                "{:?}",
+                uri
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_country_code() {
+        assert!(validate_country_code(b"PH").is_ok());
+        assert!(validate_country_code(&[0, 0]).is_ok()); // Hidden
+
+        for country in [b"ph", b"P1", b"P ", b"\0H"] {
+            assert_eq!(
+                validate_country_code(country).unwrap_err(),
+                VobleError::InvalidCountryCode.into()
+            );
+        }
+    }
+
     #[test]
     fn test_validate_period_id() {
         // Valid period IDs
@@ -401,18 +926,204 @@ mod tests {
         assert!(validate_period_id("Dabc").is_err()); // Non-numeric
     }
 
+    #[test]
+    fn test_non_canonical_period_numbers_rejected() {
+        // Each of these parses to a valid number but names an existing
+        // period under different seeds
+        for period_id in ["D05", "D007", "D00", "D+5", "W+45", "M012"] {
+            assert_eq!(
+                validate_period_id(period_id).unwrap_err(),
+                VobleError::InvalidPeriodState.into(),
+                "{}",
+                period_id
+            );
+        }
+        assert_eq!(
+            period_id_seed("D05", PeriodType::Daily).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+        assert!(period_id_seed("D5", PeriodType::Daily).is_ok());
+    }
+
+    #[test]
+    fn test_period_id_seed() {
+        assert_eq!(period_id_seed("W45", PeriodType::Weekly).unwrap(), b"W45");
+        assert_eq!(
+            period_id_seed(&format!("M{}", "9".repeat(32)), PeriodType::Monthly).unwrap_err(),
+            VobleError::PeriodIdTooLong.into()
+        );
+        assert_eq!(
+            period_id_seed("D45", PeriodType::Weekly).unwrap_err(),
+            VobleError::PeriodTypeMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_period_id_for_each_type() {
+        let types = [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly];
+        let ids = ["D123", "W45", "M12"];
+
+        for (i, &period_type) in types.iter().enumerate() {
+            for (j, period_id) in ids.iter().enumerate() {
+                let result = validate_period_id_for(period_id, period_type);
+                if i == j {
+                    assert!(result.is_ok());
+                } else {
+                    assert_eq!(result.unwrap_err(), VobleError::PeriodTypeMismatch.into());
+                }
+            }
+        }
+
+        // Malformed IDs still fail the format check first
+        assert_eq!(
+            validate_period_id_for("X123", PeriodType::Daily).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+        assert_eq!(
+            validate_period_id_for("2025-01-20", PeriodType::Daily).unwrap_err(),
+            VobleError::InvalidPeriodState.into()
+        );
+    }
+
+    #[test]
+    fn test_over_length_period_id_fails_cleanly() {
+        // Longer than a PDA seed allows; must be rejected before any seed use
+        let period_id = format!("D{}", "1".repeat(32));
+
+        assert_eq!(
+            validate_period_id(&period_id).unwrap_err(),
+            VobleError::PeriodIdTooLong.into()
+        );
+    }
+
     #[test]
     fn test_validate_guess() {
+        let letters = CharClass::Alphabetic;
+
         // Valid guesses
-        assert!(validate_guess("HELLO").is_ok());
-        assert!(validate_guess("hello").is_ok());
-        assert!(validate_guess("WoRdS").is_ok());
+        assert!(validate_guess("GARDEN", letters).is_ok());
+        assert!(validate_guess("garden", letters).is_ok());
+        assert!(validate_guess("GaRdEn", letters).is_ok());
 
         // Invalid guesses
-        assert!(validate_guess("HEL").is_err()); // Too short
-        assert!(validate_guess("HELLOO").is_err()); // Too long
-        assert!(validate_guess("HEL10").is_err()); // Contains number
-        assert!(validate_guess("HEL O").is_err()); // Contains space
+        assert!(validate_guess("HEL", letters).is_err()); // Too short
+        assert!(validate_guess("GARDENS", letters).is_err()); // Too long
+        assert!(validate_guess("GARD10", letters).is_err()); // Contains number
+        assert!(validate_guess("GAR EN", letters).is_err()); // Contains space
+    }
+
+    #[test]
+    fn test_validate_guess_follows_char_class() {
+        assert!(validate_guess("104729", CharClass::Numeric).is_ok());
+        assert!(validate_guess("GARD10", CharClass::Alphanumeric).is_ok());
+        assert!(validate_guess("b1n4ry", CharClass::Alphanumeric).is_ok());
+
+        for (guess, char_class) in [
+            ("GARDEN", CharClass::Numeric),
+            ("10472A", CharClass::Numeric),
+            ("10 729", CharClass::Numeric),
+            ("GARD-N", CharClass::Alphanumeric),
+        ] {
+            assert_eq!(
+                validate_guess(guess, char_class).unwrap_err(),
+                VobleError::InvalidGuess.into()
+            );
+        }
+        assert_eq!(
+            validate_guess("10472", CharClass::Numeric).unwrap_err(),
+            VobleError::InvalidGuessLength.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_word_list() {
+        let builtin = [&VOBLE_WORDS[..], &VOBLE_WORDS_WEEKLY, &VOBLE_WORDS_MONTHLY];
+        for words in builtin {
+            assert!(validate_word_list(words, CharClass::Alphabetic).is_ok());
+            assert!(validate_word_list(words, CharClass::Alphanumeric).is_ok());
+            assert_eq!(
+                validate_word_list(words, CharClass::Numeric).unwrap_err(),
+                VobleError::WordListCharClassMismatch.into()
+            );
+        }
+
+        assert!(validate_word_list(&["104729", "000123"], CharClass::Numeric).is_ok());
+        for words in [&["104729", "12345"][..], &["104729", "garden"]] {
+            assert_eq!(
+                validate_word_list(words, CharClass::Alphanumeric).unwrap_err(),
+                VobleError::WordListCharClassMismatch.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_guess_rejects_non_ascii_letters() {
+        for guess in [
+            "GARDÉN", // Accented letter
+            "gardén",
+            "GАRDEN", // Cyrillic А homoglyph
+            "QUАRTZ", // Cyrillic А homoglyph
+            "ΒRIDGE", // Greek Beta homoglyph
+            "ＧＡＲＤＥＮ", // Fullwidth letters
+        ] {
+            assert_eq!(
+                validate_guess(guess, CharClass::Alphabetic).unwrap_err(),
+                VobleError::NonAsciiGuess.into()
+            );
+        }
+
+        // ASCII non-letters keep the existing error
+        assert_eq!(
+            validate_guess("GARD3N", CharClass::Alphabetic).unwrap_err(),
+            VobleError::InvalidGuess.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_payout_account() {
+        let (mint, winner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let good = PayoutAccount {
+            mint,
+            owner: winner,
+            frozen: false,
+        };
+        assert!(validate_payout_account(&good, &mint, Some(&winner)).is_ok());
+        assert!(validate_payout_account(&good, &mint, None).is_ok());
+
+        let cases = [
+            (
+                PayoutAccount { mint: Pubkey::new_unique(), ..good },
+                Some(winner),
+                VobleError::PayoutMintMismatch,
+            ),
+            (
+                PayoutAccount { frozen: true, ..good },
+                Some(winner),
+                VobleError::PayoutAccountFrozen,
+            ),
+            (PayoutAccount { frozen: true, ..good }, None, VobleError::PayoutAccountFrozen),
+            (
+                PayoutAccount { owner: Pubkey::new_unique(), ..good },
+                Some(winner),
+                VobleError::PayoutOwnerMismatch,
+            ),
+            // Mint is checked first
+            (
+                PayoutAccount {
+                    mint: Pubkey::new_unique(),
+                    owner: Pubkey::new_unique(),
+                    frozen: true,
+                },
+                Some(winner),
+                VobleError::PayoutMintMismatch,
+            ),
+        ];
+        for (account, owner, expected) in cases {
+            assert_eq!(
+                validate_payout_account(&account, &mint, owner.as_ref()).unwrap_err(),
+                expected.into()
+            );
+        }
     }
 
     #[test]
@@ -431,6 +1142,39 @@ mod tests {
         assert!(validate_rank(4).is_err());
     }
 
+    #[test]
+    fn test_validate_mint_decimals() {
+        assert!(validate_mint_decimals(6).is_ok()); // USDC
+        assert!(validate_mint_decimals(1).is_ok());
+        assert!(validate_mint_decimals(9).is_ok());
+        assert!(validate_mint_decimals(0).is_err());
+        assert!(validate_mint_decimals(10).is_err());
+        assert!(validate_mint_decimals(18).is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_decimals() {
+        assert!(validate_currency_decimals(6, 6).is_ok());
+        assert_eq!(
+            validate_currency_decimals(6, 9).unwrap_err(),
+            VobleError::CurrencyDecimalsMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_currency_symbol() {
+        assert!(validate_currency_symbol("USDC").is_ok());
+        assert!(validate_currency_symbol("PYUSD").is_ok());
+        assert!(validate_currency_symbol("12345678").is_ok());
+
+        for symbol in ["", "123456789", "US DC", "USDĊ", "USD\n"] {
+            assert_eq!(
+                validate_currency_symbol(symbol).unwrap_err(),
+                VobleError::InvalidCurrencySymbol.into()
+            );
+        }
+    }
+
     #[test]
     fn test_validate_winner_splits() {
         // Valid splits
@@ -0,0 +1,251 @@
+//! Word List and Guess Evaluation Utilities
+//!
+//! The program's word lists and the pure helpers that read them. State
+//! (`GuessData::results`), gameplay and the share board all evaluate guesses
+//! the same way, so these live below the instruction layer.
+
+use crate::constants::*;
+use crate::errors::VobleError;
+use crate::state::{LetterResult, PeriodType};
+use anchor_lang::prelude::*;
+
+/// Word list for a competition and its first global word index
+///
+/// Sessions store a single global `word_index`; the lists are laid out
+/// back to back (daily, weekly, monthly) so existing daily indexes keep
+/// pointing at the same words.
+///
+/// # Returns
+/// `(words, offset)` where `words[i]` has global index `offset + i`
+pub fn word_list(source: PeriodType) -> (&'static [&'static str], u32) {
+    let weekly_offset = VOBLE_WORDS.len() as u32;
+    let monthly_offset = weekly_offset + VOBLE_WORDS_WEEKLY.len() as u32;
+
+    match source {
+        PeriodType::Daily => (&VOBLE_WORDS, 0),
+        PeriodType::Weekly => (&VOBLE_WORDS_WEEKLY, weekly_offset),
+        PeriodType::Monthly => (&VOBLE_WORDS_MONTHLY, monthly_offset),
+    }
+}
+
+/// Get a word from the word lists by global index
+///
+/// # Arguments
+/// * `word_index` - Global word index (see `word_list`)
+///
+/// # Returns
+/// The word at the specified index
+///
+/// # Errors
+/// `InvalidPeriodState` if the index is past the last list
+pub fn get_word_by_index(word_index: u32) -> Result<&'static str> {
+    [PeriodType::Daily, PeriodType::Weekly, PeriodType::Monthly]
+        .into_iter()
+        .map(word_list)
+        .find_map(|(words, offset)| {
+            word_index
+                .checked_sub(offset)
+                .and_then(|list_index| words.get(list_index as usize))
+        })
+        .copied()
+        .ok_or_else(|| error!(VobleError::InvalidPeriodState))
+}
+
+/// Validate that a word exists in any word list
+///
+/// # Arguments
+/// * `word` - The word to validate
+///
+/// # Returns
+/// True if the word exists in one of the lists, false otherwise
+pub fn is_valid_word(word: &str) -> bool {
+    let word_upper = word.to_uppercase();
+    VOBLE_WORDS
+        .iter()
+        .chain(VOBLE_WORDS_WEEKLY.iter())
+        .chain(VOBLE_WORDS_MONTHLY.iter())
+        .any(|&w| w == word_upper)
+}
+
+/// Get the total number of words available
+///
+/// # Returns
+/// The number of words across all three lists (one past the highest global index)
+pub fn get_word_count() -> usize {
+    VOBLE_WORDS.len() + VOBLE_WORDS_WEEKLY.len() + VOBLE_WORDS_MONTHLY.len()
+}
+
+/// Evaluate a guess against the target word (Wordle/Voble logic)
+///
+/// This implements the classic Wordle color-coding system:
+/// - Green (Correct): Letter is in the word and in the correct position
+/// - Yellow (Present): Letter is in the word but in wrong position
+/// - Gray (Absent): Letter is not in the word
+///
+/// Only characters are compared, so it works the same for every
+/// `CharClass`; `validate_guess` is what enforces the class.
+///
+/// # Arguments
+/// * `guess` - The player's guessed word (must be uppercase)
+/// * `target` - The target word to compare against (uppercase)
+///
+/// # Returns
+/// Array of 6 LetterResult indicating the status of each letter
+///
+/// # Algorithm
+/// 1. First pass: Mark all exact matches (correct position) as Green
+/// 2. Second pass: For remaining letters, check if they exist elsewhere (Yellow)
+/// 3. Letters marked in previous passes are consumed and won't be reused
+///
+/// # Example
+/// ```
+/// Target: "ANCHOR"
+/// Guess:  "CANTOR"
+/// Result: [Present, Present, Present, Absent, Correct, Correct]
+///         (C, A & N are in word but wrong pos, T not in word, O & R correct)
+/// ```
+pub fn evaluate_guess(guess: &str, target: &str) -> [LetterResult; WORD_LENGTH] {
+    let mut result = [LetterResult::Absent; WORD_LENGTH];
+    let mut target_chars: Vec<char> = target.chars().collect();
+    let guess_chars: Vec<char> = guess.to_uppercase().chars().collect();
+
+    // First pass: Mark correct positions (Green)
+    for i in 0..WORD_LENGTH {
+        if guess_chars[i] == target_chars[i] {
+            result[i] = LetterResult::Correct;
+            target_chars[i] = '\0'; // Mark as used
+        }
+    }
+
+    // Second pass: Mark present letters in wrong positions (Yellow)
+    for i in 0..WORD_LENGTH {
+        if matches!(result[i], LetterResult::Absent) {
+            if let Some(pos) = target_chars
+                .iter()
+                .position(|&c| c == guess_chars[i] && c != '\0')
+            {
+                result[i] = LetterResult::Present;
+                target_chars[pos] = '\0'; // Mark as used
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_word_by_index() {
+        for i in 0..get_word_count() {
+            let word = get_word_by_index(i as u32).unwrap();
+            assert_eq!(word.len(), 6);
+            assert!(word.chars().all(|c| c.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn test_is_valid_word() {
+        assert!(is_valid_word("ANCHOR"));
+        assert!(is_valid_word("anchor")); // Case insensitive
+        assert!(is_valid_word("SOLANA"));
+        assert!(!is_valid_word("NOTAWORD"));
+        assert!(!is_valid_word("XYZ"));
+    }
+
+    #[test]
+    fn test_get_word_count() {
+        assert_eq!(get_word_count(), 40);
+        assert!(get_word_by_index(40).is_err());
+    }
+
+    #[test]
+    fn test_word_lists_keep_daily_indexes() {
+        assert_eq!(get_word_by_index(18).unwrap(), "SOLANA");
+        assert_eq!(get_word_by_index(20).unwrap(), "ZEPHYR");
+        assert_eq!(get_word_by_index(30).unwrap(), "SYZYGY");
+    }
+
+    #[test]
+    fn test_frontend_word_list_matches() {
+        // The app derives letter results from wordIndex with its own copy
+        let source = include_str!("../../../../../src/lib/voble-words.ts");
+        let list = &source[source.find("VOBLE_WORDS = [").unwrap()..];
+        let list = &list[..list.find("] as const").unwrap()];
+        let frontend: Vec<&str> = list
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .flat_map(|line| line.split('\''))
+            .filter(|token| {
+                token.len() == WORD_LENGTH && token.chars().all(|c| c.is_ascii_uppercase())
+            })
+            .collect();
+
+        let program: Vec<&str> = (0..get_word_count() as u32)
+            .map(|index| get_word_by_index(index).unwrap())
+            .collect();
+        assert_eq!(frontend, program);
+    }
+
+    #[test]
+    fn test_evaluate_guess_all_correct() {
+        let result = evaluate_guess("ANCHOR", "ANCHOR");
+        assert!(result.iter().all(|&r| matches!(r, LetterResult::Correct)));
+    }
+
+    #[test]
+    fn test_evaluate_guess_all_absent() {
+        let result = evaluate_guess("ABCDEF", "GHIJKL");
+        assert!(result.iter().all(|&r| matches!(r, LetterResult::Absent)));
+    }
+
+    #[test]
+    fn test_evaluate_guess_mixed() {
+        let result = evaluate_guess("CANTOR", "ANCHOR");
+        // C, A, N - Present (in word but wrong position)
+        // T - Absent (not in word)
+        // O, R - Correct (right position)
+        assert!(matches!(result[0], LetterResult::Present)); // C
+        assert!(matches!(result[1], LetterResult::Present)); // A
+        assert!(matches!(result[2], LetterResult::Present)); // N
+        assert!(matches!(result[3], LetterResult::Absent)); // T
+        assert!(matches!(result[4], LetterResult::Correct)); // O
+        assert!(matches!(result[5], LetterResult::Correct)); // R
+    }
+
+    #[test]
+    fn test_evaluate_guess_duplicate_letters() {
+        let result = evaluate_guess("TEETER", "LETTER");
+        // T - Present (the target's other T is still unmatched)
+        // E - Correct
+        // E - Absent (both of the target's Es are already matched)
+        // T - Correct
+        // E - Correct
+        // R - Correct
+        assert!(matches!(result[0], LetterResult::Present)); // T
+        assert!(matches!(result[1], LetterResult::Correct)); // E
+        assert!(matches!(result[2], LetterResult::Absent)); // E
+        assert!(matches!(result[3], LetterResult::Correct)); // T
+        assert!(matches!(result[4], LetterResult::Correct)); // E
+        assert!(matches!(result[5], LetterResult::Correct)); // R
+    }
+
+    #[test]
+    fn test_evaluate_numeric_guess() {
+        use LetterResult::*;
+
+        assert_eq!(evaluate_guess("104729", "104729"), [Correct; WORD_LENGTH]);
+        // 1, 3, 5 and 8 in place; the swapped 2 and 1 are elsewhere in the target
+        assert_eq!(
+            evaluate_guess("121358", "112358"),
+            [Correct, Present, Present, Correct, Correct, Correct]
+        );
+        // The target's only 7 is matched in place, so the other 7s are absent
+        assert_eq!(
+            evaluate_guess("777000", "070000"),
+            [Absent, Correct, Absent, Correct, Correct, Correct]
+        );
+    }
+}
@@ -47,6 +47,7 @@ const SEEDS = {
   WEEKLY_PERIOD: "weekly_period",
   MONTHLY_PERIOD: "monthly_period",
   WINNER_ENTITLEMENT: "winner_entitlement",
+  USER_PROFILE: "user_profile",
 } as const;
 
 function getGlobalConfigPda(programId: PublicKey): [PublicKey, number] {
@@ -177,6 +178,20 @@ async function main() {
 
   // 2) Finalize period (daily/weekly/monthly)
   console.log("\n📊 Step 2: Finalize period and calculate prizes...");
+
+  // Winners' profiles let the program refresh their usernames before ranking
+  const leaderboard: any = await (program.account as any).periodLeaderboard.fetch(
+    leaderboardPda
+  );
+  const winnerProfiles = (leaderboard.entries as any[]).slice(0, 3).map((entry) => ({
+    pubkey: PublicKey.findProgramAddressSync(
+      [Buffer.from(SEEDS.USER_PROFILE, "utf8"), entry.player.toBuffer()],
+      program.programId
+    )[0],
+    isSigner: false,
+    isWritable: false,
+  }));
+
   try {
     let tx: string;
     if (periodType === "daily") {
@@ -189,10 +204,15 @@ async function main() {
             [Buffer.from("daily_prize_vault", "utf8")],
             program.programId
           )[0],
+          vaultLedger: PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_ledger", "utf8"), Buffer.from([0])],
+            program.programId
+          )[0],
           leaderboard: leaderboardPda,
           authority,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
+        .remainingAccounts(winnerProfiles)
         .rpc();
     } else if (periodType === "weekly") {
       tx = await (program.methods as any)
@@ -204,10 +224,15 @@ async function main() {
             [Buffer.from("weekly_prize_vault", "utf8")],
             program.programId
           )[0],
+          vaultLedger: PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_ledger", "utf8"), Buffer.from([1])],
+            program.programId
+          )[0],
           leaderboard: leaderboardPda,
           authority,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
+        .remainingAccounts(winnerProfiles)
         .rpc();
     } else {
       tx = await (program.methods as any)
@@ -219,10 +244,15 @@ async function main() {
             [Buffer.from("monthly_prize_vault", "utf8")],
             program.programId
           )[0],
+          vaultLedger: PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_ledger", "utf8"), Buffer.from([2])],
+            program.programId
+          )[0],
           leaderboard: leaderboardPda,
           authority,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
+        .remainingAccounts(winnerProfiles)
         .rpc();
     }
 
@@ -249,7 +279,7 @@ async function main() {
   console.log(
     "   Vault balance at finalization:",
     vaultBalanceAtFinalization.toString(),
-    "lamports"
+    "base units"
   );
 
   // Recompute prize splits off-chain using same logic as on-chain
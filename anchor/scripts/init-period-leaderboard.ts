@@ -3,8 +3,8 @@
  *
  * Usage examples:
  *   npx ts-node scripts/init-period-leaderboard.ts --type daily
- *   npx ts-node scripts/init-period-leaderboard.ts --type weekly --period 2025-W03
- *   npx ts-node scripts/init-period-leaderboard.ts --type monthly --period 2025-01
+ *   npx ts-node scripts/init-period-leaderboard.ts --type weekly --period W3
+ *   npx ts-node scripts/init-period-leaderboard.ts --type monthly --period M1
  */
 
 import * as anchor from "@coral-xyz/anchor";
@@ -20,32 +20,26 @@ const PERIOD_TYPE_BYTES = {
 
 type PeriodType = keyof typeof PERIOD_TYPE_BYTES;
 
-function getDefaultPeriodId(type: PeriodType): string {
-  // Get current time in UTC+8
-  const now = new Date();
-  const utc8TimeStr = now.toLocaleString("en-US", { timeZone: "Asia/Singapore" });
-  const nowUtc8 = new Date(utc8TimeStr);
-
-  if (type === "daily") {
-    const year = nowUtc8.getFullYear();
-    const month = (nowUtc8.getMonth() + 1).toString().padStart(2, "0");
-    const day = nowUtc8.getDate().toString().padStart(2, "0");
-    return `${year}-${month}-${day}`;
-  }
+// Default timestamp of period 0 (PERIOD_EPOCH_START in constants.rs)
+const PERIOD_EPOCH_START = 1704038400;
 
-  if (type === "weekly") {
-    const year = nowUtc8.getFullYear();
-    const startOfYear = new Date(year, 0, 1);
-    const days = Math.floor(
-      (nowUtc8.getTime() - startOfYear.getTime()) / (24 * 60 * 60 * 1000)
-    );
-    const week = Math.ceil((days + startOfYear.getDay() + 1) / 7);
-    return `${year}-W${week.toString().padStart(2, "0")}`;
-  }
+const PERIOD_SECONDS = {
+  daily: 24 * 60 * 60,
+  weekly: 7 * 24 * 60 * 60,
+  monthly: 30 * 24 * 60 * 60,
+} as const;
 
-  const year = nowUtc8.getFullYear();
-  const month = (nowUtc8.getMonth() + 1).toString().padStart(2, "0");
-  return `${year}-${month}`; // monthly
+const PERIOD_PREFIXES = {
+  daily: "D",
+  weekly: "W",
+  monthly: "M",
+} as const;
+
+// Same numbering as get_current_period_id: whole periods since the epoch.
+// The program rejects IDs whose prefix doesn't match the period type.
+function getDefaultPeriodId(type: PeriodType, epochStart = PERIOD_EPOCH_START): string {
+  const elapsed = Math.floor(Date.now() / 1000) - epochStart;
+  return `${PERIOD_PREFIXES[type]}${Math.floor(elapsed / PERIOD_SECONDS[type])}`;
 }
 
 type ParsedArgs = {
@@ -87,8 +81,7 @@ function parseArgs(): ParsedArgs {
     return { periodTypes: ["daily", "weekly", "monthly"], periodIdOverride: undefined };
   }
 
-  const id = periodId ?? getDefaultPeriodId(periodType);
-  return { periodTypes: [periodType], periodIdOverride: periodId ? id : undefined };
+  return { periodTypes: [periodType], periodIdOverride: periodId };
 }
 
 async function main() {
@@ -130,8 +123,12 @@ async function main() {
     program.programId
   );
 
+  // Deployments may start period 0 at their own epoch
+  const config: any = await (program.account as any).globalConfig.fetch(globalConfigPda);
+  const epochStart = config.periodEpochStart.toNumber() || PERIOD_EPOCH_START;
+
   for (const periodType of periodTypes) {
-    const periodId = periodIdOverride ?? getDefaultPeriodId(periodType);
+    const periodId = periodIdOverride ?? getDefaultPeriodId(periodType, epochStart);
     const periodTypeByte = PERIOD_TYPE_BYTES[periodType];
 
     console.log("\n🕒 Period:", periodType, periodId);
@@ -155,7 +152,7 @@ async function main() {
 
     try {
       const tx = await program.methods
-        .initializePeriodLeaderboard(periodId, periodTypeByte)
+        .initializePeriodLeaderboard(periodId, periodTypeByte, null)
         .accounts({
           leaderboard: leaderboardPda,
           globalConfig: globalConfigPda,
@@ -56,7 +56,7 @@ async function main() {
 
     if (existingConfig) {
       console.log("✅ Global Config already initialized!");
-      console.log("   Ticket Price:", existingConfig.ticketPrice.toString(), "lamports");
+      console.log("   Ticket Price:", existingConfig.ticketPrice.toString(), "base units");
       console.log("   Authority:", existingConfig.authority.toString());
     } else {
       // USDC Devnet Mint
@@ -72,7 +72,7 @@ async function main() {
       const winnerSplits = [5000, 3000, 2000]; // 50%, 30%, 20% for top 3 winners
 
       const tx = await program.methods
-        .initializeGlobalConfig(
+        .initializeGlobalConfig({
           ticketPrice,
           prizeSplitDaily,
           prizeSplitWeekly,
@@ -80,8 +80,11 @@ async function main() {
           platformRevenueSplit,
           luckyDrawSplit,
           winnerSplits,
-          USDC_MINT
-        )
+          usdcMint: USDC_MINT,
+          periodEpochStart: null, // period numbering starts at the default epoch
+          currencySymbol: null, // currency symbol defaults to "USDC"
+          currencyDecimals: null, // currency decimals default to 6
+        })
         .accounts({
           authority: authority,
         })
@@ -164,6 +167,42 @@ async function main() {
 
   console.log("");
 
+  // Step 2b: Vault ledgers (inflow/outflow counters for reconciliation)
+  console.log("Step 2b: Initializing Vault Ledgers...");
+
+  const ledgerVaults: [number, string, anchor.web3.PublicKey][] = [
+    [0, "Daily", dailyVaultPda],
+    [1, "Weekly", weeklyVaultPda],
+    [2, "Monthly", monthlyVaultPda],
+    [3, "Platform", platformVaultPda],
+    [4, "Lucky Draw", luckyDrawVaultPda],
+  ];
+
+  for (const [kind, label, vault] of ledgerVaults) {
+    const [ledgerPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_ledger"), Buffer.from([kind])],
+      program.programId
+    );
+
+    if (await provider.connection.getAccountInfo(ledgerPda)) {
+      console.log(`✅ ${label} ledger already initialized:`, ledgerPda.toString());
+      continue;
+    }
+
+    const tx = await program.methods
+      .initializeVaultLedger(kind)
+      .accounts({
+        vaultLedger: ledgerPda,
+        vault,
+        authority: authority,
+      })
+      .rpc();
+    console.log(`✅ ${label} ledger initialized:`, ledgerPda.toString());
+    console.log("   Transaction:", tx);
+  }
+
+  console.log("");
+
   console.log("Step 3: Initializing today's daily leaderboard...");
 
   const now = new Date();
@@ -191,7 +230,7 @@ async function main() {
       console.log("   Leaderboard PDA:", todayDailyLeaderboardPda.toString());
     } else {
       const tx = await program.methods
-        .initializePeriodLeaderboard(todayPeriodId, 0)
+        .initializePeriodLeaderboard(todayPeriodId, 0, null)
         .accounts({
           leaderboard: todayDailyLeaderboardPda,
           globalConfig: globalConfigPda,
@@ -51,21 +51,47 @@ async function main() {
     // Check current config
     const currentConfig = await (program.account as any).globalConfig.fetch(globalConfigPda);
     console.log("📊 Current Config:");
-    console.log("   Ticket Price:", currentConfig.ticketPrice.toString(), "lamports");
+    console.log("   Ticket Price:", currentConfig.ticketPrice.toString(), "base units");
     console.log("");
 
-    // Update ticket price to 0.001 SOL (1,000,000 lamports)
+    // Update ticket price to 1 USDC (1,000,000 base units at 6 decimals)
     const newTicketPrice = new anchor.BN(1_000_000);
     
-    console.log("🔄 Updating ticket price to:", newTicketPrice.toString(), "lamports (0.001 SOL)");
+    console.log("🔄 Updating ticket price to:", newTicketPrice.toString(), "base units (1 USDC)");
     
     const tx = await program.methods
-      .setConfig(
-        newTicketPrice,  // new ticket price
-        null            // keep paused state unchanged
-      )
+      // Every other field is null to leave it unchanged
+      .setConfig({
+        ticketPrice: newTicketPrice,
+        paused: null,
+        treasury: null,
+        sweepIntervalSeconds: null,
+        platformFloat: null,
+        sweepBounty: null,
+        ticketCooldownSecs: null,
+        wordStrategy: null,
+        maxParticipantsPerPeriod: null,
+        leaderboardMode: null,
+        remainderPolicy: null,
+        splitTiedPrizes: null,
+        sessionStaleAfterSecs: null,
+        maxActiveSessions: null,
+        handlerComputeUnits: null,
+        handlerEscrowIndex: null,
+        splitStatsHandlers: null,
+        holeInOneBonus: null,
+        graceWindowSeconds: null,
+        notifyLamports: null,
+        streakDecayPeriods: null,
+        allowMulligan: null,
+        sharedWordWindow: null,
+        leaderboardCapacity: null,
+        charClass: null,
+        ticketWordSource: null,
+      })
       .accounts({
         authority: authority,
+        pendingAction: null, // queue_admin_action first for price changes beyond ±10%
       })
       .rpc();
 
@@ -76,7 +102,7 @@ async function main() {
     // Verify update
     const updatedConfig = await (program.account as any).globalConfig.fetch(globalConfigPda);
     console.log("📊 Updated Config:");
-    console.log("   Ticket Price:", updatedConfig.ticketPrice.toString(), "lamports (0.001 SOL)");
+    console.log("   Ticket Price:", updatedConfig.ticketPrice.toString(), "base units (1 USDC)");
     console.log("");
     console.log("🎉 Config update complete!");
 
@@ -19,12 +19,26 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Voble } from "../target/types/voble";
-import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import { expect } from "chai";
 // Note: Delegation PDAs will be derived manually
 // MagicBlock Delegation Program ID
 const DELEGATION_PROGRAM_ID = new PublicKey("DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh");
 
+// Default timestamp of period 0 (PERIOD_EPOCH_START)
+const PERIOD_EPOCH_START = 1704038400;
+
+// Tickets are only sold for the current daily period, "D{days since epoch}"
+const currentDailyPeriodId = (epochStart: number) =>
+  `D${Math.floor((Date.now() / 1000 - epochStart) / 86_400)}`;
+
 describe("voble", () => {
   // Configure the client to use the local cluster
   const provider = anchor.AnchorProvider.env();
@@ -56,11 +70,12 @@ describe("voble", () => {
   let weeklyPrizeVault: PublicKey;
   let monthlyPrizeVault: PublicKey;
   let platformVault: PublicKey;
+  let luckyDrawVault: PublicKey;
   
   // No longer need delegation accounts - handled by #[delegate] macro
 
   const username = "TestPlayer";
-  const periodId = "2025-01-20"; // Test period ID
+  let periodId: string; // Current daily period, set in before()
 
   before(async () => {
     console.log("🎮 Setting up Voble test environment...");
@@ -82,8 +97,13 @@ describe("voble", () => {
       program.programId
     );
 
+    // Deployments may start period 0 at their own epoch
+    const existingConfig = await program.account.globalConfig.fetchNullable(globalConfigPda);
+    const epochStart = existingConfig?.periodEpochStart.toNumber() || PERIOD_EPOCH_START;
+    periodId = currentDailyPeriodId(epochStart);
+
     [leaderboardPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("leaderboard"), Buffer.from(periodId), Buffer.from("daily")],
+      [Buffer.from("leaderboard"), Buffer.from(periodId), Buffer.from([0])], // PeriodType::Daily seed byte
       program.programId
     );
 
@@ -108,6 +128,11 @@ describe("voble", () => {
       program.programId
     );
 
+    [luckyDrawVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lucky_draw_vault")],
+      program.programId
+    );
+
     // Delegation accounts are now auto-generated by #[delegate] macro
 
     console.log("User Profile PDA:", userProfilePda.toBase58());
@@ -239,6 +264,12 @@ describe("voble", () => {
         console.log(`✅ New balance: ${newBalance / LAMPORTS_PER_SOL} SOL`);
       }
 
+      // Snapshot vault balances to check the payment split afterwards
+      const vaults = [dailyPrizeVault, weeklyPrizeVault, monthlyPrizeVault, platformVault, luckyDrawVault];
+      const vaultBalance = async (vault: PublicKey) =>
+        BigInt((await provider.connection.getTokenAccountBalance(vault)).value.amount);
+      const balancesBefore = await Promise.all(vaults.map(vaultBalance));
+
       console.log("🎫 Buying ticket and creating session...");
       
       // Buy ticket and create session (no delegation yet)
@@ -262,6 +293,23 @@ describe("voble", () => {
       // Wait for transaction confirmation
       await provider.connection.confirmTransaction(txHash, "confirmed");
 
+      // Every vault received exactly its configured share of the ticket
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      const ticketPrice = BigInt(config.ticketPrice.toString());
+      const splits = [
+        config.prizeSplitDaily,
+        config.prizeSplitWeekly,
+        config.prizeSplitMonthly,
+        config.platformRevenueSplit,
+        config.luckyDrawSplit,
+      ];
+      const balancesAfter = await Promise.all(vaults.map(vaultBalance));
+      balancesAfter.forEach((after, i) => {
+        const expected = (ticketPrice * BigInt(splits[i])) / BigInt(10_000);
+        expect((after - balancesBefore[i]).toString()).to.equal(expected.toString());
+      });
+      console.log("✅ Ticket payment split verified across all vaults");
+
       // Verify session was created
       console.log("\n🔍 Verifying session creation...");
       sessionAccountInfo = await provider.connection.getAccountInfo(sessionPda);
@@ -277,7 +325,7 @@ describe("voble", () => {
       // Now delegate session to ER
       console.log("\n⚡ Delegating session to ER...");
       const delegateTx = await program.methods
-        .delegateSession()
+        .delegateSession(null)
         .accounts({
           payer: payer.publicKey,
           pda: sessionPda,
@@ -324,6 +372,149 @@ describe("voble", () => {
     }
   });
 
+  it("Buys a first ticket from a brand-new wallet in one transaction", async () => {
+    console.log("\n🆕 Test: First purchase creates the token account and leaderboard");
+
+    const config = await program.account.globalConfig.fetch(globalConfigPda);
+    const mint = config.usdcMint;
+    const { TOKEN_PROGRAM_ID, ASSOCIATED_PROGRAM_ID } = anchor.utils.token;
+    const mintInfo = await provider.connection.getAccountInfo(mint);
+    const decimals = mintInfo!.data[44]; // Mint layout: decimals after supply
+
+    const profilePda = (player: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("user_profile"), player.toBuffer()],
+        program.programId
+      )[0];
+
+    // Profile creation and the ticket go in a single transaction
+    const firstPurchase = async (player: Keypair) => {
+      const tx = new Transaction().add(
+        await program.methods
+          .initializeUserProfile("NewPlayer")
+          .accounts({
+            payer: player.publicKey,
+            userProfile: profilePda(player.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+        await program.methods
+          .buyTicketAndStartGame(periodId)
+          .accounts({
+            payer: player.publicKey,
+            mint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .instruction()
+      );
+      return provider.sendAndConfirm(tx, [player]);
+    };
+
+    const fundSol = async (player: PublicKey) =>
+      provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: player,
+            lamports: 0.1 * LAMPORTS_PER_SOL,
+          })
+        )
+      );
+
+    // A wallet that has never held USDC now gets a balance error, not a missing account
+    const unfunded = Keypair.generate();
+    await fundSol(unfunded.publicKey);
+    try {
+      await firstPurchase(unfunded);
+      expect.fail("Purchase without USDC should have been rejected");
+    } catch (error) {
+      expect(String(error)).to.include("InsufficientTicketFunds");
+      console.log("✅ Wallet without USDC rejected with InsufficientTicketFunds");
+    }
+
+    // A wallet whose USDC was sent over from another wallet's account
+    const player = Keypair.generate();
+    await fundSol(player.publicKey);
+    const funderAta = anchor.utils.token.associatedAddress({ mint, owner: payer.publicKey });
+    const playerAta = anchor.utils.token.associatedAddress({ mint, owner: player.publicKey });
+    const ticketPrice = BigInt(config.ticketPrice.toString());
+    const transferData = Buffer.alloc(10);
+    transferData.writeUInt8(12, 0); // TransferChecked
+    transferData.writeBigUInt64LE(ticketPrice, 1);
+    transferData.writeUInt8(decimals, 9);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        new TransactionInstruction({
+          programId: ASSOCIATED_PROGRAM_ID,
+          keys: [
+            { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+            { pubkey: playerAta, isSigner: false, isWritable: true },
+            { pubkey: player.publicKey, isSigner: false, isWritable: false },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          ],
+          data: Buffer.from([1]), // CreateIdempotent
+        }),
+        new TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: funderAta, isSigner: false, isWritable: true },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: playerAta, isSigner: false, isWritable: true },
+            { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+          ],
+          data: transferData,
+        })
+      )
+    );
+
+    const boardBefore = await program.account.periodLeaderboard.fetchNullable(leaderboardPda);
+    console.log(
+      boardBefore
+        ? `   Leaderboard for ${periodId} exists (${boardBefore.entrants} entrants)`
+        : `   Leaderboard for ${periodId} doesn't exist yet`
+    );
+
+    const txHash = await firstPurchase(player);
+    console.log("✅ First ticket bought. Tx:", txHash);
+
+    // The day's board exists and counts the new player
+    const board = await program.account.periodLeaderboard.fetch(leaderboardPda);
+    expect(board.periodId).to.equal(periodId);
+    expect(board.entrants).to.equal((boardBefore?.entrants ?? 0) + 1);
+
+    const balance = await provider.connection.getTokenAccountBalance(playerAta);
+    expect(balance.value.amount).to.equal("0");
+
+    // A period other than today's is rejected before anything is created
+    const stranger = Keypair.generate();
+    await fundSol(stranger.publicKey);
+    try {
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          await program.methods
+            .initializeUserProfile("Stranger")
+            .accounts({
+              payer: stranger.publicKey,
+              userProfile: profilePda(stranger.publicKey),
+              systemProgram: SystemProgram.programId,
+            })
+            .instruction(),
+          await program.methods
+            .buyTicketAndStartGame("D999999")
+            .accounts({ payer: stranger.publicKey, mint, tokenProgram: TOKEN_PROGRAM_ID })
+            .instruction()
+        ),
+        [stranger]
+      );
+      expect.fail("Future period should have been rejected");
+    } catch (error) {
+      expect(String(error)).to.include("PeriodNotCurrent");
+      console.log("✅ Future period rejected with PeriodNotCurrent");
+    }
+  });
+
   it("Submits guesses (on Ephemeral Rollup)", async () => {
     console.log("\n🎯 Test: Submit Guesses on ER");
 
@@ -342,6 +533,8 @@ describe("voble", () => {
           .submitGuess(periodId, guess)
           .accountsStrict({
             session: sessionPda,
+            userProfile: userProfilePda,
+            signer: providerEphemeralRollup.wallet.publicKey,
           })
           .transaction();
 
@@ -376,7 +569,7 @@ describe("voble", () => {
       console.log("✅ All guesses recorded on ER:");
       console.log("   Guesses used:", session.guessesUsed);
       console.log("   Is solved:", session.isSolved);
-      console.log("   Guesses:", session.guesses.filter(g => g !== null).map(g => g.guess));
+      console.log("   Guesses:", session.guesses.filter(g => g !== null).map(g => String.fromCharCode(...g.guess)));
       
       // Verify session is still on ER (owner should be delegation program or ER validator)
       console.log("\n📊 Session ownership:");
@@ -390,6 +583,36 @@ describe("voble", () => {
     }
   });
 
+  it("Rejects a guess from a stranger (on Ephemeral Rollup)", async () => {
+    console.log("\n🚫 Test: Stranger cannot write into another player's session");
+
+    const stranger = anchor.web3.Keypair.generate();
+
+    let tx = await program.methods
+      .submitGuess(periodId, "STRANG")
+      .accountsStrict({
+        session: sessionPda,
+        userProfile: userProfilePda,
+        signer: stranger.publicKey,
+      })
+      .transaction();
+
+    tx.feePayer = providerEphemeralRollup.wallet.publicKey;
+    tx.recentBlockhash = (
+      await providerEphemeralRollup.connection.getLatestBlockhash()
+    ).blockhash;
+    tx.partialSign(stranger);
+    tx = await providerEphemeralRollup.wallet.signTransaction(tx);
+
+    try {
+      await providerEphemeralRollup.sendAndConfirm(tx);
+      expect.fail("Stranger guess should have been rejected");
+    } catch (error) {
+      expect(String(error)).to.include("Unauthorized");
+      console.log("✅ Stranger guess rejected with Unauthorized");
+    }
+  });
+
   it("Completes game (undelegates session from ER)", async () => {
     console.log("\n🏁 Test: Complete Game and Undelegate from ER");
 
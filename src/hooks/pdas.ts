@@ -13,6 +13,7 @@ export const PDA_SEEDS = {
   MONTHLY_PRIZE_VAULT: 'monthly_prize_vault',
   PLATFORM_VAULT: 'platform_vault',
   LUCKY_DRAW_VAULT: 'lucky_draw_vault',
+  VAULT_LEDGER: 'vault_ledger',
   LEADERBOARD: 'leaderboard',
   DAILY_PERIOD: 'daily_period',
   WEEKLY_PERIOD: 'weekly_period',
@@ -110,6 +111,27 @@ export function getLuckyDrawVaultPDA(): [PublicKey, number] {
   )
 }
 
+/**
+ * Vault kinds tracked by vault ledgers (matches VaultKind on-chain)
+ */
+export const VAULT_KIND = {
+  daily: 0,
+  weekly: 1,
+  monthly: 2,
+  platform: 3,
+  luckyDraw: 4,
+} as const
+
+/**
+ * Derive the inflow/outflow ledger PDA of a vault
+ */
+export function getVaultLedgerPDA(kind: keyof typeof VAULT_KIND): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.VAULT_LEDGER, 'utf8'), Buffer.from([VAULT_KIND[kind]])],
+    VOBLE_PROGRAM_ID
+  )
+}
+
 
 
 /**
@@ -20,7 +20,8 @@ import {
   getWeeklyPrizeVaultPDA,
   getMonthlyPrizeVaultPDA,
   getPlatformVaultPDA,
-  getLuckyDrawVaultPDA
+  getLuckyDrawVaultPDA,
+  getVaultLedgerPDA,
 } from './pdas'
 import { handleTransactionError } from './utils'
 
@@ -91,11 +92,17 @@ export function useBuyTicket() {
           monthlyPrizeVault: monthlyPrizeVaultPDA,
           platformVault: platformVaultPDA,
           luckyDrawVault: luckyDrawVaultPDA,
+          dailyVaultLedger: getVaultLedgerPDA('daily')[0],
+          weeklyVaultLedger: getVaultLedgerPDA('weekly')[0],
+          monthlyVaultLedger: getVaultLedgerPDA('monthly')[0],
+          platformVaultLedger: getVaultLedgerPDA('platform')[0],
+          luckyDrawVaultLedger: getVaultLedgerPDA('luckyDraw')[0],
           payerTokenAccount: getAssociatedTokenAddressSync(new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), playerPublicKey),
           mint: new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
           systemProgram: SYSTEM_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          sessionSigner: tempKeypair ? tempKeypair.publicKey : null, // Allowed to sign guesses on ER
         })
         .instruction()
 
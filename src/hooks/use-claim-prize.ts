@@ -10,6 +10,7 @@ import {
   getDailyPrizeVaultPDA,
   getWeeklyPrizeVaultPDA,
   getMonthlyPrizeVaultPDA,
+  getVaultLedgerPDA,
 } from './pdas'
 import { createConnection, handleTransactionError } from './utils'
 
@@ -163,6 +164,7 @@ export function useClaimPrize() {
       } else {
         ;[vaultPda] = getMonthlyPrizeVaultPDA()
       }
+      const [vaultLedgerPda] = getVaultLedgerPDA(periodType)
 
       console.log('[useClaimPrize] claimPrize:PDAs', {
         entitlementPda: entitlementPda.toBase58(),
@@ -176,6 +178,7 @@ export function useClaimPrize() {
           .accounts({
             winnerEntitlement: entitlementPda,
             dailyPrizeVault: vaultPda,
+            vaultLedger: vaultLedgerPda,
             winner: playerPublicKey,
             winnerTokenAccount: getAssociatedTokenAddressSync(new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), playerPublicKey),
             usdcMint: new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
@@ -190,6 +193,7 @@ export function useClaimPrize() {
           .accounts({
             winnerEntitlement: entitlementPda,
             weeklyPrizeVault: vaultPda,
+            vaultLedger: vaultLedgerPda,
             winner: playerPublicKey,
             winnerTokenAccount: getAssociatedTokenAddressSync(new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), playerPublicKey),
             usdcMint: new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
@@ -204,6 +208,7 @@ export function useClaimPrize() {
           .accounts({
             winnerEntitlement: entitlementPda,
             monthlyPrizeVault: vaultPda,
+            vaultLedger: vaultLedgerPda,
             winner: playerPublicKey,
             winnerTokenAccount: getAssociatedTokenAddressSync(new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), playerPublicKey),
             usdcMint: new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
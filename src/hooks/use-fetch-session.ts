@@ -5,6 +5,7 @@ import { Program } from '@coral-xyz/anchor'
 import { vobleProgram, createReadOnlyProvider } from './program'
 import { getSessionPDA } from './pdas'
 import IDL from '@/idl/idl.json'
+import { evaluateGuess } from '@/lib/voble-words'
 
 export type LetterResult = 'Correct' | 'Present' | 'Absent'
 
@@ -145,15 +146,11 @@ export function useFetchSession(periodId: string): FetchSessionResult {
           targetWord: sessionAccount.targetWord,
           guesses: sessionAccount.guesses.map((guess: any) => {
             if (!guess) return null
+            // Only the letters are stored on-chain; derive colours from the word index
+            const word = String.fromCharCode(...guess.guess)
             return {
-              guess: guess.guess,
-              result: guess.result.map((r: any) => {
-                // Convert enum variants to string
-                if (r.correct !== undefined) return 'Correct'
-                if (r.present !== undefined) return 'Present'
-                if (r.absent !== undefined) return 'Absent'
-                return 'Absent' // fallback
-              }),
+              guess: word,
+              result: evaluateGuess(word, sessionAccount.wordIndex),
               timestamp: guess.timestamp?.toNumber() || 0,
             }
           }),
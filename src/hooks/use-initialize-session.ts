@@ -45,7 +45,7 @@ export function useInitializeSession() {
 
       // Create delegate session instruction
       const delegateIx = await vobleProgram.methods
-        .delegateSession()
+        .delegateSession(null)
         .accounts({
           payer: signerPublicKey,
           pda: sessionPDA,
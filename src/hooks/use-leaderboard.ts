@@ -13,6 +13,8 @@ export interface LeaderboardRow {
   guessesUsed: number
   timeMs: number
   timestamp: number
+  equippedFlair: number // 0 = none
+  gamesPlayed: number
 }
 
 export interface UseLeaderboardResult {
@@ -98,6 +100,8 @@ export function useLeaderboard(periodType: PeriodType): UseLeaderboardResult {
         guessesUsed: Number(e.guessesUsed ?? e.guesses_used ?? 0),
         timeMs: Number(e.timeMs ?? e.time_ms ?? 0),
         timestamp: Number(e.timestamp ?? 0),
+        equippedFlair: Number(e.equippedFlair ?? e.equipped_flair ?? 0),
+        gamesPlayed: Number(e.gamesPlayed ?? e.games_played ?? 1),
       }))
 
       const totalPlayers: number = Number(
@@ -10,7 +10,7 @@ import
 import { erConnection } from '@/hooks/mb-er/er-connection'
 
 import { vobleProgram, createVobleProgram } from './program'
-import { getSessionPDA } from './pdas'
+import { getSessionPDA, getUserProfilePDA } from './pdas'
 import { useTempKeypair } from '@/hooks/use-temp-keypair'
 
 import { 
@@ -48,6 +48,7 @@ export function useRecordKeystroke() {
 
       const signerPublicKey = new PublicKey(selectedWallet.address)
       const [sessionPDA] = getSessionPDA(signerPublicKey)
+      const [userProfilePDA] = getUserProfilePDA(signerPublicKey)
 
       if (process.env.NODE_ENV === 'development') {
         try {
@@ -74,6 +75,8 @@ export function useRecordKeystroke() {
         .recordKeystroke(key)
         .accounts({
           session: sessionPDA,
+          userProfile: userProfilePDA,
+          signer: tempKeypair.publicKey,
         })
         .transaction();
       
@@ -2,7 +2,7 @@ import { useConnectedStandardWallets } from '@privy-io/react-auth/solana'
 import { PublicKey, sendAndConfirmTransaction } from '@solana/web3.js'
 import { erConnection } from '@/hooks/mb-er/er-connection'
 import { vobleProgram } from './program'
-import { getSessionPDA } from './pdas'
+import { getSessionPDA, getUserProfilePDA } from './pdas'
 import { useTempKeypair } from '@/hooks/use-temp-keypair'
 
 export interface SubmitGuessResult {
@@ -43,12 +43,15 @@ export function useSubmitGuess() {
 
       // Derive session PDA
       const [sessionPDA] = getSessionPDA(signerPublicKey)
+      const [userProfilePDA] = getUserProfilePDA(signerPublicKey)
 
       // create transaction
       const submitGuess = await vobleProgram.methods
         .submitGuess(trimmedPeriodId, normalizedGuess)
         .accounts({
           session: sessionPDA,
+          userProfile: userProfilePDA,
+          signer: tempKeypair.publicKey,
         })
         .transaction()
 
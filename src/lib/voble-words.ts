@@ -0,0 +1,46 @@
+import type { LetterResult } from '@/hooks/use-fetch-session'
+
+// Mirrors VOBLE_WORDS, VOBLE_WORDS_WEEKLY and VOBLE_WORDS_MONTHLY in the
+// program's constants.rs; utils::words tests fail if the order drifts. A
+// session's wordIndex is global: daily 0..20, weekly 20..30, monthly 30..40.
+export const VOBLE_WORDS = [
+  // Daily
+  'ANCHOR', 'BRIDGE', 'CASTLE', 'DRAGON', 'ENERGY', 'FOREST', 'GARDEN', 'HAMMER', 'ISLAND',
+  'JUNGLE', 'KERNEL', 'LADDER', 'MARKET', 'NATURE', 'ORANGE', 'PUZZLE', 'QUARTZ', 'ROCKET',
+  'SOLANA', 'TEMPLE',
+  // Weekly
+  'ZEPHYR', 'QUORUM', 'JIGSAW', 'SPHINX', 'RHYTHM', 'VORTEX', 'OXYGEN', 'JOCKEY', 'FJORDS',
+  'PYTHON',
+  // Monthly
+  'SYZYGY', 'CRYPTS', 'GLYPHS', 'NYMPHS', 'JUJUBE', 'SQUAWK', 'JINXED', 'FIZZLE', 'WHELKS',
+  'BYWAYS',
+] as const
+
+// Same two-pass Wordle evaluation as utils::words::evaluate_guess on-chain.
+// Sessions only store guessed letters, so results are derived here from wordIndex.
+export function evaluateGuess(guess: string, wordIndex: number): LetterResult[] {
+  const target = VOBLE_WORDS[wordIndex]
+  if (!target) return Array(guess.length).fill('Absent')
+
+  const guessChars = guess.toUpperCase().split('')
+  const targetChars: (string | null)[] = target.split('')
+  const result: LetterResult[] = guessChars.map(() => 'Absent')
+
+  guessChars.forEach((char, i) => {
+    if (char === targetChars[i]) {
+      result[i] = 'Correct'
+      targetChars[i] = null
+    }
+  })
+
+  guessChars.forEach((char, i) => {
+    if (result[i] !== 'Absent') return
+    const pos = targetChars.indexOf(char)
+    if (pos !== -1) {
+      result[i] = 'Present'
+      targetChars[pos] = null
+    }
+  })
+
+  return result
+}